@@ -51,6 +51,7 @@ pub enum ProxyType {
     ChildKeys,
     SudoUncheckedSetCode,
     SwapHotkey,
+    SubnetLeaseBeneficiary, // Subnet owner Calls of a leased subnet
 }
 
 impl Default for ProxyType {
@@ -22,6 +22,31 @@ export const IEd25519VerifyABI = [
 
 export const IBALANCETRANSFER_ADDRESS = "0x0000000000000000000000000000000000000800";
 export const IBalanceTransferABI = [
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "address",
+                name: "from",
+                type: "address"
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "to",
+                type: "bytes32"
+            },
+            {
+                indexed: false,
+                internalType: "uint256",
+                name: "value",
+                type: "uint256"
+            }
+        ],
+        name: "Transfer",
+        type: "event"
+    },
     {
         inputs: [
             {
@@ -0,0 +1,370 @@
+export const ICHILDREN_ADDRESS = "0x000000000000000000000000000000000000080a";
+
+export const IChildrenABI = [
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "parent",
+                type: "bytes32"
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "child",
+                type: "bytes32"
+            },
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "ChildkeyAccepted",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "parent",
+                type: "bytes32"
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "child",
+                type: "bytes32"
+            },
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "ChildkeyResigned",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: false,
+                internalType: "uint16",
+                name: "take",
+                type: "uint16"
+            }
+        ],
+        name: "ChildkeyTakeSet",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "ChildrenSet",
+        type: "event"
+    },
+    {
+        inputs: [
+            {
+                internalType: "bytes32",
+                name: "child",
+                type: "bytes32"
+            },
+            {
+                internalType: "bytes32",
+                name: "parent",
+                type: "bytes32"
+            },
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "acceptChildkey",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "getChildkeyTake",
+        outputs: [
+            {
+                internalType: "uint16",
+                name: "",
+                type: "uint16"
+            }
+        ],
+        stateMutability: "view",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "getChildren",
+        outputs: [
+            {
+                components: [
+                    {
+                        internalType: "bytes32",
+                        name: "hotkey",
+                        type: "bytes32"
+                    },
+                    {
+                        internalType: "uint64",
+                        name: "proportion",
+                        type: "uint64"
+                    }
+                ],
+                internalType: "struct ChildKey[]",
+                name: "",
+                type: "tuple[]"
+            }
+        ],
+        stateMutability: "view",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "getParents",
+        outputs: [
+            {
+                components: [
+                    {
+                        internalType: "bytes32",
+                        name: "hotkey",
+                        type: "bytes32"
+                    },
+                    {
+                        internalType: "uint64",
+                        name: "proportion",
+                        type: "uint64"
+                    }
+                ],
+                internalType: "struct ChildKey[]",
+                name: "",
+                type: "tuple[]"
+            }
+        ],
+        stateMutability: "view",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "getPendingChildren",
+        outputs: [
+            {
+                components: [
+                    {
+                        internalType: "bytes32",
+                        name: "hotkey",
+                        type: "bytes32"
+                    },
+                    {
+                        internalType: "uint64",
+                        name: "proportion",
+                        type: "uint64"
+                    }
+                ],
+                internalType: "struct ChildKey[]",
+                name: "",
+                type: "tuple[]"
+            },
+            {
+                internalType: "uint64",
+                name: "",
+                type: "uint64"
+            }
+        ],
+        stateMutability: "view",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "bytes32",
+                name: "child",
+                type: "bytes32"
+            },
+            {
+                internalType: "bytes32",
+                name: "parent",
+                type: "bytes32"
+            },
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "resignChildkey",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                internalType: "uint16",
+                name: "take",
+                type: "uint16"
+            }
+        ],
+        name: "setChildkeyTake",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                internalType: "bytes32[]",
+                name: "children",
+                type: "bytes32[]"
+            },
+            {
+                internalType: "uint64[]",
+                name: "proportions",
+                type: "uint64[]"
+            }
+        ],
+        name: "setChildren",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                internalType: "bytes32[]",
+                name: "children",
+                type: "bytes32[]"
+            },
+            {
+                internalType: "uint64[]",
+                name: "proportions",
+                type: "uint64[]"
+            },
+            {
+                internalType: "uint64",
+                name: "term",
+                type: "uint64"
+            },
+            {
+                internalType: "bool",
+                name: "autoRenew",
+                type: "bool"
+            }
+        ],
+        name: "setChildrenWithTerms",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    }
+];
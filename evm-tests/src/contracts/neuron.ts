@@ -1,6 +1,132 @@
 export const INEURON_ADDRESS = "0x0000000000000000000000000000000000000804";
 
 export const INeuronABI = [
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: true,
+                internalType: "address",
+                name: "hotkey",
+                type: "address"
+            }
+        ],
+        name: "WeightsSet",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: true,
+                internalType: "address",
+                name: "hotkey",
+                type: "address"
+            },
+            {
+                indexed: false,
+                internalType: "bytes32",
+                name: "commitHash",
+                type: "bytes32"
+            }
+        ],
+        name: "WeightsCommitted",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: true,
+                internalType: "address",
+                name: "hotkey",
+                type: "address"
+            }
+        ],
+        name: "WeightsRevealed",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                indexed: false,
+                internalType: "uint16",
+                name: "uid",
+                type: "uint16"
+            }
+        ],
+        name: "NeuronRegistered",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: true,
+                internalType: "address",
+                name: "hotkey",
+                type: "address"
+            }
+        ],
+        name: "AxonServed",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: true,
+                internalType: "address",
+                name: "hotkey",
+                type: "address"
+            }
+        ],
+        name: "PrometheusServed",
+        type: "event"
+    },
     {
         inputs: [
             {
@@ -232,4 +358,151 @@ export const INeuronABI = [
         stateMutability: "payable",
         type: "function",
     },
-];
\ No newline at end of file
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32",
+            },
+            {
+                internalType: "bytes32",
+                name: "commitHash",
+                type: "bytes32",
+            },
+        ],
+        name: "commitWeightsForHotkey",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32",
+            },
+            {
+                internalType: "uint16[]",
+                name: "uids",
+                type: "uint16[]",
+            },
+            {
+                internalType: "uint16[]",
+                name: "values",
+                type: "uint16[]",
+            },
+            {
+                internalType: "uint16[]",
+                name: "salt",
+                type: "uint16[]",
+            },
+            {
+                internalType: "uint64",
+                name: "versionKey",
+                type: "uint64",
+            },
+        ],
+        name: "revealWeightsForHotkey",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32",
+            },
+            {
+                internalType: "uint32",
+                name: "version",
+                type: "uint32",
+            },
+            {
+                internalType: "uint128",
+                name: "ip",
+                type: "uint128",
+            },
+            {
+                internalType: "uint16",
+                name: "port",
+                type: "uint16",
+            },
+            {
+                internalType: "uint8",
+                name: "ipType",
+                type: "uint8",
+            },
+            {
+                internalType: "uint8",
+                name: "protocol",
+                type: "uint8",
+            },
+            {
+                internalType: "uint8",
+                name: "placeholder1",
+                type: "uint8",
+            },
+            {
+                internalType: "uint8",
+                name: "placeholder2",
+                type: "uint8",
+            },
+        ],
+        name: "serveAxonForHotkey",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32",
+            },
+            {
+                internalType: "uint16[]",
+                name: "dests",
+                type: "uint16[]",
+            },
+            {
+                internalType: "uint16[]",
+                name: "weights",
+                type: "uint16[]",
+            },
+            {
+                internalType: "uint64",
+                name: "versionKey",
+                type: "uint64",
+            },
+        ],
+        name: "setWeightsForHotkey",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function",
+    },
+];
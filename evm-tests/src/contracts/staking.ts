@@ -2,6 +2,118 @@ export const ISTAKING_ADDRESS = "0x0000000000000000000000000000000000000801";
 export const ISTAKING_V2_ADDRESS = "0x0000000000000000000000000000000000000805";
 
 export const IStakingABI = [
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "address",
+                name: "staker",
+                type: "address"
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: false,
+                internalType: "uint256",
+                name: "tao",
+                type: "uint256"
+            },
+            {
+                indexed: false,
+                internalType: "uint256",
+                name: "alpha",
+                type: "uint256"
+            }
+        ],
+        name: "StakeAdded",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "address",
+                name: "staker",
+                type: "address"
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: false,
+                internalType: "uint256",
+                name: "alpha",
+                type: "uint256"
+            },
+            {
+                indexed: false,
+                internalType: "uint256",
+                name: "tao",
+                type: "uint256"
+            }
+        ],
+        name: "StakeRemoved",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "address",
+                name: "account",
+                type: "address"
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "delegate",
+                type: "bytes32"
+            }
+        ],
+        name: "ProxyAdded",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "address",
+                name: "account",
+                type: "address"
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "delegate",
+                type: "bytes32"
+            }
+        ],
+        name: "ProxyRemoved",
+        type: "event"
+    },
     {
         inputs: [
             {
@@ -101,6 +213,347 @@ export const IStakingABI = [
 ];
 
 export const IStakingV2ABI = [
+    {
+        "anonymous": false,
+        "inputs": [
+            {
+                "indexed": true,
+                "internalType": "address",
+                "name": "staker",
+                "type": "address"
+            },
+            {
+                "indexed": true,
+                "internalType": "bytes32",
+                "name": "hotkey",
+                "type": "bytes32"
+            },
+            {
+                "indexed": true,
+                "internalType": "uint16",
+                "name": "netuid",
+                "type": "uint16"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint256",
+                "name": "tao",
+                "type": "uint256"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint256",
+                "name": "alpha",
+                "type": "uint256"
+            }
+        ],
+        "name": "StakeAdded",
+        "type": "event"
+    },
+    {
+        "anonymous": false,
+        "inputs": [
+            {
+                "indexed": true,
+                "internalType": "address",
+                "name": "staker",
+                "type": "address"
+            },
+            {
+                "indexed": true,
+                "internalType": "bytes32",
+                "name": "hotkey",
+                "type": "bytes32"
+            },
+            {
+                "indexed": true,
+                "internalType": "uint16",
+                "name": "netuid",
+                "type": "uint16"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint256",
+                "name": "alpha",
+                "type": "uint256"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint256",
+                "name": "tao",
+                "type": "uint256"
+            }
+        ],
+        "name": "StakeRemoved",
+        "type": "event"
+    },
+    {
+        "anonymous": false,
+        "inputs": [
+            {
+                "indexed": true,
+                "internalType": "address",
+                "name": "account",
+                "type": "address"
+            },
+            {
+                "indexed": true,
+                "internalType": "bytes32",
+                "name": "delegate",
+                "type": "bytes32"
+            }
+        ],
+        "name": "ProxyAdded",
+        "type": "event"
+    },
+    {
+        "anonymous": false,
+        "inputs": [
+            {
+                "indexed": true,
+                "internalType": "address",
+                "name": "account",
+                "type": "address"
+            },
+            {
+                "indexed": true,
+                "internalType": "bytes32",
+                "name": "delegate",
+                "type": "bytes32"
+            }
+        ],
+        "name": "ProxyRemoved",
+        "type": "event"
+    },
+    {
+        "anonymous": false,
+        "inputs": [
+            {
+                "indexed": true,
+                "internalType": "address",
+                "name": "staker",
+                "type": "address"
+            },
+            {
+                "indexed": true,
+                "internalType": "bytes32",
+                "name": "originHotkey",
+                "type": "bytes32"
+            },
+            {
+                "indexed": true,
+                "internalType": "bytes32",
+                "name": "destinationHotkey",
+                "type": "bytes32"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint16",
+                "name": "originNetuid",
+                "type": "uint16"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint16",
+                "name": "destinationNetuid",
+                "type": "uint16"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint256",
+                "name": "alpha",
+                "type": "uint256"
+            }
+        ],
+        "name": "StakeMoved",
+        "type": "event"
+    },
+    {
+        "anonymous": false,
+        "inputs": [
+            {
+                "indexed": true,
+                "internalType": "address",
+                "name": "staker",
+                "type": "address"
+            },
+            {
+                "indexed": true,
+                "internalType": "bytes32",
+                "name": "destinationColdkey",
+                "type": "bytes32"
+            },
+            {
+                "indexed": true,
+                "internalType": "bytes32",
+                "name": "hotkey",
+                "type": "bytes32"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint16",
+                "name": "originNetuid",
+                "type": "uint16"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint16",
+                "name": "destinationNetuid",
+                "type": "uint16"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint256",
+                "name": "alpha",
+                "type": "uint256"
+            }
+        ],
+        "name": "StakeTransferred",
+        "type": "event"
+    },
+    {
+        "anonymous": false,
+        "inputs": [
+            {
+                "indexed": true,
+                "internalType": "address",
+                "name": "staker",
+                "type": "address"
+            },
+            {
+                "indexed": true,
+                "internalType": "bytes32",
+                "name": "hotkey",
+                "type": "bytes32"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint16",
+                "name": "originNetuid",
+                "type": "uint16"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint16",
+                "name": "destinationNetuid",
+                "type": "uint16"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint256",
+                "name": "alpha",
+                "type": "uint256"
+            }
+        ],
+        "name": "StakeSwapped",
+        "type": "event"
+    },
+    {
+        "anonymous": false,
+        "inputs": [
+            {
+                "indexed": true,
+                "internalType": "address",
+                "name": "staker",
+                "type": "address"
+            },
+            {
+                "indexed": true,
+                "internalType": "bytes32",
+                "name": "hotkey",
+                "type": "bytes32"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint256",
+                "name": "tao",
+                "type": "uint256"
+            }
+        ],
+        "name": "AllStakeRemoved",
+        "type": "event"
+    },
+    {
+        "anonymous": false,
+        "inputs": [
+            {
+                "indexed": true,
+                "internalType": "address",
+                "name": "staker",
+                "type": "address"
+            },
+            {
+                "indexed": true,
+                "internalType": "bytes32",
+                "name": "hotkey",
+                "type": "bytes32"
+            }
+        ],
+        "name": "AllAlphaUnstaked",
+        "type": "event"
+    },
+    {
+        "anonymous": false,
+        "inputs": [
+            {
+                "indexed": true,
+                "internalType": "address",
+                "name": "staker",
+                "type": "address"
+            },
+            {
+                "indexed": true,
+                "internalType": "bytes32",
+                "name": "hotkey",
+                "type": "bytes32"
+            },
+            {
+                "indexed": true,
+                "internalType": "uint16",
+                "name": "netuid",
+                "type": "uint16"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint256",
+                "name": "alpha",
+                "type": "uint256"
+            }
+        ],
+        "name": "AlphaRecycled",
+        "type": "event"
+    },
+    {
+        "anonymous": false,
+        "inputs": [
+            {
+                "indexed": true,
+                "internalType": "address",
+                "name": "staker",
+                "type": "address"
+            },
+            {
+                "indexed": true,
+                "internalType": "bytes32",
+                "name": "hotkey",
+                "type": "bytes32"
+            },
+            {
+                "indexed": true,
+                "internalType": "uint16",
+                "name": "netuid",
+                "type": "uint16"
+            },
+            {
+                "indexed": false,
+                "internalType": "uint256",
+                "name": "alpha",
+                "type": "uint256"
+            }
+        ],
+        "name": "AlphaBurned",
+        "type": "event"
+    },
     {
         "inputs": [
             {
@@ -354,4 +807,391 @@ export const IStakingV2ABI = [
     "stateMutability": "nonpayable",
     "type": "function"
   },
-];
\ No newline at end of file
+  {
+      "inputs": [
+          {
+              "internalType": "bytes32",
+              "name": "hotkey",
+              "type": "bytes32"
+          },
+          {
+              "internalType": "uint256",
+              "name": "origin_netuid",
+              "type": "uint256"
+          },
+          {
+              "internalType": "uint256",
+              "name": "destination_netuid",
+              "type": "uint256"
+          },
+          {
+              "internalType": "uint256",
+              "name": "amount",
+              "type": "uint256"
+          }
+      ],
+      "name": "swapStake",
+      "outputs": [],
+      "stateMutability": "nonpayable",
+      "type": "function"
+  },
+  {
+      "inputs": [
+          {
+              "internalType": "bytes32",
+              "name": "hotkey",
+              "type": "bytes32"
+          },
+          {
+              "internalType": "uint256",
+              "name": "origin_netuid",
+              "type": "uint256"
+          },
+          {
+              "internalType": "uint256",
+              "name": "destination_netuid",
+              "type": "uint256"
+          },
+          {
+              "internalType": "uint256",
+              "name": "amount",
+              "type": "uint256"
+          },
+          {
+              "internalType": "uint256",
+              "name": "limit_price",
+              "type": "uint256"
+          },
+          {
+              "internalType": "bool",
+              "name": "allow_partial",
+              "type": "bool"
+          }
+      ],
+      "name": "swapStakeLimit",
+      "outputs": [],
+      "stateMutability": "nonpayable",
+      "type": "function"
+  },
+  {
+      "inputs": [
+          {
+              "internalType": "bytes32",
+              "name": "hotkey",
+              "type": "bytes32"
+          }
+      ],
+      "name": "unstakeAll",
+      "outputs": [],
+      "stateMutability": "nonpayable",
+      "type": "function"
+  },
+  {
+      "inputs": [
+          {
+              "internalType": "bytes32",
+              "name": "hotkey",
+              "type": "bytes32"
+          }
+      ],
+      "name": "unstakeAllAlpha",
+      "outputs": [],
+      "stateMutability": "nonpayable",
+      "type": "function"
+  },
+  {
+      "inputs": [
+          {
+              "internalType": "bytes32",
+              "name": "hotkey",
+              "type": "bytes32"
+          },
+          {
+              "internalType": "uint256",
+              "name": "amount",
+              "type": "uint256"
+          },
+          {
+              "internalType": "uint256",
+              "name": "netuid",
+              "type": "uint256"
+          }
+      ],
+      "name": "recycleAlpha",
+      "outputs": [],
+      "stateMutability": "nonpayable",
+      "type": "function"
+  },
+  {
+      "inputs": [
+          {
+              "internalType": "bytes32",
+              "name": "hotkey",
+              "type": "bytes32"
+          },
+          {
+              "internalType": "uint256",
+              "name": "amount",
+              "type": "uint256"
+          },
+          {
+              "internalType": "uint256",
+              "name": "netuid",
+              "type": "uint256"
+          }
+      ],
+      "name": "burnAlpha",
+      "outputs": [],
+      "stateMutability": "nonpayable",
+      "type": "function"
+  },
+  {
+      "inputs": [
+          {
+              "internalType": "uint256",
+              "name": "netuid",
+              "type": "uint256"
+          }
+      ],
+      "name": "getAlphaPrice",
+      "outputs": [
+          {
+              "internalType": "uint256",
+              "name": "",
+              "type": "uint256"
+          }
+      ],
+      "stateMutability": "view",
+      "type": "function"
+  },
+  {
+      "inputs": [
+          {
+              "internalType": "uint256",
+              "name": "netuid",
+              "type": "uint256"
+          }
+      ],
+      "name": "getMovingAlphaPrice",
+      "outputs": [
+          {
+              "internalType": "uint256",
+              "name": "",
+              "type": "uint256"
+          }
+      ],
+      "stateMutability": "view",
+      "type": "function"
+  },
+  {
+      "inputs": [
+          {
+              "internalType": "uint256",
+              "name": "netuid",
+              "type": "uint256"
+          },
+          {
+              "internalType": "uint256",
+              "name": "amount",
+              "type": "uint256"
+          }
+      ],
+      "name": "simSwapTaoForAlpha",
+      "outputs": [
+          {
+              "internalType": "uint256",
+              "name": "",
+              "type": "uint256"
+          }
+      ],
+      "stateMutability": "view",
+      "type": "function"
+  },
+  {
+      "inputs": [
+          {
+              "internalType": "uint256",
+              "name": "netuid",
+              "type": "uint256"
+          },
+          {
+              "internalType": "uint256",
+              "name": "amount",
+              "type": "uint256"
+          }
+      ],
+      "name": "simSwapAlphaForTao",
+      "outputs": [
+          {
+              "internalType": "uint256",
+              "name": "",
+              "type": "uint256"
+          }
+      ],
+      "stateMutability": "view",
+      "type": "function"
+  },
+  {
+      "inputs": [
+          {
+              "internalType": "bytes32",
+              "name": "hotkey",
+              "type": "bytes32"
+          },
+          {
+              "internalType": "uint256",
+              "name": "netuid",
+              "type": "uint256"
+          },
+          {
+              "internalType": "uint256",
+              "name": "amount",
+              "type": "uint256"
+          }
+      ],
+      "name": "getAddStakeFee",
+      "outputs": [
+          {
+              "internalType": "uint256",
+              "name": "",
+              "type": "uint256"
+          }
+      ],
+      "stateMutability": "view",
+      "type": "function"
+  },
+  {
+      "inputs": [
+          {
+              "internalType": "bytes32",
+              "name": "hotkey",
+              "type": "bytes32"
+          },
+          {
+              "internalType": "uint256",
+              "name": "netuid",
+              "type": "uint256"
+          },
+          {
+              "internalType": "uint256",
+              "name": "amount",
+              "type": "uint256"
+          }
+      ],
+      "name": "getRemoveStakeFee",
+      "outputs": [
+          {
+              "internalType": "uint256",
+              "name": "",
+              "type": "uint256"
+          }
+      ],
+      "stateMutability": "view",
+      "type": "function"
+  },
+  {
+      "inputs": [
+          {
+              "internalType": "bytes32",
+              "name": "origin_hotkey",
+              "type": "bytes32"
+          },
+          {
+              "internalType": "bytes32",
+              "name": "destination_hotkey",
+              "type": "bytes32"
+          },
+          {
+              "internalType": "uint256",
+              "name": "origin_netuid",
+              "type": "uint256"
+          },
+          {
+              "internalType": "uint256",
+              "name": "destination_netuid",
+              "type": "uint256"
+          },
+          {
+              "internalType": "uint256",
+              "name": "amount",
+              "type": "uint256"
+          }
+      ],
+      "name": "getMoveStakeFee",
+      "outputs": [
+          {
+              "internalType": "uint256",
+              "name": "",
+              "type": "uint256"
+          }
+      ],
+      "stateMutability": "view",
+      "type": "function"
+  },
+  {
+      "inputs": [
+          {
+              "internalType": "bytes32",
+              "name": "coldkey",
+              "type": "bytes32"
+          }
+      ],
+      "name": "getStakeInfoForColdkey",
+      "outputs": [
+          {
+              "components": [
+                  {
+                      "internalType": "bytes32",
+                      "name": "hotkey",
+                      "type": "bytes32"
+                  },
+                  {
+                      "internalType": "bytes32",
+                      "name": "coldkey",
+                      "type": "bytes32"
+                  },
+                  {
+                      "internalType": "uint16",
+                      "name": "netuid",
+                      "type": "uint16"
+                  },
+                  {
+                      "internalType": "uint64",
+                      "name": "stake",
+                      "type": "uint64"
+                  },
+                  {
+                      "internalType": "uint64",
+                      "name": "locked",
+                      "type": "uint64"
+                  },
+                  {
+                      "internalType": "uint64",
+                      "name": "emission",
+                      "type": "uint64"
+                  },
+                  {
+                      "internalType": "uint64",
+                      "name": "tao_emission",
+                      "type": "uint64"
+                  },
+                  {
+                      "internalType": "uint64",
+                      "name": "drain",
+                      "type": "uint64"
+                  },
+                  {
+                      "internalType": "bool",
+                      "name": "is_registered",
+                      "type": "bool"
+                  }
+              ],
+              "internalType": "struct StakeInfo[]",
+              "name": "",
+              "type": "tuple[]"
+          }
+      ],
+      "stateMutability": "view",
+      "type": "function"
+  },
+];
@@ -1,6 +1,81 @@
 export const ISUBNET_ADDRESS = "0x0000000000000000000000000000000000000803";
 
 export const ISubnetABI = [
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                indexed: true,
+                internalType: "address",
+                name: "owner",
+                type: "address"
+            }
+        ],
+        name: "NetworkRegistered",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "id",
+                type: "uint16"
+            },
+            {
+                indexed: false,
+                internalType: "uint64",
+                name: "value",
+                type: "uint64"
+            }
+        ],
+        name: "HyperparameterSet",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: false,
+                internalType: "uint16",
+                name: "alphaLow",
+                type: "uint16"
+            },
+            {
+                indexed: false,
+                internalType: "uint16",
+                name: "alphaHigh",
+                type: "uint16"
+            }
+        ],
+        name: "AlphaValuesSet",
+        type: "event"
+    },
     {
         inputs: [
             {
@@ -0,0 +1,561 @@
+export const ISUBNET_OWNER_ADDRESS = "0x0000000000000000000000000000000000000809";
+
+export const ISubnetOwnerABI = [
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: false,
+                internalType: "uint64",
+                name: "firstEmissionBlock",
+                type: "uint64"
+            }
+        ],
+        name: "CallStarted",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: false,
+                internalType: "uint16",
+                name: "take",
+                type: "uint16"
+            }
+        ],
+        name: "ChildkeyTakeSet",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "ChildrenSet",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: false,
+                internalType: "uint32",
+                name: "delay",
+                type: "uint32"
+            }
+        ],
+        name: "HyperparameterAnnouncementDelaySet",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "id",
+                type: "uint16"
+            },
+            {
+                indexed: false,
+                internalType: "uint32",
+                name: "changeId",
+                type: "uint32"
+            },
+            {
+                indexed: false,
+                internalType: "uint64",
+                name: "value",
+                type: "uint64"
+            }
+        ],
+        name: "HyperparameterChangeAnnounced",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "SubnetIdentitySet",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: false,
+                internalType: "uint64",
+                name: "price",
+                type: "uint64"
+            },
+            {
+                indexed: false,
+                internalType: "uint64",
+                name: "deadline",
+                type: "uint64"
+            }
+        ],
+        name: "SubnetListedForSale",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "SubnetListingCancelled",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            }
+        ],
+        name: "SubnetOwnerHotkeySet",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: true,
+                internalType: "address",
+                name: "newOwner",
+                type: "address"
+            }
+        ],
+        name: "SubnetOwnershipAccepted",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "SubnetOwnershipOfferCancelled",
+        type: "event"
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "newOwner",
+                type: "bytes32"
+            }
+        ],
+        name: "SubnetOwnershipOffered",
+        type: "event"
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            }
+        ],
+        name: "acceptSubnetOwnership",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                internalType: "uint16",
+                name: "id",
+                type: "uint16"
+            },
+            {
+                internalType: "uint64",
+                name: "value",
+                type: "uint64"
+            }
+        ],
+        name: "announceHyperparameterChange",
+        outputs: [
+            {
+                internalType: "uint32",
+                name: "",
+                type: "uint32"
+            }
+        ],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "cancelSubnetListing",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "cancelSubnetOwnershipOffer",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "getSubnetOwner",
+        outputs: [
+            {
+                internalType: "bytes32",
+                name: "",
+                type: "bytes32"
+            }
+        ],
+        stateMutability: "view",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "getSubnetOwnerHotkey",
+        outputs: [
+            {
+                internalType: "bytes32",
+                name: "",
+                type: "bytes32"
+            }
+        ],
+        stateMutability: "view",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                internalType: "uint64",
+                name: "price",
+                type: "uint64"
+            },
+            {
+                internalType: "uint64",
+                name: "deadline",
+                type: "uint64"
+            }
+        ],
+        name: "listSubnetForSale",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                internalType: "bytes32",
+                name: "newOwner",
+                type: "bytes32"
+            }
+        ],
+        name: "offerSubnetOwnership",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                internalType: "bytes32[]",
+                name: "coOwners",
+                type: "bytes32[]"
+            },
+            {
+                internalType: "uint16",
+                name: "threshold",
+                type: "uint16"
+            }
+        ],
+        name: "offerSubnetOwnershipToCoOwners",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                internalType: "uint16",
+                name: "take",
+                type: "uint16"
+            }
+        ],
+        name: "setChildkeyTake",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            },
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                internalType: "bytes32[]",
+                name: "children",
+                type: "bytes32[]"
+            },
+            {
+                internalType: "uint64[]",
+                name: "proportions",
+                type: "uint64[]"
+            }
+        ],
+        name: "setChildren",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                internalType: "uint32",
+                name: "delay",
+                type: "uint32"
+            }
+        ],
+        name: "setHyperparameterAnnouncementDelay",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                internalType: "string",
+                name: "subnetName",
+                type: "string"
+            },
+            {
+                internalType: "string",
+                name: "githubRepo",
+                type: "string"
+            },
+            {
+                internalType: "string",
+                name: "subnetContact",
+                type: "string"
+            },
+            {
+                internalType: "string",
+                name: "subnetUrl",
+                type: "string"
+            },
+            {
+                internalType: "string",
+                name: "discord",
+                type: "string"
+            },
+            {
+                internalType: "string",
+                name: "description",
+                type: "string"
+            },
+            {
+                internalType: "string",
+                name: "additional",
+                type: "string"
+            }
+        ],
+        name: "setSubnetIdentity",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            },
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32"
+            }
+        ],
+        name: "setSubnetOwnerHotkey",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16"
+            }
+        ],
+        name: "startCall",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function"
+    }
+];
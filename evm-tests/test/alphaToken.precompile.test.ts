@@ -0,0 +1,127 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate";
+import { devnet } from "@polkadot-api/descriptors";
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58 } from "../src/address-utils";
+import { tao } from "../src/balance-math";
+import {
+  addNewSubnetwork,
+  forceSetBalanceToEthAddress,
+  forceSetBalanceToSs58Address,
+  startCall,
+} from "../src/subtensor";
+import { ethers } from "ethers";
+import { generateRandomEthersWallet } from "../src/utils";
+import { ISTAKING_V2_ADDRESS, IStakingV2ABI } from "../src/contracts/staking";
+import {
+  IALPHA_TOKEN_REGISTRY_ADDRESS,
+  IAlphaTokenABI,
+  IAlphaTokenRegistryABI,
+} from "../src/contracts/alphaToken";
+
+describe("Test alpha token precompiles", () => {
+  const hotkey = getRandomSubstrateKeypair();
+  const coldkey = getRandomSubstrateKeypair();
+  const wallet1 = generateRandomEthersWallet();
+  const wallet2 = generateRandomEthersWallet();
+  const wallet3 = generateRandomEthersWallet();
+
+  let api: TypedApi<typeof devnet>;
+  let netuid: number;
+  let tokenAddress: string;
+
+  before(async () => {
+    api = await getDevnetApi();
+    await forceSetBalanceToSs58Address(
+      api,
+      convertPublicKeyToSs58(hotkey.publicKey),
+    );
+    await forceSetBalanceToSs58Address(
+      api,
+      convertPublicKeyToSs58(coldkey.publicKey),
+    );
+    await forceSetBalanceToEthAddress(api, wallet1.address);
+    await forceSetBalanceToEthAddress(api, wallet2.address);
+
+    netuid = await addNewSubnetwork(api, hotkey, coldkey);
+    await startCall(api, netuid, coldkey);
+
+    const staking = new ethers.Contract(
+      ISTAKING_V2_ADDRESS,
+      IStakingV2ABI,
+      wallet1,
+    );
+    const tx = await staking.addStake(hotkey.publicKey, tao(100), netuid);
+    await tx.wait();
+  });
+
+  it("Anyone can register an alpha token for a position", async () => {
+    const registry = new ethers.Contract(
+      IALPHA_TOKEN_REGISTRY_ADDRESS,
+      IAlphaTokenRegistryABI,
+      wallet1,
+    );
+
+    const tx = await registry.registerAlphaToken(hotkey.publicKey, netuid);
+    await tx.wait();
+
+    tokenAddress = await registry.getAlphaToken(hotkey.publicKey, netuid);
+    assert.notEqual(tokenAddress, ethers.ZeroAddress);
+
+    const token = new ethers.Contract(tokenAddress, IAlphaTokenABI, wallet1);
+    assert.equal(Number(await token.decimals()), 9);
+    assert.equal(Number(await token.netuid()), netuid);
+    assert.ok(BigInt(await token.balanceOf(wallet1.address)) > BigInt(0));
+  });
+
+  it("Alpha token transfer moves stake and emits Transfer", async () => {
+    const token = new ethers.Contract(tokenAddress, IAlphaTokenABI, wallet1);
+    const balance = BigInt(await token.balanceOf(wallet1.address));
+    const amount = balance / BigInt(4);
+
+    const tx = await token.transfer(wallet2.address, amount);
+    const receipt = await tx.wait();
+
+    const event = token.interface.parseLog(receipt.logs[0]);
+    assert.equal(event?.name, "Transfer");
+    assert.equal(event?.args.to, wallet2.address);
+    assert.equal(BigInt(event?.args.value), amount);
+
+    assert.equal(BigInt(await token.balanceOf(wallet2.address)), amount);
+    assert.equal(
+      BigInt(await token.balanceOf(wallet1.address)),
+      balance - amount,
+    );
+  });
+
+  it("Spender can transferFrom within its allowance", async () => {
+    const token = new ethers.Contract(tokenAddress, IAlphaTokenABI, wallet1);
+    const balance = BigInt(await token.balanceOf(wallet1.address));
+    const amount = balance / BigInt(4);
+
+    const approveTx = await token.approve(wallet2.address, amount);
+    await approveTx.wait();
+    assert.equal(
+      BigInt(await token.allowance(wallet1.address, wallet2.address)),
+      amount,
+    );
+
+    const spenderToken = new ethers.Contract(
+      tokenAddress,
+      IAlphaTokenABI,
+      wallet2,
+    );
+    const tx = await spenderToken.transferFrom(
+      wallet1.address,
+      wallet3.address,
+      amount,
+    );
+    await tx.wait();
+
+    assert.equal(
+      BigInt(await token.allowance(wallet1.address, wallet2.address)),
+      BigInt(0),
+    );
+    assert.equal(BigInt(await token.balanceOf(wallet3.address)), amount);
+  });
+});
@@ -0,0 +1,90 @@
+import * as assert from "assert";
+
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58 } from "../src/address-utils"
+import { ethers } from "ethers"
+import { INEURON_ADDRESS, INeuronABI } from "../src/contracts/neuron"
+import { ICHILDREN_ADDRESS, IChildrenABI } from "../src/contracts/children"
+import { generateRandomEthersWallet } from "../src/utils"
+import { forceSetBalanceToSs58Address, forceSetBalanceToEthAddress, addNewSubnetwork, startCall } from "../src/subtensor"
+
+describe("Test the children precompile", () => {
+    const wallet = generateRandomEthersWallet();
+    const otherWallet = generateRandomEthersWallet();
+
+    const hotkey = getRandomSubstrateKeypair();
+    const child = getRandomSubstrateKeypair();
+    const subnetHotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+    let netuid: number
+
+    before(async () => {
+        api = await getDevnetApi()
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(subnetHotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        await forceSetBalanceToEthAddress(api, otherWallet.address)
+
+        netuid = await addNewSubnetwork(api, subnetHotkey, coldkey)
+        await startCall(api, netuid, coldkey)
+
+        // The hotkey is owned by the account mapped from `wallet`.
+        const neuron = new ethers.Contract(INEURON_ADDRESS, INeuronABI, wallet);
+        const tx = await neuron.burnedRegister(netuid, hotkey.publicKey);
+        await tx.wait();
+    })
+
+    it("Hotkey owner can set the childkey take", async () => {
+        const contract = new ethers.Contract(ICHILDREN_ADDRESS, IChildrenABI, wallet);
+        const take = 1000;
+
+        const tx = await contract.setChildkeyTake(hotkey.publicKey, netuid, take);
+        const receipt = await tx.wait();
+
+        const event = contract.interface.parseLog(receipt.logs[0]);
+        assert.equal(event?.name, "ChildkeyTakeSet");
+        assert.equal(Number(event?.args.take), take);
+
+        const onchainTake = await api.query.SubtensorModule.ChildkeyTake.getValue(
+            convertPublicKeyToSs58(hotkey.publicKey),
+            netuid
+        )
+        assert.equal(Number(await contract.getChildkeyTake(hotkey.publicKey, netuid)), take);
+        assert.equal(onchainTake, take);
+    });
+
+    it("Only the hotkey owner can schedule children", async () => {
+        const contract = new ethers.Contract(ICHILDREN_ADDRESS, IChildrenABI, otherWallet);
+        await assert.rejects(async () => {
+            const tx = await contract.setChildren(
+                hotkey.publicKey,
+                netuid,
+                [child.publicKey],
+                [BigInt(2) ** BigInt(63)]
+            );
+            await tx.wait();
+        });
+
+        const [pending] = await contract.getPendingChildren(hotkey.publicKey, netuid);
+        assert.equal(pending.length, 0);
+    });
+
+    it("A child cannot accept a schedule it is not part of", async () => {
+        const contract = new ethers.Contract(ICHILDREN_ADDRESS, IChildrenABI, wallet);
+        await assert.rejects(async () => {
+            const tx = await contract.acceptChildkey(hotkey.publicKey, child.publicKey, netuid);
+            await tx.wait();
+        });
+    });
+
+    it("Relationships are empty for a fresh hotkey", async () => {
+        const contract = new ethers.Contract(ICHILDREN_ADDRESS, IChildrenABI, wallet);
+
+        assert.equal((await contract.getChildren(hotkey.publicKey, netuid)).length, 0);
+        assert.equal((await contract.getParents(child.publicKey, netuid)).length, 0);
+    });
+});
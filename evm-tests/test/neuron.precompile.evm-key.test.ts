@@ -0,0 +1,79 @@
+import * as assert from "assert";
+
+import { getDevnetApi, getRandomSubstrateKeypair, getSignerFromKeypair, waitForTransactionWithRetry } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { Binary, TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58, ethAddressToH160 } from "../src/address-utils"
+import { ethers } from "ethers"
+import { INEURON_ADDRESS, INeuronABI } from "../src/contracts/neuron"
+import { generateRandomEthersWallet } from "../src/utils"
+import {
+    forceSetBalanceToSs58Address, forceSetBalanceToEthAddress, addNewSubnetwork, burnedRegister, setCommitRevealWeightsEnabled,
+    setWeightsSetRateLimit,
+    startCall
+} from "../src/subtensor"
+
+describe("Test neuron precompile calls authorised by an associated EVM key", () => {
+    // the EVM key associated with the hotkey, and an unrelated one
+    const wallet = generateRandomEthersWallet();
+    const otherWallet = generateRandomEthersWallet();
+
+    const hotkey = getRandomSubstrateKeypair();
+    const subnetHotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+    let netuid: number
+
+    before(async () => {
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(subnetHotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        await forceSetBalanceToEthAddress(api, otherWallet.address)
+
+        netuid = await addNewSubnetwork(api, subnetHotkey, coldkey)
+        await startCall(api, netuid, coldkey)
+
+        await burnedRegister(api, netuid, convertPublicKeyToSs58(hotkey.publicKey), coldkey)
+        await setCommitRevealWeightsEnabled(api, netuid, false)
+        await setWeightsSetRateLimit(api, netuid, BigInt(0))
+
+        // sign hotkey ++ keccak256(block_number) with the EVM key and associate it
+        const blockNumber = BigInt(await api.query.System.Number.getValue())
+        const blockBytes = new Uint8Array(8)
+        new DataView(blockBytes.buffer).setBigUint64(0, blockNumber, true)
+        const message = ethers.concat([hotkey.publicKey, ethers.keccak256(blockBytes)])
+        const signature = await wallet.signMessage(ethers.getBytes(message))
+
+        const tx = api.tx.SubtensorModule.associate_evm_key({
+            netuid: netuid,
+            hotkey: convertPublicKeyToSs58(hotkey.publicKey),
+            evm_key: ethAddressToH160(wallet.address),
+            block_number: blockNumber,
+            signature: Binary.fromHex(signature),
+        })
+        await waitForTransactionWithRetry(api, tx, getSignerFromKeypair(coldkey))
+    })
+
+    it("Associated EVM key can set weights for the hotkey", async () => {
+        const uid = await api.query.SubtensorModule.Uids.getValue(netuid, convertPublicKeyToSs58(hotkey.publicKey))
+        assert.notEqual(uid, undefined)
+
+        const contract = new ethers.Contract(INEURON_ADDRESS, INeuronABI, wallet);
+        const tx = await contract.setWeightsForHotkey(netuid, hotkey.publicKey, [0], [2], 0);
+        await tx.wait();
+
+        const weightsOnChain = await api.query.SubtensorModule.Weights.getValue(netuid, uid!)
+        assert.ok(weightsOnChain.length > 0)
+    })
+
+    it("Other EVM keys cannot act for the hotkey", async () => {
+        const contract = new ethers.Contract(INEURON_ADDRESS, INeuronABI, otherWallet);
+        await assert.rejects(async () => {
+            const tx = await contract.setWeightsForHotkey(netuid, hotkey.publicKey, [0], [2], 0);
+            await tx.wait();
+        });
+    })
+});
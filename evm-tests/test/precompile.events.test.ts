@@ -0,0 +1,89 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate";
+import { devnet } from "@polkadot-api/descriptors";
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58 } from "../src/address-utils";
+import { tao } from "../src/balance-math";
+import {
+  addNewSubnetwork,
+  forceSetBalanceToEthAddress,
+  forceSetBalanceToSs58Address,
+  startCall,
+} from "../src/subtensor";
+import { ethers } from "ethers";
+import { generateRandomEthersWallet } from "../src/utils";
+import { ISTAKING_V2_ADDRESS, IStakingV2ABI } from "../src/contracts/staking";
+import { INEURON_ADDRESS, INeuronABI } from "../src/contracts/neuron";
+
+describe("Test EVM logs emitted by precompiles", () => {
+  const hotkey = getRandomSubstrateKeypair();
+  const coldkey = getRandomSubstrateKeypair();
+  const newHotkey = getRandomSubstrateKeypair();
+  const wallet1 = generateRandomEthersWallet();
+
+  let api: TypedApi<typeof devnet>;
+  let netuid: number;
+
+  before(async () => {
+    api = await getDevnetApi();
+    await forceSetBalanceToSs58Address(
+      api,
+      convertPublicKeyToSs58(hotkey.publicKey),
+    );
+    await forceSetBalanceToSs58Address(
+      api,
+      convertPublicKeyToSs58(coldkey.publicKey),
+    );
+    await forceSetBalanceToEthAddress(api, wallet1.address);
+
+    netuid = await addNewSubnetwork(api, hotkey, coldkey);
+    await startCall(api, netuid, coldkey);
+  });
+
+  it("addStake and removeStake emit StakeAdded and StakeRemoved", async () => {
+    const contract = new ethers.Contract(
+      ISTAKING_V2_ADDRESS,
+      IStakingV2ABI,
+      wallet1,
+    );
+
+    const addTx = await contract.addStake(hotkey.publicKey, tao(10), netuid);
+    const addReceipt = await addTx.wait();
+    const added = contract.interface.parseLog(addReceipt.logs[0]);
+    assert.equal(added?.name, "StakeAdded");
+    assert.equal(added?.args.staker, wallet1.address);
+    assert.equal(Number(added?.args.netuid), netuid);
+    assert.equal(BigInt(added?.args.tao), tao(10));
+
+    const alpha = BigInt(added?.args.alpha);
+    assert.ok(alpha > BigInt(0));
+
+    const removeTx = await contract.removeStake(
+      hotkey.publicKey,
+      alpha / BigInt(2),
+      netuid,
+    );
+    const removeReceipt = await removeTx.wait();
+    const removed = contract.interface.parseLog(removeReceipt.logs[0]);
+    assert.equal(removed?.name, "StakeRemoved");
+    assert.equal(BigInt(removed?.args.alpha), alpha / BigInt(2));
+    assert.ok(BigInt(removed?.args.tao) > BigInt(0));
+  });
+
+  it("burnedRegister emits NeuronRegistered", async () => {
+    const contract = new ethers.Contract(INEURON_ADDRESS, INeuronABI, wallet1);
+
+    const tx = await contract.burnedRegister(netuid, newHotkey.publicKey);
+    const receipt = await tx.wait();
+    const registered = receipt.logs
+      .map((log: ethers.Log) => contract.interface.parseLog(log))
+      .find((log: ethers.LogDescription | null) => log?.name === "NeuronRegistered");
+
+    assert.ok(registered);
+    assert.equal(Number(registered.args.netuid), netuid);
+    assert.equal(
+      registered.args.hotkey,
+      ethers.hexlify(newHotkey.publicKey),
+    );
+  });
+});
@@ -0,0 +1,182 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate";
+import { devnet } from "@polkadot-api/descriptors";
+import { TypedApi } from "polkadot-api";
+import {
+  convertH160ToPublicKey,
+  convertH160ToSS58,
+  convertPublicKeyToSs58,
+} from "../src/address-utils";
+import { tao } from "../src/balance-math";
+import {
+  addNewSubnetwork,
+  burnedRegister,
+  forceSetBalanceToEthAddress,
+  forceSetBalanceToSs58Address,
+  startCall,
+} from "../src/subtensor";
+import { ethers } from "ethers";
+import { generateRandomEthersWallet } from "../src/utils";
+import { ISTAKING_V2_ADDRESS, IStakingV2ABI } from "../src/contracts/staking";
+
+describe("Test staking precompile swap, unstake all and recycle methods", () => {
+  const hotkey = getRandomSubstrateKeypair();
+  const coldkey = getRandomSubstrateKeypair();
+  const wallet1 = generateRandomEthersWallet();
+
+  let api: TypedApi<typeof devnet>;
+  let originNetuid: number;
+  let destinationNetuid: number;
+
+  before(async () => {
+    api = await getDevnetApi();
+    await forceSetBalanceToSs58Address(
+      api,
+      convertPublicKeyToSs58(hotkey.publicKey),
+    );
+    await forceSetBalanceToSs58Address(
+      api,
+      convertPublicKeyToSs58(coldkey.publicKey),
+    );
+    await forceSetBalanceToEthAddress(api, wallet1.address);
+
+    originNetuid = await addNewSubnetwork(api, hotkey, coldkey);
+    await startCall(api, originNetuid, coldkey);
+    destinationNetuid = await addNewSubnetwork(api, hotkey, coldkey);
+    await startCall(api, destinationNetuid, coldkey);
+    await burnedRegister(
+      api,
+      destinationNetuid,
+      convertPublicKeyToSs58(hotkey.publicKey),
+      coldkey,
+    );
+    console.log("will test in subnets: ", originNetuid, destinationNetuid);
+
+    const contract = new ethers.Contract(
+      ISTAKING_V2_ADDRESS,
+      IStakingV2ABI,
+      wallet1,
+    );
+    const tx = await contract.addStake(hotkey.publicKey, tao(100), originNetuid);
+    await tx.wait();
+  });
+
+  it("Staker can quote prices and fees", async () => {
+    const contract = new ethers.Contract(
+      ISTAKING_V2_ADDRESS,
+      IStakingV2ABI,
+      wallet1,
+    );
+
+    const price = BigInt(await contract.getAlphaPrice(originNetuid));
+    assert.ok(price > BigInt(0));
+
+    const alpha = BigInt(await contract.simSwapTaoForAlpha(originNetuid, tao(1)));
+    assert.ok(alpha > BigInt(0));
+
+    const fee = BigInt(
+      await contract.getAddStakeFee(hotkey.publicKey, originNetuid, tao(1)),
+    );
+    assert.ok(fee >= BigInt(0));
+
+    const stakeInfo = await contract.getStakeInfoForColdkey(
+      convertH160ToPublicKey(wallet1.address),
+    );
+    assert.ok(
+      stakeInfo.some(
+        (info: { netuid: bigint }) => Number(info.netuid) === originNetuid,
+      ),
+    );
+  });
+
+  it("Staker can swap stake between subnets", async () => {
+    const ss58Address = convertH160ToSS58(wallet1.address);
+    const hotkeySs58 = convertPublicKeyToSs58(hotkey.publicKey);
+    const originAlpha = await api.query.SubtensorModule.Alpha.getValue(
+      hotkeySs58,
+      ss58Address,
+      originNetuid,
+    );
+    const destinationAlpha = await api.query.SubtensorModule.Alpha.getValue(
+      hotkeySs58,
+      ss58Address,
+      destinationNetuid,
+    );
+
+    const contract = new ethers.Contract(
+      ISTAKING_V2_ADDRESS,
+      IStakingV2ABI,
+      wallet1,
+    );
+    const tx = await contract.swapStake(
+      hotkey.publicKey,
+      originNetuid,
+      destinationNetuid,
+      tao(10),
+    );
+    await tx.wait();
+
+    assert.ok(
+      (await api.query.SubtensorModule.Alpha.getValue(
+        hotkeySs58,
+        ss58Address,
+        originNetuid,
+      )) < originAlpha,
+    );
+    assert.ok(
+      (await api.query.SubtensorModule.Alpha.getValue(
+        hotkeySs58,
+        ss58Address,
+        destinationNetuid,
+      )) > destinationAlpha,
+    );
+  });
+
+  it("Staker can recycle alpha", async () => {
+    const ss58Address = convertH160ToSS58(wallet1.address);
+    const hotkeySs58 = convertPublicKeyToSs58(hotkey.publicKey);
+    const alpha = await api.query.SubtensorModule.Alpha.getValue(
+      hotkeySs58,
+      ss58Address,
+      originNetuid,
+    );
+
+    const contract = new ethers.Contract(
+      ISTAKING_V2_ADDRESS,
+      IStakingV2ABI,
+      wallet1,
+    );
+    const tx = await contract.recycleAlpha(hotkey.publicKey, tao(1), originNetuid);
+    await tx.wait();
+
+    assert.ok(
+      (await api.query.SubtensorModule.Alpha.getValue(
+        hotkeySs58,
+        ss58Address,
+        originNetuid,
+      )) < alpha,
+    );
+  });
+
+  it("Staker can unstake all", async () => {
+    const ss58Address = convertH160ToSS58(wallet1.address);
+    const hotkeySs58 = convertPublicKeyToSs58(hotkey.publicKey);
+
+    const contract = new ethers.Contract(
+      ISTAKING_V2_ADDRESS,
+      IStakingV2ABI,
+      wallet1,
+    );
+    const tx = await contract.unstakeAll(hotkey.publicKey);
+    await tx.wait();
+
+    for (const netuid of [originNetuid, destinationNetuid]) {
+      const alpha = await api.query.SubtensorModule.Alpha.getValue(
+        hotkeySs58,
+        ss58Address,
+        netuid,
+      );
+      assert.equal(alpha, BigInt(0));
+    }
+  });
+});
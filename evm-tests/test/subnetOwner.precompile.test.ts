@@ -0,0 +1,81 @@
+import * as assert from "assert";
+
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertH160ToPublicKey, convertPublicKeyToSs58 } from "../src/address-utils"
+import { generateRandomEthersWallet } from "../src/utils";
+import { ISubnetABI, ISUBNET_ADDRESS } from "../src/contracts/subnet"
+import { ISubnetOwnerABI, ISUBNET_OWNER_ADDRESS } from "../src/contracts/subnetOwner"
+import { ethers } from "ethers"
+import { forceSetBalanceToEthAddress, forceSetBalanceToSs58Address } from "../src/subtensor"
+
+describe("Test the subnet owner precompile", () => {
+    const owner = generateRandomEthersWallet();
+    const newOwner = generateRandomEthersWallet();
+    const hotkey = getRandomSubstrateKeypair();
+    let api: TypedApi<typeof devnet>
+    let netuid: number
+
+    before(async () => {
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToEthAddress(api, owner.address)
+        await forceSetBalanceToEthAddress(api, newOwner.address)
+
+        const subnet = new ethers.Contract(ISUBNET_ADDRESS, ISubnetABI, owner);
+        const tx = await subnet.registerNetwork(hotkey.publicKey);
+        await tx.wait();
+
+        netuid = (await api.query.SubtensorModule.TotalNetworks.getValue()) - 1
+    })
+
+    it("EVM owner can set the subnet identity", async () => {
+        const contract = new ethers.Contract(ISUBNET_OWNER_ADDRESS, ISubnetOwnerABI, owner);
+        const tx = await contract.setSubnetIdentity(netuid,
+            "name",
+            "repo",
+            "contact",
+            "subnetUrl",
+            "discord",
+            "description",
+            "additional"
+        );
+        const receipt = await tx.wait();
+
+        const event = contract.interface.parseLog(receipt.logs[0]);
+        assert.equal(event?.name, "SubnetIdentitySet");
+        assert.equal(Number(event?.args.netuid), netuid);
+
+        const identity = await api.query.SubtensorModule.SubnetIdentitiesV2.getValue(netuid)
+        assert.equal(identity?.subnet_name.asText(), "name")
+    });
+
+    it("Non-owner cannot start the subnet", async () => {
+        const contract = new ethers.Contract(ISUBNET_OWNER_ADDRESS, ISubnetOwnerABI, newOwner);
+        await assert.rejects(async () => {
+            const tx = await contract.startCall(netuid);
+            await tx.wait();
+        });
+    });
+
+    it("EVM owner can hand the subnet over to another EVM account", async () => {
+        const contract = new ethers.Contract(ISUBNET_OWNER_ADDRESS, ISubnetOwnerABI, owner);
+        const newOwnerPublicKey = convertH160ToPublicKey(newOwner.address);
+
+        const offerTx = await contract.offerSubnetOwnership(netuid, newOwnerPublicKey);
+        await offerTx.wait();
+
+        const accepting = new ethers.Contract(ISUBNET_OWNER_ADDRESS, ISubnetOwnerABI, newOwner);
+        const newOwnerHotkey = getRandomSubstrateKeypair();
+        const acceptTx = await accepting.acceptSubnetOwnership(netuid, newOwnerHotkey.publicKey);
+        await acceptTx.wait();
+
+        const ownerFromContract = await contract.getSubnetOwner(netuid);
+        assert.equal(ownerFromContract, ethers.hexlify(newOwnerPublicKey));
+
+        const ownerHotkey = await api.query.SubtensorModule.SubnetOwnerHotkey.getValue(netuid)
+        assert.equal(ownerHotkey, convertPublicKeyToSs58(newOwnerHotkey.publicKey))
+    });
+});
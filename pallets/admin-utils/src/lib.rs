@@ -1,6 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-// extern crate alloc;
+extern crate alloc;
 
 use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet::*;
@@ -20,6 +20,7 @@ mod tests;
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
+    use alloc::vec::Vec;
     use frame_support::pallet_prelude::*;
     use frame_support::traits::tokens::Balance;
     use frame_support::{
@@ -28,9 +29,18 @@ pub mod pallet {
     };
     use frame_system::pallet_prelude::*;
     use pallet_evm_chain_id::{self, ChainId};
-    use pallet_subtensor::utils::rate_limiting::TransactionType;
-    use sp_runtime::BoundedVec;
+    use pallet_subtensor::utils::{
+        hyperparameters::HyperparameterId, rate_limiting::TransactionType,
+    };
+    use sp_runtime::{
+        BoundedVec, SaturatedConversion,
+        traits::{Saturating, Zero},
+    };
     use substrate_fixed::types::I96F32;
+    use subtensor_macros::freeze_struct;
+
+    /// Maximum number of hyperparameter changes a subnet can have pending at once.
+    pub const MAX_PENDING_HYPERPARAMETER_CHANGES: usize = 32;
 
     /// The main data structure of the module.
     #[pallet::pallet]
@@ -84,6 +94,49 @@ pub mod pallet {
             /// Indicates if the Yuma3 enable was enabled or disabled.
             enabled: bool,
         },
+        /// Event emitted when the hyperparameter announcement delay of a subnet is set.
+        HyperparameterAnnouncementDelaySet {
+            /// The network identifier.
+            netuid: u16,
+            /// The number of blocks an announced change waits before it is applied.
+            delay: BlockNumberFor<T>,
+        },
+        /// Event emitted when a subnet owner announces a hyperparameter change.
+        HyperparameterChangeAnnounced {
+            /// The network identifier.
+            netuid: u16,
+            /// The identifier of the announced change.
+            id: u32,
+            /// The subnet owner who announced the change.
+            who: T::AccountId,
+            /// The block at which the change will be applied.
+            apply_at: BlockNumberFor<T>,
+        },
+        /// Event emitted when an announced hyperparameter change is applied.
+        HyperparameterChangeApplied {
+            /// The network identifier.
+            netuid: u16,
+            /// The identifier of the applied change.
+            id: u32,
+            /// The result of applying the announced change.
+            result: DispatchResult,
+        },
+        /// Event emitted when a hyperparameter is set through the hyperparameter registry.
+        HyperparameterSet {
+            /// The network identifier.
+            netuid: u16,
+            /// The hyperparameter that was set.
+            id: HyperparameterId,
+            /// The new value of the hyperparameter.
+            value: u64,
+        },
+        /// Event emitted when root vetoes an announced hyperparameter change.
+        HyperparameterChangeVetoed {
+            /// The network identifier.
+            netuid: u16,
+            /// The identifier of the vetoed change.
+            id: u32,
+        },
     }
 
     // Errors inform users that something went wrong.
@@ -97,6 +150,16 @@ pub mod pallet {
         MaxAllowedUIdsLessThanCurrentUIds,
         /// The maximum value for bonds moving average is reached
         BondsMovingAverageMaxReached,
+        /// The subnet has an announcement delay, owner changes must be announced first.
+        HyperparameterChangeMustBeAnnounced,
+        /// The subnet has no announcement delay set.
+        HyperparameterAnnouncementsDisabled,
+        /// The hyperparameter cannot be set by the subnet owner.
+        HyperparameterNotAnnounceable,
+        /// The subnet has too many pending hyperparameter changes.
+        TooManyPendingHyperparameterChanges,
+        /// The announced hyperparameter change does not exist.
+        HyperparameterChangeNotFound,
     }
     /// Enum for specifying the type of precompile operation.
     #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug, Copy)]
@@ -113,6 +176,14 @@ pub mod pallet {
         Neuron,
         /// Enum for UID lookup precompile
         UidLookup,
+        /// Enum for randomness precompile
+        Randomness,
+        /// Enum for alpha token precompiles
+        AlphaToken,
+        /// Enum for subnet owner precompile
+        SubnetOwner,
+        /// Enum for child-key precompile
+        Children,
     }
 
     #[pallet::type_value]
@@ -132,6 +203,54 @@ pub mod pallet {
         DefaultPrecompileEnabled<T>,
     >;
 
+    /// Data structure for a hyperparameter change announced by a subnet owner.
+    #[freeze_struct("a7377e3658fcf08a")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct PendingHyperparameterChange<AccountId, BlockNumber> {
+        /// The subnet owner who announced the change.
+        pub who: AccountId,
+        /// The announced hyperparameter.
+        pub hyperparameter: HyperparameterId,
+        /// The announced value, encoded as for `set_hyperparameter`.
+        pub value: u64,
+        /// The block at which the change was announced.
+        pub announced_at: BlockNumber,
+        /// The block at which the change will be applied.
+        pub apply_at: BlockNumber,
+    }
+
+    /// Alias for a pending hyperparameter change of this pallet.
+    pub type PendingHyperparameterChangeOf<T> =
+        PendingHyperparameterChange<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    #[pallet::storage]
+    /// DMap ( netuid, id ) --> announced hyperparameter change
+    pub type PendingHyperparameterChanges<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Identity,
+        u32,
+        PendingHyperparameterChangeOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    /// Map block --> ( netuid, id ) of the announced changes to apply at that block
+    pub type HyperparameterChangesDue<T: Config> =
+        StorageMap<_, Identity, BlockNumberFor<T>, Vec<(u16, u32)>, ValueQuery>;
+
+    #[pallet::storage]
+    /// Identifier of the next announced hyperparameter change
+    pub type NextHyperparameterChangeId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+            Self::apply_due_hyperparameter_changes(block_number)
+        }
+    }
+
     /// Dispatchable functions allows users to interact with the pallet and invoke state changes.
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -194,7 +313,7 @@ pub mod pallet {
             netuid: u16,
             serving_rate_limit: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
 
             pallet_subtensor::Pallet::<T>::set_serving_rate_limit(netuid, serving_rate_limit);
             log::debug!(
@@ -243,7 +362,7 @@ pub mod pallet {
             netuid: u16,
             max_difficulty: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -270,7 +389,7 @@ pub mod pallet {
             netuid: u16,
             weights_version_key: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin.clone(), netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin.clone(), netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -381,7 +500,7 @@ pub mod pallet {
             netuid: u16,
             adjustment_alpha: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -407,7 +526,7 @@ pub mod pallet {
             netuid: u16,
             max_weight_limit: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -434,7 +553,7 @@ pub mod pallet {
             netuid: u16,
             immunity_period: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
@@ -461,7 +580,7 @@ pub mod pallet {
             netuid: u16,
             min_allowed_weights: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -514,7 +633,7 @@ pub mod pallet {
         .saturating_add(T::DbWeight::get().reads(1_u64))
         .saturating_add(T::DbWeight::get().writes(1_u64)))]
         pub fn sudo_set_kappa(origin: OriginFor<T>, netuid: u16, kappa: u16) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -533,7 +652,7 @@ pub mod pallet {
         .saturating_add(T::DbWeight::get().reads(1_u64))
         .saturating_add(T::DbWeight::get().writes(1_u64)))]
         pub fn sudo_set_rho(origin: OriginFor<T>, netuid: u16, rho: u16) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -556,7 +675,7 @@ pub mod pallet {
             netuid: u16,
             activity_cutoff: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -593,7 +712,7 @@ pub mod pallet {
             netuid: u16,
             registration_allowed: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
 
             pallet_subtensor::Pallet::<T>::set_network_registration_allowed(
                 netuid,
@@ -621,7 +740,7 @@ pub mod pallet {
             netuid: u16,
             registration_allowed: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
 
             pallet_subtensor::Pallet::<T>::set_network_pow_registration_allowed(
                 netuid,
@@ -791,7 +910,7 @@ pub mod pallet {
             netuid: u16,
             bonds_moving_average: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin.clone(), netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin.clone(), netuid)?;
 
             if pallet_subtensor::Pallet::<T>::ensure_subnet_owner(origin, netuid).is_ok() {
                 ensure!(
@@ -825,7 +944,7 @@ pub mod pallet {
             netuid: u16,
             bonds_penalty: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -1145,7 +1264,7 @@ pub mod pallet {
             netuid: u16,
             enabled: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -1173,7 +1292,7 @@ pub mod pallet {
             netuid: u16,
             enabled: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
             pallet_subtensor::Pallet::<T>::set_liquid_alpha_enabled(netuid, enabled);
             log::debug!(
                 "LiquidAlphaEnableToggled( netuid: {:?}, Enabled: {:?} ) ",
@@ -1346,7 +1465,7 @@ pub mod pallet {
             netuid: u16,
             interval: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -1431,7 +1550,7 @@ pub mod pallet {
             netuid: u16,
             toggle: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
             pallet_subtensor::Pallet::<T>::toggle_transfer(netuid, toggle)
         }
 
@@ -1590,7 +1709,7 @@ pub mod pallet {
             netuid: u16,
             enabled: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_subnet_owner_or_root_immediate(origin, netuid)?;
             pallet_subtensor::Pallet::<T>::set_yuma3_enabled(netuid, enabled);
 
             Self::deposit_event(Event::Yuma3EnableToggled { netuid, enabled });
@@ -1671,6 +1790,320 @@ pub mod pallet {
             );
             Ok(())
         }
+
+        /// Sets the number of blocks an owner hyperparameter change waits before it is applied.
+        ///
+        /// A delay of zero disables announcements, owner changes then take effect right away.
+        /// While a delay is set, the subnet owner may raise it right away, but lowering it must
+        /// be announced as a change of `HyperparameterId::AnnouncementDelay`.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the subnet owner or the root account.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `delay` - The announcement delay, in number of blocks.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither the subnet owner nor the root account.
+        /// * `SubnetDoesNotExist` - If the specified subnet does not exist.
+        /// * `HyperparameterChangeMustBeAnnounced` - If the owner lowers a delay that is set.
+        #[pallet::call_index(70)]
+        #[pallet::weight(Weight::from_parts(20_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(3_u64))
+        .saturating_add(T::DbWeight::get().writes(1_u64)))]
+        pub fn sudo_set_hyperparameter_announcement_delay(
+            origin: OriginFor<T>,
+            netuid: u16,
+            delay: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin.clone(), netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            let new_delay: u64 = delay.saturated_into();
+            if ensure_root(origin).is_err()
+                && new_delay
+                    < pallet_subtensor::Pallet::<T>::get_hyperparameter_announcement_delay(netuid)
+            {
+                return Err(Error::<T>::HyperparameterChangeMustBeAnnounced.into());
+            }
+
+            pallet_subtensor::Pallet::<T>::set_hyperparameter_announcement_delay(netuid, new_delay);
+            Self::deposit_event(Event::HyperparameterAnnouncementDelaySet { netuid, delay });
+            log::debug!(
+                "HyperparameterAnnouncementDelaySet( netuid: {:?}, delay: {:?} )",
+                netuid,
+                delay
+            );
+            Ok(())
+        }
+
+        /// Announces an owner hyperparameter change to be applied once the subnet's
+        /// announcement delay has passed. Only hyperparameters of the registry can be announced,
+        /// owner setters outside the registry are not delayed. Ownership is checked here, the
+        /// change is applied only if the announcer still owns the subnet when it is due.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `hyperparameter` - The hyperparameter to set.
+        /// * `value` - The new value, encoded as for `set_hyperparameter`.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the subnet owner.
+        /// * `HyperparameterAnnouncementsDisabled` - If the subnet has no announcement delay.
+        /// * `HyperparameterNotAnnounceable` - If the owner cannot set the hyperparameter.
+        /// * `TooManyPendingHyperparameterChanges` - If the subnet has too many pending changes.
+        #[pallet::call_index(71)]
+        #[pallet::weight(Weight::from_parts(25_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(4_u64))
+        .saturating_add(T::DbWeight::get().writes(3_u64)))]
+        pub fn announce_hyperparameter_change(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hyperparameter: HyperparameterId,
+            value: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin.clone())?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner(origin, netuid)?;
+
+            let delay: BlockNumberFor<T> =
+                pallet_subtensor::Pallet::<T>::get_hyperparameter_announcement_delay(netuid)
+                    .saturated_into();
+            ensure!(
+                !delay.is_zero(),
+                Error::<T>::HyperparameterAnnouncementsDisabled
+            );
+            ensure!(
+                hyperparameter.owner_settable(),
+                Error::<T>::HyperparameterNotAnnounceable
+            );
+            ensure!(
+                PendingHyperparameterChanges::<T>::iter_prefix(netuid).count()
+                    < MAX_PENDING_HYPERPARAMETER_CHANGES,
+                Error::<T>::TooManyPendingHyperparameterChanges
+            );
+
+            let announced_at = frame_system::Pallet::<T>::block_number();
+            let apply_at = announced_at.saturating_add(delay);
+            let id = NextHyperparameterChangeId::<T>::get();
+            NextHyperparameterChangeId::<T>::put(id.wrapping_add(1));
+
+            PendingHyperparameterChanges::<T>::insert(
+                netuid,
+                id,
+                PendingHyperparameterChange {
+                    who: who.clone(),
+                    hyperparameter,
+                    value,
+                    announced_at,
+                    apply_at,
+                },
+            );
+            HyperparameterChangesDue::<T>::append(apply_at, (netuid, id));
+
+            Self::deposit_event(Event::HyperparameterChangeAnnounced {
+                netuid,
+                id,
+                who,
+                apply_at,
+            });
+            Ok(())
+        }
+
+        /// Vetoes an announced hyperparameter change before it is applied.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `id` - The identifier of the announced change.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `HyperparameterChangeNotFound` - If no such change is pending.
+        #[pallet::call_index(72)]
+        #[pallet::weight((
+            Weight::from_parts(15_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(2))
+                .saturating_add(T::DbWeight::get().writes(2)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn veto_hyperparameter_change(
+            origin: OriginFor<T>,
+            netuid: u16,
+            id: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let change = PendingHyperparameterChanges::<T>::take(netuid, id)
+                .ok_or(Error::<T>::HyperparameterChangeNotFound)?;
+            HyperparameterChangesDue::<T>::mutate_exists(change.apply_at, |due| {
+                if let Some(changes) = due {
+                    changes.retain(|&(n, i)| n != netuid || i != id);
+                    if changes.is_empty() {
+                        *due = None;
+                    }
+                }
+            });
+
+            Self::deposit_event(Event::HyperparameterChangeVetoed { netuid, id });
+            Ok(())
+        }
+
+        /// Sets any hyperparameter described by the hyperparameter registry.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account, or the subnet
+        ///   owner if the hyperparameter is owner-settable.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `id` - The hyperparameter to set.
+        /// * `value` - The new value, encoded as `u64` (`0` or `1` for booleans).
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller may not set this hyperparameter.
+        /// * `HyperparameterChangeMustBeAnnounced` - If the owner calls it while a delay is set.
+        /// * `SubNetworkDoesNotExist` - If the specified subnet does not exist.
+        /// * `HyperparameterOutOfBounds` - If `value` is outside the bounds of the hyperparameter.
+        /// * `TxRateLimitExceeded` - If the owner changed the hyperparameter too recently.
+        #[pallet::call_index(73)]
+        #[pallet::weight(Weight::from_parts(25_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(4_u64))
+        .saturating_add(T::DbWeight::get().writes(2_u64)))]
+        pub fn set_hyperparameter(
+            origin: OriginFor<T>,
+            netuid: u16,
+            id: HyperparameterId,
+            value: u64,
+        ) -> DispatchResult {
+            let owner = if id.owner_settable() {
+                Self::ensure_subnet_owner_or_root_immediate(origin.clone(), netuid)?;
+                match origin.into() {
+                    Ok(RawOrigin::Signed(who)) => Some(who),
+                    _ => None,
+                }
+            } else {
+                ensure_root(origin)?;
+                None
+            };
+
+            pallet_subtensor::Pallet::<T>::do_set_hyperparameter(
+                netuid,
+                id,
+                value,
+                owner.as_ref(),
+            )?;
+
+            Self::deposit_event(Event::HyperparameterSet { netuid, id, value });
+            log::debug!(
+                "HyperparameterSet( netuid: {:?}, id: {:?}, value: {:?} )",
+                netuid,
+                id,
+                value
+            );
+            Ok(())
+        }
+
+        /// Sets the number of hops stake and dividends travel along child keys.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `depth` - The maximum child-key depth, between 1 and `MAX_CHILDKEY_DEPTH`.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `InvalidChildKeyDepth` - If `depth` is outside of the allowed range.
+        #[pallet::call_index(74)]
+        #[pallet::weight(Weight::from_parts(7_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(0_u64))
+        .saturating_add(T::DbWeight::get().writes(1_u64)))]
+        pub fn sudo_set_max_childkey_depth(origin: OriginFor<T>, depth: u16) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                (1..=pallet_subtensor::MAX_CHILDKEY_DEPTH).contains(&depth),
+                pallet_subtensor::Error::<T>::InvalidChildKeyDepth
+            );
+
+            pallet_subtensor::Pallet::<T>::set_max_childkey_depth(depth);
+            log::debug!("MaxChildKeyDepthSet( depth: {:?} ) ", depth);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Ensures the origin is root or the subnet owner. Subnet owner changes are only allowed
+        /// to take effect right away if the subnet has no announcement delay.
+        pub fn ensure_subnet_owner_or_root_immediate(
+            origin: OriginFor<T>,
+            netuid: u16,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin.clone(), netuid)?;
+
+            if ensure_root(origin).is_err()
+                && pallet_subtensor::Pallet::<T>::get_hyperparameter_announcement_delay(netuid) > 0
+            {
+                return Err(Error::<T>::HyperparameterChangeMustBeAnnounced.into());
+            }
+            Ok(())
+        }
+
+        /// Applies the announced hyperparameter changes that are due at `block_number`.
+        ///
+        /// Each change is applied with the owner bounds and rate limit of the owner who
+        /// announced it, provided they still own the subnet.
+        pub fn apply_due_hyperparameter_changes(block_number: BlockNumberFor<T>) -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+            for (netuid, id) in HyperparameterChangesDue::<T>::take(block_number) {
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+                let Some(change) = PendingHyperparameterChanges::<T>::take(netuid, id) else {
+                    continue;
+                };
+
+                weight.saturating_accrue(
+                    Weight::from_parts(25_000_000, 0)
+                        .saturating_add(T::DbWeight::get().reads_writes(5, 2)),
+                );
+
+                let result = if pallet_subtensor::SubnetOwner::<T>::get(netuid) == change.who {
+                    pallet_subtensor::Pallet::<T>::do_set_hyperparameter(
+                        netuid,
+                        change.hyperparameter,
+                        change.value,
+                        Some(&change.who),
+                    )
+                } else {
+                    Err(DispatchError::BadOrigin)
+                };
+                if result.is_ok() {
+                    Self::deposit_event(Event::HyperparameterSet {
+                        netuid,
+                        id: change.hyperparameter,
+                        value: change.value,
+                    });
+                }
+
+                log::debug!(
+                    "HyperparameterChangeApplied( netuid: {:?}, id: {:?}, result: {:?} )",
+                    netuid,
+                    id,
+                    result
+                );
+                Self::deposit_event(Event::HyperparameterChangeApplied { netuid, id, result });
+            }
+
+            weight
+        }
+
+        /// Returns the announced hyperparameter changes pending for a subnet.
+        pub fn get_pending_hyperparameter_changes(
+            netuid: u16,
+        ) -> Vec<(u32, PendingHyperparameterChangeOf<T>)> {
+            PendingHyperparameterChanges::<T>::iter_prefix(netuid).collect()
+        }
     }
 }
 
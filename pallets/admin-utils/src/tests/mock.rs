@@ -153,6 +153,8 @@ impl pallet_subtensor::Config for Test {
     type CouncilOrigin = EnsureNever<AccountId>;
     type SenateMembers = ();
     type TriumvirateInterface = ();
+    type CrowdloanInterface = ();
+    type ProxyInterface = ();
     type Scheduler = Scheduler;
     type InitialMinAllowedWeights = InitialMinAllowedWeights;
     type InitialEmissionValue = InitialEmissionValue;
@@ -323,9 +325,10 @@ impl pallet_evm_chain_id::Config for Test {}
 impl pallet_drand::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type AuthorityId = TestAuthId;
-    type Verifier = pallet_drand::verifier::QuicknetVerifier;
+    type Verifier = pallet_drand::verifier::SchemeVerifier;
     type UnsignedPriority = ConstU64<{ 1 << 20 }>;
     type HttpFetchTimeout = ConstU64<1_000>;
+    type MaxPulsesPrunedPerBlock = ConstU32<64>;
 }
 
 impl frame_system::offchain::SigningTypes for Test {
@@ -407,6 +410,7 @@ pub(crate) fn run_to_block(n: u64) {
         System::set_block_number(System::block_number() + 1);
         System::on_initialize(System::block_number());
         SubtensorModule::on_initialize(System::block_number());
+        AdminUtils::on_initialize(System::block_number());
     }
 }
 
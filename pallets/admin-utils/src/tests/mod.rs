@@ -8,6 +8,7 @@ use frame_system::Config;
 use pallet_subtensor::{Error as SubtensorError, SubnetOwner, Tempo, WeightsVersionKeyRateLimit};
 // use pallet_subtensor::{migrations, Event};
 use pallet_subtensor::Event;
+use pallet_subtensor::utils::hyperparameters::HyperparameterId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_core::{Pair, U256, ed25519};
 use substrate_fixed::types::I96F32;
@@ -1780,3 +1781,490 @@ fn test_set_sn_owner_hotkey_root() {
         assert_eq!(actual_hotkey, hotkey);
     });
 }
+
+#[test]
+fn test_sudo_set_hyperparameter_announcement_delay() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        assert_eq!(
+            AdminUtils::sudo_set_hyperparameter_announcement_delay(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_announcement_delay(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            100
+        ));
+        assert_eq!(
+            SubtensorModule::get_hyperparameter_announcement_delay(netuid),
+            100
+        );
+
+        // Owner can no longer change registry hyperparameters directly
+        assert_noop!(
+            AdminUtils::sudo_set_kappa(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                42
+            ),
+            Error::<Test>::HyperparameterChangeMustBeAnnounced
+        );
+
+        // The delay can be raised right away, but lowering it must be announced
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_announcement_delay(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            200
+        ));
+        assert_noop!(
+            AdminUtils::sudo_set_hyperparameter_announcement_delay(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                0
+            ),
+            Error::<Test>::HyperparameterChangeMustBeAnnounced
+        );
+        assert_ok!(AdminUtils::announce_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            HyperparameterId::AnnouncementDelay,
+            0
+        ));
+        run_to_block(System::block_number() + 200);
+        assert_eq!(
+            SubtensorModule::get_hyperparameter_announcement_delay(netuid),
+            0
+        );
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            43
+        ));
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_announcement_delay(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            100
+        ));
+
+        // Owner setters outside the registry are not delayed
+        assert_ok!(AdminUtils::sudo_set_liquid_alpha_enabled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        assert_ok!(AdminUtils::sudo_set_alpha_values(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            1000,
+            60000
+        ));
+
+        // Root changes are not delayed
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            42
+        ));
+        assert_eq!(SubtensorModule::get_kappa(netuid), 42);
+    });
+}
+
+#[test]
+fn test_announced_hyperparameter_change_is_applied_after_delay() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        let delay: u64 = 10;
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_announcement_delay(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            delay
+        ));
+
+        let init_value = SubtensorModule::get_immunity_period(netuid);
+        let to_be_set: u16 = init_value + 1;
+        assert_ok!(AdminUtils::announce_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            HyperparameterId::ImmunityPeriod,
+            u64::from(to_be_set)
+        ));
+        let apply_at = System::block_number() + delay;
+        System::assert_last_event(RuntimeEvent::AdminUtils(
+            crate::Event::HyperparameterChangeAnnounced {
+                netuid,
+                id: 0,
+                who: owner,
+                apply_at,
+            },
+        ));
+        assert_eq!(
+            AdminUtils::get_pending_hyperparameter_changes(netuid).len(),
+            1
+        );
+
+        run_to_block(apply_at - 1);
+        assert_eq!(SubtensorModule::get_immunity_period(netuid), init_value);
+
+        run_to_block(apply_at);
+        assert_eq!(SubtensorModule::get_immunity_period(netuid), to_be_set);
+        assert!(AdminUtils::get_pending_hyperparameter_changes(netuid).is_empty());
+        System::assert_has_event(RuntimeEvent::AdminUtils(
+            crate::Event::HyperparameterChangeApplied {
+                netuid,
+                id: 0,
+                result: Ok(()),
+            },
+        ));
+    });
+}
+
+#[test]
+fn test_announced_hyperparameter_change_requires_current_owner() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_announcement_delay(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            5
+        ));
+
+        let init_value = SubtensorModule::get_kappa(netuid);
+        assert_ok!(AdminUtils::announce_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            HyperparameterId::Kappa,
+            u64::from(init_value + 1)
+        ));
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, U256::from(11));
+
+        run_to_block(System::block_number() + 5);
+        assert_eq!(SubtensorModule::get_kappa(netuid), init_value);
+        System::assert_has_event(RuntimeEvent::AdminUtils(
+            crate::Event::HyperparameterChangeApplied {
+                netuid,
+                id: 0,
+                result: Err(DispatchError::BadOrigin),
+            },
+        ));
+    });
+}
+
+#[test]
+fn test_vetoed_hyperparameter_change_is_not_applied() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_announcement_delay(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            5
+        ));
+
+        let init_value = SubtensorModule::get_rho(netuid);
+        assert_ok!(AdminUtils::announce_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            HyperparameterId::Rho,
+            u64::from(init_value + 1)
+        ));
+        let apply_at = System::block_number() + 5;
+
+        assert_eq!(
+            AdminUtils::veto_hyperparameter_change(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                0
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::veto_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            0
+        ));
+        assert_noop!(
+            AdminUtils::veto_hyperparameter_change(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                0
+            ),
+            Error::<Test>::HyperparameterChangeNotFound
+        );
+        assert!(!crate::HyperparameterChangesDue::<Test>::contains_key(
+            apply_at
+        ));
+
+        run_to_block(10);
+        assert_eq!(SubtensorModule::get_rho(netuid), init_value);
+    });
+}
+
+#[test]
+fn test_announce_hyperparameter_change_rejects_invalid_changes() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        // No delay set for the subnet
+        assert_noop!(
+            AdminUtils::announce_hyperparameter_change(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                HyperparameterId::Kappa,
+                1
+            ),
+            Error::<Test>::HyperparameterAnnouncementsDisabled
+        );
+
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_announcement_delay(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            5
+        ));
+
+        // Only the subnet owner can announce
+        assert_noop!(
+            AdminUtils::announce_hyperparameter_change(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                HyperparameterId::Kappa,
+                1
+            ),
+            DispatchError::BadOrigin
+        );
+
+        // Root-only hyperparameters cannot be announced
+        assert_noop!(
+            AdminUtils::announce_hyperparameter_change(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                HyperparameterId::Tempo,
+                1
+            ),
+            Error::<Test>::HyperparameterNotAnnounceable
+        );
+    });
+}
+
+#[test]
+fn test_set_hyperparameter_permissions_and_bounds() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+
+        // Owner can set an owner-settable hyperparameter
+        assert_ok!(AdminUtils::set_hyperparameter(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            HyperparameterId::Kappa,
+            42
+        ));
+        assert_eq!(SubtensorModule::get_kappa(netuid), 42);
+        System::assert_last_event(RuntimeEvent::AdminUtils(crate::Event::HyperparameterSet {
+            netuid,
+            id: HyperparameterId::Kappa,
+            value: 42,
+        }));
+
+        // Owner cannot set a root-only hyperparameter, root can
+        assert_noop!(
+            AdminUtils::set_hyperparameter(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                HyperparameterId::Tempo,
+                20
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(AdminUtils::set_hyperparameter(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            HyperparameterId::Tempo,
+            20
+        ));
+        assert_eq!(SubtensorModule::get_tempo(netuid), 20);
+
+        // Values must fit the type of the hyperparameter
+        assert_noop!(
+            AdminUtils::set_hyperparameter(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                HyperparameterId::Kappa,
+                u64::from(u16::MAX) + 1
+            ),
+            SubtensorError::<Test>::HyperparameterOutOfBounds
+        );
+        assert_noop!(
+            AdminUtils::set_hyperparameter(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                HyperparameterId::LiquidAlphaEnabled,
+                2
+            ),
+            SubtensorError::<Test>::HyperparameterOutOfBounds
+        );
+
+        // Owners are held to a tighter bonds moving average than root
+        assert_noop!(
+            AdminUtils::set_hyperparameter(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                HyperparameterId::BondsMovingAverage,
+                975_001
+            ),
+            SubtensorError::<Test>::HyperparameterOutOfBounds
+        );
+        assert_ok!(AdminUtils::set_hyperparameter(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            HyperparameterId::BondsMovingAverage,
+            975_001
+        ));
+        assert_eq!(SubtensorModule::get_bonds_moving_average(netuid), 975_001);
+
+        // Dynamic bounds are read from the subnet state
+        let min_cutoff = pallet_subtensor::MinActivityCutoff::<Test>::get();
+        assert_noop!(
+            AdminUtils::set_hyperparameter(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                HyperparameterId::ActivityCutoff,
+                u64::from(min_cutoff) - 1
+            ),
+            SubtensorError::<Test>::HyperparameterOutOfBounds
+        );
+        assert_noop!(
+            AdminUtils::set_hyperparameter(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                HyperparameterId::MaxAllowedValidators,
+                u64::from(SubtensorModule::get_max_allowed_uids(netuid)) + 1
+            ),
+            SubtensorError::<Test>::HyperparameterOutOfBounds
+        );
+
+        // The subnet must exist
+        assert_noop!(
+            AdminUtils::set_hyperparameter(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                2,
+                HyperparameterId::Kappa,
+                1
+            ),
+            SubtensorError::<Test>::SubNetworkDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn test_set_hyperparameter_owner_rate_limit() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        WeightsVersionKeyRateLimit::<Test>::put(5);
+
+        assert_ok!(AdminUtils::set_hyperparameter(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            HyperparameterId::WeightsVersionKey,
+            1
+        ));
+        assert_noop!(
+            AdminUtils::set_hyperparameter(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                HyperparameterId::WeightsVersionKey,
+                2
+            ),
+            SubtensorError::<Test>::TxRateLimitExceeded
+        );
+
+        // Root is not rate limited
+        assert_ok!(AdminUtils::set_hyperparameter(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            HyperparameterId::WeightsVersionKey,
+            2
+        ));
+        assert_eq!(SubtensorModule::get_weights_version_key(netuid), 2);
+    });
+}
+
+#[test]
+fn test_get_hyperparameters_info() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        assert_eq!(SubtensorModule::get_hyperparameters_info(netuid), None);
+
+        add_network(netuid, 10);
+        let info = SubtensorModule::get_hyperparameters_info(netuid).unwrap_or_default();
+        assert_eq!(info.len(), HyperparameterId::ALL.len());
+
+        for (index, id) in HyperparameterId::ALL.iter().enumerate() {
+            assert_eq!(
+                HyperparameterId::try_from(u16::try_from(index).unwrap_or_default()),
+                Ok(*id)
+            );
+        }
+        assert_eq!(
+            HyperparameterId::try_from(
+                u16::try_from(HyperparameterId::ALL.len()).unwrap_or_default()
+            ),
+            Err(())
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_max_childkey_depth() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u16 = 2;
+        let init_value: u16 = SubtensorModule::get_max_childkey_depth();
+        assert_eq!(
+            AdminUtils::sudo_set_max_childkey_depth(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        for depth in [0, pallet_subtensor::MAX_CHILDKEY_DEPTH + 1] {
+            assert_noop!(
+                AdminUtils::sudo_set_max_childkey_depth(
+                    <<Test as Config>::RuntimeOrigin>::root(),
+                    depth
+                ),
+                SubtensorError::<Test>::InvalidChildKeyDepth
+            );
+        }
+        assert_eq!(SubtensorModule::get_max_childkey_depth(), init_value);
+
+        assert_ok!(AdminUtils::sudo_set_max_childkey_depth(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_max_childkey_depth(), to_be_set);
+        System::assert_last_event(Event::MaxChildKeyDepthSet(to_be_set).into());
+    });
+}
@@ -65,5 +65,34 @@ mod benchmarks {
         assert_eq!(MaxSpace::<T>::get(), new_space);
     }
 
+    #[benchmark]
+    fn set_commitment_schema() {
+        let netuid = 1;
+        let field = CommitmentSchemaField {
+            name: vec![b'a'; MAX_SCHEMA_FIELD_NAME_LEN as usize]
+                .try_into()
+                .expect("name within bounds; qed"),
+            field_type: CommitmentFieldType::Bytes,
+            max_len: MAX_BIGRAW_COMMITMENT_SIZE_BYTES,
+        };
+        let schema: CommitmentSchemaOf<T> = vec![field; T::MaxFields::get() as usize]
+            .into_iter()
+            .enumerate()
+            .map(|(i, mut field)| {
+                if let Some(byte) = field.name.get_mut(0) {
+                    *byte = i as u8;
+                }
+                field
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("within max fields; qed");
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, netuid, schema.clone());
+
+        assert_eq!(CommitmentSchemas::<T>::get(netuid), Some(schema));
+    }
+
     //impl_benchmark_test_suite!(Commitments, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -7,6 +7,7 @@ mod tests;
 #[cfg(test)]
 mod mock;
 
+pub mod migrations;
 pub mod types;
 pub mod weights;
 
@@ -15,21 +16,22 @@ use subtensor_macros::freeze_struct;
 pub use types::*;
 pub use weights::WeightInfo;
 
-use ark_serialize::CanonicalDeserialize;
-use frame_support::{BoundedVec, traits::Currency};
+use frame_support::{
+    BoundedVec, ensure,
+    traits::{Currency, Get},
+    weights::Weight,
+};
+use pallet_drand::types::{BeaconId, DEFAULT_BEACON_ID, RoundNumber};
 use scale_info::prelude::collections::BTreeSet;
 use sp_runtime::SaturatedConversion;
 use sp_runtime::{Saturating, traits::Zero};
 use sp_std::{boxed::Box, vec::Vec};
-use tle::{
-    curves::drand::TinyBLS381,
-    stream_ciphers::AESGCMStreamCipherProvider,
-    tlock::{TLECiphertext, tld},
-};
-use w3f_bls::EngineBLS;
 
 type BalanceOf<T> =
     <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// The fields of a commitment schema, in the order of the fields of the commitments.
+pub type CommitmentSchemaOf<T> = BoundedVec<CommitmentSchemaField, <T as Config>::MaxFields>;
 #[deny(missing_docs)]
 #[frame_support::pallet]
 pub mod pallet {
@@ -37,7 +39,11 @@ pub mod pallet {
     use frame_support::{pallet_prelude::*, traits::ReservableCurrency};
     use frame_system::pallet_prelude::{BlockNumberFor, *};
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
@@ -56,6 +62,9 @@ pub mod pallet {
         /// Interface to access-limit metadata commitments
         type CanCommit: CanCommit<Self::AccountId>;
 
+        /// Interface to check the owner of a subnet, who manages its commitment schema
+        type SubnetOwner: IsSubnetOwner<Self::AccountId>;
+
         /// Interface to trigger other pallets when metadata is committed
         type OnMetadataCommitment: OnMetadataCommitment<Self::AccountId>;
 
@@ -71,6 +80,14 @@ pub mod pallet {
         #[pallet::constant]
         type FieldDeposit: Get<BalanceOf<Self>>;
 
+        /// The number of past commitments kept per account, 0 to keep no history
+        #[pallet::constant]
+        type MaxCommitmentHistory: Get<u32>;
+
+        /// The amount held on deposit per commitment kept in the history of an account.
+        #[pallet::constant]
+        type HistoryDeposit: Get<BalanceOf<Self>>;
+
         /// Used to retrieve the given subnet's tempo
         type TempoInterface: GetTempoInterface;
     }
@@ -97,6 +114,8 @@ pub mod pallet {
             netuid: u16,
             /// The account
             who: T::AccountId,
+            /// The drand beacon the commitment is encrypted to
+            beacon_id: BeaconId,
             /// The drand round to reveal
             reveal_round: u64,
         },
@@ -107,6 +126,16 @@ pub mod pallet {
             /// The account
             who: T::AccountId,
         },
+        /// The commitment schema of a subnet was set
+        CommitmentSchemaSet {
+            /// The netuid of the schema
+            netuid: u16,
+        },
+        /// The commitment schema of a subnet was removed
+        CommitmentSchemaRemoved {
+            /// The netuid of the schema
+            netuid: u16,
+        },
     }
 
     #[pallet::error]
@@ -119,6 +148,16 @@ pub mod pallet {
         SpaceLimitExceeded,
         /// Indicates that unreserve returned a leftover, which is unexpected.
         UnexpectedUnreserveLeftover,
+        /// Account is neither root nor the owner of the subnet
+        NotSubnetOwner,
+        /// A schema field name is empty or used by another field of the schema
+        InvalidCommitmentSchema,
+        /// The commitment has more fields than the schema of the subnet
+        TooManyFieldsForSchema,
+        /// A field does not match the type or maximum length of the schema of the subnet
+        CommitmentFieldMismatch,
+        /// A field is timelock-encrypted to a drand beacon which is not bridged
+        UnknownDrandBeacon,
     }
 
     /// Tracks all CommitmentOf that have at least one timelocked field.
@@ -176,6 +215,23 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// The last commitments of an account with the blocks they were set at, oldest first
+    #[pallet::storage]
+    pub type CommitmentHistory<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Twox64Concat,
+        T::AccountId,
+        BoundedVec<(BlockNumberFor<T>, CommitmentInfo<T::MaxFields>), T::MaxCommitmentHistory>,
+        ValueQuery,
+    >;
+
+    /// The amount held on deposit for the commitment history of an account
+    #[pallet::storage]
+    pub type CommitmentHistoryDeposit<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
     /// Maps (netuid, who) -> usage (how many “bytes” they've committed)
     /// in the RateLimit window
     #[pallet::storage]
@@ -183,6 +239,24 @@ pub mod pallet {
     pub type UsedSpaceOf<T: Config> =
         StorageDoubleMap<_, Identity, u16, Twox64Concat, T::AccountId, UsageTracker, OptionQuery>;
 
+    /// The commitment schema of each subnet, if its owner registered one
+    #[pallet::storage]
+    pub type CommitmentSchemas<T: Config> =
+        StorageMap<_, Identity, u16, CommitmentSchemaOf<T>, OptionQuery>;
+
+    /// The positions, in the commitment as it was set, of the timelocked fields removed from
+    /// the commitment of an account once their reveal round passed
+    #[pallet::storage]
+    pub type RevealedFieldIndices<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Twox64Concat,
+        T::AccountId,
+        BoundedVec<u32, T::MaxFields>,
+        ValueQuery,
+    >;
+
     #[pallet::type_value]
     /// The default Maximum Space
     pub fn DefaultMaxSpace() -> u32 {
@@ -199,8 +273,8 @@ pub mod pallet {
         #[pallet::call_index(0)]
         #[pallet::weight((
             Weight::from_parts(38_000_000, 0)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64)),
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64)),
             DispatchClass::Operational,
             Pays::No
         ))]
@@ -220,6 +294,13 @@ pub mod pallet {
                 extra_fields <= T::MaxFields::get(),
                 Error::<T>::TooManyFieldsInCommitmentInfo
             );
+            Self::ensure_matches_schema(netuid, &info)?;
+            ensure!(
+                Self::timelocked_rounds(&info).all(|(beacon_id, _)| {
+                    pallet_drand::Pallet::<T>::beacon_config(beacon_id).is_some()
+                }),
+                Error::<T>::UnknownDrandBeacon
+            );
 
             let cur_block = <frame_system::Pallet<T>>::block_number();
 
@@ -260,8 +341,10 @@ pub mod pallet {
 
             UsedSpaceOf::<T>::insert(netuid, &who, usage);
 
+            let mut previous_rounds = Vec::new();
             let mut id = match <CommitmentOf<T>>::get(netuid, &who) {
                 Some(mut id) => {
+                    previous_rounds = Self::timelocked_rounds(&id.info).collect();
                     id.info = *info.clone();
                     id.block = cur_block;
                     id
@@ -289,16 +372,24 @@ pub mod pallet {
 
             <CommitmentOf<T>>::insert(netuid, &who, id);
             <LastCommitment<T>>::insert(netuid, &who, cur_block);
+            RevealedFieldIndices::<T>::remove(netuid, &who);
 
-            if let Some(Data::TimelockEncrypted { reveal_round, .. }) = info
-                .fields
-                .iter()
-                .find(|data| matches!(data, Data::TimelockEncrypted { .. }))
-            {
+            // Keep the drand pulses the new fields are revealed with, the replaced fields no
+            // longer need theirs
+            for (beacon_id, round) in Self::timelocked_rounds(&info) {
+                pallet_drand::Pallet::<T>::reference_round(beacon_id, round);
+            }
+            for (beacon_id, round) in previous_rounds {
+                pallet_drand::Pallet::<T>::release_round(beacon_id, round);
+            }
+            Self::record_history(netuid, &who, cur_block, &info);
+
+            if let Some((beacon_id, reveal_round)) = Self::timelocked_rounds(&info).next() {
                 Self::deposit_event(Event::TimelockCommitment {
                     netuid,
                     who: who.clone(),
-                    reveal_round: *reveal_round,
+                    beacon_id,
+                    reveal_round,
                 });
 
                 TimelockedIndex::<T>::mutate(|index| {
@@ -347,6 +438,59 @@ pub mod pallet {
             MaxSpace::<T>::set(new_limit);
             Ok(())
         }
+
+        /// Set the schema the commitments of a subnet are validated against.
+        ///
+        /// The fields of a commitment are matched in order against the fields of the schema,
+        /// and may be fewer. `Data::None` leaves a field empty.
+        ///
+        /// Only callable by root or the owner of the subnet.
+        #[pallet::call_index(3)]
+        #[pallet::weight((
+            Weight::from_parts(12_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn set_commitment_schema(
+            origin: OriginFor<T>,
+            netuid: u16,
+            schema: CommitmentSchemaOf<T>,
+        ) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            let mut names = BTreeSet::new();
+            ensure!(
+                schema
+                    .iter()
+                    .all(|field| !field.name.is_empty() && names.insert(&field.name)),
+                Error::<T>::InvalidCommitmentSchema
+            );
+
+            CommitmentSchemas::<T>::insert(netuid, schema);
+            Self::deposit_event(Event::CommitmentSchemaSet { netuid });
+            Ok(())
+        }
+
+        /// Remove the commitment schema of a subnet, so its commitments are no longer validated.
+        ///
+        /// Only callable by root or the owner of the subnet.
+        #[pallet::call_index(4)]
+        #[pallet::weight((
+            Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn remove_commitment_schema(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            CommitmentSchemas::<T>::remove(netuid);
+            Self::deposit_event(Event::CommitmentSchemaRemoved { netuid });
+            Ok(())
+        }
     }
 
     #[pallet::hooks]
@@ -361,6 +505,10 @@ pub mod pallet {
             }
             Weight::from_parts(0, 0)
         }
+
+        fn on_runtime_upgrade() -> Weight {
+            migrations::migrate_reference_timelocked_rounds::<T>()
+        }
     }
 }
 
@@ -375,6 +523,16 @@ impl<A> CanCommit<A> for () {
     }
 }
 
+pub trait IsSubnetOwner<AccountId> {
+    fn is_subnet_owner(netuid: u16, who: &AccountId) -> bool;
+}
+
+impl<A> IsSubnetOwner<A> for () {
+    fn is_subnet_owner(_: u16, _: &A) -> bool {
+        false
+    }
+}
+
 pub trait OnMetadataCommitment<AccountId> {
     fn on_metadata_commitment(netuid: u16, account: &AccountId);
 }
@@ -503,6 +661,177 @@ where
 }
 
 impl<T: Config> Pallet<T> {
+    fn ensure_subnet_owner_or_root(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
+        match frame_system::ensure_signed_or_root(origin)? {
+            Some(who) if !T::SubnetOwner::is_subnet_owner(netuid, &who) => {
+                Err(Error::<T>::NotSubnetOwner.into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Ensures the fields of a commitment match the schema of the subnet, if it has one.
+    pub fn ensure_matches_schema(
+        netuid: u16,
+        info: &CommitmentInfo<T::MaxFields>,
+    ) -> DispatchResult {
+        let Some(schema) = CommitmentSchemas::<T>::get(netuid) else {
+            return Ok(());
+        };
+
+        ensure!(
+            info.fields.len() <= schema.len(),
+            Error::<T>::TooManyFieldsForSchema
+        );
+        ensure!(
+            info.fields
+                .iter()
+                .zip(schema.iter())
+                .all(|(data, field)| field.accepts(data)),
+            Error::<T>::CommitmentFieldMismatch
+        );
+        Ok(())
+    }
+
+    /// Returns the commitment of an account decoded against the schema of the subnet.
+    ///
+    /// Timelocked fields are removed from the commitment once revealed, their positions are
+    /// kept in `RevealedFieldIndices` so the schema fields they held are reported as `Revealed`
+    /// and the following fields are still decoded against their own schema fields.
+    pub fn get_structured_commitment(
+        netuid: u16,
+        who: &T::AccountId,
+    ) -> Option<Vec<StructuredField>> {
+        let schema = CommitmentSchemas::<T>::get(netuid)?;
+        let revealed = RevealedFieldIndices::<T>::get(netuid, who);
+        let fields = match <CommitmentOf<T>>::get(netuid, who) {
+            Some(registration) => registration.info.fields.into_inner(),
+            // every field was timelocked and revealed
+            None if !revealed.is_empty() => Vec::new(),
+            None => return None,
+        };
+
+        let mut fields = fields.into_iter();
+        let structured = schema
+            .into_iter()
+            .zip(0u32..)
+            .map(|(field, index)| {
+                let value = if revealed.contains(&index) {
+                    CommitmentValue::Revealed
+                } else {
+                    fields
+                        .next()
+                        .map(|data| field.decode(data))
+                        .unwrap_or(CommitmentValue::None)
+                };
+
+                StructuredField {
+                    name: field.name.into_inner(),
+                    value,
+                }
+            })
+            .collect();
+
+        Some(structured)
+    }
+
+    /// Returns the commitments of a subnet decoded against its schema.
+    pub fn get_structured_commitments(netuid: u16) -> Vec<(T::AccountId, Vec<StructuredField>)> {
+        if !CommitmentSchemas::<T>::contains_key(netuid) {
+            return Vec::new();
+        }
+
+        let fully_revealed = RevealedFieldIndices::<T>::iter_key_prefix(netuid)
+            .filter(|who| !<CommitmentOf<T>>::contains_key(netuid, who));
+        <CommitmentOf<T>>::iter_key_prefix(netuid)
+            .chain(fully_revealed)
+            .filter_map(|who| {
+                Self::get_structured_commitment(netuid, &who).map(|fields| (who, fields))
+            })
+            .collect()
+    }
+
+    /// Append a commitment to the history of an account, dropping the oldest one when full.
+    ///
+    /// Each kept commitment holds `HistoryDeposit`. The commitment is not kept when the history
+    /// is not full and the account can't hold the deposit of one more commitment.
+    fn record_history(
+        netuid: u16,
+        who: &T::AccountId,
+        block: BlockNumberFor<T>,
+        info: &CommitmentInfo<T::MaxFields>,
+    ) {
+        let max_history = T::MaxCommitmentHistory::get();
+        if max_history == 0 {
+            return;
+        }
+
+        CommitmentHistory::<T>::mutate(netuid, who, |history| {
+            let len = history.len();
+            if u32::try_from(len).unwrap_or(u32::MAX) < max_history {
+                let deposit = T::HistoryDeposit::get();
+                if T::Currency::reserve(who, deposit).is_err() {
+                    return;
+                }
+                CommitmentHistoryDeposit::<T>::mutate(netuid, who, |held| {
+                    *held = held.saturating_add(deposit)
+                });
+            }
+            let _ = history.force_insert_keep_right(len, (block, info.clone()));
+        });
+    }
+
+    /// Remove the commitment history of an account and release its deposit.
+    fn clear_history(netuid: u16, who: &T::AccountId) {
+        CommitmentHistory::<T>::remove(netuid, who);
+        let deposit = CommitmentHistoryDeposit::<T>::take(netuid, who);
+        let _ = T::Currency::unreserve(who, deposit);
+    }
+
+    /// Returns the last commitments of an account with the blocks they were set at, oldest
+    /// first.
+    pub fn get_commitment_history(netuid: u16, who: &T::AccountId) -> Vec<(u64, Vec<Data>)> {
+        CommitmentHistory::<T>::get(netuid, who)
+            .into_iter()
+            .map(|(block, info)| (block.saturated_into::<u64>(), info.fields.into_inner()))
+            .collect()
+    }
+
+    /// Returns the fields changed by each commitment in the history of an account, compared
+    /// to the commitment before it. The oldest commitment is compared to an empty one.
+    pub fn get_commitment_diffs(
+        netuid: u16,
+        who: &T::AccountId,
+    ) -> Vec<(u64, Vec<CommitmentFieldChange>)> {
+        let mut previous: Vec<Data> = Vec::new();
+        Self::get_commitment_history(netuid, who)
+            .into_iter()
+            .map(|(block, current)| {
+                let len = previous.len().max(current.len());
+                let changes = (0..len)
+                    .filter_map(|index| {
+                        let before = previous.get(index).cloned().unwrap_or_default();
+                        let after = current.get(index).cloned().unwrap_or_default();
+                        (before != after).then(|| CommitmentFieldChange {
+                            index: u32::try_from(index).unwrap_or(u32::MAX),
+                            previous: before,
+                            current: after,
+                        })
+                    })
+                    .collect();
+                previous = current;
+                (block, changes)
+            })
+            .collect()
+    }
+
+    /// The drand beacons and rounds the timelocked fields of a commitment are revealed with.
+    pub(crate) fn timelocked_rounds<FieldLimit: Get<u32>>(
+        info: &CommitmentInfo<FieldLimit>,
+    ) -> impl Iterator<Item = (BeaconId, RoundNumber)> + '_ {
+        info.fields.iter().filter_map(Data::timelock_round)
+    }
+
     pub fn reveal_timelocked_commitments() -> DispatchResult {
         let index = TimelockedIndex::<T>::get();
         for (netuid, who) in index.clone() {
@@ -517,80 +846,54 @@ impl<T: Config> Pallet<T> {
             let mut remain_fields = Vec::new();
             let mut revealed_fields = Vec::new();
 
-            for data in original_fields {
-                match data {
+            // the positions of the remaining fields in the commitment as it was set
+            let mut revealed_indices = RevealedFieldIndices::<T>::get(netuid, &who).into_inner();
+            let positions: Vec<u32> = (0u32..)
+                .filter(|index| !revealed_indices.contains(index))
+                .take(original_fields.len())
+                .collect();
+            let previously_revealed = revealed_indices.len();
+
+            for (data, position) in original_fields.into_iter().zip(positions) {
+                let (encrypted, beacon_id, reveal_round) = match &data {
                     Data::TimelockEncrypted {
                         encrypted,
                         reveal_round,
-                    } => {
-                        let pulse = match pallet_drand::Pulses::<T>::get(reveal_round) {
-                            Some(p) => p,
-                            None => {
-                                remain_fields.push(Data::TimelockEncrypted {
-                                    encrypted,
-                                    reveal_round,
-                                });
-                                continue;
-                            }
-                        };
-
-                        let signature_bytes = pulse
-                            .signature
-                            .strip_prefix(b"0x")
-                            .unwrap_or(&pulse.signature);
-                        let sig_reader = &mut &signature_bytes[..];
-                        let sig =
-                            <TinyBLS381 as EngineBLS>::SignatureGroup::deserialize_compressed(
-                                sig_reader,
-                            )
-                            .map_err(|e| {
-                                log::warn!(
-                                    "Failed to deserialize drand signature for {:?}: {:?}",
-                                    who,
-                                    e
-                                )
-                            })
-                            .ok();
-
-                        let Some(sig) = sig else {
-                            log::warn!("No sig after deserialization");
-                            continue;
-                        };
-
-                        let reader = &mut &encrypted[..];
-                        let commit = TLECiphertext::<TinyBLS381>::deserialize_compressed(reader)
-                            .map_err(|e| {
-                                log::warn!(
-                                    "Failed to deserialize TLECiphertext for {:?}: {:?}",
-                                    who,
-                                    e
-                                )
-                            })
-                            .ok();
-
-                        let Some(commit) = commit else {
-                            log::warn!("No commit after deserialization");
-                            continue;
-                        };
-
-                        let decrypted_bytes: Vec<u8> =
-                            tld::<TinyBLS381, AESGCMStreamCipherProvider>(commit, sig)
-                                .map_err(|e| {
-                                    log::warn!("Failed to decrypt timelock for {:?}: {:?}", who, e)
-                                })
-                                .ok()
-                                .unwrap_or_default();
-
-                        if decrypted_bytes.is_empty() {
-                            log::warn!("Bytes were decrypted for {:?} but they are empty", who);
-                            continue;
-                        }
-
-                        revealed_fields.push(decrypted_bytes);
+                    } => (encrypted, DEFAULT_BEACON_ID, *reveal_round),
+                    Data::BeaconTimelockEncrypted {
+                        encrypted,
+                        beacon_id,
+                        reveal_round,
+                    } => (encrypted, *beacon_id, *reveal_round),
+                    _ => {
+                        remain_fields.push(data);
+                        continue;
                     }
-
-                    other => remain_fields.push(other),
+                };
+
+                let Some(pulse) = pallet_drand::Pallet::<T>::pulse(beacon_id, reveal_round) else {
+                    remain_fields.push(data);
+                    continue;
+                };
+                // The field is consumed from here on, whether it can be decrypted or not
+                pallet_drand::Pallet::<T>::release_round(beacon_id, reveal_round);
+                revealed_indices.push(position);
+
+                // Decrypted on the curve of the beacon the field was encrypted to
+                let decrypted_bytes: Vec<u8> =
+                    pallet_drand::Pallet::<T>::timelock_decrypt(beacon_id, encrypted, &pulse)
+                        .map_err(|e| {
+                            log::warn!("Failed to decrypt timelock for {:?}: {:?}", who, e)
+                        })
+                        .ok()
+                        .unwrap_or_default();
+
+                if decrypted_bytes.is_empty() {
+                    log::warn!("Bytes were decrypted for {:?} but they are empty", who);
+                    continue;
                 }
+
+                revealed_fields.push(decrypted_bytes);
             }
 
             if !revealed_fields.is_empty() {
@@ -622,9 +925,19 @@ impl<T: Config> Pallet<T> {
             registration.info.fields = BoundedVec::try_from(remain_fields)
                 .map_err(|_| "Failed to build BoundedVec for remain_fields")?;
 
+            if revealed_indices.len() != previously_revealed {
+                revealed_indices.sort_unstable();
+                RevealedFieldIndices::<T>::insert(
+                    netuid,
+                    &who,
+                    BoundedVec::truncate_from(revealed_indices),
+                );
+            }
+
             match registration.info.fields.is_empty() {
                 true => {
                     <CommitmentOf<T>>::remove(netuid, &who);
+                    Self::clear_history(netuid, &who);
                     TimelockedIndex::<T>::mutate(|idx| {
                         idx.remove(&(netuid, who.clone()));
                     });
@@ -635,7 +948,7 @@ impl<T: Config> Pallet<T> {
                         .info
                         .fields
                         .iter()
-                        .any(|f| matches!(f, Data::TimelockEncrypted { .. }));
+                        .any(Data::is_timelock_encrypted);
                     if !has_timelock {
                         TimelockedIndex::<T>::mutate(|idx| {
                             idx.remove(&(netuid, who.clone()));
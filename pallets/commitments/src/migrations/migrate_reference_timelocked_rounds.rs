@@ -0,0 +1,45 @@
+use frame_support::{
+    traits::{Get, GetStorageVersion, StorageVersion},
+    weights::Weight,
+};
+
+use crate::*;
+
+/// References the drand rounds of the timelocked commitments stored before the rounds were
+/// tracked, so their pulses are kept until they are revealed.
+pub fn migrate_reference_timelocked_rounds<T: Config>() -> Weight {
+    let mut weight = T::DbWeight::get().reads(1);
+
+    if Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(1) {
+        log::info!("Timelocked commitment rounds are already referenced. Skipping.");
+        return weight;
+    }
+
+    let index = TimelockedIndex::<T>::get();
+    weight.saturating_accrue(T::DbWeight::get().reads(1));
+
+    let mut referenced_rounds_count = 0u64;
+    for (netuid, who) in index {
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+        let Some(registration) = CommitmentOf::<T>::get(netuid, &who) else {
+            continue;
+        };
+        for (beacon_id, round) in Pallet::<T>::timelocked_rounds(&registration.info) {
+            pallet_drand::Pallet::<T>::reference_round(beacon_id, round);
+            referenced_rounds_count.saturating_inc();
+        }
+    }
+    weight.saturating_accrue(
+        T::DbWeight::get().reads_writes(referenced_rounds_count, referenced_rounds_count),
+    );
+
+    StorageVersion::new(1).put::<Pallet<T>>();
+    weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Referenced {} drand rounds of timelocked commitments.",
+        referenced_rounds_count
+    );
+
+    weight
+}
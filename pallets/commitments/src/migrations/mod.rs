@@ -0,0 +1,2 @@
+mod migrate_reference_timelocked_rounds;
+pub use migrate_reference_timelocked_rounds::*;
@@ -92,14 +92,26 @@ impl pallet_commitments::CanCommit<u64> for TestCanCommit {
     }
 }
 
+pub const SUBNET_OWNER: u64 = 99;
+
+pub struct TestSubnetOwner;
+impl pallet_commitments::IsSubnetOwner<u64> for TestSubnetOwner {
+    fn is_subnet_owner(_netuid: u16, who: &u64) -> bool {
+        *who == SUBNET_OWNER
+    }
+}
+
 impl pallet_commitments::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type WeightInfo = ();
     type MaxFields = TestMaxFields;
     type CanCommit = TestCanCommit;
+    type SubnetOwner = TestSubnetOwner;
     type FieldDeposit = ConstU64<0>;
     type InitialDeposit = ConstU64<0>;
+    type MaxCommitmentHistory = ConstU32<3>;
+    type HistoryDeposit = ConstU64<10>;
     type TempoInterface = MockTempoInterface;
     type OnMetadataCommitment = ();
 }
@@ -119,9 +131,10 @@ impl pallet_commitments::GetTempoInterface for MockTempoInterface {
 impl pallet_drand::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type AuthorityId = test_crypto::TestAuthId;
-    type Verifier = pallet_drand::verifier::QuicknetVerifier;
+    type Verifier = pallet_drand::verifier::SchemeVerifier;
     type UnsignedPriority = ConstU64<{ 1 << 20 }>;
     type HttpFetchTimeout = ConstU64<1_000>;
+    type MaxPulsesPrunedPerBlock = ConstU32<64>;
 }
 
 pub mod test_crypto {
@@ -3,11 +3,13 @@ use sp_std::prelude::*;
 
 #[cfg(test)]
 use crate::{
-    BalanceOf, CommitmentInfo, CommitmentOf, Config, Data, Error, Event, MaxSpace, Pallet,
-    Registration, RevealedCommitments, TimelockedIndex, UsedSpaceOf,
+    BalanceOf, CommitmentFieldChange, CommitmentFieldType, CommitmentHistory,
+    CommitmentHistoryDeposit, CommitmentInfo, CommitmentOf, CommitmentSchemaField,
+    CommitmentSchemas, CommitmentValue, Config, Data, Error, Event, MaxSpace, Pallet, Registration,
+    RevealedCommitments, StructuredField, TimelockedIndex, UsedSpaceOf,
     mock::{
         Balances, DRAND_QUICKNET_SIG_2000_HEX, DRAND_QUICKNET_SIG_HEX, RuntimeEvent, RuntimeOrigin,
-        Test, TestMaxFields, insert_drand_pulse, new_test_ext, produce_ciphertext,
+        SUBNET_OWNER, Test, TestMaxFields, insert_drand_pulse, new_test_ext, produce_ciphertext,
     },
 };
 use frame_support::pallet_prelude::Hooks;
@@ -16,6 +18,7 @@ use frame_support::{
     traits::{Currency, Get, ReservableCurrency},
 };
 use frame_system::{Pallet as System, RawOrigin};
+use pallet_drand::{ReferencedRounds, types::DEFAULT_BEACON_ID};
 
 #[allow(clippy::indexing_slicing)]
 #[test]
@@ -36,6 +39,7 @@ fn manual_data_type_info() {
             Data::TimelockEncrypted { .. } => "TimelockEncrypted".to_string(),
             Data::ResetBondsFlag => "ResetBondsFlag".to_string(),
             Data::BigRaw(_) => "BigRaw".to_string(),
+            Data::BeaconTimelockEncrypted { .. } => "BeaconTimelockEncrypted".to_string(),
         };
         if let scale_info::TypeDef::Variant(variant) = &type_info.type_def {
             let variant = variant
@@ -66,6 +70,16 @@ fn manual_data_type_info() {
                         let reveal_round_len = reveal_round.encode().len() as u32; // Typically 8 bytes
                         encrypted_len + reveal_round_len
                     }
+                    Data::BeaconTimelockEncrypted {
+                        encrypted,
+                        beacon_id,
+                        reveal_round,
+                    } => {
+                        let encrypted_len = encrypted.encode().len() as u32;
+                        let beacon_id_len = beacon_id.encode().len() as u32;
+                        let reveal_round_len = reveal_round.encode().len() as u32;
+                        encrypted_len + beacon_id_len + reveal_round_len
+                    }
                     Data::ResetBondsFlag => 0,
                 };
                 assert_eq!(
@@ -110,6 +124,11 @@ fn manual_data_type_info() {
         encrypted: vec![0u8; 64].try_into().expect("Expected not to panic"),
         reveal_round: 12345,
     });
+    data.push(Data::BeaconTimelockEncrypted {
+        encrypted: vec![0u8; 64].try_into().expect("Expected not to panic"),
+        beacon_id: 1,
+        reveal_round: 12345,
+    });
 
     for d in data.iter() {
         check_type_info(d);
@@ -264,6 +283,10 @@ fn happy_path_timelock_commitments() {
             netuid,
             Box::new(info_outer)
         ));
+        assert_eq!(
+            ReferencedRounds::<Test>::get(DEFAULT_BEACON_ID, reveal_round),
+            Some(1)
+        );
 
         let drand_signature_bytes =
             hex::decode(DRAND_QUICKNET_SIG_HEX).expect("Expected not to panic");
@@ -271,6 +294,7 @@ fn happy_path_timelock_commitments() {
 
         System::<Test>::set_block_number(9999);
         assert_ok!(Pallet::<Test>::reveal_timelocked_commitments());
+        assert!(ReferencedRounds::<Test>::get(DEFAULT_BEACON_ID, reveal_round).is_none());
 
         let revealed =
             RevealedCommitments::<Test>::get(netuid, who).expect("Should have revealed data");
@@ -289,6 +313,69 @@ fn happy_path_timelock_commitments() {
     });
 }
 
+#[test]
+fn timelock_commitments_to_an_additional_beacon() {
+    new_test_ext().execute_with(|| {
+        let message_text = b"Hello beacon!";
+        let reveal_round = 1000;
+        let beacon_id = 1;
+        let data = Data::BeaconTimelockEncrypted {
+            encrypted: produce_ciphertext(message_text, reveal_round),
+            beacon_id,
+            reveal_round,
+        };
+        let info = CommitmentInfo {
+            fields: BoundedVec::try_from(vec![data]).expect("Too many fields"),
+        };
+
+        let who = 123;
+        let netuid = 42;
+        System::<Test>::set_block_number(1);
+
+        // The beacon must be bridged
+        assert_noop!(
+            Pallet::<Test>::set_commitment(
+                RuntimeOrigin::signed(who),
+                netuid,
+                Box::new(info.clone())
+            ),
+            Error::<Test>::UnknownDrandBeacon
+        );
+
+        // A quicknet-like beacon other than the default one
+        pallet_drand::Beacons::<Test>::insert(beacon_id, pallet_drand::BeaconConfig::<Test>::get());
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            Box::new(info)
+        ));
+        assert_eq!(
+            ReferencedRounds::<Test>::get(beacon_id, reveal_round),
+            Some(1)
+        );
+        assert!(ReferencedRounds::<Test>::get(DEFAULT_BEACON_ID, reveal_round).is_none());
+
+        // The pulse of the default beacon doesn't reveal it
+        let drand_signature_bytes =
+            hex::decode(DRAND_QUICKNET_SIG_HEX).expect("Expected not to panic");
+        insert_drand_pulse(reveal_round, &drand_signature_bytes);
+        assert_ok!(Pallet::<Test>::reveal_timelocked_commitments());
+        assert!(RevealedCommitments::<Test>::get(netuid, who).is_none());
+
+        let pulse = pallet_drand::Pulses::<Test>::get(reveal_round).expect("pulse inserted");
+        pallet_drand::BeaconPulses::<Test>::insert(beacon_id, reveal_round, pulse);
+        assert_ok!(Pallet::<Test>::reveal_timelocked_commitments());
+        assert!(ReferencedRounds::<Test>::get(beacon_id, reveal_round).is_none());
+
+        let revealed =
+            RevealedCommitments::<Test>::get(netuid, who).expect("Should have revealed data");
+        assert_eq!(
+            revealed.first().map(|(bytes, _)| bytes.as_slice()),
+            Some(&message_text[..])
+        );
+    });
+}
+
 #[test]
 fn reveal_timelocked_commitment_missing_round_does_nothing() {
     new_test_ext().execute_with(|| {
@@ -315,6 +402,88 @@ fn reveal_timelocked_commitment_missing_round_does_nothing() {
     });
 }
 
+#[test]
+fn replaced_timelocked_commitment_releases_its_round() {
+    new_test_ext().execute_with(|| {
+        let who = 1;
+        let netuid = 2;
+        System::<Test>::set_block_number(5);
+        let timelocked = |reveal_round| {
+            let data = Data::TimelockEncrypted {
+                encrypted: produce_ciphertext(b"My plaintext", reveal_round),
+                reveal_round,
+            };
+            let fields: BoundedVec<_, <Test as Config>::MaxFields> =
+                BoundedVec::try_from(vec![data]).expect("Expected not to panic");
+            Box::new(CommitmentInfo { fields })
+        };
+
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            timelocked(1000)
+        ));
+        assert_eq!(
+            ReferencedRounds::<Test>::get(DEFAULT_BEACON_ID, 1000),
+            Some(1)
+        );
+
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            timelocked(2000)
+        ));
+        assert!(ReferencedRounds::<Test>::get(DEFAULT_BEACON_ID, 1000).is_none());
+        assert_eq!(
+            ReferencedRounds::<Test>::get(DEFAULT_BEACON_ID, 2000),
+            Some(1)
+        );
+    });
+}
+
+#[test]
+fn migrate_reference_timelocked_rounds_works() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+        let who = 1;
+        let netuid = 2;
+        let data = Data::TimelockEncrypted {
+            encrypted: produce_ciphertext(b"My plaintext", 1000),
+            reveal_round: 1000,
+        };
+        let fields: BoundedVec<_, <Test as Config>::MaxFields> =
+            BoundedVec::try_from(vec![data]).expect("Expected not to panic");
+        CommitmentOf::<Test>::insert(
+            netuid,
+            who,
+            Registration {
+                deposit: Default::default(),
+                block: 1,
+                info: CommitmentInfo { fields },
+            },
+        );
+        TimelockedIndex::<Test>::mutate(|index| {
+            index.insert((netuid, who));
+        });
+        StorageVersion::new(0).put::<Pallet<Test>>();
+
+        crate::migrations::migrate_reference_timelocked_rounds::<Test>();
+        assert_eq!(
+            ReferencedRounds::<Test>::get(DEFAULT_BEACON_ID, 1000),
+            Some(1)
+        );
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+
+        // Running it again does not reference the rounds twice
+        crate::migrations::migrate_reference_timelocked_rounds::<Test>();
+        assert_eq!(
+            ReferencedRounds::<Test>::get(DEFAULT_BEACON_ID, 1000),
+            Some(1)
+        );
+    });
+}
+
 #[allow(clippy::indexing_slicing)]
 #[test]
 fn reveal_timelocked_commitment_cant_deserialize_ciphertext() {
@@ -2185,3 +2354,601 @@ fn mixed_timelocked_and_raw_fields_works() {
         );
     });
 }
+
+fn schema_field(
+    name: &[u8],
+    field_type: CommitmentFieldType,
+    max_len: u32,
+) -> CommitmentSchemaField {
+    CommitmentSchemaField {
+        name: name.to_vec().try_into().expect("name within bounds"),
+        field_type,
+        max_len,
+    }
+}
+
+fn commitment(fields: Vec<Data>) -> Box<CommitmentInfo<TestMaxFields>> {
+    Box::new(CommitmentInfo {
+        fields: BoundedVec::try_from(fields).expect("within max fields"),
+    })
+}
+
+fn raw(bytes: &[u8]) -> Data {
+    Data::Raw(
+        bytes
+            .to_vec()
+            .try_into()
+            .expect("<= 128 bytes for Raw variant"),
+    )
+}
+
+fn set_test_schema(netuid: u16) {
+    assert_ok!(Pallet::<Test>::set_commitment_schema(
+        RuntimeOrigin::signed(SUBNET_OWNER),
+        netuid,
+        BoundedVec::try_from(vec![
+            schema_field(b"endpoint", CommitmentFieldType::Text, 16),
+            schema_field(b"version", CommitmentFieldType::U64, 0),
+            schema_field(b"model", CommitmentFieldType::Hash, 0),
+        ])
+        .expect("within max fields"),
+    ));
+}
+
+#[test]
+fn set_commitment_schema_requires_subnet_owner_or_root() {
+    new_test_ext().execute_with(|| {
+        System::<Test>::set_block_number(1);
+        let netuid = 1;
+        let schema: BoundedVec<_, TestMaxFields> =
+            BoundedVec::try_from(vec![schema_field(b"a", CommitmentFieldType::Bytes, 8)])
+                .expect("within max fields");
+
+        assert_noop!(
+            Pallet::<Test>::set_commitment_schema(RuntimeOrigin::signed(1), netuid, schema.clone()),
+            Error::<Test>::NotSubnetOwner
+        );
+
+        assert_ok!(Pallet::<Test>::set_commitment_schema(
+            RuntimeOrigin::signed(SUBNET_OWNER),
+            netuid,
+            schema.clone()
+        ));
+        assert_eq!(CommitmentSchemas::<Test>::get(netuid), Some(schema.clone()));
+        System::<Test>::assert_last_event(RuntimeEvent::Commitments(Event::CommitmentSchemaSet {
+            netuid,
+        }));
+
+        assert_noop!(
+            Pallet::<Test>::remove_commitment_schema(RuntimeOrigin::signed(1), netuid),
+            Error::<Test>::NotSubnetOwner
+        );
+        assert_ok!(Pallet::<Test>::remove_commitment_schema(
+            RawOrigin::Root.into(),
+            netuid
+        ));
+        assert!(CommitmentSchemas::<Test>::get(netuid).is_none());
+        System::<Test>::assert_last_event(RuntimeEvent::Commitments(
+            Event::CommitmentSchemaRemoved { netuid },
+        ));
+    });
+}
+
+#[test]
+fn set_commitment_schema_rejects_invalid_names() {
+    new_test_ext().execute_with(|| {
+        for fields in [
+            vec![schema_field(b"", CommitmentFieldType::Bytes, 8)],
+            vec![
+                schema_field(b"a", CommitmentFieldType::Bytes, 8),
+                schema_field(b"a", CommitmentFieldType::U64, 0),
+            ],
+        ] {
+            assert_noop!(
+                Pallet::<Test>::set_commitment_schema(
+                    RawOrigin::Root.into(),
+                    1,
+                    BoundedVec::try_from(fields).expect("within max fields")
+                ),
+                Error::<Test>::InvalidCommitmentSchema
+            );
+        }
+    });
+}
+
+#[test]
+fn set_commitment_is_validated_against_schema() {
+    new_test_ext().execute_with(|| {
+        System::<Test>::set_block_number(1);
+        let netuid = 1;
+        set_test_schema(netuid);
+
+        let invalid = [
+            (
+                vec![
+                    raw(b"a"),
+                    raw(&7u64.encode()),
+                    Data::Sha256([1; 32]),
+                    Data::None,
+                ],
+                Error::<Test>::TooManyFieldsForSchema,
+            ),
+            (
+                vec![Data::Sha256([1; 32])],
+                Error::<Test>::CommitmentFieldMismatch,
+            ),
+            (
+                vec![raw(b"an endpoint longer than 16 bytes")],
+                Error::<Test>::CommitmentFieldMismatch,
+            ),
+            (
+                vec![raw(&[0xff, 0xfe])],
+                Error::<Test>::CommitmentFieldMismatch,
+            ),
+            (
+                vec![raw(b"a"), raw(&7u32.encode())],
+                Error::<Test>::CommitmentFieldMismatch,
+            ),
+            (
+                vec![raw(b"a"), raw(&7u64.encode()), raw(b"not a hash")],
+                Error::<Test>::CommitmentFieldMismatch,
+            ),
+        ];
+        for (fields, error) in invalid {
+            assert_noop!(
+                Pallet::<Test>::set_commitment(
+                    RuntimeOrigin::signed(1),
+                    netuid,
+                    commitment(fields)
+                ),
+                error
+            );
+        }
+
+        // fields can be left empty or omitted
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(1),
+            netuid,
+            commitment(vec![Data::None, raw(&7u64.encode())])
+        ));
+
+        // other subnets are not validated
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(1),
+            netuid + 1,
+            commitment(vec![Data::Sha256([1; 32])])
+        ));
+    });
+}
+
+#[test]
+fn get_structured_commitment_decodes_fields() {
+    new_test_ext().execute_with(|| {
+        System::<Test>::set_block_number(1);
+        let netuid = 1;
+        let who = 1;
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            commitment(vec![raw(b"1.2.3.4:8091"), raw(&7u64.encode())])
+        ));
+        assert!(Pallet::<Test>::get_structured_commitment(netuid, &who).is_none());
+
+        set_test_schema(netuid);
+
+        assert_eq!(
+            Pallet::<Test>::get_structured_commitment(netuid, &who),
+            Some(vec![
+                StructuredField {
+                    name: b"endpoint".to_vec(),
+                    value: CommitmentValue::Text(b"1.2.3.4:8091".to_vec()),
+                },
+                StructuredField {
+                    name: b"version".to_vec(),
+                    value: CommitmentValue::U64(7),
+                },
+                StructuredField {
+                    name: b"model".to_vec(),
+                    value: CommitmentValue::None,
+                },
+            ])
+        );
+        assert_eq!(
+            Pallet::<Test>::get_structured_commitments(netuid)
+                .into_iter()
+                .map(|(account, _)| account)
+                .collect::<Vec<_>>(),
+            vec![who]
+        );
+    });
+}
+
+#[test]
+fn get_structured_commitment_reports_revealed_fields() {
+    new_test_ext().execute_with(|| {
+        System::<Test>::set_block_number(1);
+        let netuid = 1;
+        let who = 1;
+        let reveal_round = 1000;
+        assert_ok!(Pallet::<Test>::set_commitment_schema(
+            RawOrigin::Root.into(),
+            netuid,
+            BoundedVec::try_from(vec![
+                schema_field(b"secret", CommitmentFieldType::TimelockEncrypted, 1024),
+                schema_field(b"version", CommitmentFieldType::U64, 0),
+            ])
+            .expect("within max fields"),
+        ));
+
+        let encrypted = produce_ciphertext(b"secret", reveal_round);
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            commitment(vec![
+                Data::TimelockEncrypted {
+                    encrypted,
+                    reveal_round,
+                },
+                raw(&7u64.encode()),
+            ])
+        ));
+        assert_eq!(
+            Pallet::<Test>::get_structured_commitment(netuid, &who)
+                .expect("commitment exists")
+                .first()
+                .map(|field| field.value.clone()),
+            Some(CommitmentValue::TimelockEncrypted {
+                beacon_id: DEFAULT_BEACON_ID,
+                reveal_round
+            })
+        );
+
+        let signature = hex::decode(DRAND_QUICKNET_SIG_HEX).expect("Expected not to panic");
+        insert_drand_pulse(reveal_round, &signature);
+        assert_ok!(Pallet::<Test>::reveal_timelocked_commitments());
+
+        assert_eq!(
+            Pallet::<Test>::get_structured_commitment(netuid, &who),
+            Some(vec![
+                StructuredField {
+                    name: b"secret".to_vec(),
+                    value: CommitmentValue::Revealed,
+                },
+                StructuredField {
+                    name: b"version".to_vec(),
+                    value: CommitmentValue::U64(7),
+                },
+            ])
+        );
+    });
+}
+
+fn set_schema(netuid: u16, fields: Vec<CommitmentSchemaField>) {
+    assert_ok!(Pallet::<Test>::set_commitment_schema(
+        RawOrigin::Root.into(),
+        netuid,
+        BoundedVec::try_from(fields).expect("within max fields"),
+    ));
+}
+
+fn timelocked(plaintext: &[u8], reveal_round: u64) -> Data {
+    Data::TimelockEncrypted {
+        encrypted: produce_ciphertext(plaintext, reveal_round),
+        reveal_round,
+    }
+}
+
+fn structured_values(netuid: u16, who: u64) -> Option<Vec<CommitmentValue>> {
+    Pallet::<Test>::get_structured_commitment(netuid, &who)
+        .map(|fields| fields.into_iter().map(|field| field.value).collect())
+}
+
+#[test]
+fn get_structured_commitment_keeps_positions_after_empty_fields() {
+    new_test_ext().execute_with(|| {
+        System::<Test>::set_block_number(1);
+        let netuid = 1;
+        let who = 1;
+        let reveal_round = 1000;
+        set_schema(
+            netuid,
+            vec![
+                schema_field(b"secret", CommitmentFieldType::TimelockEncrypted, 1024),
+                schema_field(b"payload", CommitmentFieldType::Bytes, 16),
+                schema_field(b"endpoint", CommitmentFieldType::Text, 16),
+            ],
+        );
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            commitment(vec![
+                timelocked(b"secret", reveal_round),
+                Data::None,
+                raw(b"1.2.3.4:8091"),
+            ])
+        ));
+
+        let signature = hex::decode(DRAND_QUICKNET_SIG_HEX).expect("Expected not to panic");
+        insert_drand_pulse(reveal_round, &signature);
+        assert_ok!(Pallet::<Test>::reveal_timelocked_commitments());
+
+        assert_eq!(
+            structured_values(netuid, who),
+            Some(vec![
+                CommitmentValue::Revealed,
+                CommitmentValue::None,
+                CommitmentValue::Text(b"1.2.3.4:8091".to_vec()),
+            ])
+        );
+    });
+}
+
+#[test]
+fn get_structured_commitment_reports_partially_revealed_timelocks() {
+    new_test_ext().execute_with(|| {
+        System::<Test>::set_block_number(1);
+        let netuid = 1;
+        let who = 1;
+        set_schema(
+            netuid,
+            vec![
+                schema_field(b"first", CommitmentFieldType::TimelockEncrypted, 1024),
+                schema_field(b"second", CommitmentFieldType::TimelockEncrypted, 1024),
+            ],
+        );
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            commitment(vec![
+                timelocked(b"first", 1000),
+                timelocked(b"second", 2000)
+            ])
+        ));
+
+        let signature = hex::decode(DRAND_QUICKNET_SIG_HEX).expect("Expected not to panic");
+        insert_drand_pulse(1000, &signature);
+        assert_ok!(Pallet::<Test>::reveal_timelocked_commitments());
+
+        assert_eq!(
+            structured_values(netuid, who),
+            Some(vec![
+                CommitmentValue::Revealed,
+                CommitmentValue::TimelockEncrypted {
+                    beacon_id: DEFAULT_BEACON_ID,
+                    reveal_round: 2000
+                },
+            ])
+        );
+
+        let signature = hex::decode(DRAND_QUICKNET_SIG_2000_HEX).expect("Expected not to panic");
+        insert_drand_pulse(2000, &signature);
+        assert_ok!(Pallet::<Test>::reveal_timelocked_commitments());
+
+        assert_eq!(
+            structured_values(netuid, who),
+            Some(vec![CommitmentValue::Revealed, CommitmentValue::Revealed])
+        );
+    });
+}
+
+#[test]
+fn get_structured_commitment_reports_fully_revealed_commitments() {
+    new_test_ext().execute_with(|| {
+        System::<Test>::set_block_number(1);
+        let netuid = 1;
+        let who = 1;
+        let reveal_round = 1000;
+        set_schema(
+            netuid,
+            vec![schema_field(
+                b"secret",
+                CommitmentFieldType::TimelockEncrypted,
+                1024,
+            )],
+        );
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            commitment(vec![timelocked(b"secret", reveal_round)])
+        ));
+
+        let signature = hex::decode(DRAND_QUICKNET_SIG_HEX).expect("Expected not to panic");
+        insert_drand_pulse(reveal_round, &signature);
+        assert_ok!(Pallet::<Test>::reveal_timelocked_commitments());
+
+        // The commitment is removed once every field is revealed
+        assert!(CommitmentOf::<Test>::get(netuid, who).is_none());
+        assert_eq!(
+            structured_values(netuid, who),
+            Some(vec![CommitmentValue::Revealed])
+        );
+        assert_eq!(
+            Pallet::<Test>::get_structured_commitments(netuid)
+                .into_iter()
+                .map(|(account, _)| account)
+                .collect::<Vec<_>>(),
+            vec![who]
+        );
+
+        // A new commitment starts from a clean layout
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            commitment(vec![Data::None])
+        ));
+        assert_eq!(
+            structured_values(netuid, who),
+            Some(vec![CommitmentValue::None])
+        );
+    });
+}
+
+#[test]
+fn commitment_history_keeps_the_last_commitments() {
+    new_test_ext().execute_with(|| {
+        let netuid = 1;
+        let who = 1;
+        let max_history: u32 = <Test as Config>::MaxCommitmentHistory::get();
+        let history_deposit: u64 = <Test as Config>::HistoryDeposit::get();
+        Balances::make_free_balance_be(&who, 1_000);
+
+        for block in 1..=u64::from(max_history) + 2 {
+            System::<Test>::set_block_number(block);
+            assert_ok!(Pallet::<Test>::set_commitment(
+                RuntimeOrigin::signed(who),
+                netuid,
+                commitment(vec![raw(&block.encode())])
+            ));
+        }
+
+        let history = Pallet::<Test>::get_commitment_history(netuid, &who);
+        assert_eq!(history.len(), max_history as usize);
+        assert_eq!(
+            history,
+            (3..=u64::from(max_history) + 2)
+                .map(|block| (block, vec![raw(&block.encode())]))
+                .collect::<Vec<_>>()
+        );
+        assert!(Pallet::<Test>::get_commitment_history(netuid + 1, &who).is_empty());
+
+        // Only the kept commitments hold a deposit
+        assert_eq!(
+            CommitmentHistoryDeposit::<Test>::get(netuid, who),
+            u64::from(max_history) * history_deposit
+        );
+        assert_eq!(
+            Balances::reserved_balance(who),
+            u64::from(max_history) * history_deposit
+        );
+    });
+}
+
+#[test]
+fn commitment_history_requires_a_deposit() {
+    new_test_ext().execute_with(|| {
+        let netuid = 1;
+        let who = 1;
+        let history_deposit: u64 = <Test as Config>::HistoryDeposit::get();
+
+        // The commitment is set but not kept without the deposit
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            commitment(vec![raw(b"a")])
+        ));
+        assert!(CommitmentOf::<Test>::get(netuid, who).is_some());
+        assert!(Pallet::<Test>::get_commitment_history(netuid, &who).is_empty());
+
+        Balances::make_free_balance_be(&who, history_deposit + 1);
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            commitment(vec![raw(b"b")])
+        ));
+        assert_eq!(
+            Pallet::<Test>::get_commitment_history(netuid, &who),
+            vec![(1, vec![raw(b"b")])]
+        );
+        assert_eq!(Balances::reserved_balance(who), history_deposit);
+    });
+}
+
+#[test]
+fn commitment_history_is_cleared_with_the_commitment() {
+    new_test_ext().execute_with(|| {
+        let netuid = 1;
+        let who = 1;
+        let reveal_round = 1000;
+        Balances::make_free_balance_be(&who, 1_000);
+
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            commitment(vec![Data::TimelockEncrypted {
+                encrypted: produce_ciphertext(b"revealed", reveal_round),
+                reveal_round,
+            }])
+        ));
+        assert_eq!(CommitmentHistory::<Test>::get(netuid, who).len(), 1);
+        assert!(Balances::reserved_balance(who) > 0);
+
+        // Revealing the only field removes the commitment, its history and their deposit
+        let signature = hex::decode(DRAND_QUICKNET_SIG_HEX).expect("Expected not to panic");
+        insert_drand_pulse(reveal_round, &signature);
+        assert_ok!(Pallet::<Test>::reveal_timelocked_commitments());
+
+        assert!(CommitmentOf::<Test>::get(netuid, who).is_none());
+        assert!(CommitmentHistory::<Test>::get(netuid, who).is_empty());
+        assert_eq!(CommitmentHistoryDeposit::<Test>::get(netuid, who), 0);
+        assert_eq!(Balances::reserved_balance(who), 0);
+    });
+}
+
+#[test]
+fn commitment_diffs_report_changed_fields() {
+    new_test_ext().execute_with(|| {
+        let netuid = 1;
+        let who = 1;
+        let endpoint = raw(b"1.2.3.4:8091");
+        Balances::make_free_balance_be(&who, 1_000);
+        let repo = Data::Sha256([1; 32]);
+        let new_repo = Data::Sha256([2; 32]);
+
+        System::<Test>::set_block_number(1);
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            commitment(vec![endpoint.clone(), repo.clone()])
+        ));
+        System::<Test>::set_block_number(2);
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            commitment(vec![endpoint.clone(), new_repo.clone()])
+        ));
+        System::<Test>::set_block_number(3);
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            commitment(vec![endpoint.clone()])
+        ));
+
+        assert_eq!(CommitmentHistory::<Test>::get(netuid, who).len(), 3);
+        assert_eq!(
+            Pallet::<Test>::get_commitment_diffs(netuid, &who),
+            vec![
+                (
+                    1,
+                    vec![
+                        CommitmentFieldChange {
+                            index: 0,
+                            previous: Data::None,
+                            current: endpoint,
+                        },
+                        CommitmentFieldChange {
+                            index: 1,
+                            previous: Data::None,
+                            current: repo.clone(),
+                        },
+                    ]
+                ),
+                (
+                    2,
+                    vec![CommitmentFieldChange {
+                        index: 1,
+                        previous: repo,
+                        current: new_repo.clone(),
+                    }]
+                ),
+                (
+                    3,
+                    vec![CommitmentFieldChange {
+                        index: 1,
+                        previous: new_repo,
+                        current: Data::None,
+                    }]
+                ),
+            ]
+        );
+    });
+}
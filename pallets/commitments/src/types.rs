@@ -20,6 +20,7 @@ use frame_support::{
     BoundedVec, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound,
     traits::{ConstU32, Get},
 };
+use pallet_drand::types::{BeaconId, DEFAULT_BEACON_ID, RoundNumber};
 use scale_info::{
     Path, Type, TypeInfo,
     build::{Fields, Variants},
@@ -35,7 +36,7 @@ use subtensor_macros::freeze_struct;
 /// - `Raw`: a direct blob up to 128 bytes
 /// - `BigRaw`: a larger blob up to 512 bytes
 /// - A cryptographic hash (BlakeTwo256, Sha256, Keccak256, ShaThree256)
-/// - A timelock-encrypted blob with a reveal round
+/// - A timelock-encrypted blob with a reveal round, of the default drand beacon or another one
 /// - A reset flag (`ResetBondsFlag`)
 ///   Can also be `None`.
 #[derive(Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
@@ -65,6 +66,12 @@ pub enum Data {
     ResetBondsFlag,
     /// The data is stored directly (up to 512 bytes).
     BigRaw(BoundedVec<u8, ConstU32<MAX_BIGRAW_COMMITMENT_SIZE_BYTES>>),
+    /// A commitment timelock-encrypted to a round of an additional drand beacon.
+    BeaconTimelockEncrypted {
+        encrypted: BoundedVec<u8, ConstU32<MAX_TIMELOCK_COMMITMENT_SIZE_BYTES>>,
+        beacon_id: BeaconId,
+        reveal_round: u64,
+    },
 }
 
 impl Data {
@@ -74,7 +81,22 @@ impl Data {
 
     /// Check if this is a timelock-encrypted commitment.
     pub fn is_timelock_encrypted(&self) -> bool {
-        matches!(self, Data::TimelockEncrypted { .. })
+        self.timelock_round().is_some()
+    }
+
+    /// The drand beacon and round a timelock-encrypted commitment is revealed with.
+    pub fn timelock_round(&self) -> Option<(BeaconId, RoundNumber)> {
+        match self {
+            Data::TimelockEncrypted { reveal_round, .. } => {
+                Some((DEFAULT_BEACON_ID, *reveal_round))
+            }
+            Data::BeaconTimelockEncrypted {
+                beacon_id,
+                reveal_round,
+                ..
+            } => Some((*beacon_id, *reveal_round)),
+            _ => None,
+        }
     }
 
     pub fn len_for_rate_limit(&self) -> u64 {
@@ -85,7 +107,8 @@ impl Data {
             | Data::Sha256(arr)
             | Data::Keccak256(arr)
             | Data::ShaThree256(arr) => arr.len() as u64,
-            Data::TimelockEncrypted { encrypted, .. } => encrypted.len() as u64,
+            Data::TimelockEncrypted { encrypted, .. }
+            | Data::BeaconTimelockEncrypted { encrypted, .. } => encrypted.len() as u64,
             Data::ResetBondsFlag => 0,
             Data::BigRaw(bytes) => bytes.len() as u64,
         }
@@ -123,6 +146,17 @@ impl Decode for Data {
                     BoundedVec::<u8, ConstU32<MAX_BIGRAW_COMMITMENT_SIZE_BYTES>>::decode(input)?;
                 Data::BigRaw(bigvec)
             }
+            137 => {
+                let encrypted =
+                    BoundedVec::<u8, ConstU32<MAX_TIMELOCK_COMMITMENT_SIZE_BYTES>>::decode(input)?;
+                let beacon_id = BeaconId::decode(input)?;
+                let reveal_round = u64::decode(input)?;
+                Data::BeaconTimelockEncrypted {
+                    encrypted,
+                    beacon_id,
+                    reveal_round,
+                }
+            }
             _ => return Err(codec::Error::from("invalid leading byte")),
         })
     }
@@ -157,6 +191,17 @@ impl Encode for Data {
                 r.extend_from_slice(&bigvec.encode());
                 r
             }
+            Data::BeaconTimelockEncrypted {
+                encrypted,
+                beacon_id,
+                reveal_round,
+            } => {
+                let mut r = vec![137];
+                r.extend_from_slice(&encrypted.encode());
+                r.extend_from_slice(&beacon_id.encode());
+                r.extend_from_slice(&reveal_round.encode());
+                r
+            }
         }
     }
 }
@@ -348,6 +393,18 @@ impl TypeInfo for Data {
                 v.index(136).fields(Fields::unnamed().field(|f| {
                     f.ty::<BoundedVec<u8, ConstU32<MAX_BIGRAW_COMMITMENT_SIZE_BYTES>>>()
                 }))
+            })
+            .variant("BeaconTimelockEncrypted", |v| {
+                v.index(137).fields(
+                    Fields::named()
+                        .field(|f| {
+                            f.name("encrypted")
+                                .ty::<BoundedVec<u8, ConstU32<MAX_TIMELOCK_COMMITMENT_SIZE_BYTES>>>(
+                                )
+                        })
+                        .field(|f| f.name("beacon_id").ty::<BeaconId>())
+                        .field(|f| f.name("reveal_round").ty::<u64>()),
+                )
             });
 
         Type::builder()
@@ -376,6 +433,153 @@ pub struct CommitmentInfo<FieldLimit: Get<u32>> {
 /// Maximum size of the serialized timelock commitment in bytes
 pub const MAX_TIMELOCK_COMMITMENT_SIZE_BYTES: u32 = 1024;
 pub const MAX_BIGRAW_COMMITMENT_SIZE_BYTES: u32 = 512;
+/// Maximum length of the name of a commitment schema field in bytes
+pub const MAX_SCHEMA_FIELD_NAME_LEN: u32 = 32;
+
+/// The type of a field of a commitment schema, and the `Data` variants it accepts.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum CommitmentFieldType {
+    /// `Raw` or `BigRaw` bytes.
+    Bytes,
+    /// `Raw` or `BigRaw` bytes which are valid UTF-8.
+    Text,
+    /// `Raw` bytes holding a SCALE-encoded (little-endian) `u64`.
+    U64,
+    /// Any of the hash variants.
+    Hash,
+    /// A `TimelockEncrypted` or `BeaconTimelockEncrypted` blob.
+    TimelockEncrypted,
+    /// The `ResetBondsFlag`.
+    ResetBondsFlag,
+}
+
+/// A named and typed field of a commitment schema.
+#[freeze_struct("6172b5478b320029")]
+#[derive(Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct CommitmentSchemaField {
+    /// The name of the field.
+    pub name: BoundedVec<u8, ConstU32<MAX_SCHEMA_FIELD_NAME_LEN>>,
+    /// The type of the field.
+    pub field_type: CommitmentFieldType,
+    /// The maximum length in bytes of `Bytes`, `Text` and `TimelockEncrypted` fields.
+    pub max_len: u32,
+}
+
+impl CommitmentSchemaField {
+    /// Whether the data can be committed to this field. `Data::None` leaves the field empty.
+    pub fn accepts(&self, data: &Data) -> bool {
+        let max_len = usize::try_from(self.max_len).unwrap_or(usize::MAX);
+        let is_text = |bytes: &[u8]| core::str::from_utf8(bytes).is_ok();
+        match (self.field_type, data) {
+            (_, Data::None) => true,
+            (CommitmentFieldType::Bytes, Data::Raw(bytes)) => bytes.len() <= max_len,
+            (CommitmentFieldType::Bytes, Data::BigRaw(bytes)) => bytes.len() <= max_len,
+            (CommitmentFieldType::Text, Data::Raw(bytes)) => {
+                bytes.len() <= max_len && is_text(bytes)
+            }
+            (CommitmentFieldType::Text, Data::BigRaw(bytes)) => {
+                bytes.len() <= max_len && is_text(bytes)
+            }
+            (CommitmentFieldType::U64, Data::Raw(bytes)) => bytes.len() == 8,
+            (
+                CommitmentFieldType::Hash,
+                Data::BlakeTwo256(_) | Data::Sha256(_) | Data::Keccak256(_) | Data::ShaThree256(_),
+            ) => true,
+            (
+                CommitmentFieldType::TimelockEncrypted,
+                Data::TimelockEncrypted { encrypted, .. }
+                | Data::BeaconTimelockEncrypted { encrypted, .. },
+            ) => encrypted.len() <= max_len,
+            (CommitmentFieldType::ResetBondsFlag, Data::ResetBondsFlag) => true,
+            _ => false,
+        }
+    }
+
+    /// Decode data accepted by this field.
+    pub fn decode(&self, data: Data) -> CommitmentValue {
+        match (self.field_type, data) {
+            (CommitmentFieldType::Text, Data::Raw(bytes)) => CommitmentValue::Text(bytes.into()),
+            (CommitmentFieldType::Text, Data::BigRaw(bytes)) => CommitmentValue::Text(bytes.into()),
+            (CommitmentFieldType::U64, Data::Raw(bytes)) => u64::decode(&mut &bytes[..])
+                .map(CommitmentValue::U64)
+                .unwrap_or(CommitmentValue::Bytes(bytes.into())),
+            (_, Data::None) => CommitmentValue::None,
+            (_, Data::Raw(bytes)) => CommitmentValue::Bytes(bytes.into()),
+            (_, Data::BigRaw(bytes)) => CommitmentValue::Bytes(bytes.into()),
+            (
+                _,
+                Data::BlakeTwo256(hash)
+                | Data::Sha256(hash)
+                | Data::Keccak256(hash)
+                | Data::ShaThree256(hash),
+            ) => CommitmentValue::Hash(hash),
+            (_, Data::TimelockEncrypted { reveal_round, .. }) => {
+                CommitmentValue::TimelockEncrypted {
+                    beacon_id: DEFAULT_BEACON_ID,
+                    reveal_round,
+                }
+            }
+            (
+                _,
+                Data::BeaconTimelockEncrypted {
+                    beacon_id,
+                    reveal_round,
+                    ..
+                },
+            ) => CommitmentValue::TimelockEncrypted {
+                beacon_id,
+                reveal_round,
+            },
+            (_, Data::ResetBondsFlag) => CommitmentValue::ResetBondsFlag,
+        }
+    }
+}
+
+/// The decoded value of a commitment field.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum CommitmentValue {
+    /// The field was left empty.
+    None,
+    /// Raw bytes.
+    Bytes(Vec<u8>),
+    /// UTF-8 text.
+    Text(Vec<u8>),
+    /// An unsigned integer.
+    U64(u64),
+    /// A hash.
+    Hash([u8; 32]),
+    /// A blob still encrypted to its reveal round of a drand beacon.
+    TimelockEncrypted {
+        beacon_id: BeaconId,
+        reveal_round: u64,
+    },
+    /// A timelocked blob already revealed, found in the revealed commitments.
+    Revealed,
+    /// The bonds reset flag.
+    ResetBondsFlag,
+}
+
+/// A field of a commitment which differs from the previous commitment of the account.
+#[freeze_struct("4138bb536ffcb13f")]
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CommitmentFieldChange {
+    /// The position of the field in the commitment.
+    pub index: u32,
+    /// The previous value of the field, `Data::None` if it was absent.
+    pub previous: Data,
+    /// The new value of the field, `Data::None` if it was removed.
+    pub current: Data,
+}
+
+/// A commitment field decoded against the schema of its subnet.
+#[freeze_struct("889485d733484c64")]
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct StructuredField {
+    /// The name of the field in the schema.
+    pub name: Vec<u8>,
+    /// The decoded value.
+    pub value: CommitmentValue,
+}
 
 /// Contains the decrypted data of a revealed commitment.
 #[freeze_struct("bf575857b57f9bef")]
@@ -5,7 +5,10 @@
     clippy::indexing_slicing,
     clippy::unwrap_used
 )]
-use crate::{BalanceOf, CrowdloanId, CrowdloanInfo, CurrencyOf, pallet::*};
+use crate::{
+    BalanceOf, ContributionTier, ContributionTiersOf, CrowdloanId, CrowdloanInfo, CurrencyOf,
+    pallet::*,
+};
 use frame_benchmarking::{account, v2::*};
 use frame_support::traits::{Get, StorePreimage, fungible::*};
 use frame_system::{RawOrigin, pallet_prelude::BlockNumberFor};
@@ -494,5 +497,252 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn set_terms() {
+        // create a crowdloan
+        let creator: T::AccountId = account::<T::AccountId>("creator", 0, SEED);
+        let deposit = T::MinimumDeposit::get();
+        let min_contribution = T::AbsoluteMinimumContribution::get();
+        let cap = deposit + deposit;
+        let now = frame_system::Pallet::<T>::block_number();
+        let end = now + T::MaximumBlockDuration::get();
+        let target_address: T::AccountId = account::<T::AccountId>("target_address", 0, SEED);
+        let call: Box<<T as Config>::RuntimeCall> =
+            Box::new(frame_system::Call::<T>::remark { remark: vec![] }.into());
+        let _ = CurrencyOf::<T>::set_balance(&creator, deposit);
+        let _ = Pallet::<T>::create(
+            RawOrigin::Signed(creator.clone()).into(),
+            deposit,
+            min_contribution,
+            cap,
+            end,
+            Some(call),
+            Some(target_address),
+        );
+
+        let crowdloan_id: CrowdloanId = 0;
+        let soft_cap = Some(cap);
+        let tiers: ContributionTiersOf<T> = (0..T::MaxContributionTiers::get())
+            .map(|i| ContributionTier {
+                start: now + i.into(),
+                max_contribution: min_contribution,
+            })
+            .collect::<alloc::vec::Vec<_>>()
+            .try_into()
+            .unwrap();
+        let vesting_duration = Some(T::MaximumBlockDuration::get());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(creator.clone()),
+            crowdloan_id,
+            soft_cap,
+            tiers,
+            vesting_duration,
+        );
+
+        // ensure the terms are stored
+        assert!(Terms::<T>::get(crowdloan_id).is_some_and(|t| t.soft_cap == soft_cap));
+        // ensure the event is emitted
+        assert_last_event::<T>(
+            Event::<T>::TermsSet {
+                crowdloan_id,
+                soft_cap,
+                vesting_duration,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn claim_vested() {
+        // create a crowdloan vesting to the target address
+        let creator: T::AccountId = account::<T::AccountId>("creator", 0, SEED);
+        let deposit = T::MinimumDeposit::get();
+        let min_contribution = T::AbsoluteMinimumContribution::get();
+        let cap = deposit + deposit;
+        let now = frame_system::Pallet::<T>::block_number();
+        let end = now + T::MaximumBlockDuration::get();
+        let duration = T::MaximumBlockDuration::get();
+        let target_address: T::AccountId = account::<T::AccountId>("target_address", 0, SEED);
+        let call: Box<<T as Config>::RuntimeCall> =
+            Box::new(frame_system::Call::<T>::remark { remark: vec![] }.into());
+        let _ = CurrencyOf::<T>::set_balance(&creator, deposit);
+        let _ = Pallet::<T>::create(
+            RawOrigin::Signed(creator.clone()).into(),
+            deposit,
+            min_contribution,
+            cap,
+            end,
+            Some(call),
+            Some(target_address.clone()),
+        );
+        let crowdloan_id: CrowdloanId = 0;
+        let _ = Pallet::<T>::set_terms(
+            RawOrigin::Signed(creator.clone()).into(),
+            crowdloan_id,
+            None,
+            Default::default(),
+            Some(duration),
+        );
+
+        // create contribution fullfilling the cap
+        let contributor: T::AccountId = account::<T::AccountId>("contributor", 0, SEED);
+        let amount: BalanceOf<T> = cap - deposit;
+        let _ = CurrencyOf::<T>::set_balance(&contributor, amount);
+        let _ = Pallet::<T>::contribute(
+            RawOrigin::Signed(contributor.clone()).into(),
+            crowdloan_id,
+            amount,
+        );
+
+        // finalize and run to the end of the vesting
+        frame_system::Pallet::<T>::set_block_number(end);
+        let _ = Pallet::<T>::finalize(RawOrigin::Signed(creator.clone()).into(), crowdloan_id);
+        frame_system::Pallet::<T>::set_block_number(end + duration);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(contributor.clone()), crowdloan_id);
+
+        // ensure the target address has received the raised amount
+        assert_eq!(CurrencyOf::<T>::balance(&target_address), cap);
+        // ensure the vesting schedule is removed
+        assert!(!Vesting::<T>::contains_key(crowdloan_id));
+        // ensure the event is emitted
+        assert_last_event::<T>(
+            Event::<T>::VestedClaimed {
+                crowdloan_id,
+                beneficiary: target_address,
+                amount: cap,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn claim_refund() {
+        // create a crowdloan
+        let creator: T::AccountId = account::<T::AccountId>("creator", 0, SEED);
+        let deposit = T::MinimumDeposit::get();
+        let min_contribution = T::AbsoluteMinimumContribution::get();
+        let cap = deposit + deposit;
+        let now = frame_system::Pallet::<T>::block_number();
+        let end = now + T::MaximumBlockDuration::get();
+        let call: Box<<T as Config>::RuntimeCall> =
+            Box::new(frame_system::Call::<T>::remark { remark: vec![] }.into());
+        let _ = CurrencyOf::<T>::set_balance(&creator, deposit);
+        let _ = Pallet::<T>::create(
+            RawOrigin::Signed(creator.clone()).into(),
+            deposit,
+            min_contribution,
+            cap,
+            end,
+            Some(call),
+            None,
+        );
+
+        // create a contribution below the cap
+        let crowdloan_id: CrowdloanId = 0;
+        let contributor: T::AccountId = account::<T::AccountId>("contributor", 0, SEED);
+        let amount: BalanceOf<T> = min_contribution;
+        let _ = CurrencyOf::<T>::set_balance(&contributor, amount);
+        let _ = Pallet::<T>::contribute(
+            RawOrigin::Signed(contributor.clone()).into(),
+            crowdloan_id,
+            amount,
+        );
+
+        // run to the end of the contribution period
+        frame_system::Pallet::<T>::set_block_number(end);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(contributor.clone()), crowdloan_id);
+
+        // ensure the contributor has been refunded
+        assert_eq!(CurrencyOf::<T>::balance(&contributor), amount);
+        assert!(!Contributions::<T>::contains_key(
+            crowdloan_id,
+            &contributor
+        ));
+        // ensure the crowdloan is updated
+        assert!(Crowdloans::<T>::get(crowdloan_id).is_some_and(|c| c.raised == deposit));
+        // ensure the event is emitted
+        assert_last_event::<T>(
+            Event::<T>::RefundClaimed {
+                crowdloan_id,
+                contributor,
+                amount,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn transfer_contribution() {
+        // create a crowdloan
+        let creator: T::AccountId = account::<T::AccountId>("creator", 0, SEED);
+        let deposit = T::MinimumDeposit::get();
+        let min_contribution = T::AbsoluteMinimumContribution::get();
+        let cap = deposit + deposit;
+        let now = frame_system::Pallet::<T>::block_number();
+        let end = now + T::MaximumBlockDuration::get();
+        let call: Box<<T as Config>::RuntimeCall> =
+            Box::new(frame_system::Call::<T>::remark { remark: vec![] }.into());
+        let _ = CurrencyOf::<T>::set_balance(&creator, deposit);
+        let _ = Pallet::<T>::create(
+            RawOrigin::Signed(creator.clone()).into(),
+            deposit,
+            min_contribution,
+            cap,
+            end,
+            Some(call),
+            None,
+        );
+
+        // create a contribution
+        let crowdloan_id: CrowdloanId = 0;
+        let contributor: T::AccountId = account::<T::AccountId>("contributor", 0, SEED);
+        let amount: BalanceOf<T> = min_contribution + min_contribution;
+        let _ = CurrencyOf::<T>::set_balance(&contributor, amount);
+        let _ = Pallet::<T>::contribute(
+            RawOrigin::Signed(contributor.clone()).into(),
+            crowdloan_id,
+            amount,
+        );
+
+        // transfer part of it to a new contributor
+        let dest: T::AccountId = account::<T::AccountId>("dest", 0, SEED);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(contributor.clone()),
+            crowdloan_id,
+            dest.clone(),
+            min_contribution,
+        );
+
+        // ensure the contributions are updated
+        assert_eq!(
+            Contributions::<T>::get(crowdloan_id, &contributor),
+            Some(min_contribution)
+        );
+        assert_eq!(
+            Contributions::<T>::get(crowdloan_id, &dest),
+            Some(min_contribution)
+        );
+        // ensure the crowdloan is updated
+        assert!(Crowdloans::<T>::get(crowdloan_id).is_some_and(|c| c.contributors_count == 3));
+        // ensure the event is emitted
+        assert_last_event::<T>(
+            Event::<T>::ContributionTransferred {
+                crowdloan_id,
+                from: contributor,
+                to: dest,
+                amount: min_contribution,
+            }
+            .into(),
+        );
+    }
+
     impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -14,8 +14,8 @@ use frame_support::{
     dispatch::GetDispatchInfo,
     pallet_prelude::*,
     sp_runtime::{
-        RuntimeDebug,
-        traits::{AccountIdConversion, Dispatchable, Zero},
+        Perquintill, RuntimeDebug, SaturatedConversion,
+        traits::{AccountIdConversion, Dispatchable, Saturating, Zero},
     },
     traits::{
         Bounded, Defensive, Get, IsSubType, QueryPreimage, StorePreimage, fungible, fungible::*,
@@ -87,6 +87,56 @@ pub type CrowdloanInfoOf<T> = CrowdloanInfo<
     BoundedCallOf<T>,
 >;
 
+/// A contribution tier, limiting the total contribution of each account from its start block.
+#[freeze_struct("3e213f07a09981bf")]
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ContributionTier<Balance, BlockNumber> {
+    /// The block from which the tier applies.
+    pub start: BlockNumber,
+    /// The maximum total contribution of an account while the tier applies.
+    pub max_contribution: Balance,
+}
+
+/// The optional terms of a crowdloan on top of its cap.
+#[freeze_struct("5992d2ea51423e40")]
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CrowdloanTerms<Balance, BlockNumber, Tiers> {
+    /// The amount which, once raised by the end, allows the crowdloan to be finalized
+    /// without reaching the cap.
+    pub soft_cap: Option<Balance>,
+    /// The contribution tiers, ordered by start block.
+    pub tiers: Tiers,
+    /// The number of blocks over which the raised funds vest to the target address.
+    pub vesting_duration: Option<BlockNumber>,
+}
+
+pub type ContributionTiersOf<T> = BoundedVec<
+    ContributionTier<BalanceOf<T>, BlockNumberFor<T>>,
+    <T as Config>::MaxContributionTiers,
+>;
+
+pub type CrowdloanTermsOf<T> =
+    CrowdloanTerms<BalanceOf<T>, BlockNumberFor<T>, ContributionTiersOf<T>>;
+
+/// The linear release of the funds raised by a finalized crowdloan to its target address.
+#[freeze_struct("c917c32a045eeb13")]
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct VestingSchedule<AccountId, Balance, BlockNumber> {
+    /// The account receiving the funds.
+    pub beneficiary: AccountId,
+    /// The total amount vesting.
+    pub total: Balance,
+    /// The amount already claimed.
+    pub claimed: Balance,
+    /// The block at which the vesting started.
+    pub start: BlockNumber,
+    /// The number of blocks over which the total vests.
+    pub duration: BlockNumber,
+}
+
+pub type VestingScheduleOf<T> =
+    VestingSchedule<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -145,6 +195,10 @@ pub mod pallet {
         // The maximum number of contributors that can contribute to a crowdloan.
         #[pallet::constant]
         type MaxContributors: Get<u32>;
+
+        /// The maximum number of contribution tiers of a crowdloan.
+        #[pallet::constant]
+        type MaxContributionTiers: Get<u32>;
     }
 
     /// A map of crowdloan ids to their information.
@@ -173,6 +227,16 @@ pub mod pallet {
     #[pallet::storage]
     pub type CurrentCrowdloanId<T: Config> = StorageValue<_, CrowdloanId, OptionQuery>;
 
+    /// A map of crowdloan ids to their optional terms.
+    #[pallet::storage]
+    pub type Terms<T: Config> =
+        StorageMap<_, Twox64Concat, CrowdloanId, CrowdloanTermsOf<T>, OptionQuery>;
+
+    /// A map of finalized crowdloan ids to the vesting of their raised funds.
+    #[pallet::storage]
+    pub type Vesting<T: Config> =
+        StorageMap<_, Twox64Concat, CrowdloanId, VestingScheduleOf<T>, OptionQuery>;
+
     /// Storage for the migration run status.
     #[pallet::storage]
     pub type HasMigrationRun<T: Config> =
@@ -223,6 +287,31 @@ pub mod pallet {
             crowdloan_id: CrowdloanId,
             new_cap: BalanceOf<T>,
         },
+        /// The soft cap, contribution tiers and vesting of a crowdloan were set.
+        TermsSet {
+            crowdloan_id: CrowdloanId,
+            soft_cap: Option<BalanceOf<T>>,
+            vesting_duration: Option<BlockNumberFor<T>>,
+        },
+        /// Vested funds of a finalized crowdloan were released to the beneficiary.
+        VestedClaimed {
+            crowdloan_id: CrowdloanId,
+            beneficiary: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A contributor claimed the refund of their contribution to an ended crowdloan.
+        RefundClaimed {
+            crowdloan_id: CrowdloanId,
+            contributor: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A contribution was transferred to another account.
+        ContributionTransferred {
+            crowdloan_id: CrowdloanId,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -271,6 +360,24 @@ pub mod pallet {
         DepositCannotBeWithdrawn,
         /// The maximum number of contributors has been reached.
         MaxContributorsReached,
+        /// The terms can only be set before anyone but the creator contributed.
+        TermsLocked,
+        /// The soft cap must be above the deposit and not above the cap.
+        InvalidSoftCap,
+        /// The tiers must start at increasing blocks and allow the minimum contribution.
+        InvalidContributionTiers,
+        /// Vesting requires a target address and a non-zero duration.
+        InvalidVesting,
+        /// The contributor has reached the maximum contribution of the current tier.
+        TierMaximumReached,
+        /// The crowdloan has no vesting schedule.
+        NoVestingSchedule,
+        /// Nothing has vested since the last claim.
+        NothingToClaim,
+        /// The contribution is lower than the amount to transfer.
+        InsufficientContribution,
+        /// A contribution can't be transferred to its owner.
+        SelfTransfer,
     }
 
     #[pallet::hooks]
@@ -401,7 +508,9 @@ pub mod pallet {
         ///
         /// The contribution will be transfered to the crowdloan account and will be refunded
         /// if the crowdloan fails to raise the cap. If the contribution would raise the amount above the cap,
-        /// the contribution will be set to the amount that is left to be raised.
+        /// the contribution will be set to the amount that is left to be raised. The same applies to the
+        /// maximum contribution of the current tier, if the crowdloan has contribution tiers, as long as
+        /// what is left of the tier maximum is not below the minimum contribution.
         ///
         /// The dispatch origin for this call must be _Signed_.
         ///
@@ -447,6 +556,24 @@ pub mod pallet {
             // set the contribution to the amount that is left to be raised
             let amount = amount.min(left_to_raise);
 
+            // If a contribution tier applies, the total contribution of the
+            // contributor is limited to the tier maximum
+            let amount = match Self::current_tier(crowdloan_id, now) {
+                Some(tier) => {
+                    let contributed = Contributions::<T>::get(crowdloan_id, &contributor)
+                        .unwrap_or_else(Zero::zero);
+                    let allowance = tier.max_contribution.saturating_sub(contributed);
+                    ensure!(allowance > Zero::zero(), Error::<T>::TierMaximumReached);
+                    // A contribution clamped to the tier maximum must still meet the minimum
+                    ensure!(
+                        allowance >= amount || allowance >= crowdloan.min_contribution,
+                        Error::<T>::TierMaximumReached
+                    );
+                    amount.min(allowance)
+                }
+                None => amount,
+            };
+
             // Ensure contribution does not overflow the actual raised amount
             crowdloan.raised = crowdloan
                 .raised
@@ -552,8 +679,10 @@ pub mod pallet {
 
         /// Finalize a successful crowdloan.
         ///
-        /// The call will transfer the raised amount to the target address if it was provided when the crowdloan was created
-        /// and dispatch the call that was provided using the creator origin. The CurrentCrowdloanId will be set to the
+        /// A crowdloan is successful once it raised its cap, or its soft cap if it has one.
+        ///
+        /// The call will transfer the raised amount to the target address if it was provided when the crowdloan was created,
+        /// or start vesting it to the target address if the crowdloan has a vesting duration, and dispatch the call that was provided using the creator origin. The CurrentCrowdloanId will be set to the
         /// crowdloan id being finalized so the dispatched call can access it temporarily by accessing
         /// the `CurrentCrowdloanId` storage item.
         ///
@@ -574,19 +703,40 @@ pub mod pallet {
 
             // Ensure the origin is the creator of the crowdloan and the crowdloan has ended,
             // raised the cap and is not finalized.
+            // A crowdloan with a soft cap succeeds once the soft cap is raised.
+            let terms = Terms::<T>::get(crowdloan_id);
+            let soft_cap = terms.as_ref().and_then(|terms| terms.soft_cap);
             ensure!(who == crowdloan.creator, Error::<T>::InvalidOrigin);
             ensure!(now >= crowdloan.end, Error::<T>::ContributionPeriodNotEnded);
-            ensure!(crowdloan.raised == crowdloan.cap, Error::<T>::CapNotRaised);
+            ensure!(
+                crowdloan.raised == crowdloan.cap
+                    || soft_cap.is_some_and(|soft_cap| crowdloan.raised >= soft_cap),
+                Error::<T>::CapNotRaised
+            );
             ensure!(!crowdloan.finalized, Error::<T>::AlreadyFinalized);
 
-            // If the target address is provided, transfer the raised amount to it.
+            // If the target address is provided, transfer the raised amount to it,
+            // or let it vest to it if a vesting duration is set.
             if let Some(ref target_address) = crowdloan.target_address {
-                CurrencyOf::<T>::transfer(
-                    &crowdloan.funds_account,
-                    target_address,
-                    crowdloan.raised,
-                    Preservation::Expendable,
-                )?;
+                match terms.and_then(|terms| terms.vesting_duration) {
+                    Some(duration) => Vesting::<T>::insert(
+                        crowdloan_id,
+                        VestingSchedule {
+                            beneficiary: target_address.clone(),
+                            total: crowdloan.raised,
+                            claimed: Zero::zero(),
+                            start: now,
+                            duration,
+                        },
+                    ),
+                    None => CurrencyOf::<T>::transfer(
+                        &crowdloan.funds_account,
+                        target_address,
+                        crowdloan.raised,
+                        Preservation::Expendable,
+                    )
+                    .map(|_| ())?,
+                }
             }
 
             // If the call is provided, dispatch it.
@@ -748,6 +898,7 @@ pub mod pallet {
             // Remove the crowdloan
             let _ = frame_system::Pallet::<T>::dec_providers(&crowdloan.funds_account).defensive();
             Crowdloans::<T>::remove(crowdloan_id);
+            Terms::<T>::remove(crowdloan_id);
 
             Self::deposit_event(Event::<T>::Dissolved { crowdloan_id });
             Ok(())
@@ -861,6 +1012,283 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Set the soft cap, contribution tiers and vesting of a crowdloan.
+        ///
+        /// The terms can only be set before anyone but the creator has contributed. A crowdloan
+        /// with a soft cap can be finalized once the soft cap is raised by the end. A contribution
+        /// tier limits the total contribution of each account from its start block until the next
+        /// tier starts. With a vesting duration, the funds raised are released linearly to the
+        /// target address after the finalization instead of being transferred at once.
+        ///
+        /// The dispatch origin for this call must be _Signed_ and must be the creator of the crowdloan.
+        ///
+        /// Parameters:
+        /// - `crowdloan_id`: The id of the crowdloan to set the terms of.
+        /// - `soft_cap`: The amount that makes the crowdloan successful, if any.
+        /// - `tiers`: The contribution tiers, ordered by start block.
+        /// - `vesting_duration`: The number of blocks over which the funds vest, if any.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_terms())]
+        pub fn set_terms(
+            origin: OriginFor<T>,
+            #[pallet::compact] crowdloan_id: CrowdloanId,
+            soft_cap: Option<BalanceOf<T>>,
+            tiers: ContributionTiersOf<T>,
+            vesting_duration: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let crowdloan = Self::ensure_crowdloan_exists(crowdloan_id)?;
+            ensure!(!crowdloan.finalized, Error::<T>::AlreadyFinalized);
+
+            // Only the creator can set the terms, before anyone else contributed.
+            ensure!(who == crowdloan.creator, Error::<T>::InvalidOrigin);
+            ensure!(
+                crowdloan.contributors_count == 1 && crowdloan.raised == crowdloan.deposit,
+                Error::<T>::TermsLocked
+            );
+
+            ensure!(
+                soft_cap.is_none_or(
+                    |soft_cap| soft_cap > crowdloan.deposit && soft_cap <= crowdloan.cap
+                ),
+                Error::<T>::InvalidSoftCap
+            );
+            ensure!(
+                tiers
+                    .iter()
+                    .all(|tier| tier.max_contribution >= crowdloan.min_contribution)
+                    && tiers.windows(2).all(|pair| match pair {
+                        [previous, next] => previous.start < next.start,
+                        _ => true,
+                    }),
+                Error::<T>::InvalidContributionTiers
+            );
+            ensure!(
+                vesting_duration.is_none_or(
+                    |duration| !duration.is_zero() && crowdloan.target_address.is_some()
+                ),
+                Error::<T>::InvalidVesting
+            );
+
+            Terms::<T>::insert(
+                crowdloan_id,
+                CrowdloanTerms {
+                    soft_cap,
+                    tiers,
+                    vesting_duration,
+                },
+            );
+
+            Self::deposit_event(Event::<T>::TermsSet {
+                crowdloan_id,
+                soft_cap,
+                vesting_duration,
+            });
+            Ok(())
+        }
+
+        /// Release the funds vested so far by a finalized crowdloan to its target address.
+        ///
+        /// The dispatch origin for this call must be _Signed_ and doesn't need to be the beneficiary.
+        ///
+        /// Parameters:
+        /// - `crowdloan_id`: The id of the crowdloan to claim the vested funds of.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::claim_vested())]
+        pub fn claim_vested(
+            origin: OriginFor<T>,
+            #[pallet::compact] crowdloan_id: CrowdloanId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let now = frame_system::Pallet::<T>::block_number();
+
+            let crowdloan = Self::ensure_crowdloan_exists(crowdloan_id)?;
+            let mut schedule =
+                Vesting::<T>::get(crowdloan_id).ok_or(Error::<T>::NoVestingSchedule)?;
+
+            let amount = Self::vested_amount(&schedule, now).saturating_sub(schedule.claimed);
+            ensure!(amount > Zero::zero(), Error::<T>::NothingToClaim);
+
+            CurrencyOf::<T>::transfer(
+                &crowdloan.funds_account,
+                &schedule.beneficiary,
+                amount,
+                Preservation::Expendable,
+            )?;
+
+            schedule.claimed = schedule.claimed.saturating_add(amount);
+            if schedule.claimed >= schedule.total {
+                Vesting::<T>::remove(crowdloan_id);
+            } else {
+                Vesting::<T>::insert(crowdloan_id, &schedule);
+            }
+
+            Self::deposit_event(Event::<T>::VestedClaimed {
+                crowdloan_id,
+                beneficiary: schedule.beneficiary,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Claim the refund of a contribution to an ended crowdloan that was not finalized.
+        ///
+        /// This refunds a single contributor without waiting for `refund` to process the
+        /// contributors in batches. The creator's deposit is refunded on `dissolve`.
+        ///
+        /// The dispatch origin for this call must be _Signed_ and must be a contributor.
+        ///
+        /// Parameters:
+        /// - `crowdloan_id`: The id of the crowdloan to claim the refund from.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::claim_refund())]
+        pub fn claim_refund(
+            origin: OriginFor<T>,
+            #[pallet::compact] crowdloan_id: CrowdloanId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let now = frame_system::Pallet::<T>::block_number();
+
+            let mut crowdloan = Self::ensure_crowdloan_exists(crowdloan_id)?;
+
+            // Ensure the crowdloan has ended and is not finalized
+            ensure!(now >= crowdloan.end, Error::<T>::ContributionPeriodNotEnded);
+            ensure!(!crowdloan.finalized, Error::<T>::AlreadyFinalized);
+            ensure!(
+                who != crowdloan.creator,
+                Error::<T>::DepositCannotBeWithdrawn
+            );
+
+            let amount =
+                Contributions::<T>::take(crowdloan_id, &who).ok_or(Error::<T>::NoContribution)?;
+
+            CurrencyOf::<T>::transfer(
+                &crowdloan.funds_account,
+                &who,
+                amount,
+                Preservation::Expendable,
+            )?;
+
+            crowdloan.raised = crowdloan.raised.saturating_sub(amount);
+            crowdloan.contributors_count = crowdloan
+                .contributors_count
+                .checked_sub(1)
+                .ok_or(Error::<T>::Underflow)?;
+            Crowdloans::<T>::insert(crowdloan_id, &crowdloan);
+
+            Self::deposit_event(Event::<T>::RefundClaimed {
+                crowdloan_id,
+                contributor: who,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Transfer a contribution, or part of it, to another account, until the crowdloan ends.
+        ///
+        /// The receiver becomes a contributor of the crowdloan for the amount transferred and
+        /// can withdraw it, get it refunded or keep it until the crowdloan is finalized. The
+        /// creator can only transfer what they contributed above the deposit.
+        ///
+        /// The amount transferred and the contribution left to the sender must each be at least
+        /// the minimum contribution, unless nothing is left. If a contribution tier applies, the
+        /// total contribution of the receiver is limited to the tier maximum.
+        ///
+        /// The dispatch origin for this call must be _Signed_ and must be a contributor.
+        ///
+        /// Parameters:
+        /// - `crowdloan_id`: The id of the crowdloan the contribution was made to.
+        /// - `dest`: The account receiving the contribution.
+        /// - `amount`: The amount of the contribution to transfer.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::transfer_contribution())]
+        pub fn transfer_contribution(
+            origin: OriginFor<T>,
+            #[pallet::compact] crowdloan_id: CrowdloanId,
+            dest: T::AccountId,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let now = frame_system::Pallet::<T>::block_number();
+
+            let mut crowdloan = Self::ensure_crowdloan_exists(crowdloan_id)?;
+            ensure!(!crowdloan.finalized, Error::<T>::AlreadyFinalized);
+            ensure!(now < crowdloan.end, Error::<T>::ContributionPeriodEnded);
+            ensure!(who != dest, Error::<T>::SelfTransfer);
+            ensure!(
+                amount > Zero::zero() && amount >= crowdloan.min_contribution,
+                Error::<T>::ContributionTooLow
+            );
+
+            // Ensure the sender has enough contribution, keeping the deposit of the creator
+            let contribution =
+                Contributions::<T>::get(crowdloan_id, &who).ok_or(Error::<T>::NoContribution)?;
+            let transferable = if who == crowdloan.creator {
+                contribution.saturating_sub(crowdloan.deposit)
+            } else {
+                contribution
+            };
+            ensure!(transferable >= amount, Error::<T>::InsufficientContribution);
+
+            // Ensure the sender is not left with less than the minimum contribution
+            let remaining = contribution.saturating_sub(amount);
+            ensure!(
+                who == crowdloan.creator
+                    || remaining.is_zero()
+                    || remaining >= crowdloan.min_contribution,
+                Error::<T>::ContributionTooLow
+            );
+
+            // Credit the receiver, who may become a new contributor
+            let dest_contribution =
+                if let Some(dest_contribution) = Contributions::<T>::get(crowdloan_id, &dest) {
+                    dest_contribution
+                        .checked_add(amount)
+                        .ok_or(Error::<T>::Overflow)?
+                } else {
+                    ensure!(
+                        crowdloan.contributors_count < T::MaxContributors::get(),
+                        Error::<T>::MaxContributorsReached
+                    );
+                    crowdloan.contributors_count = crowdloan
+                        .contributors_count
+                        .checked_add(1)
+                        .ok_or(Error::<T>::Overflow)?;
+                    amount
+                };
+
+            // If a contribution tier applies, the total contribution of the
+            // receiver is limited to the tier maximum
+            if let Some(tier) = Self::current_tier(crowdloan_id, now) {
+                ensure!(
+                    dest_contribution <= tier.max_contribution,
+                    Error::<T>::TierMaximumReached
+                );
+            }
+
+            // Debit the sender, who stops being a contributor once nothing is left
+            if remaining.is_zero() {
+                Contributions::<T>::remove(crowdloan_id, &who);
+                crowdloan.contributors_count = crowdloan
+                    .contributors_count
+                    .checked_sub(1)
+                    .ok_or(Error::<T>::Underflow)?;
+            } else {
+                Contributions::<T>::insert(crowdloan_id, &who, remaining);
+            }
+            Contributions::<T>::insert(crowdloan_id, &dest, dest_contribution);
+            Crowdloans::<T>::insert(crowdloan_id, &crowdloan);
+
+            Self::deposit_event(Event::<T>::ContributionTransferred {
+                crowdloan_id,
+                from: who,
+                to: dest,
+                amount,
+            });
+            Ok(())
+        }
     }
 }
 
@@ -873,6 +1301,38 @@ impl<T: Config> Pallet<T> {
         Crowdloans::<T>::get(crowdloan_id).ok_or(Error::<T>::InvalidCrowdloanId)
     }
 
+    /// The contributions of an account across all crowdloans.
+    pub fn get_contributions(contributor: &T::AccountId) -> Vec<(CrowdloanId, BalanceOf<T>)> {
+        Crowdloans::<T>::iter_keys()
+            .filter_map(|crowdloan_id| {
+                Contributions::<T>::get(crowdloan_id, contributor)
+                    .map(|amount| (crowdloan_id, amount))
+            })
+            .collect()
+    }
+
+    // The contribution tier applying at the given block, if any
+    fn current_tier(
+        crowdloan_id: CrowdloanId,
+        now: BlockNumberFor<T>,
+    ) -> Option<ContributionTier<BalanceOf<T>, BlockNumberFor<T>>> {
+        Terms::<T>::get(crowdloan_id)?
+            .tiers
+            .into_iter()
+            .rev()
+            .find(|tier| tier.start <= now)
+    }
+
+    // The amount of a vesting schedule released at the given block
+    fn vested_amount(schedule: &VestingScheduleOf<T>, now: BlockNumberFor<T>) -> BalanceOf<T> {
+        let elapsed = now.saturating_sub(schedule.start).min(schedule.duration);
+        Perquintill::from_rational(
+            elapsed.saturated_into::<u64>(),
+            schedule.duration.saturated_into::<u64>(),
+        )
+        .mul_floor(schedule.total)
+    }
+
     // Ensure the provided end block is after the current block and the duration is
     // between the minimum and maximum block duration
     fn ensure_valid_end(now: BlockNumberFor<T>, end: BlockNumberFor<T>) -> Result<(), Error<T>> {
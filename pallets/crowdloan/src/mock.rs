@@ -88,6 +88,18 @@ impl WeightInfo for TestWeightInfo {
     fn update_cap() -> Weight {
         Weight::zero()
     }
+    fn set_terms() -> Weight {
+        Weight::zero()
+    }
+    fn claim_vested() -> Weight {
+        Weight::zero()
+    }
+    fn claim_refund() -> Weight {
+        Weight::zero()
+    }
+    fn transfer_contribution() -> Weight {
+        Weight::zero()
+    }
 }
 
 parameter_types! {
@@ -112,6 +124,7 @@ parameter_types! {
     pub const MaximumBlockDuration: u64 = 100;
     pub const RefundContributorsLimit: u32 = 5;
     pub const MaxContributors: u32 = 10;
+    pub const MaxContributionTiers: u32 = 4;
 }
 
 impl pallet_crowdloan::Config for Test {
@@ -127,6 +140,7 @@ impl pallet_crowdloan::Config for Test {
     type MaximumBlockDuration = MaximumBlockDuration;
     type RefundContributorsLimit = RefundContributorsLimit;
     type MaxContributors = MaxContributors;
+    type MaxContributionTiers = MaxContributionTiers;
 }
 
 // A test pallet used to test some behavior of the crowdloan pallet
@@ -6,7 +6,9 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use sp_core::U256;
 use sp_runtime::DispatchError;
 
-use crate::{BalanceOf, CrowdloanId, CrowdloanInfo, mock::*, pallet as pallet_crowdloan};
+use crate::{
+    BalanceOf, ContributionTier, CrowdloanId, CrowdloanInfo, mock::*, pallet as pallet_crowdloan,
+};
 
 #[test]
 fn test_create_succeeds() {
@@ -2612,3 +2614,802 @@ fn test_update_cap_fails_if_new_cap_is_too_low() {
             );
         });
 }
+
+#[test]
+fn test_set_terms_succeeds() {
+    TestState::default()
+        .with_balance(U256::from(1), 100)
+        .build_and_execute(|| {
+            // create a crowdloan
+            let creator: AccountOf<Test> = U256::from(1);
+            assert_ok!(Crowdloan::create(
+                RuntimeOrigin::signed(creator),
+                50,
+                10,
+                300,
+                50,
+                Some(noop_call()),
+                Some(U256::from(42))
+            ));
+
+            // set the terms
+            let crowdloan_id: CrowdloanId = 0;
+            let tiers = vec![
+                ContributionTier {
+                    start: 0,
+                    max_contribution: 20,
+                },
+                ContributionTier {
+                    start: 20,
+                    max_contribution: 100,
+                },
+            ];
+            assert_ok!(Crowdloan::set_terms(
+                RuntimeOrigin::signed(creator),
+                crowdloan_id,
+                Some(150),
+                tiers.clone().try_into().unwrap(),
+                Some(100)
+            ));
+
+            // ensure the terms are stored
+            let terms = pallet_crowdloan::Terms::<Test>::get(crowdloan_id).unwrap();
+            assert_eq!(terms.soft_cap, Some(150));
+            assert_eq!(terms.tiers.into_inner(), tiers);
+            assert_eq!(terms.vesting_duration, Some(100));
+
+            // ensure the event is emitted
+            assert_eq!(
+                last_event(),
+                pallet_crowdloan::Event::<Test>::TermsSet {
+                    crowdloan_id,
+                    soft_cap: Some(150),
+                    vesting_duration: Some(100),
+                }
+                .into()
+            );
+        });
+}
+
+#[test]
+fn test_set_terms_fails_if_invalid_terms() {
+    TestState::default()
+        .with_balance(U256::from(1), 100)
+        .build_and_execute(|| {
+            // create a crowdloan without target address
+            let creator: AccountOf<Test> = U256::from(1);
+            assert_ok!(Crowdloan::create(
+                RuntimeOrigin::signed(creator),
+                50,
+                10,
+                300,
+                50,
+                Some(noop_call()),
+                None
+            ));
+
+            let crowdloan_id: CrowdloanId = 0;
+
+            // the soft cap must be above the deposit and not above the cap
+            for soft_cap in [50, 301] {
+                assert_err!(
+                    Crowdloan::set_terms(
+                        RuntimeOrigin::signed(creator),
+                        crowdloan_id,
+                        Some(soft_cap),
+                        Default::default(),
+                        None
+                    ),
+                    pallet_crowdloan::Error::<Test>::InvalidSoftCap
+                );
+            }
+
+            // the tiers must start at increasing blocks and allow the minimum contribution
+            for tiers in [
+                vec![
+                    ContributionTier {
+                        start: 10,
+                        max_contribution: 20,
+                    },
+                    ContributionTier {
+                        start: 10,
+                        max_contribution: 30,
+                    },
+                ],
+                vec![ContributionTier {
+                    start: 0,
+                    max_contribution: 5,
+                }],
+            ] {
+                assert_err!(
+                    Crowdloan::set_terms(
+                        RuntimeOrigin::signed(creator),
+                        crowdloan_id,
+                        None,
+                        tiers.try_into().unwrap(),
+                        None
+                    ),
+                    pallet_crowdloan::Error::<Test>::InvalidContributionTiers
+                );
+            }
+
+            // vesting requires a target address
+            assert_err!(
+                Crowdloan::set_terms(
+                    RuntimeOrigin::signed(creator),
+                    crowdloan_id,
+                    None,
+                    Default::default(),
+                    Some(100)
+                ),
+                pallet_crowdloan::Error::<Test>::InvalidVesting
+            );
+        });
+}
+
+#[test]
+fn test_set_terms_fails_if_not_creator_or_contributions_made() {
+    TestState::default()
+        .with_balance(U256::from(1), 100)
+        .with_balance(U256::from(2), 100)
+        .build_and_execute(|| {
+            // create a crowdloan
+            let creator: AccountOf<Test> = U256::from(1);
+            assert_ok!(Crowdloan::create(
+                RuntimeOrigin::signed(creator),
+                50,
+                10,
+                300,
+                50,
+                Some(noop_call()),
+                None
+            ));
+
+            let crowdloan_id: CrowdloanId = 0;
+            let contributor: AccountOf<Test> = U256::from(2);
+            assert_err!(
+                Crowdloan::set_terms(
+                    RuntimeOrigin::signed(contributor),
+                    crowdloan_id,
+                    Some(100),
+                    Default::default(),
+                    None
+                ),
+                pallet_crowdloan::Error::<Test>::InvalidOrigin
+            );
+
+            // the terms can't change once someone contributed
+            assert_ok!(Crowdloan::contribute(
+                RuntimeOrigin::signed(contributor),
+                crowdloan_id,
+                50
+            ));
+            assert_err!(
+                Crowdloan::set_terms(
+                    RuntimeOrigin::signed(creator),
+                    crowdloan_id,
+                    Some(100),
+                    Default::default(),
+                    None
+                ),
+                pallet_crowdloan::Error::<Test>::TermsLocked
+            );
+        });
+}
+
+#[test]
+fn test_contribute_is_limited_by_current_tier() {
+    TestState::default()
+        .with_balance(U256::from(1), 100)
+        .with_balance(U256::from(2), 200)
+        .with_balance(U256::from(3), 100)
+        .build_and_execute(|| {
+            // create a crowdloan with an early tier
+            let creator: AccountOf<Test> = U256::from(1);
+            assert_ok!(Crowdloan::create(
+                RuntimeOrigin::signed(creator),
+                50,
+                10,
+                300,
+                50,
+                Some(noop_call()),
+                None
+            ));
+            let crowdloan_id: CrowdloanId = 0;
+            let tiers = vec![
+                ContributionTier {
+                    start: 0,
+                    max_contribution: 30,
+                },
+                ContributionTier {
+                    start: 20,
+                    max_contribution: 100,
+                },
+            ];
+            assert_ok!(Crowdloan::set_terms(
+                RuntimeOrigin::signed(creator),
+                crowdloan_id,
+                None,
+                tiers.try_into().unwrap(),
+                None
+            ));
+
+            // the contribution is clamped to the tier maximum
+            let contributor: AccountOf<Test> = U256::from(2);
+            assert_ok!(Crowdloan::contribute(
+                RuntimeOrigin::signed(contributor),
+                crowdloan_id,
+                50
+            ));
+            assert_eq!(
+                pallet_crowdloan::Contributions::<Test>::get(crowdloan_id, contributor),
+                Some(30)
+            );
+            assert_err!(
+                Crowdloan::contribute(RuntimeOrigin::signed(contributor), crowdloan_id, 10),
+                pallet_crowdloan::Error::<Test>::TierMaximumReached
+            );
+
+            // the contribution can't be clamped below the minimum contribution
+            let contributor2: AccountOf<Test> = U256::from(3);
+            assert_ok!(Crowdloan::contribute(
+                RuntimeOrigin::signed(contributor2),
+                crowdloan_id,
+                25
+            ));
+            assert_err!(
+                Crowdloan::contribute(RuntimeOrigin::signed(contributor2), crowdloan_id, 10),
+                pallet_crowdloan::Error::<Test>::TierMaximumReached
+            );
+            assert_eq!(
+                pallet_crowdloan::Contributions::<Test>::get(crowdloan_id, contributor2),
+                Some(25)
+            );
+
+            // the next tier raises the maximum
+            run_to_block(20);
+            assert_ok!(Crowdloan::contribute(
+                RuntimeOrigin::signed(contributor),
+                crowdloan_id,
+                100
+            ));
+            assert_eq!(
+                pallet_crowdloan::Contributions::<Test>::get(crowdloan_id, contributor),
+                Some(100)
+            );
+        });
+}
+
+#[test]
+fn test_finalize_succeeds_with_soft_cap_raised() {
+    TestState::default()
+        .with_balance(U256::from(1), 100)
+        .with_balance(U256::from(2), 100)
+        .build_and_execute(|| {
+            // create two crowdloans with a soft cap
+            let creator: AccountOf<Test> = U256::from(1);
+            for crowdloan_id in [0, 1] {
+                assert_ok!(Crowdloan::create(
+                    RuntimeOrigin::signed(creator),
+                    50,
+                    10,
+                    300,
+                    50,
+                    Some(noop_call()),
+                    Some(U256::from(42))
+                ));
+                assert_ok!(Crowdloan::set_terms(
+                    RuntimeOrigin::signed(creator),
+                    crowdloan_id,
+                    Some(100),
+                    Default::default(),
+                    None
+                ));
+            }
+
+            // raise less than the soft cap on the first and the soft cap on the second
+            let contributor: AccountOf<Test> = U256::from(2);
+            assert_ok!(Crowdloan::contribute(
+                RuntimeOrigin::signed(contributor),
+                0,
+                40
+            ));
+            assert_ok!(Crowdloan::contribute(
+                RuntimeOrigin::signed(contributor),
+                1,
+                50
+            ));
+
+            run_to_block(50);
+            assert_err!(
+                Crowdloan::finalize(RuntimeOrigin::signed(creator), 0),
+                pallet_crowdloan::Error::<Test>::CapNotRaised
+            );
+            assert_ok!(Crowdloan::finalize(RuntimeOrigin::signed(creator), 1));
+
+            // ensure the target address has received the raised amount
+            assert_eq!(
+                pallet_balances::Pallet::<Test>::free_balance(U256::from(42)),
+                100
+            );
+        });
+}
+
+#[test]
+fn test_claim_vested_succeeds() {
+    TestState::default()
+        .with_balance(U256::from(1), 100)
+        .with_balance(U256::from(2), 100)
+        .build_and_execute(|| {
+            // create a crowdloan vesting over 100 blocks
+            let creator: AccountOf<Test> = U256::from(1);
+            let target_address: AccountOf<Test> = U256::from(42);
+            assert_ok!(Crowdloan::create(
+                RuntimeOrigin::signed(creator),
+                50,
+                10,
+                100,
+                50,
+                Some(noop_call()),
+                Some(target_address)
+            ));
+            let crowdloan_id: CrowdloanId = 0;
+            assert_ok!(Crowdloan::set_terms(
+                RuntimeOrigin::signed(creator),
+                crowdloan_id,
+                None,
+                Default::default(),
+                Some(100)
+            ));
+            assert_ok!(Crowdloan::contribute(
+                RuntimeOrigin::signed(U256::from(2)),
+                crowdloan_id,
+                50
+            ));
+
+            // finalize the crowdloan, nothing is transferred yet
+            run_to_block(50);
+            assert_ok!(Crowdloan::finalize(
+                RuntimeOrigin::signed(creator),
+                crowdloan_id
+            ));
+            assert_eq!(
+                pallet_balances::Pallet::<Test>::free_balance(target_address),
+                0
+            );
+            assert_err!(
+                Crowdloan::claim_vested(RuntimeOrigin::signed(creator), crowdloan_id),
+                pallet_crowdloan::Error::<Test>::NothingToClaim
+            );
+
+            // a quarter of the funds vested
+            run_to_block(75);
+            assert_ok!(Crowdloan::claim_vested(
+                RuntimeOrigin::signed(creator),
+                crowdloan_id
+            ));
+            assert_eq!(
+                pallet_balances::Pallet::<Test>::free_balance(target_address),
+                25
+            );
+            assert_eq!(
+                last_event(),
+                pallet_crowdloan::Event::<Test>::VestedClaimed {
+                    crowdloan_id,
+                    beneficiary: target_address,
+                    amount: 25,
+                }
+                .into()
+            );
+
+            // the rest vested after the vesting duration
+            run_to_block(200);
+            assert_ok!(Crowdloan::claim_vested(
+                RuntimeOrigin::signed(creator),
+                crowdloan_id
+            ));
+            assert_eq!(
+                pallet_balances::Pallet::<Test>::free_balance(target_address),
+                100
+            );
+            assert!(!pallet_crowdloan::Vesting::<Test>::contains_key(
+                crowdloan_id
+            ));
+            assert_err!(
+                Crowdloan::claim_vested(RuntimeOrigin::signed(creator), crowdloan_id),
+                pallet_crowdloan::Error::<Test>::NoVestingSchedule
+            );
+        });
+}
+
+#[test]
+fn test_claim_refund_succeeds() {
+    TestState::default()
+        .with_balance(U256::from(1), 100)
+        .with_balance(U256::from(2), 100)
+        .build_and_execute(|| {
+            // create a crowdloan
+            let creator: AccountOf<Test> = U256::from(1);
+            assert_ok!(Crowdloan::create(
+                RuntimeOrigin::signed(creator),
+                50,
+                10,
+                300,
+                50,
+                Some(noop_call()),
+                None
+            ));
+            let crowdloan_id: CrowdloanId = 0;
+            let contributor: AccountOf<Test> = U256::from(2);
+            assert_ok!(Crowdloan::contribute(
+                RuntimeOrigin::signed(contributor),
+                crowdloan_id,
+                40
+            ));
+
+            // the refund can't be claimed before the end
+            assert_err!(
+                Crowdloan::claim_refund(RuntimeOrigin::signed(contributor), crowdloan_id),
+                pallet_crowdloan::Error::<Test>::ContributionPeriodNotEnded
+            );
+
+            run_to_block(50);
+
+            // the creator's deposit is refunded on dissolve
+            assert_err!(
+                Crowdloan::claim_refund(RuntimeOrigin::signed(creator), crowdloan_id),
+                pallet_crowdloan::Error::<Test>::DepositCannotBeWithdrawn
+            );
+
+            assert_ok!(Crowdloan::claim_refund(
+                RuntimeOrigin::signed(contributor),
+                crowdloan_id
+            ));
+
+            // ensure the contributor has been refunded
+            assert_eq!(
+                pallet_balances::Pallet::<Test>::free_balance(contributor),
+                100
+            );
+            assert_eq!(
+                pallet_crowdloan::Contributions::<Test>::get(crowdloan_id, contributor),
+                None
+            );
+            assert!(
+                pallet_crowdloan::Crowdloans::<Test>::get(crowdloan_id)
+                    .is_some_and(|c| c.raised == 50 && c.contributors_count == 1)
+            );
+            assert_eq!(
+                last_event(),
+                pallet_crowdloan::Event::<Test>::RefundClaimed {
+                    crowdloan_id,
+                    contributor,
+                    amount: 40,
+                }
+                .into()
+            );
+
+            // nothing left to claim
+            assert_err!(
+                Crowdloan::claim_refund(RuntimeOrigin::signed(contributor), crowdloan_id),
+                pallet_crowdloan::Error::<Test>::NoContribution
+            );
+        });
+}
+
+#[test]
+fn test_transfer_contribution_succeeds() {
+    TestState::default()
+        .with_balance(U256::from(1), 100)
+        .with_balance(U256::from(2), 100)
+        .build_and_execute(|| {
+            // create a crowdloan
+            let creator: AccountOf<Test> = U256::from(1);
+            assert_ok!(Crowdloan::create(
+                RuntimeOrigin::signed(creator),
+                50,
+                10,
+                300,
+                50,
+                Some(noop_call()),
+                None
+            ));
+            let crowdloan_id: CrowdloanId = 0;
+            let contributor: AccountOf<Test> = U256::from(2);
+            assert_ok!(Crowdloan::contribute(
+                RuntimeOrigin::signed(contributor),
+                crowdloan_id,
+                60
+            ));
+
+            // transfer part of the contribution to a new account
+            let dest: AccountOf<Test> = U256::from(3);
+            assert_ok!(Crowdloan::transfer_contribution(
+                RuntimeOrigin::signed(contributor),
+                crowdloan_id,
+                dest,
+                20
+            ));
+            assert_eq!(
+                pallet_crowdloan::Contributions::<Test>::get(crowdloan_id, contributor),
+                Some(40)
+            );
+            assert_eq!(
+                pallet_crowdloan::Contributions::<Test>::get(crowdloan_id, dest),
+                Some(20)
+            );
+            assert!(
+                pallet_crowdloan::Crowdloans::<Test>::get(crowdloan_id)
+                    .is_some_and(|c| c.raised == 110 && c.contributors_count == 3)
+            );
+            assert_eq!(
+                last_event(),
+                pallet_crowdloan::Event::<Test>::ContributionTransferred {
+                    crowdloan_id,
+                    from: contributor,
+                    to: dest,
+                    amount: 20,
+                }
+                .into()
+            );
+
+            // transfer the rest, the contributor is no longer counted
+            assert_ok!(Crowdloan::transfer_contribution(
+                RuntimeOrigin::signed(contributor),
+                crowdloan_id,
+                dest,
+                40
+            ));
+            assert_eq!(
+                pallet_crowdloan::Contributions::<Test>::get(crowdloan_id, contributor),
+                None
+            );
+            assert_eq!(
+                pallet_crowdloan::Contributions::<Test>::get(crowdloan_id, dest),
+                Some(60)
+            );
+            assert!(
+                pallet_crowdloan::Crowdloans::<Test>::get(crowdloan_id)
+                    .is_some_and(|c| c.contributors_count == 2)
+            );
+
+            // the receiver can withdraw the contribution
+            assert_ok!(Crowdloan::withdraw(
+                RuntimeOrigin::signed(dest),
+                crowdloan_id
+            ));
+            assert_eq!(pallet_balances::Pallet::<Test>::free_balance(dest), 60);
+
+            // ensure the contributions are listed per account
+            assert_eq!(
+                Crowdloan::get_contributions(&creator),
+                vec![(crowdloan_id, 50)]
+            );
+            assert!(Crowdloan::get_contributions(&dest).is_empty());
+        });
+}
+
+#[test]
+fn test_transfer_contribution_fails_if_below_minimum_contribution() {
+    TestState::default()
+        .with_balance(U256::from(1), 100)
+        .with_balance(U256::from(2), 100)
+        .build_and_execute(|| {
+            // create a crowdloan
+            let creator: AccountOf<Test> = U256::from(1);
+            assert_ok!(Crowdloan::create(
+                RuntimeOrigin::signed(creator),
+                50,
+                10,
+                300,
+                50,
+                Some(noop_call()),
+                None
+            ));
+            let crowdloan_id: CrowdloanId = 0;
+            let contributor: AccountOf<Test> = U256::from(2);
+            let dest: AccountOf<Test> = U256::from(3);
+            assert_ok!(Crowdloan::contribute(
+                RuntimeOrigin::signed(contributor),
+                crowdloan_id,
+                25
+            ));
+
+            // the amount transferred is below the minimum contribution
+            assert_err!(
+                Crowdloan::transfer_contribution(
+                    RuntimeOrigin::signed(contributor),
+                    crowdloan_id,
+                    dest,
+                    5
+                ),
+                pallet_crowdloan::Error::<Test>::ContributionTooLow
+            );
+
+            // the contribution left to the sender is below the minimum contribution
+            assert_err!(
+                Crowdloan::transfer_contribution(
+                    RuntimeOrigin::signed(contributor),
+                    crowdloan_id,
+                    dest,
+                    20
+                ),
+                pallet_crowdloan::Error::<Test>::ContributionTooLow
+            );
+
+            assert_ok!(Crowdloan::transfer_contribution(
+                RuntimeOrigin::signed(contributor),
+                crowdloan_id,
+                dest,
+                15
+            ));
+            assert_eq!(
+                pallet_crowdloan::Contributions::<Test>::get(crowdloan_id, contributor),
+                Some(10)
+            );
+        });
+}
+
+#[test]
+fn test_transfer_contribution_is_limited_by_current_tier() {
+    TestState::default()
+        .with_balance(U256::from(1), 100)
+        .with_balance(U256::from(2), 100)
+        .with_balance(U256::from(3), 100)
+        .build_and_execute(|| {
+            // create a crowdloan with a single tier
+            let creator: AccountOf<Test> = U256::from(1);
+            assert_ok!(Crowdloan::create(
+                RuntimeOrigin::signed(creator),
+                50,
+                10,
+                300,
+                50,
+                Some(noop_call()),
+                None
+            ));
+            let crowdloan_id: CrowdloanId = 0;
+            let tiers = vec![ContributionTier {
+                start: 0,
+                max_contribution: 30,
+            }];
+            assert_ok!(Crowdloan::set_terms(
+                RuntimeOrigin::signed(creator),
+                crowdloan_id,
+                None,
+                tiers.try_into().unwrap(),
+                None
+            ));
+
+            let contributor: AccountOf<Test> = U256::from(2);
+            let dest: AccountOf<Test> = U256::from(3);
+            for who in [contributor, dest] {
+                assert_ok!(Crowdloan::contribute(
+                    RuntimeOrigin::signed(who),
+                    crowdloan_id,
+                    20
+                ));
+            }
+
+            // the receiver would exceed the tier maximum
+            assert_err!(
+                Crowdloan::transfer_contribution(
+                    RuntimeOrigin::signed(contributor),
+                    crowdloan_id,
+                    dest,
+                    20
+                ),
+                pallet_crowdloan::Error::<Test>::TierMaximumReached
+            );
+
+            assert_ok!(Crowdloan::transfer_contribution(
+                RuntimeOrigin::signed(contributor),
+                crowdloan_id,
+                dest,
+                10
+            ));
+            assert_eq!(
+                pallet_crowdloan::Contributions::<Test>::get(crowdloan_id, dest),
+                Some(30)
+            );
+        });
+}
+
+#[test]
+fn test_transfer_contribution_fails_if_insufficient_contribution() {
+    TestState::default()
+        .with_balance(U256::from(1), 100)
+        .with_balance(U256::from(2), 100)
+        .build_and_execute(|| {
+            // create a crowdloan
+            let creator: AccountOf<Test> = U256::from(1);
+            assert_ok!(Crowdloan::create(
+                RuntimeOrigin::signed(creator),
+                50,
+                10,
+                300,
+                50,
+                Some(noop_call()),
+                None
+            ));
+            let crowdloan_id: CrowdloanId = 0;
+            let contributor: AccountOf<Test> = U256::from(2);
+            let dest: AccountOf<Test> = U256::from(3);
+            assert_ok!(Crowdloan::contribute(
+                RuntimeOrigin::signed(contributor),
+                crowdloan_id,
+                20
+            ));
+
+            assert_err!(
+                Crowdloan::transfer_contribution(
+                    RuntimeOrigin::signed(contributor),
+                    crowdloan_id,
+                    dest,
+                    30
+                ),
+                pallet_crowdloan::Error::<Test>::InsufficientContribution
+            );
+
+            // the creator can't transfer the deposit
+            assert_err!(
+                Crowdloan::transfer_contribution(
+                    RuntimeOrigin::signed(creator),
+                    crowdloan_id,
+                    dest,
+                    10
+                ),
+                pallet_crowdloan::Error::<Test>::InsufficientContribution
+            );
+
+            assert_err!(
+                Crowdloan::transfer_contribution(
+                    RuntimeOrigin::signed(dest),
+                    crowdloan_id,
+                    contributor,
+                    10
+                ),
+                pallet_crowdloan::Error::<Test>::NoContribution
+            );
+        });
+}
+
+#[test]
+fn test_transfer_contribution_fails_if_crowdloan_has_ended() {
+    TestState::default()
+        .with_balance(U256::from(1), 100)
+        .with_balance(U256::from(2), 100)
+        .build_and_execute(|| {
+            // create a crowdloan
+            let creator: AccountOf<Test> = U256::from(1);
+            assert_ok!(Crowdloan::create(
+                RuntimeOrigin::signed(creator),
+                50,
+                10,
+                300,
+                50,
+                Some(noop_call()),
+                None
+            ));
+            let crowdloan_id: CrowdloanId = 0;
+            let contributor: AccountOf<Test> = U256::from(2);
+            assert_ok!(Crowdloan::contribute(
+                RuntimeOrigin::signed(contributor),
+                crowdloan_id,
+                50
+            ));
+
+            // run past the end of the crowdloan
+            run_to_block(50);
+
+            assert_err!(
+                Crowdloan::transfer_contribution(
+                    RuntimeOrigin::signed(contributor),
+                    crowdloan_id,
+                    U256::from(3),
+                    20
+                ),
+                pallet_crowdloan::Error::<Test>::ContributionPeriodEnded
+            );
+        });
+}
@@ -40,6 +40,10 @@ pub trait WeightInfo {
 	fn update_min_contribution() -> Weight;
 	fn update_end() -> Weight;
 	fn update_cap() -> Weight;
+	fn set_terms() -> Weight;
+	fn claim_vested() -> Weight;
+	fn claim_refund() -> Weight;
+	fn transfer_contribution() -> Weight;
 }
 
 /// Weights for `pallet_crowdloan` using the Substrate node and recommended hardware.
@@ -177,6 +181,62 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Crowdloan::Crowdloans` (r:1 w:0)
+	/// Proof: `Crowdloan::Crowdloans` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+	/// Storage: `Crowdloan::Terms` (r:0 w:1)
+	/// Proof: `Crowdloan::Terms` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn set_terms() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `224`
+		//  Estimated: `3743`
+		// Minimum execution time: 10_240_000 picoseconds.
+		Weight::from_parts(10_651_000, 3743)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Crowdloan::Crowdloans` (r:1 w:0)
+	/// Proof: `Crowdloan::Crowdloans` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+	/// Storage: `Crowdloan::Vesting` (r:1 w:1)
+	/// Proof: `Crowdloan::Vesting` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn claim_vested() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `6148`
+		// Minimum execution time: 41_317_000 picoseconds.
+		Weight::from_parts(42_209_000, 6148)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Crowdloan::Crowdloans` (r:1 w:1)
+	/// Proof: `Crowdloan::Crowdloans` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+	/// Storage: `Crowdloan::Contributions` (r:1 w:1)
+	/// Proof: `Crowdloan::Contributions` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn claim_refund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `436`
+		//  Estimated: `6148`
+		// Minimum execution time: 44_583_000 picoseconds.
+		Weight::from_parts(45_394_000, 6148)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Crowdloan::Crowdloans` (r:1 w:1)
+	/// Proof: `Crowdloan::Crowdloans` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+	/// Storage: `Crowdloan::Contributions` (r:2 w:2)
+	/// Proof: `Crowdloan::Contributions` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn transfer_contribution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `380`
+		//  Estimated: `6044`
+		// Minimum execution time: 18_452_000 picoseconds.
+		Weight::from_parts(19_013_000, 6044)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -313,4 +373,60 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Crowdloan::Crowdloans` (r:1 w:0)
+	/// Proof: `Crowdloan::Crowdloans` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+	/// Storage: `Crowdloan::Terms` (r:0 w:1)
+	/// Proof: `Crowdloan::Terms` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn set_terms() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `224`
+		//  Estimated: `3743`
+		// Minimum execution time: 10_240_000 picoseconds.
+		Weight::from_parts(10_651_000, 3743)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Crowdloan::Crowdloans` (r:1 w:0)
+	/// Proof: `Crowdloan::Crowdloans` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+	/// Storage: `Crowdloan::Vesting` (r:1 w:1)
+	/// Proof: `Crowdloan::Vesting` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn claim_vested() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `6148`
+		// Minimum execution time: 41_317_000 picoseconds.
+		Weight::from_parts(42_209_000, 6148)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Crowdloan::Crowdloans` (r:1 w:1)
+	/// Proof: `Crowdloan::Crowdloans` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+	/// Storage: `Crowdloan::Contributions` (r:1 w:1)
+	/// Proof: `Crowdloan::Contributions` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn claim_refund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `436`
+		//  Estimated: `6148`
+		// Minimum execution time: 44_583_000 picoseconds.
+		Weight::from_parts(45_394_000, 6148)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Crowdloan::Crowdloans` (r:1 w:1)
+	/// Proof: `Crowdloan::Crowdloans` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+	/// Storage: `Crowdloan::Contributions` (r:2 w:2)
+	/// Proof: `Crowdloan::Contributions` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn transfer_contribution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `380`
+		//  Estimated: `6044`
+		// Minimum execution time: 18_452_000 picoseconds.
+		Weight::from_parts(19_013_000, 6044)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
\ No newline at end of file
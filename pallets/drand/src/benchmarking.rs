@@ -79,5 +79,75 @@ mod benchmarks {
         assert_eq!(Pulses::<T>::get(p.round), Some(p));
     }
 
+    #[benchmark]
+    fn set_beacon_sources() {
+        let sources = BeaconSources::truncate_from(
+            ENDPOINTS
+                .iter()
+                .map(|endpoint| BeaconSource::truncate_from(endpoint.as_bytes().to_vec()))
+                .collect(),
+        );
+
+        #[extrinsic_call]
+        set_beacon_sources(RawOrigin::Root, sources.clone(), 2);
+
+        assert_eq!(BeaconSourceList::<T>::get(), sources);
+        assert_eq!(SourceQuorum::<T>::get(), 2);
+    }
+
+    #[benchmark]
+    fn register_beacon() {
+        let info: BeaconInfoResponse = serde_json::from_str(DRAND_INFO_RESPONSE).unwrap();
+        let config = info.try_into_beacon_config().unwrap();
+
+        #[extrinsic_call]
+        register_beacon(RawOrigin::Root, 1, config.clone());
+
+        assert_eq!(Beacons::<T>::get(1), Some(config));
+    }
+
+    #[benchmark]
+    fn remove_beacon() {
+        let info: BeaconInfoResponse = serde_json::from_str(DRAND_INFO_RESPONSE).unwrap();
+        let config = info.try_into_beacon_config().unwrap();
+        Beacons::<T>::insert(1, config);
+
+        #[extrinsic_call]
+        remove_beacon(RawOrigin::Root, 1);
+
+        assert!(Beacons::<T>::get(1).is_none());
+    }
+
+    #[benchmark]
+    fn write_beacon_pulses() {
+        let info: BeaconInfoResponse = serde_json::from_str(DRAND_INFO_RESPONSE).unwrap();
+        let config = info.try_into_beacon_config().unwrap();
+        let u_p: DrandResponseBody = serde_json::from_str(DRAND_PULSE).unwrap();
+        let p = u_p.try_into_beacon_pulse().unwrap();
+
+        let alice = sp_keyring::Sr25519Keyring::Alice.public();
+        Beacons::<T>::insert(1, config);
+
+        let pulses_payload = BeaconPulsesPayload {
+            block_number: 1u32.into(),
+            beacon_id: 1,
+            pulses: vec![p.clone()],
+            public: alice.into(),
+        };
+
+        #[extrinsic_call]
+        write_beacon_pulses(RawOrigin::None, pulses_payload, None);
+
+        assert_eq!(BeaconPulses::<T>::get(1, p.pulse.round), Some(p.pulse));
+    }
+
+    #[benchmark]
+    fn set_pulse_retention() {
+        #[extrinsic_call]
+        set_pulse_retention(RawOrigin::Root, 100);
+
+        assert_eq!(PulseRetention::<T>::get(), 100);
+    }
+
     impl_benchmark_test_suite!(Drand, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -26,6 +26,23 @@
 //! signed transaction to encode them in the runtime. The runtime uses the optimized arkworks host
 //! functions to efficiently verify the pulse.
 //!
+//! Besides the default beacon, additional drand chains can be registered, each with its own
+//! configuration, pulse storage and verification scheme, so consumers can choose which beacon
+//! they rely on.
+//!
+//! Other pallets consume the pulses through the `RandomnessProvider` trait, by round or by the
+//! block at which a round was the latest one stored.
+//!
+//! Pulses older than the retention window set by root are pruned in `on_idle`, in chunks of at
+//! most `MaxPulsesPrunedPerBlock` rounds. Other pallets reference the rounds of a beacon they
+//! are waiting on with `reference_round` and release them with `release_round`, and referenced
+//! pulses are kept until they are released.
+//!
+//! The offchain worker queries every configured beacon source and only accepts a pulse once a
+//! quorum of sources served a pulse passing the BLS verification, preferring the most recent
+//! round so a stuck relay can't hold the chain back. The health of each source is tracked in the
+//! persistent offchain storage, and sources failing repeatedly are skipped for a while.
+//!
 //! Run `cargo doc --package pallet-drand --open` to view this pallet's documentation.
 
 // We make sure this pallet uses `no_std` for compiling to Wasm.
@@ -39,6 +56,7 @@ pub use pallet::*;
 extern crate alloc;
 
 use alloc::{format, string::String, vec, vec::Vec};
+use ark_serialize::CanonicalDeserialize;
 use codec::Encode;
 use frame_support::{pallet_prelude::*, traits::Randomness};
 use frame_system::{
@@ -50,14 +68,22 @@ use frame_system::{
 };
 use scale_info::prelude::cmp;
 use sha2::{Digest, Sha256};
-use sp_core::blake2_256;
+use sp_core::{blake2_256, keccak_256};
 use sp_runtime::{
     KeyTypeId, Saturating,
-    traits::{Hash, One},
+    offchain::storage::StorageValueRef,
+    traits::{Hash, One, Zero},
     transaction_validity::{InvalidTransaction, TransactionValidity, ValidTransaction},
 };
+use tle::{
+    curves::drand::TinyBLS381,
+    stream_ciphers::AESGCMStreamCipherProvider,
+    tlock::{TLECiphertext, tld},
+};
+use w3f_bls::{EngineBLS, ZBLS};
 
 pub mod bls12_381;
+pub mod migrations;
 pub mod types;
 pub mod utils;
 pub mod verifier;
@@ -74,8 +100,8 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
-/// the main drand api endpoint
-const ENDPOINTS: [&str; 5] = [
+/// the default drand api endpoints
+pub const ENDPOINTS: [&str; 5] = [
     "https://api.drand.sh",
     "https://api2.drand.sh",
     "https://api3.drand.sh",
@@ -88,10 +114,20 @@ const ENDPOINTS: [&str; 5] = [
 pub const QUICKNET_CHAIN_HASH: &str =
     "52db9ba70e0cc0f6eaf7803dd07447a1f5477735fd3f661792ba94600c84e971";
 
-const CHAIN_HASH: &str = QUICKNET_CHAIN_HASH;
-
 pub const MAX_PULSES_TO_FETCH: u64 = 50;
 
+/// the prefix of the persistent offchain storage keys holding the health of each source
+pub const SOURCE_HEALTH_KEY_PREFIX: &[u8] = b"drand::source_health::";
+
+/// the number of consecutive failures after which a source is skipped
+pub const SOURCE_FAILURE_THRESHOLD: u32 = 3;
+
+/// the number of milliseconds a failing source is skipped for
+pub const SOURCE_BACKOFF_MS: u64 = 30_000;
+
+/// the default number of rounds kept by the pruning, about a week of quicknet pulses
+pub const DEFAULT_PULSE_RETENTION: RoundNumber = 201_600;
+
 /// Defines application identifier for crypto keys of this module.
 ///
 /// Every module that deals with signatures needs to declare its unique identifier for
@@ -146,6 +182,12 @@ impl<T: SigningTypes> SignedPayload<T> for PulsesPayload<T::Public, BlockNumberF
     }
 }
 
+impl<T: SigningTypes> SignedPayload<T> for BeaconPulsesPayload<T::Public, BlockNumberFor<T>> {
+    fn public(&self) -> T::Public {
+        self.public.clone()
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -172,6 +214,9 @@ pub mod pallet {
         /// complete.
         #[pallet::constant]
         type HttpFetchTimeout: Get<u64>;
+        /// The maximum number of rounds the pruning removes in a block.
+        #[pallet::constant]
+        type MaxPulsesPrunedPerBlock: Get<u32>;
     }
 
     /// the drand beacon configuration
@@ -210,12 +255,98 @@ pub mod pallet {
         }
     }
 
+    /// the drand relays queried by the offchain worker
+    #[pallet::storage]
+    pub type BeaconSourceList<T: Config> =
+        StorageValue<_, BeaconSources, ValueQuery, DefaultBeaconSources<T>>;
+
+    #[pallet::type_value]
+    pub fn DefaultBeaconSources<T: Config>() -> BeaconSources {
+        BeaconSources::truncate_from(
+            ENDPOINTS
+                .iter()
+                .map(|endpoint| BeaconSource::truncate_from(endpoint.as_bytes().to_vec()))
+                .collect(),
+        )
+    }
+
+    /// the number of sources that must serve a verified pulse before it is accepted
+    #[pallet::storage]
+    pub type SourceQuorum<T: Config> = StorageValue<_, u32, ValueQuery, DefaultSourceQuorum<T>>;
+
+    #[pallet::type_value]
+    pub fn DefaultSourceQuorum<T: Config>() -> u32 {
+        1
+    }
+
     /// map round number to pulse
     #[pallet::storage]
     pub type Pulses<T: Config> = StorageMap<_, Blake2_128Concat, RoundNumber, Pulse, OptionQuery>;
 
     #[pallet::storage]
-    pub(super) type LastStoredRound<T: Config> = StorageValue<_, RoundNumber, ValueQuery>;
+    pub type LastStoredRound<T: Config> = StorageValue<_, RoundNumber, ValueQuery>;
+
+    /// the last round of the default beacon stored as of each block
+    #[pallet::storage]
+    pub type RoundAtBlock<T: Config> =
+        StorageMap<_, Twox64Concat, BlockNumberFor<T>, RoundNumber, OptionQuery>;
+
+    /// the oldest block of `RoundAtBlock` which may still be recorded
+    #[pallet::storage]
+    pub type OldestRecordedBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// the oldest round of `Pulses` which may still be stored, the next one the pruning considers
+    #[pallet::storage]
+    pub type OldestStoredRound<T: Config> = StorageValue<_, RoundNumber, ValueQuery>;
+
+    /// the number of references other pallets hold on each round of a beacon, whose pulse is
+    /// not pruned
+    #[pallet::storage]
+    pub type ReferencedRounds<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, BeaconId, Twox64Concat, RoundNumber, u32, OptionQuery>;
+
+    /// the number of rounds behind the last stored round which are kept, 0 keeps every pulse
+    #[pallet::storage]
+    pub type PulseRetention<T: Config> =
+        StorageValue<_, RoundNumber, ValueQuery, DefaultPulseRetention<T>>;
+
+    #[pallet::type_value]
+    pub fn DefaultPulseRetention<T: Config>() -> RoundNumber {
+        DEFAULT_PULSE_RETENTION
+    }
+
+    /// the configurations of the beacons bridged besides the default one
+    #[pallet::storage]
+    pub type Beacons<T: Config> =
+        StorageMap<_, Twox64Concat, BeaconId, BeaconConfiguration, OptionQuery>;
+
+    /// map beacon and round number to pulse, for the beacons besides the default one
+    #[pallet::storage]
+    pub type BeaconPulses<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BeaconId,
+        Blake2_128Concat,
+        RoundNumber,
+        Pulse,
+        OptionQuery,
+    >;
+
+    /// the last round stored for each of the beacons besides the default one
+    #[pallet::storage]
+    pub type BeaconLastStoredRound<T: Config> =
+        StorageMap<_, Twox64Concat, BeaconId, RoundNumber, ValueQuery>;
+
+    /// the oldest round which may still be stored for each of the beacons besides the default one
+    #[pallet::storage]
+    pub type BeaconOldestStoredRound<T: Config> =
+        StorageMap<_, Twox64Concat, BeaconId, RoundNumber, ValueQuery>;
+
+    /// the block when the next unsigned transaction will be accepted for each of the beacons
+    /// besides the default one
+    #[pallet::storage]
+    pub(super) type BeaconNextUnsignedAt<T: Config> =
+        StorageMap<_, Twox64Concat, BeaconId, BlockNumberFor<T>, ValueQuery>;
 
     /// Defines the block when next unsigned transaction will be accepted.
     ///
@@ -229,10 +360,31 @@ pub mod pallet {
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         BeaconConfigChanged,
+        /// The beacon sources or their quorum changed.
+        BeaconSourcesChanged {
+            quorum: u32,
+        },
         /// Successfully set a new pulse(s).
         NewPulse {
             rounds: Vec<RoundNumber>,
         },
+        /// A beacon was registered or its configuration changed.
+        BeaconRegistered {
+            beacon_id: BeaconId,
+        },
+        /// A beacon and its pulses were removed.
+        BeaconRemoved {
+            beacon_id: BeaconId,
+        },
+        /// Successfully set new pulse(s) of a beacon besides the default one.
+        NewBeaconPulse {
+            beacon_id: BeaconId,
+            rounds: Vec<RoundNumber>,
+        },
+        /// The number of rounds kept by the pruning was changed.
+        PulseRetentionSet {
+            retention: RoundNumber,
+        },
     }
 
     #[pallet::error]
@@ -249,15 +401,57 @@ pub mod pallet {
         InvalidRoundNumber,
         /// the pulse could not be verified
         PulseVerificationError,
+        /// the quorum must be at least one and at most the number of sources
+        InvalidSourceQuorum,
+        /// the default beacon is configured with `set_beacon_config`
+        DefaultBeaconId,
+        /// the beacon scheme is not supported
+        UnsupportedBeaconScheme,
+        /// no beacon is registered with this id
+        UnknownBeacon,
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_block_number: BlockNumberFor<T>) -> Weight {
+            // the round recorded in `on_finalize`
+            T::DbWeight::get().reads_writes(2, 2)
+        }
+
+        fn on_finalize(block_number: BlockNumberFor<T>) {
+            let round = LastStoredRound::<T>::get();
+            if round > 0 {
+                RoundAtBlock::<T>::insert(block_number, round);
+                if OldestRecordedBlock::<T>::get().is_zero() {
+                    OldestRecordedBlock::<T>::put(block_number);
+                }
+            }
+        }
+
+        fn on_idle(_block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::prune_pulses(remaining_weight)
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            migrations::migrate_init_oldest_stored_round::<T>()
+        }
+
         fn offchain_worker(block_number: BlockNumberFor<T>) {
             log::debug!("Drand OCW working on block: {:?}", block_number);
             if let Err(e) = Self::fetch_drand_pulse_and_send_unsigned(block_number) {
                 log::debug!("Drand: Failed to fetch pulse from drand. {:?}", e);
             }
+            for (beacon_id, config) in Beacons::<T>::iter() {
+                if let Err(e) =
+                    Self::fetch_beacon_pulses_and_send_unsigned(beacon_id, config, block_number)
+                {
+                    log::debug!(
+                        "Drand: Failed to fetch pulse from beacon {}. {:?}",
+                        beacon_id,
+                        e
+                    );
+                }
+            }
         }
     }
 
@@ -300,6 +494,20 @@ pub mod pallet {
                         &payload.public,
                     )
                 }
+                Call::write_beacon_pulses {
+                    pulses_payload: payload,
+                    signature,
+                } => {
+                    let signature = signature.as_ref().ok_or(InvalidTransaction::BadSigner)?;
+                    if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+                        return InvalidTransaction::BadProof.into();
+                    }
+                    Self::validate_beacon_transaction_parameters(
+                        payload.beacon_id,
+                        &payload.block_number,
+                        &payload.public,
+                    )
+                }
                 _ => InvalidTransaction::Call.into(),
             }
         }
@@ -310,8 +518,8 @@ pub mod pallet {
         /// Verify and write a pulse from the beacon into the runtime
         #[pallet::call_index(0)]
         #[pallet::weight(Weight::from_parts(5_708_000_000, 0)
-        .saturating_add(T::DbWeight::get().reads(2_u64))
-        .saturating_add(T::DbWeight::get().writes(3_u64)))]
+        .saturating_add(T::DbWeight::get().reads(3_u64))
+        .saturating_add(T::DbWeight::get().writes(4_u64)))]
         pub fn write_pulse(
             origin: OriginFor<T>,
             pulses_payload: PulsesPayload<T::Public, BlockNumberFor<T>>,
@@ -347,6 +555,13 @@ pub mod pallet {
             // Update LastStoredRound storage
             LastStoredRound::<T>::put(last_stored_round);
 
+            // The first pulses stored are the oldest ones the pruning starts from
+            if let Some(first_round) = new_rounds.first() {
+                if OldestStoredRound::<T>::get() == 0 {
+                    OldestStoredRound::<T>::put(first_round);
+                }
+            }
+
             // Update the next unsigned block number
             let current_block = frame_system::Pallet::<T>::block_number();
             <NextUnsignedAt<T>>::put(current_block.saturating_add(One::one()));
@@ -360,10 +575,11 @@ pub mod pallet {
         }
         /// allows the root user to set the beacon configuration
         /// generally this would be called from an offchain worker context.
-        /// there is no verification of configurations, so be careful with this.
+        /// only the scheme of the configuration is checked, so be careful with this.
         ///
         /// * `origin`: the root user
-        /// * `config`: the beacon configuration
+        /// * `config`: the beacon configuration, with one of the supported unchained schemes;
+        ///   chained beacons are bridged with `register_beacon`
         #[pallet::call_index(1)]
         #[pallet::weight(Weight::from_parts(9_878_000, 0)
         .saturating_add(T::DbWeight::get().reads(0_u64))
@@ -374,6 +590,11 @@ pub mod pallet {
             _signature: Option<T::Signature>,
         ) -> DispatchResult {
             ensure_root(origin)?;
+            ensure!(
+                BeaconScheme::from_scheme_id(&config_payload.config.scheme_id)
+                    .is_some_and(|scheme| !scheme.is_chained()),
+                Error::<T>::UnsupportedBeaconScheme
+            );
             BeaconConfig::<T>::put(config_payload.config);
 
             // now increment the block number at which we expect next unsigned transaction.
@@ -383,6 +604,162 @@ pub mod pallet {
             Self::deposit_event(Event::BeaconConfigChanged {});
             Ok(())
         }
+
+        /// allows the root user to set the drand relays queried by the offchain worker
+        /// and the number of them that must serve a verified pulse for it to be accepted
+        ///
+        /// * `origin`: the root user
+        /// * `sources`: the base urls of the relays
+        /// * `quorum`: the number of sources that must agree
+        #[pallet::call_index(2)]
+        #[pallet::weight(Weight::from_parts(8_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(0_u64))
+        .saturating_add(T::DbWeight::get().writes(2_u64)))]
+        pub fn set_beacon_sources(
+            origin: OriginFor<T>,
+            sources: BeaconSources,
+            quorum: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                quorum > 0 && usize::try_from(quorum).is_ok_and(|q| q <= sources.len()),
+                Error::<T>::InvalidSourceQuorum
+            );
+
+            BeaconSourceList::<T>::put(sources);
+            SourceQuorum::<T>::put(quorum);
+
+            Self::deposit_event(Event::BeaconSourcesChanged { quorum });
+            Ok(())
+        }
+
+        /// allows the root user to bridge an additional drand chain, or change its configuration
+        ///
+        /// * `origin`: the root user
+        /// * `beacon_id`: the id of the beacon, other than the default beacon
+        /// * `config`: the beacon configuration, with one of the supported schemes
+        #[pallet::call_index(3)]
+        #[pallet::weight(Weight::from_parts(9_878_000, 0)
+        .saturating_add(T::DbWeight::get().reads(0_u64))
+        .saturating_add(T::DbWeight::get().writes(1_u64)))]
+        pub fn register_beacon(
+            origin: OriginFor<T>,
+            beacon_id: BeaconId,
+            config: BeaconConfiguration,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(beacon_id != DEFAULT_BEACON_ID, Error::<T>::DefaultBeaconId);
+            ensure!(
+                BeaconScheme::from_scheme_id(&config.scheme_id).is_some(),
+                Error::<T>::UnsupportedBeaconScheme
+            );
+
+            Beacons::<T>::insert(beacon_id, config);
+
+            Self::deposit_event(Event::BeaconRegistered { beacon_id });
+            Ok(())
+        }
+
+        /// allows the root user to stop bridging an additional drand chain and remove its pulses
+        ///
+        /// * `origin`: the root user
+        /// * `beacon_id`: the id of the beacon to remove
+        #[pallet::call_index(4)]
+        #[pallet::weight(Weight::from_parts(9_878_000, 0)
+        .saturating_add(T::DbWeight::get().reads(1_u64))
+        .saturating_add(T::DbWeight::get().writes(4_u64)))]
+        pub fn remove_beacon(origin: OriginFor<T>, beacon_id: BeaconId) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                Beacons::<T>::contains_key(beacon_id),
+                Error::<T>::UnknownBeacon
+            );
+
+            Beacons::<T>::remove(beacon_id);
+            BeaconLastStoredRound::<T>::remove(beacon_id);
+            BeaconOldestStoredRound::<T>::remove(beacon_id);
+            BeaconNextUnsignedAt::<T>::remove(beacon_id);
+            let _ = BeaconPulses::<T>::clear_prefix(beacon_id, u32::MAX, None);
+
+            Self::deposit_event(Event::BeaconRemoved { beacon_id });
+            Ok(())
+        }
+
+        /// Verify and write pulses from an additional beacon into the runtime
+        #[pallet::call_index(5)]
+        #[pallet::weight(Weight::from_parts(5_708_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(4_u64))
+        .saturating_add(T::DbWeight::get().writes(4_u64)))]
+        pub fn write_beacon_pulses(
+            origin: OriginFor<T>,
+            pulses_payload: BeaconPulsesPayload<T::Public, BlockNumberFor<T>>,
+            _signature: Option<T::Signature>,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+            let beacon_id = pulses_payload.beacon_id;
+            let config = Beacons::<T>::get(beacon_id).ok_or(Error::<T>::UnknownBeacon)?;
+
+            let mut last_stored_round = BeaconLastStoredRound::<T>::get(beacon_id);
+            let mut new_rounds = Vec::new();
+
+            for beacon_pulse in &pulses_payload.pulses {
+                let pulse = &beacon_pulse.pulse;
+                let is_verified = T::Verifier::verify_chained(
+                    config.clone(),
+                    pulse.clone(),
+                    &beacon_pulse.previous_signature,
+                )
+                .map_err(|_| Error::<T>::PulseVerificationError)?;
+
+                if is_verified {
+                    ensure!(
+                        pulse.round > last_stored_round,
+                        Error::<T>::InvalidRoundNumber
+                    );
+
+                    BeaconPulses::<T>::insert(beacon_id, pulse.round, pulse.clone());
+                    last_stored_round = pulse.round;
+                    new_rounds.push(pulse.round);
+                }
+            }
+
+            BeaconLastStoredRound::<T>::insert(beacon_id, last_stored_round);
+            if let Some(first_round) = new_rounds.first() {
+                if BeaconOldestStoredRound::<T>::get(beacon_id) == 0 {
+                    BeaconOldestStoredRound::<T>::insert(beacon_id, first_round);
+                }
+            }
+
+            let current_block = frame_system::Pallet::<T>::block_number();
+            BeaconNextUnsignedAt::<T>::insert(beacon_id, current_block.saturating_add(One::one()));
+
+            if !new_rounds.is_empty() {
+                Self::deposit_event(Event::NewBeaconPulse {
+                    beacon_id,
+                    rounds: new_rounds,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// allows the root user to set the number of rounds kept by the pruning
+        ///
+        /// * `origin`: the root user
+        /// * `retention`: the number of rounds behind the last stored round which are kept,
+        ///   0 disables the pruning
+        #[pallet::call_index(6)]
+        #[pallet::weight(Weight::from_parts(9_878_000, 0)
+        .saturating_add(T::DbWeight::get().reads(0_u64))
+        .saturating_add(T::DbWeight::get().writes(1_u64)))]
+        pub fn set_pulse_retention(origin: OriginFor<T>, retention: RoundNumber) -> DispatchResult {
+            ensure_root(origin)?;
+
+            PulseRetention::<T>::put(retention);
+
+            Self::deposit_event(Event::PulseRetentionSet { retention });
+            Ok(())
+        }
     }
 }
 
@@ -422,13 +799,23 @@ impl<T: Config> Pallet<T> {
             for round in (last_stored_round.saturating_add(1))
                 ..=(last_stored_round.saturating_add(rounds_to_fetch))
             {
-                let unbounded_pulse = Self::fetch_drand_by_round(round)
-                    .map_err(|_| "Drand: Failed to query drand for round")?;
+                // Submit the rounds fetched so far rather than nothing if a round is missing
+                let Ok(unbounded_pulse) = Self::fetch_drand_by_round(round) else {
+                    log::warn!("Drand: Failed to query drand for round {}", round);
+                    break;
+                };
                 let pulse = unbounded_pulse
                     .try_into_pulse()
                     .map_err(|_| "Drand: Received pulse contains invalid data")?;
                 pulses.push(pulse);
             }
+            if pulses.is_empty() {
+                return Err("Drand: Failed to query drand for round");
+            }
+            let last_fetched_round = pulses
+                .last()
+                .map(|pulse| pulse.round)
+                .unwrap_or(last_stored_round);
 
             let signer = Signer::<T, T::AuthorityId>::all_accounts();
 
@@ -449,7 +836,7 @@ impl<T: Config> Pallet<T> {
                     Ok(()) => log::debug!(
                         "Drand: [{:?}] Submitted new pulses up to round: {:?}",
                         acc.id,
-                        last_stored_round.saturating_add(rounds_to_fetch)
+                        last_fetched_round
                     ),
                     Err(e) => log::error!(
                         "Drand: [{:?}] Failed to submit transaction: {:?}",
@@ -463,40 +850,142 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// fetch the pulses of an additional beacon missing on-chain
+    /// then send a signed transaction to include them on-chain
+    fn fetch_beacon_pulses_and_send_unsigned(
+        beacon_id: BeaconId,
+        config: BeaconConfiguration,
+        block_number: BlockNumberFor<T>,
+    ) -> Result<(), &'static str> {
+        // Ensure we can send an unsigned transaction
+        if BeaconNextUnsignedAt::<T>::get(beacon_id) > block_number {
+            return Err("Drand: Too early to send unsigned transaction");
+        }
+
+        let chain_hash = Self::chain_hash(&config);
+        let latest_path = format!("/{}/public/latest", chain_hash);
+        let current_round = Self::fetch_and_decode_from_sources(&latest_path, &config, None)
+            .map_err(|_| "Failed to query drand")?
+            .round;
+
+        let mut last_stored_round = BeaconLastStoredRound::<T>::get(beacon_id);
+        if last_stored_round == 0 {
+            last_stored_round = current_round.saturating_sub(1);
+        }
+        if current_round <= last_stored_round {
+            return Ok(());
+        }
+
+        let rounds_to_fetch = cmp::min(
+            current_round.saturating_sub(last_stored_round),
+            MAX_PULSES_TO_FETCH,
+        );
+        let mut pulses = Vec::new();
+        for round in (last_stored_round.saturating_add(1))
+            ..=(last_stored_round.saturating_add(rounds_to_fetch))
+        {
+            let round_path = format!("/{}/public/{}", chain_hash, round);
+            let Ok(response) =
+                Self::fetch_and_decode_from_sources(&round_path, &config, Some(round))
+            else {
+                log::warn!(
+                    "Drand: Failed to query beacon {} for round {}",
+                    beacon_id,
+                    round
+                );
+                break;
+            };
+            let pulse = response
+                .try_into_beacon_pulse()
+                .map_err(|_| "Drand: Received pulse contains invalid data")?;
+            pulses.push(pulse);
+        }
+        if pulses.is_empty() {
+            return Err("Drand: Failed to query drand for round");
+        }
+
+        let signer = Signer::<T, T::AuthorityId>::all_accounts();
+        let results = signer.send_unsigned_transaction(
+            |account| BeaconPulsesPayload {
+                block_number,
+                beacon_id,
+                pulses: pulses.clone(),
+                public: account.public.clone(),
+            },
+            |pulses_payload, signature| Call::write_beacon_pulses {
+                pulses_payload,
+                signature: Some(signature),
+            },
+        );
+
+        for (acc, res) in &results {
+            if let Err(e) = res {
+                log::error!(
+                    "Drand: [{:?}] Failed to submit transaction for beacon {}: {:?}",
+                    acc.id,
+                    beacon_id,
+                    e
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     fn fetch_drand_by_round(round: RoundNumber) -> Result<DrandResponseBody, &'static str> {
-        let relative_path = format!("/{}/public/{}", CHAIN_HASH, round);
-        Self::fetch_and_decode_from_any_endpoint(&relative_path)
+        let config = BeaconConfig::<T>::get();
+        let relative_path = format!("/{}/public/{}", Self::chain_hash(&config), round);
+        Self::fetch_and_decode_from_sources(&relative_path, &config, Some(round))
     }
 
     fn fetch_drand_latest() -> Result<DrandResponseBody, &'static str> {
-        let relative_path = format!("/{}/public/latest", CHAIN_HASH);
-        Self::fetch_and_decode_from_any_endpoint(&relative_path)
+        let config = BeaconConfig::<T>::get();
+        let relative_path = format!("/{}/public/latest", Self::chain_hash(&config));
+        Self::fetch_and_decode_from_sources(&relative_path, &config, None)
+    }
+
+    /// the hex encoded chain hash of a beacon, which the relays serve its pulses under
+    fn chain_hash(config: &BeaconConfiguration) -> String {
+        config.hash.iter().map(|b| format!("{:02x}", b)).collect()
     }
 
-    /// Try to fetch from multiple endpoints simultaneously and return the first successfully decoded JSON response.
-    fn fetch_and_decode_from_any_endpoint(
+    /// Fetch from the available sources simultaneously until a quorum of them served a verified pulse.
+    ///
+    /// Responses which don't decode, are not for the expected round or fail the verification
+    /// count as failures of their source. When fetching the latest round every source is awaited
+    /// and the most recent round is returned, so a relay stuck on an old round doesn't hold the
+    /// chain back.
+    fn fetch_and_decode_from_sources(
         relative_path: &str,
+        config: &BeaconConfiguration,
+        expected_round: Option<RoundNumber>,
     ) -> Result<DrandResponseBody, &'static str> {
-        let uris: Vec<String> = ENDPOINTS
-            .iter()
-            .map(|e| format!("{}{}", e, relative_path))
-            .collect();
-        let deadline = sp_io::offchain::timestamp().add(
-            sp_runtime::offchain::Duration::from_millis(T::HttpFetchTimeout::get()),
-        );
-
-        let mut pending_requests: Vec<(String, sp_runtime::offchain::http::PendingRequest)> =
-            vec![];
-
-        // Try sending requests to all endpoints.
-        for uri in &uris {
-            let request = sp_runtime::offchain::http::Request::get(uri);
+        let started_at = sp_io::offchain::timestamp();
+        let deadline = started_at.add(sp_runtime::offchain::Duration::from_millis(
+            T::HttpFetchTimeout::get(),
+        ));
+        let sources = Self::available_sources(started_at.unix_millis());
+        let quorum = usize::try_from(SourceQuorum::<T>::get())
+            .unwrap_or(usize::MAX)
+            .clamp(1, sources.len().max(1));
+
+        let mut pending_requests: Vec<(
+            String,
+            String,
+            sp_runtime::offchain::http::PendingRequest,
+        )> = vec![];
+
+        // Try sending requests to all sources.
+        for source in sources {
+            let uri = format!("{}{}", source, relative_path);
+            let request = sp_runtime::offchain::http::Request::get(&uri);
             match request.deadline(deadline).send() {
                 Ok(pending_req) => {
-                    pending_requests.push((uri.clone(), pending_req));
+                    pending_requests.push((source, uri, pending_req));
                 }
                 Err(_) => {
                     log::warn!("Drand: HTTP IO Error on endpoint {}", uri);
+                    Self::record_source_failure(&source);
                 }
             }
         }
@@ -506,7 +995,9 @@ impl<T: Config> Pallet<T> {
             return Err("Drand: No endpoints could be queried");
         }
 
-        loop {
+        let mut accepted: Vec<DrandResponseBody> = Vec::new();
+
+        'fetch: loop {
             let now = sp_io::offchain::timestamp();
             if now > deadline {
                 // We've passed our deadline without getting a valid response.
@@ -517,7 +1008,7 @@ impl<T: Config> Pallet<T> {
             let mut still_pending = false;
             let mut next_iteration_requests = Vec::new();
 
-            for (uri, request) in pending_requests.drain(..) {
+            for (source, uri, request) in pending_requests.drain(..) {
                 match request.try_wait(Some(deadline)) {
                     Ok(Ok(response)) => {
                         if response.code != 200 {
@@ -526,13 +1017,34 @@ impl<T: Config> Pallet<T> {
                                 response.code,
                                 uri
                             );
+                            Self::record_source_failure(&source);
                             continue;
                         }
 
                         let body = response.body().collect::<Vec<u8>>();
                         match serde_json::from_slice::<DrandResponseBody>(&body) {
                             Ok(decoded) => {
-                                return Ok(decoded);
+                                if expected_round.is_some_and(|round| round != decoded.round)
+                                    || !Self::verify_response(config, &decoded)
+                                {
+                                    log::warn!(
+                                        "Drand: Unverified pulse for round {} from {}",
+                                        decoded.round,
+                                        uri
+                                    );
+                                    Self::record_source_failure(&source);
+                                    continue;
+                                }
+
+                                let latency =
+                                    sp_io::offchain::timestamp().diff(&started_at).millis();
+                                Self::record_source_success(&source, decoded.round, latency);
+                                accepted.push(decoded);
+                                // A round is the same on every source, while the latest round
+                                // is awaited from all of them.
+                                if expected_round.is_some() && accepted.len() >= quorum {
+                                    break 'fetch;
+                                }
                             }
                             Err(e) => {
                                 log::warn!(
@@ -541,15 +1053,17 @@ impl<T: Config> Pallet<T> {
                                     e,
                                     String::from_utf8_lossy(&body)
                                 );
+                                Self::record_source_failure(&source);
                             }
                         }
                     }
                     Ok(Err(e)) => {
                         log::warn!("Drand: HTTP error from {}: {:?}", uri, e);
+                        Self::record_source_failure(&source);
                     }
                     Err(pending_req) => {
                         still_pending = true;
-                        next_iteration_requests.push((uri, pending_req));
+                        next_iteration_requests.push((source, uri, pending_req));
                     }
                 }
             }
@@ -561,21 +1075,356 @@ impl<T: Config> Pallet<T> {
             }
         }
 
-        // If we reached here, no valid response was obtained from any endpoint.
-        log::warn!("Drand: No valid response from any endpoint");
+        // The sources which didn't answer before the deadline are considered failing too.
+        for (source, _, _) in &pending_requests {
+            Self::record_source_failure(source);
+        }
+
+        if accepted.len() >= quorum {
+            return accepted
+                .into_iter()
+                .max_by_key(|response| response.round)
+                .ok_or("Drand: No valid response from any endpoint");
+        }
+
+        // If we reached here, not enough valid responses were obtained.
+        log::warn!(
+            "Drand: {} of {} required valid responses",
+            accepted.len(),
+            quorum
+        );
         Err("Drand: No valid response from any endpoint")
     }
 
+    /// Verify a response against the beacon configuration before relaying it.
+    fn verify_response(config: &BeaconConfiguration, response: &DrandResponseBody) -> bool {
+        let Ok(pulse) = response.try_into_pulse() else {
+            return false;
+        };
+        let chained = BeaconScheme::from_scheme_id(&config.scheme_id)
+            .is_some_and(|scheme| scheme.is_chained());
+        if chained {
+            T::Verifier::verify_chained(config.clone(), pulse, &response.previous_signature)
+        } else {
+            T::Verifier::verify(config.clone(), pulse)
+        }
+        .unwrap_or(false)
+    }
+
+    /// The configured sources, without the ones failing repeatedly unless too few are left for the quorum.
+    fn available_sources(now: u64) -> Vec<String> {
+        let sources: Vec<String> = BeaconSourceList::<T>::get()
+            .iter()
+            .map(|source| String::from_utf8_lossy(source).into_owned())
+            .collect();
+        let healthy: Vec<String> = sources
+            .iter()
+            .filter(|source| {
+                Self::source_health(source).is_none_or(|health| {
+                    health.consecutive_failures < SOURCE_FAILURE_THRESHOLD
+                        || now >= health.last_failure_at.saturating_add(SOURCE_BACKOFF_MS)
+                })
+            })
+            .cloned()
+            .collect();
+
+        let quorum = usize::try_from(SourceQuorum::<T>::get()).unwrap_or(usize::MAX);
+        if healthy.len() >= quorum.max(1) {
+            healthy
+        } else {
+            sources
+        }
+    }
+
+    fn source_health_key(source: &str) -> Vec<u8> {
+        [SOURCE_HEALTH_KEY_PREFIX, source.as_bytes()].concat()
+    }
+
+    /// the health of a source as recorded by the offchain worker of this node
+    pub fn source_health(source: &str) -> Option<SourceHealth> {
+        StorageValueRef::persistent(&Self::source_health_key(source))
+            .get::<SourceHealth>()
+            .ok()
+            .flatten()
+    }
+
+    fn update_source_health(source: &str, update: impl FnOnce(&mut SourceHealth)) {
+        let mut health = Self::source_health(source).unwrap_or_default();
+        update(&mut health);
+        StorageValueRef::persistent(&Self::source_health_key(source)).set(&health);
+    }
+
+    fn record_source_success(source: &str, round: RoundNumber, latency_ms: u64) {
+        let now = sp_io::offchain::timestamp().unix_millis();
+        Self::update_source_health(source, |health| {
+            health.successes = health.successes.saturating_add(1);
+            health.consecutive_failures = 0;
+            health.last_success_at = now;
+            health.last_round = cmp::max(health.last_round, round);
+            health.last_latency_ms = latency_ms;
+        });
+    }
+
+    fn record_source_failure(source: &str) {
+        let now = sp_io::offchain::timestamp().unix_millis();
+        Self::update_source_health(source, |health| {
+            health.failures = health.failures.saturating_add(1);
+            health.consecutive_failures = health.consecutive_failures.saturating_add(1);
+            health.last_failure_at = now;
+        });
+    }
+
     /// get the randomness at a specific block height
     /// returns [0u8;32] if it does not exist
     pub fn random_at(round: RoundNumber) -> [u8; 32] {
-        let pulse = Pulses::<T>::get(round).unwrap_or_default();
+        Self::beacon_random_at(DEFAULT_BEACON_ID, round)
+    }
+
+    /// get the randomness of a beacon at a specific round
+    /// returns [0u8;32] if it does not exist
+    pub fn beacon_random_at(beacon_id: BeaconId, round: RoundNumber) -> [u8; 32] {
+        let pulse = Self::pulse(beacon_id, round).unwrap_or_default();
         let rand = pulse.randomness.clone();
         let bounded_rand: [u8; 32] = rand.into_inner().try_into().unwrap_or([0u8; 32]);
 
         bounded_rand
     }
 
+    /// get the pulse of a beacon at a specific round, if stored
+    pub fn pulse(beacon_id: BeaconId, round: RoundNumber) -> Option<Pulse> {
+        if beacon_id == DEFAULT_BEACON_ID {
+            Pulses::<T>::get(round)
+        } else {
+            BeaconPulses::<T>::get(beacon_id, round)
+        }
+    }
+
+    /// get the configuration of a beacon, if it is bridged
+    pub fn beacon_config(beacon_id: BeaconId) -> Option<BeaconConfiguration> {
+        if beacon_id == DEFAULT_BEACON_ID {
+            Some(BeaconConfig::<T>::get())
+        } else {
+            Beacons::<T>::get(beacon_id)
+        }
+    }
+
+    /// Decrypt a ciphertext timelock-encrypted to a beacon round with the pulse of that round.
+    ///
+    /// The ciphertext is decoded for the curve the beacon signs in: G1 for quicknet-like
+    /// beacons and G2 for the pedersen schemes.
+    pub fn timelock_decrypt(
+        beacon_id: BeaconId,
+        ciphertext: &[u8],
+        pulse: &Pulse,
+    ) -> Result<Vec<u8>, TimelockDecryptError> {
+        let scheme = Self::beacon_config(beacon_id)
+            .and_then(|config| BeaconScheme::from_scheme_id(&config.scheme_id))
+            .ok_or(TimelockDecryptError::UnknownBeacon)?;
+        let signature = pulse
+            .signature
+            .strip_prefix(b"0x")
+            .unwrap_or(&pulse.signature);
+
+        match scheme {
+            BeaconScheme::BlsUnchainedG1Rfc9380 => {
+                Self::decrypt_with::<TinyBLS381>(ciphertext, signature)
+            }
+            BeaconScheme::PedersenBlsChained | BeaconScheme::PedersenBlsUnchained => {
+                Self::decrypt_with::<ZBLS>(ciphertext, signature)
+            }
+        }
+    }
+
+    fn decrypt_with<E: EngineBLS>(
+        ciphertext: &[u8],
+        signature: &[u8],
+    ) -> Result<Vec<u8>, TimelockDecryptError> {
+        let signature = E::SignatureGroup::deserialize_compressed(&mut &signature[..])
+            .map_err(|_| TimelockDecryptError::InvalidSignature)?;
+        let ciphertext = TLECiphertext::<E>::deserialize_compressed(&mut &ciphertext[..])
+            .map_err(|_| TimelockDecryptError::InvalidCiphertext)?;
+
+        tld::<E, AESGCMStreamCipherProvider>(ciphertext, signature)
+            .map_err(|_| TimelockDecryptError::DecryptionFailed)
+    }
+
+    /// Keep the pulse of a beacon round from being pruned until the reference is released, e.g.
+    /// while something encrypted to the round waits to be revealed.
+    pub fn reference_round(beacon_id: BeaconId, round: RoundNumber) {
+        ReferencedRounds::<T>::mutate(beacon_id, round, |count| {
+            *count = Some(count.unwrap_or_default().saturating_add(1));
+        });
+    }
+
+    /// Release a reference taken with `reference_round`. Once a round is no longer referenced,
+    /// its pulse is removed if the pruning already went past it.
+    pub fn release_round(beacon_id: BeaconId, round: RoundNumber) {
+        let remaining = ReferencedRounds::<T>::mutate_exists(beacon_id, round, |count| {
+            let remaining = count.unwrap_or_default().saturating_sub(1);
+            *count = (remaining > 0).then_some(remaining);
+            remaining
+        });
+        if remaining > 0 {
+            return;
+        }
+        if beacon_id == DEFAULT_BEACON_ID {
+            if round < OldestStoredRound::<T>::get() {
+                Pulses::<T>::remove(round);
+            }
+        } else if round < BeaconOldestStoredRound::<T>::get(beacon_id) {
+            BeaconPulses::<T>::remove(beacon_id, round);
+        }
+    }
+
+    /// Remove the pulses older than the retention window, within `limit`.
+    ///
+    /// Each beacon is pruned from its oldest stored round upward. Referenced rounds are skipped
+    /// and their pulses are removed when the last reference is released.
+    pub fn prune_pulses(limit: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let mut used = db_weight.reads(3);
+        let retention = PulseRetention::<T>::get();
+        if retention == 0 || used.any_gt(limit) {
+            return used;
+        }
+
+        let mut budget = u64::from(T::MaxPulsesPrunedPerBlock::get());
+
+        let start = OldestStoredRound::<T>::get().max(1);
+        let cutoff = LastStoredRound::<T>::get().saturating_sub(retention);
+        if start < cutoff {
+            let next = Self::prune_rounds(
+                DEFAULT_BEACON_ID,
+                start,
+                cutoff,
+                limit,
+                &mut used,
+                &mut budget,
+                |round| Pulses::<T>::remove(round),
+            );
+            if next != start {
+                OldestStoredRound::<T>::put(next);
+                used.saturating_accrue(db_weight.writes(1));
+            }
+
+            // the blocks are only recorded while the round they point to is stored
+            let mut block = OldestRecordedBlock::<T>::get();
+            let first_block = block;
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let block_weight = db_weight.reads_writes(1, 1);
+            used.saturating_accrue(db_weight.reads(2));
+            while block < current_block
+                && budget > 0
+                && used
+                    .saturating_add(block_weight)
+                    .saturating_add(block_weight)
+                    .all_lte(limit)
+            {
+                used.saturating_accrue(block_weight);
+                if RoundAtBlock::<T>::get(block).is_some_and(|round| round >= next) {
+                    break;
+                }
+                RoundAtBlock::<T>::remove(block);
+                budget.saturating_dec();
+                block.saturating_inc();
+            }
+            if block != first_block {
+                OldestRecordedBlock::<T>::put(block);
+                used.saturating_accrue(db_weight.writes(1));
+            }
+        }
+
+        for beacon_id in Beacons::<T>::iter_keys() {
+            used.saturating_accrue(db_weight.reads(3));
+            if budget == 0 || used.any_gt(limit) {
+                break;
+            }
+            let start = BeaconOldestStoredRound::<T>::get(beacon_id).max(1);
+            let cutoff = BeaconLastStoredRound::<T>::get(beacon_id).saturating_sub(retention);
+            let next = Self::prune_rounds(
+                beacon_id,
+                start,
+                cutoff,
+                limit,
+                &mut used,
+                &mut budget,
+                |round| BeaconPulses::<T>::remove(beacon_id, round),
+            );
+            if next != start {
+                BeaconOldestStoredRound::<T>::insert(beacon_id, next);
+                used.saturating_accrue(db_weight.writes(1));
+            }
+        }
+
+        used
+    }
+
+    /// Remove the unreferenced rounds of a beacon from `start` up to `end` excluded while the
+    /// budget and the weight limit allow it, returning the first round which was not considered.
+    fn prune_rounds(
+        beacon_id: BeaconId,
+        start: RoundNumber,
+        end: RoundNumber,
+        limit: Weight,
+        used: &mut Weight,
+        budget: &mut u64,
+        remove: impl Fn(RoundNumber),
+    ) -> RoundNumber {
+        let round_weight = T::DbWeight::get().reads_writes(1, 1);
+        let mut round = start;
+        // keep room for the write of the new oldest round
+        while round < end
+            && *budget > 0
+            && used
+                .saturating_add(round_weight)
+                .saturating_add(round_weight)
+                .all_lte(limit)
+        {
+            if !ReferencedRounds::<T>::contains_key(beacon_id, round) {
+                remove(round);
+            }
+            used.saturating_accrue(round_weight);
+            budget.saturating_dec();
+            round.saturating_inc();
+        }
+        round
+    }
+
+    /// The ranges of rounds which may still be stored for every beacon, as
+    /// `(beacon_id, oldest_round, last_round)`.
+    ///
+    /// The ranges are upper bounds: rounds below the oldest round are pruned, while rounds in the
+    /// range may be missing if the bridge did not relay them.
+    pub fn retained_rounds() -> Vec<(BeaconId, RoundNumber, RoundNumber)> {
+        let default_beacon = (
+            DEFAULT_BEACON_ID,
+            OldestStoredRound::<T>::get(),
+            LastStoredRound::<T>::get(),
+        );
+        core::iter::once(default_beacon)
+            .chain(Beacons::<T>::iter_keys().map(|beacon_id| {
+                (
+                    beacon_id,
+                    BeaconOldestStoredRound::<T>::get(beacon_id),
+                    BeaconLastStoredRound::<T>::get(beacon_id),
+                )
+            }))
+            .filter(|(_, _, last_round)| *last_round > 0)
+            .map(|(beacon_id, oldest_round, last_round)| {
+                (beacon_id, oldest_round.clamp(1, last_round), last_round)
+            })
+            .collect()
+    }
+
+    /// get the last round stored for a beacon
+    pub fn last_stored_round(beacon_id: BeaconId) -> RoundNumber {
+        if beacon_id == DEFAULT_BEACON_ID {
+            LastStoredRound::<T>::get()
+        } else {
+            BeaconLastStoredRound::<T>::get(beacon_id)
+        }
+    }
+
     fn validate_signature_and_parameters(
         payload: &impl SignedPayload<T>,
         signature: &T::Signature,
@@ -590,6 +1439,31 @@ impl<T: Config> Pallet<T> {
         Self::validate_transaction_parameters(block_number, public)
     }
 
+    fn validate_beacon_transaction_parameters(
+        beacon_id: BeaconId,
+        block_number: &BlockNumberFor<T>,
+        public: &T::Public,
+    ) -> TransactionValidity {
+        // Same checks as for the default beacon, with a schedule and tag for each beacon.
+        let next_unsigned_at = BeaconNextUnsignedAt::<T>::get(beacon_id);
+        if &next_unsigned_at > block_number {
+            return InvalidTransaction::Stale.into();
+        }
+        let current_block = frame_system::Pallet::<T>::block_number();
+        if &current_block < block_number {
+            return InvalidTransaction::Future.into();
+        }
+
+        let provides_tag = (beacon_id, next_unsigned_at, public.encode()).using_encoded(blake2_256);
+
+        ValidTransaction::with_tag_prefix("DrandOffchainWorker")
+            .priority(T::UnsignedPriority::get())
+            .and_provides(provides_tag)
+            .longevity(1)
+            .propagate(true)
+            .build()
+    }
+
     fn validate_transaction_parameters(
         block_number: &BlockNumberFor<T>,
         public: &T::Public,
@@ -640,6 +1514,48 @@ pub fn message(current_round: RoundNumber, prev_sig: &[u8]) -> Vec<u8> {
     hasher.finalize().to_vec()
 }
 
+/// Verifiable randomness backed by the drand pulses stored on chain.
+///
+/// Consumers should only rely on rounds published after the state they randomise was fixed,
+/// e.g. a round after the block a selection was requested in.
+pub trait RandomnessProvider<BlockNumber> {
+    /// the pulse of a beacon at a round, if it is stored
+    fn pulse_at(beacon_id: BeaconId, round: RoundNumber) -> Option<Pulse>;
+
+    /// the last round of the default beacon stored as of a block, if it is still recorded
+    fn round_at_block(block_number: BlockNumber) -> Option<RoundNumber>;
+
+    /// the randomness of the pulse of a beacon at a round, derived for a subject as
+    /// `keccak256(randomness ++ subject)` so contracts can derive it as well
+    fn random_for(beacon_id: BeaconId, round: RoundNumber, subject: &[u8]) -> Option<[u8; 32]> {
+        Self::pulse_at(beacon_id, round).map(|pulse| derive_randomness(&pulse.randomness, subject))
+    }
+
+    /// the randomness derived for a subject from the last round stored as of a block
+    fn random_at_block(block_number: BlockNumber, subject: &[u8]) -> Option<[u8; 32]> {
+        Self::round_at_block(block_number)
+            .and_then(|round| Self::random_for(DEFAULT_BEACON_ID, round, subject))
+    }
+}
+
+/// derive randomness for a subject from the randomness of a pulse
+pub fn derive_randomness(randomness: &[u8], subject: &[u8]) -> [u8; 32] {
+    let mut preimage = Vec::with_capacity(randomness.len().saturating_add(subject.len()));
+    preimage.extend_from_slice(randomness);
+    preimage.extend_from_slice(subject);
+    keccak_256(&preimage)
+}
+
+impl<T: Config> RandomnessProvider<BlockNumberFor<T>> for Pallet<T> {
+    fn pulse_at(beacon_id: BeaconId, round: RoundNumber) -> Option<Pulse> {
+        Self::pulse(beacon_id, round)
+    }
+
+    fn round_at_block(block_number: BlockNumberFor<T>) -> Option<RoundNumber> {
+        RoundAtBlock::<T>::get(block_number)
+    }
+}
+
 impl<T: Config> Randomness<T::Hash, BlockNumberFor<T>> for Pallet<T> {
     // this function hashes together the subject with the latest known randomness from quicknet
     fn random(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
@@ -0,0 +1,39 @@
+use frame_support::{traits::Get, weights::Weight};
+
+use crate::*;
+
+/// Sets `OldestStoredRound` to the oldest pulse stored before the pruning was introduced, so the
+/// pruning starts from there instead of walking up from round 1.
+///
+/// The pulses are relayed in increasing rounds, so the oldest one is found with a binary search
+/// over `1..=LastStoredRound`. If the relayed rounds have gaps, pulses below a gap may be found
+/// later than the oldest one, and are then kept.
+pub fn migrate_init_oldest_stored_round<T: Config>() -> Weight {
+    let mut weight = T::DbWeight::get().reads(2);
+
+    let last_round = LastStoredRound::<T>::get();
+    if OldestStoredRound::<T>::get() != 0 || last_round == 0 {
+        log::info!("Drand: OldestStoredRound is already initialised. Skipping.");
+        return weight;
+    }
+
+    // the lowest stored round in `low..=high`, `high` is stored
+    let mut low: RoundNumber = 1;
+    let mut high = last_round;
+    while low < high {
+        let middle = low.saturating_add(high.saturating_sub(low).saturating_div(2));
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+        if Pulses::<T>::contains_key(middle) {
+            high = middle;
+        } else {
+            low = middle.saturating_add(1);
+        }
+    }
+
+    OldestStoredRound::<T>::put(high);
+    weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+    log::info!("Drand: OldestStoredRound initialised to {}", high);
+
+    weight
+}
@@ -0,0 +1,2 @@
+mod migrate_init_oldest_stored_round;
+pub use migrate_init_oldest_stored_round::*;
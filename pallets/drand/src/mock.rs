@@ -3,7 +3,7 @@ use crate::verifier::*;
 use crate::*;
 use frame_support::{
     derive_impl, parameter_types,
-    traits::{ConstU16, ConstU64},
+    traits::{ConstU16, ConstU32, ConstU64},
 };
 use sp_core::{H256, sr25519::Signature};
 use sp_keystore::{KeystoreExt, testing::MemoryKeystore};
@@ -88,9 +88,10 @@ parameter_types! {
 impl pallet_drand_bridge::Config for Test {
     type AuthorityId = crypto::TestAuthId;
     type RuntimeEvent = RuntimeEvent;
-    type Verifier = QuicknetVerifier;
+    type Verifier = SchemeVerifier;
     type UnsignedPriority = UnsignedPriority;
     type HttpFetchTimeout = ConstU64<1_000>;
+    type MaxPulsesPrunedPerBlock = ConstU32<64>;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -15,18 +15,26 @@
  */
 
 use crate::{
-    BeaconConfig, BeaconConfigurationPayload, BeaconInfoResponse, Call, DrandResponseBody,
-    ENDPOINTS, Error, Pulse, Pulses, PulsesPayload, QUICKNET_CHAIN_HASH, mock::*,
+    BeaconConfig, BeaconConfigurationPayload, BeaconInfoResponse, BeaconLastStoredRound,
+    BeaconPulse, BeaconPulses, BeaconPulsesPayload, BeaconSource, BeaconSourceList, BeaconSources,
+    Beacons, Call, DEFAULT_BEACON_ID, DrandResponseBody, ENDPOINTS, Error, LastStoredRound,
+    OldestRecordedBlock, OldestStoredRound, Pulse, PulseRetention, Pulses, PulsesPayload,
+    QUICKNET_CHAIN_HASH, RandomnessProvider, ReferencedRounds, RoundAtBlock, SOURCE_BACKOFF_MS,
+    SOURCE_FAILURE_THRESHOLD, SourceQuorum, TimelockDecryptError, mock::*,
 };
 use codec::Encode;
 use frame_support::{
     assert_noop, assert_ok,
     pallet_prelude::{InvalidTransaction, TransactionSource},
+    traits::Hooks,
+    weights::Weight,
 };
 use frame_system::RawOrigin;
+use sp_core::keccak_256;
 use sp_runtime::{
+    DispatchError,
     offchain::{
-        OffchainWorkerExt,
+        OffchainDbExt, OffchainWorkerExt, Timestamp,
         testing::{PendingRequest, TestOffchainExt},
     },
     traits::ValidateUnsigned,
@@ -39,6 +47,7 @@ pub const ROUND_NUMBER: u64 = 1000;
 pub const DRAND_PULSE: &str = "{\"round\":1000,\"randomness\":\"fe290beca10872ef2fb164d2aa4442de4566183ec51c56ff3cd603d930e54fdd\",\"signature\":\"b44679b9a59af2ec876b1a6b1ad52ea9b1615fc3982b19576350f93447cb1125e342b73a8dd2bacbe47e4b6b63ed5e39\"}";
 pub const DRAND_INFO_RESPONSE: &str = "{\"public_key\":\"83cf0f2896adee7eb8b5f01fcad3912212c437e0073e911fb90022d3e760183c8c4b450b6a0a6c3ac6a5776a2d1064510d1fec758c921cc22b0e17e63aaf4bcb5ed66304de9cf809bd274ca73bab4af5a6e9c76a4bc09e76eae8991ef5ece45a\",\"period\":3,\"genesis_time\":1692803367,\"hash\":\"52db9ba70e0cc0f6eaf7803dd07447a1f5477735fd3f661792ba94600c84e971\",\"groupHash\":\"f477d5c89f21a17c863a7f937c6a6d15859414d2be09cd448d4279af331c5d3e\",\"schemeID\":\"bls-unchained-g1-rfc9380\",\"metadata\":{\"beaconID\":\"quicknet\"}}";
 const INVALID_JSON: &str = r#"{"round":1000,"randomness":"not base64??","signature":}"#;
+const UNVERIFIED_PULSE: &str = "{\"round\":1000,\"randomness\":\"87f03ef5f62885390defedf60d5b8132b4dc2115b1efc6e99d166a37ab2f3a02\",\"signature\":\"b0a8b04e009cf72534321aca0f50048da596a3feec1172a0244d9a4a623a3123d0402da79854d4c705e94bc73224c341\"}";
 
 #[test]
 fn it_can_submit_valid_pulse_when_beacon_config_exists() {
@@ -350,6 +359,7 @@ fn can_execute_and_handle_valid_http_responses() {
 
     let (offchain, state) = TestOffchainExt::new();
     let mut t = sp_io::TestExternalities::default();
+    t.register_extension(OffchainDbExt::new(offchain.clone()));
     t.register_extension(OffchainWorkerExt::new(offchain));
 
     {
@@ -415,6 +425,7 @@ fn validate_unsigned_rejects_future_block_number() {
 fn test_all_endpoints_fail() {
     let (offchain, state) = TestOffchainExt::new();
     let mut t = sp_io::TestExternalities::default();
+    t.register_extension(OffchainDbExt::new(offchain.clone()));
     t.register_extension(OffchainWorkerExt::new(offchain));
 
     {
@@ -447,6 +458,7 @@ fn test_eventual_success() {
 
     let (offchain, state) = TestOffchainExt::new();
     let mut t = sp_io::TestExternalities::default();
+    t.register_extension(OffchainDbExt::new(offchain.clone()));
     t.register_extension(OffchainWorkerExt::new(offchain));
 
     {
@@ -518,6 +530,7 @@ fn test_invalid_json_then_success() {
 
     let (offchain, state) = TestOffchainExt::new();
     let mut t = sp_io::TestExternalities::default();
+    t.register_extension(OffchainDbExt::new(offchain.clone()));
     t.register_extension(OffchainWorkerExt::new(offchain));
 
     {
@@ -549,3 +562,483 @@ fn test_invalid_json_then_success() {
         assert_eq!(actual, expected_pulse);
     });
 }
+
+// Local stand-ins for the drand relays
+fn local_sources(count: u16) -> BeaconSources {
+    BeaconSources::truncate_from(
+        (0..count)
+            .map(|i| BeaconSource::truncate_from(format!("http://localhost:{}", 8080 + i).into()))
+            .collect(),
+    )
+}
+
+fn expect_round_request(
+    state: &mut sp_runtime::offchain::testing::OffchainState,
+    source: u16,
+    response: &str,
+) {
+    state.expect_request(PendingRequest {
+        method: "GET".into(),
+        uri: format!(
+            "http://localhost:{}/{}/public/1000",
+            8080 + source,
+            QUICKNET_CHAIN_HASH
+        ),
+        response: Some(response.as_bytes().to_vec()),
+        sent: true,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn test_set_beacon_sources() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            Drand::set_beacon_sources(
+                RuntimeOrigin::signed(sp_keyring::Sr25519Keyring::Alice.public()),
+                local_sources(3),
+                2
+            ),
+            DispatchError::BadOrigin
+        );
+        for quorum in [0, 4] {
+            assert_noop!(
+                Drand::set_beacon_sources(RuntimeOrigin::root(), local_sources(3), quorum),
+                Error::<Test>::InvalidSourceQuorum
+            );
+        }
+
+        assert_ok!(Drand::set_beacon_sources(
+            RuntimeOrigin::root(),
+            local_sources(3),
+            2
+        ));
+        assert_eq!(BeaconSourceList::<Test>::get(), local_sources(3));
+        assert_eq!(SourceQuorum::<Test>::get(), 2);
+        System::assert_last_event(crate::Event::<Test>::BeaconSourcesChanged { quorum: 2 }.into());
+    });
+}
+
+#[test]
+fn test_quorum_of_verified_pulses_required() {
+    let expected_pulse: DrandResponseBody = serde_json::from_str(DRAND_PULSE).unwrap();
+
+    let (offchain, state) = TestOffchainExt::new();
+    let mut t = sp_io::TestExternalities::default();
+    t.register_extension(OffchainDbExt::new(offchain.clone()));
+    t.register_extension(OffchainWorkerExt::new(offchain));
+
+    {
+        let mut state = state.write();
+        // Two of the three sources serve a verified pulse
+        expect_round_request(&mut state, 0, DRAND_PULSE);
+        expect_round_request(&mut state, 1, UNVERIFIED_PULSE);
+        expect_round_request(&mut state, 2, DRAND_PULSE);
+        // Only one of the three sources serves a verified pulse
+        expect_round_request(&mut state, 0, DRAND_PULSE);
+        expect_round_request(&mut state, 1, UNVERIFIED_PULSE);
+        expect_round_request(&mut state, 2, INVALID_JSON);
+    }
+
+    t.execute_with(|| {
+        BeaconSourceList::<Test>::put(local_sources(3));
+        SourceQuorum::<Test>::put(2);
+
+        let actual = Drand::fetch_drand_by_round(1000u64).unwrap();
+        assert_eq!(actual, expected_pulse);
+
+        assert!(Drand::fetch_drand_by_round(1000u64).is_err());
+    });
+}
+
+#[test]
+fn test_source_health_is_tracked() {
+    let (offchain, state) = TestOffchainExt::new();
+    let mut t = sp_io::TestExternalities::default();
+    t.register_extension(OffchainDbExt::new(offchain.clone()));
+    t.register_extension(OffchainWorkerExt::new(offchain));
+
+    {
+        let mut state = state.write();
+        state.timestamp = Timestamp::from_unix_millis(1_000);
+        expect_round_request(&mut state, 0, UNVERIFIED_PULSE);
+        expect_round_request(&mut state, 1, DRAND_PULSE);
+    }
+
+    t.execute_with(|| {
+        BeaconSourceList::<Test>::put(local_sources(2));
+
+        assert_ok!(Drand::fetch_drand_by_round(1000u64));
+
+        let failing = Drand::source_health("http://localhost:8080").unwrap();
+        assert_eq!(failing.failures, 1);
+        assert_eq!(failing.consecutive_failures, 1);
+        assert_eq!(failing.last_failure_at, 1_000);
+        assert_eq!(failing.successes, 0);
+
+        let healthy = Drand::source_health("http://localhost:8081").unwrap();
+        assert_eq!(healthy.successes, 1);
+        assert_eq!(healthy.failures, 0);
+        assert_eq!(healthy.last_success_at, 1_000);
+        assert_eq!(healthy.last_round, 1000);
+    });
+}
+
+#[test]
+fn test_failing_source_is_skipped_until_backoff() {
+    let expected_pulse: DrandResponseBody = serde_json::from_str(DRAND_PULSE).unwrap();
+
+    let (offchain, state) = TestOffchainExt::new();
+    let mut t = sp_io::TestExternalities::default();
+    t.register_extension(OffchainDbExt::new(offchain.clone()));
+    t.register_extension(OffchainWorkerExt::new(offchain));
+
+    {
+        let mut state = state.write();
+        // The first source fails until it reaches the failure threshold
+        for _ in 0..SOURCE_FAILURE_THRESHOLD {
+            expect_round_request(&mut state, 0, INVALID_JSON);
+            expect_round_request(&mut state, 1, DRAND_PULSE);
+        }
+        // Then only the second source is queried
+        expect_round_request(&mut state, 1, DRAND_PULSE);
+        // Until the backoff ends
+        expect_round_request(&mut state, 0, DRAND_PULSE);
+        expect_round_request(&mut state, 1, DRAND_PULSE);
+    }
+
+    t.execute_with(|| {
+        BeaconSourceList::<Test>::put(local_sources(2));
+
+        for _ in 0..SOURCE_FAILURE_THRESHOLD {
+            assert_eq!(
+                Drand::fetch_drand_by_round(1000u64).unwrap(),
+                expected_pulse
+            );
+        }
+        assert_eq!(
+            Drand::fetch_drand_by_round(1000u64).unwrap(),
+            expected_pulse
+        );
+
+        state.write().timestamp = Timestamp::from_unix_millis(SOURCE_BACKOFF_MS);
+        assert_eq!(
+            Drand::fetch_drand_by_round(1000u64).unwrap(),
+            expected_pulse
+        );
+        assert_eq!(
+            Drand::source_health("http://localhost:8080")
+                .unwrap()
+                .consecutive_failures,
+            0
+        );
+    });
+}
+
+fn store_pulses(rounds: core::ops::RangeInclusive<u64>) {
+    for round in rounds.clone() {
+        Pulses::<Test>::insert(
+            round,
+            Pulse {
+                round,
+                ..Default::default()
+            },
+        );
+    }
+    OldestStoredRound::<Test>::put(rounds.start());
+    LastStoredRound::<Test>::put(rounds.end());
+}
+
+#[test]
+fn it_can_register_a_beacon_and_write_its_pulses() {
+    new_test_ext().execute_with(|| {
+        let alice = sp_keyring::Sr25519Keyring::Alice;
+        let block_number = 100_000_000;
+        System::set_block_number(block_number);
+
+        let info: BeaconInfoResponse = serde_json::from_str(DRAND_INFO_RESPONSE).unwrap();
+        let config = info.try_into_beacon_config().unwrap();
+        let beacon_id = 1;
+
+        assert_noop!(
+            Drand::register_beacon(RuntimeOrigin::root(), 0, config.clone()),
+            Error::<Test>::DefaultBeaconId
+        );
+        assert_ok!(Drand::register_beacon(
+            RuntimeOrigin::root(),
+            beacon_id,
+            config.clone()
+        ));
+        assert_eq!(Beacons::<Test>::get(beacon_id), Some(config));
+
+        let u_p: DrandResponseBody = serde_json::from_str(DRAND_PULSE).unwrap();
+        let pulse = u_p.try_into_beacon_pulse().unwrap();
+        let pulses_payload = BeaconPulsesPayload {
+            block_number,
+            beacon_id,
+            pulses: vec![pulse.clone()],
+            public: alice.public(),
+        };
+        assert_ok!(Drand::write_beacon_pulses(
+            RuntimeOrigin::none(),
+            pulses_payload,
+            None
+        ));
+
+        // The pulse is stored for the beacon only
+        assert_eq!(
+            BeaconPulses::<Test>::get(beacon_id, ROUND_NUMBER),
+            Some(pulse.pulse.clone())
+        );
+        assert_eq!(BeaconLastStoredRound::<Test>::get(beacon_id), ROUND_NUMBER);
+        assert!(Pulses::<Test>::get(ROUND_NUMBER).is_none());
+        assert_eq!(
+            Drand::beacon_random_at(beacon_id, ROUND_NUMBER).to_vec(),
+            pulse.pulse.randomness.to_vec()
+        );
+
+        assert_ok!(Drand::remove_beacon(RuntimeOrigin::root(), beacon_id));
+        assert!(Beacons::<Test>::get(beacon_id).is_none());
+        assert!(BeaconPulses::<Test>::get(beacon_id, ROUND_NUMBER).is_none());
+        assert_eq!(BeaconLastStoredRound::<Test>::get(beacon_id), 0);
+    });
+}
+
+#[test]
+fn it_rejects_unsupported_beacon_schemes_and_unknown_beacons() {
+    new_test_ext().execute_with(|| {
+        let alice = sp_keyring::Sr25519Keyring::Alice;
+        let block_number = 100_000_000;
+        System::set_block_number(block_number);
+
+        let info: BeaconInfoResponse = serde_json::from_str(DRAND_INFO_RESPONSE).unwrap();
+        let mut config = info.try_into_beacon_config().unwrap();
+        config.scheme_id = b"bls-unknown-scheme".to_vec().try_into().unwrap();
+
+        assert_noop!(
+            Drand::register_beacon(RuntimeOrigin::root(), 1, config),
+            Error::<Test>::UnsupportedBeaconScheme
+        );
+        assert_noop!(
+            Drand::remove_beacon(RuntimeOrigin::root(), 1),
+            Error::<Test>::UnknownBeacon
+        );
+
+        let u_p: DrandResponseBody = serde_json::from_str(DRAND_PULSE).unwrap();
+        let pulses_payload = BeaconPulsesPayload {
+            block_number,
+            beacon_id: 1,
+            pulses: vec![BeaconPulse {
+                pulse: u_p.try_into_pulse().unwrap(),
+                previous_signature: Default::default(),
+            }],
+            public: alice.public(),
+        };
+        assert_noop!(
+            Drand::write_beacon_pulses(RuntimeOrigin::none(), pulses_payload, None),
+            Error::<Test>::UnknownBeacon
+        );
+
+        // The default beacon only verifies unchained pulses
+        let mut config = info.try_into_beacon_config().unwrap();
+        config.scheme_id = b"pedersen-bls-chained".to_vec().try_into().unwrap();
+        let config_payload = BeaconConfigurationPayload {
+            block_number,
+            config,
+            public: alice.public(),
+        };
+        assert_noop!(
+            Drand::set_beacon_config(RuntimeOrigin::root(), config_payload, None),
+            Error::<Test>::UnsupportedBeaconScheme
+        );
+    });
+}
+
+#[test]
+fn test_timelock_decrypt_uses_the_curve_of_the_beacon() {
+    new_test_ext().execute_with(|| {
+        let u_p: DrandResponseBody = serde_json::from_str(DRAND_PULSE).unwrap();
+        let pulse = u_p.try_into_pulse().unwrap();
+
+        assert_eq!(
+            Drand::timelock_decrypt(1, &[], &pulse),
+            Err(TimelockDecryptError::UnknownBeacon)
+        );
+
+        // The quicknet signature is a point of G1, the signature group of the default beacon
+        assert_eq!(
+            Drand::timelock_decrypt(DEFAULT_BEACON_ID, &[0u8; 8], &pulse),
+            Err(TimelockDecryptError::InvalidCiphertext)
+        );
+
+        // but not of G2, the signature group of the pedersen beacons
+        let info: BeaconInfoResponse = serde_json::from_str(DRAND_INFO_RESPONSE).unwrap();
+        let mut config = info.try_into_beacon_config().unwrap();
+        config.scheme_id = b"pedersen-bls-unchained".to_vec().try_into().unwrap();
+        assert_ok!(Drand::register_beacon(RuntimeOrigin::root(), 1, config));
+        assert_eq!(
+            Drand::timelock_decrypt(1, &[0u8; 8], &pulse),
+            Err(TimelockDecryptError::InvalidSignature)
+        );
+    });
+}
+
+fn store_pulses(rounds: core::ops::RangeInclusive<u64>) {
+    for round in rounds.clone() {
+        Pulses::<Test>::insert(
+            round,
+            Pulse {
+                round,
+                ..Default::default()
+            },
+        );
+    }
+    OldestStoredRound::<Test>::put(rounds.start());
+    LastStoredRound::<Test>::put(rounds.end());
+}
+
+#[test]
+fn test_set_pulse_retention() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            Drand::set_pulse_retention(
+                RuntimeOrigin::signed(sp_keyring::Sr25519Keyring::Alice.public()),
+                10
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(Drand::set_pulse_retention(RuntimeOrigin::root(), 10));
+        assert_eq!(PulseRetention::<Test>::get(), 10);
+        System::assert_last_event(crate::Event::<Test>::PulseRetentionSet { retention: 10 }.into());
+    });
+}
+
+#[test]
+fn test_pulses_outside_retention_are_pruned_in_chunks() {
+    new_test_ext().execute_with(|| {
+        PulseRetention::<Test>::put(10);
+        store_pulses(1..=100);
+
+        // At most `MaxPulsesPrunedPerBlock` rounds are removed at once
+        Drand::prune_pulses(Weight::MAX);
+        assert!(Pulses::<Test>::get(64).is_none());
+        assert!(Pulses::<Test>::get(65).is_some());
+        assert_eq!(OldestStoredRound::<Test>::get(), 65);
+
+        // Then the pruning stops at the retention window
+        Drand::prune_pulses(Weight::MAX);
+        assert!(Pulses::<Test>::get(89).is_none());
+        assert!(Pulses::<Test>::get(90).is_some());
+        assert_eq!(OldestStoredRound::<Test>::get(), 90);
+        assert_eq!(Drand::retained_rounds(), vec![(0, 90, 100)]);
+
+        Drand::prune_pulses(Weight::MAX);
+        assert_eq!(OldestStoredRound::<Test>::get(), 90);
+
+        // Nothing is removed when the retention is disabled
+        LastStoredRound::<Test>::put(110);
+        PulseRetention::<Test>::put(0);
+        Drand::prune_pulses(Weight::MAX);
+        assert!(Pulses::<Test>::get(90).is_some());
+    });
+}
+
+#[test]
+fn test_referenced_pulses_are_not_pruned() {
+    new_test_ext().execute_with(|| {
+        PulseRetention::<Test>::put(10);
+        store_pulses(1..=50);
+        Drand::reference_round(DEFAULT_BEACON_ID, 20);
+        Drand::reference_round(DEFAULT_BEACON_ID, 20);
+
+        Drand::prune_pulses(Weight::MAX);
+        assert!(Pulses::<Test>::get(19).is_none());
+        assert!(Pulses::<Test>::get(20).is_some());
+        assert!(Pulses::<Test>::get(21).is_none());
+        assert!(Pulses::<Test>::get(40).is_some());
+        assert_eq!(OldestStoredRound::<Test>::get(), 40);
+
+        // The pulse is removed once the last reference is released
+        Drand::release_round(DEFAULT_BEACON_ID, 20);
+        assert!(Pulses::<Test>::get(20).is_some());
+        Drand::release_round(DEFAULT_BEACON_ID, 20);
+        assert!(Pulses::<Test>::get(20).is_none());
+        assert!(ReferencedRounds::<Test>::get(DEFAULT_BEACON_ID, 20).is_none());
+
+        // Releasing a round which is still retained keeps its pulse
+        Drand::reference_round(DEFAULT_BEACON_ID, 45);
+        Drand::release_round(DEFAULT_BEACON_ID, 45);
+        assert!(Pulses::<Test>::get(45).is_some());
+    });
+}
+
+#[test]
+fn test_migrate_init_oldest_stored_round() {
+    new_test_ext().execute_with(|| {
+        // Nothing to do before any pulse is stored
+        crate::migrations::migrate_init_oldest_stored_round::<Test>();
+        assert_eq!(OldestStoredRound::<Test>::get(), 0);
+
+        store_pulses(37..=80);
+        OldestStoredRound::<Test>::kill();
+
+        crate::migrations::migrate_init_oldest_stored_round::<Test>();
+        assert_eq!(OldestStoredRound::<Test>::get(), 37);
+        assert_eq!(Drand::retained_rounds(), vec![(0, 37, 80)]);
+
+        // The pruning goes on from the oldest stored round
+        PulseRetention::<Test>::put(10);
+        Drand::prune_pulses(Weight::MAX);
+        assert!(Pulses::<Test>::get(69).is_none());
+        assert!(Pulses::<Test>::get(70).is_some());
+    });
+}
+
+#[test]
+fn test_randomness_provider_by_round_and_block() {
+    new_test_ext().execute_with(|| {
+        let u_p: DrandResponseBody = serde_json::from_str(DRAND_PULSE).unwrap();
+        let pulse = u_p.try_into_pulse().unwrap();
+        Pulses::<Test>::insert(ROUND_NUMBER, pulse.clone());
+        LastStoredRound::<Test>::put(ROUND_NUMBER);
+        Drand::on_finalize(5);
+
+        assert_eq!(Drand::round_at_block(5), Some(ROUND_NUMBER));
+        assert_eq!(Drand::round_at_block(4), None);
+        assert_eq!(Drand::pulse_at(0, ROUND_NUMBER), Some(pulse.clone()));
+
+        let expected = keccak_256(&[pulse.randomness.to_vec(), b"subject".to_vec()].concat());
+        assert_eq!(
+            Drand::random_for(0, ROUND_NUMBER, b"subject"),
+            Some(expected)
+        );
+        assert_eq!(Drand::random_at_block(5, b"subject"), Some(expected));
+        assert_eq!(Drand::random_for(0, ROUND_NUMBER + 1, b"subject"), None);
+        assert_eq!(Drand::random_for(1, ROUND_NUMBER, b"subject"), None);
+    });
+}
+
+#[test]
+fn test_recorded_blocks_are_pruned_with_their_rounds() {
+    new_test_ext().execute_with(|| {
+        PulseRetention::<Test>::put(10);
+        store_pulses(1..=50);
+        for block in 1..=5u64 {
+            LastStoredRound::<Test>::put(block * 10);
+            Drand::on_finalize(block);
+        }
+        assert_eq!(OldestRecordedBlock::<Test>::get(), 1);
+        System::set_block_number(6);
+
+        Drand::prune_pulses(Weight::MAX);
+        assert_eq!(OldestStoredRound::<Test>::get(), 40);
+        assert!(RoundAtBlock::<Test>::get(3).is_none());
+        assert_eq!(RoundAtBlock::<Test>::get(4), Some(40));
+        assert_eq!(OldestRecordedBlock::<Test>::get(), 4);
+    });
+}
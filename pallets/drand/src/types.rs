@@ -28,6 +28,15 @@ pub type BoundedHash = BoundedVec<u8, ConstU32<32>>;
 /// the round number to track rounds of the beacon
 pub type RoundNumber = u64;
 
+/// the identifier of a drand chain bridged by the pallet
+pub type BeaconId = u16;
+
+/// the beacon configured by `BeaconConfig`, whose pulses are stored in `Pulses`
+pub const DEFAULT_BEACON_ID: BeaconId = 0;
+
+/// A BLS signature of a beacon, in G1 or G2 depending on the scheme
+pub type BeaconSignature = BoundedVec<u8, ConstU32<144>>;
+
 /// the expected response body from the drand api endpoint `api.drand.sh/{chainId}/info`
 #[freeze_struct("f9e09b3273fe00cd")]
 #[derive(Debug, Decode, Default, PartialEq, Encode, Serialize, Deserialize, TypeInfo, Clone)]
@@ -84,7 +93,7 @@ impl BeaconInfoResponse {
 
 /// a pulse from the drand beacon
 /// the expected response body from the drand api endpoint `api.drand.sh/{chainId}/public/latest`
-#[freeze_struct("a3fed2c99a0638bf")]
+#[freeze_struct("66e64a4cd2d8fee4")]
 #[derive(Debug, Decode, Default, PartialEq, Encode, Serialize, Deserialize)]
 pub struct DrandResponseBody {
     /// the randomness round number
@@ -97,6 +106,9 @@ pub struct DrandResponseBody {
     // TODO: use Signature (https://github.com/ideal-lab5/pallet-drand/issues/2)
     #[serde(with = "hex::serde")]
     pub signature: Vec<u8>,
+    /// BLS sig for the previous round, only served by chained beacons
+    #[serde(default, with = "hex::serde")]
+    pub previous_signature: Vec<u8>,
 }
 
 impl DrandResponseBody {
@@ -114,7 +126,59 @@ impl DrandResponseBody {
             signature: bounded_signature,
         })
     }
+
+    pub fn try_into_beacon_pulse(&self) -> Result<BeaconPulse, String> {
+        let bounded_previous_signature = BeaconSignature::try_from(self.previous_signature.clone())
+            .map_err(|_| "Failed to convert previous_signature")?;
+
+        Ok(BeaconPulse {
+            pulse: self.try_into_pulse()?,
+            previous_signature: bounded_previous_signature,
+        })
+    }
 }
+
+/// The signature schemes of the drand chains
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BeaconScheme {
+    /// signatures in G2 over the round and the previous signature, e.g. the drand default chain
+    PedersenBlsChained,
+    /// signatures in G2 over the round only
+    PedersenBlsUnchained,
+    /// signatures in G1 over the round only, e.g. quicknet
+    BlsUnchainedG1Rfc9380,
+}
+
+impl BeaconScheme {
+    /// the scheme of a beacon, from the `schemeID` of its info
+    pub fn from_scheme_id(scheme_id: &[u8]) -> Option<Self> {
+        match scheme_id {
+            b"pedersen-bls-chained" => Some(Self::PedersenBlsChained),
+            b"pedersen-bls-unchained" => Some(Self::PedersenBlsUnchained),
+            b"bls-unchained-g1-rfc9380" => Some(Self::BlsUnchainedG1Rfc9380),
+            _ => None,
+        }
+    }
+
+    /// whether the signed message includes the previous signature
+    pub fn is_chained(&self) -> bool {
+        matches!(self, Self::PedersenBlsChained)
+    }
+}
+
+/// The reasons a ciphertext timelock-encrypted to a beacon round can't be decrypted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimelockDecryptError {
+    /// the beacon is not configured or its scheme is not supported
+    UnknownBeacon,
+    /// the pulse signature is not a point of the signature group of the beacon
+    InvalidSignature,
+    /// the ciphertext does not decode for the curve of the beacon
+    InvalidCiphertext,
+    /// the ciphertext was not encrypted to the round of the pulse
+    DecryptionFailed,
+}
+
 /// A drand chain configuration
 #[freeze_struct("1e01e739e2a5c940")]
 #[derive(
@@ -203,3 +267,50 @@ pub struct PulsesPayload<Public, BlockNumber> {
     pub pulses: Vec<Pulse>,
     pub public: Public,
 }
+
+/// The base url of a drand relay, e.g. `https://api.drand.sh`
+pub type BeaconSource = BoundedVec<u8, ConstU32<256>>;
+
+/// The drand relays queried by the offchain worker
+pub type BeaconSources = BoundedVec<BeaconSource, ConstU32<16>>;
+
+/// The health of a beacon source as seen by the offchain worker of this node,
+/// exported in the persistent offchain storage
+#[freeze_struct("9bc6b961a33f62ea")]
+#[derive(Clone, Debug, Decode, Default, PartialEq, Encode, TypeInfo, Eq)]
+pub struct SourceHealth {
+    /// the number of requests answered with a verified pulse
+    pub successes: u64,
+    /// the number of requests that failed, timed out or returned an unverified pulse
+    pub failures: u64,
+    /// the number of failures since the last success
+    pub consecutive_failures: u32,
+    /// the timestamp of the last success, in milliseconds
+    pub last_success_at: u64,
+    /// the timestamp of the last failure, in milliseconds
+    pub last_failure_at: u64,
+    /// the round of the last verified pulse served
+    pub last_round: RoundNumber,
+    /// the time taken by the last successful request, in milliseconds
+    pub last_latency_ms: u64,
+}
+
+/// A pulse from any of the bridged beacons along with what is needed to verify it
+#[freeze_struct("39d7d3f3005e7d5c")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BeaconPulse {
+    pub pulse: Pulse,
+    /// the signature of the previous round, empty for unchained beacons
+    pub previous_signature: BeaconSignature,
+}
+
+/// Payload used to hold the pulses of
+/// an additional beacon required to submit a transaction.
+#[freeze_struct("b3f2482af98031ea")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BeaconPulsesPayload<Public, BlockNumber> {
+    pub block_number: BlockNumber,
+    pub beacon_id: BeaconId,
+    pub pulses: Vec<BeaconPulse>,
+    pub public: Public,
+}
@@ -20,10 +20,15 @@
 
 use crate::{
     bls12_381,
-    types::{BeaconConfiguration, Pulse, RoundNumber},
+    types::{BeaconConfiguration, BeaconScheme, Pulse, RoundNumber},
 };
 use alloc::{format, string::String, vec::Vec};
-use ark_ec::{AffineRepr, hashing::HashToCurve};
+use ark_bls12_381::{G2Projective, g2};
+use ark_ec::{
+    AffineRepr,
+    hashing::{HashToCurve, curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurveBasedHasher},
+};
+use ark_ff::field_hashers::DefaultFieldHasher;
 use ark_serialize::CanonicalSerialize;
 use codec::Decode;
 use sha2::{Digest, Sha256};
@@ -32,6 +37,9 @@ use tle::curves::drand::TinyBLS381;
 use w3f_bls::engine::EngineBLS;
 
 const USAGE: ark_scale::Usage = ark_scale::WIRE;
+
+/// the domain separation tag of the beacons signing in G2
+const G2_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
 pub type ArkScale<T> = ark_scale::ArkScale<T, USAGE>;
 
 /// construct a message (e.g. signed by drand)
@@ -46,6 +54,15 @@ fn message(current_round: RoundNumber, prev_sig: &[u8]) -> Vec<u8> {
 pub trait Verifier {
     /// verify the given pulse using beacon_config
     fn verify(beacon_config: BeaconConfiguration, pulse: Pulse) -> Result<bool, String>;
+
+    /// verify the given pulse of a chained beacon, whose signature covers the previous signature
+    fn verify_chained(
+        _beacon_config: BeaconConfiguration,
+        _pulse: Pulse,
+        _previous_signature: &[u8],
+    ) -> Result<bool, String> {
+        Err(String::from("Chained beacons are not supported"))
+    }
 }
 
 /// A verifier to check values received from quicknet. It outputs true if valid, false otherwise
@@ -102,10 +119,105 @@ impl Verifier for QuicknetVerifier {
     }
 }
 
+/// A verifier for every drand scheme, chosen from the scheme id of the beacon configuration
+///
+/// Quicknet-like beacons are verified by the [`QuicknetVerifier`]. The other beacons have their
+/// public keys in G1 and signatures in G2, in which case values are valid if:
+///   $e(g_1, sig) == e(pk, msg_on_curve)$
+/// where the message is the hash of the round number, preceded by the previous signature for
+/// chained beacons
+pub struct SchemeVerifier;
+
+impl SchemeVerifier {
+    fn verify_on_g2(
+        beacon_config: BeaconConfiguration,
+        pulse: Pulse,
+        previous_signature: &[u8],
+    ) -> Result<bool, String> {
+        // decode public key (pk)
+        let pk =
+            ArkScale::<G1AffineOpt>::decode(&mut beacon_config.public_key.into_inner().as_slice())
+                .map_err(|e| format!("Failed to decode public key: {}", e))?;
+
+        // decode signature (sigma)
+        let signature =
+            ArkScale::<G2AffineOpt>::decode(&mut pulse.signature.into_inner().as_slice())
+                .map_err(|e| format!("Failed to decode signature: {}", e))?;
+
+        // m = sha256({previous_signature} || {round})
+        let message = message(pulse.round, previous_signature);
+        let hasher = MapToCurveBasedHasher::<
+            G2Projective,
+            DefaultFieldHasher<Sha256, 128>,
+            WBMap<g2::Config>,
+        >::new(G2_DST)
+        .map_err(|e| format!("Failed to create hasher: {}", e))?;
+        // H(m) \in G2
+        let message_hash = hasher
+            .hash(&message)
+            .map_err(|e| format!("Failed to hash message: {}", e))?;
+
+        let mut bytes = Vec::new();
+        message_hash
+            .serialize_compressed(&mut bytes)
+            .map_err(|e| format!("Failed to serialize message hash: {}", e))?;
+
+        let message_on_curve = ArkScale::<G2AffineOpt>::decode(&mut &bytes[..])
+            .map_err(|e| format!("Failed to decode message on curve: {}", e))?;
+
+        let g1 = G1AffineOpt::generator();
+
+        Ok(bls12_381::fast_pairing_opt(
+            g1,
+            signature.0,
+            pk.0,
+            message_on_curve.0,
+        ))
+    }
+}
+
+impl Verifier for SchemeVerifier {
+    fn verify(beacon_config: BeaconConfiguration, pulse: Pulse) -> Result<bool, String> {
+        match BeaconScheme::from_scheme_id(&beacon_config.scheme_id) {
+            Some(BeaconScheme::BlsUnchainedG1Rfc9380) => {
+                QuicknetVerifier::verify(beacon_config, pulse)
+            }
+            Some(BeaconScheme::PedersenBlsUnchained) => {
+                Self::verify_on_g2(beacon_config, pulse, &[])
+            }
+            Some(BeaconScheme::PedersenBlsChained) => Err(String::from(
+                "Chained beacons require the previous signature",
+            )),
+            None => Err(String::from("Unsupported beacon scheme")),
+        }
+    }
+
+    fn verify_chained(
+        beacon_config: BeaconConfiguration,
+        pulse: Pulse,
+        previous_signature: &[u8],
+    ) -> Result<bool, String> {
+        match BeaconScheme::from_scheme_id(&beacon_config.scheme_id) {
+            Some(BeaconScheme::PedersenBlsChained) => {
+                Self::verify_on_g2(beacon_config, pulse, previous_signature)
+            }
+            _ => Self::verify(beacon_config, pulse),
+        }
+    }
+}
+
 /// The unsafe skip verifier is just a pass-through verification, always returns true
 pub struct UnsafeSkipVerifier;
 impl Verifier for UnsafeSkipVerifier {
     fn verify(_beacon_config: BeaconConfiguration, _pulse: Pulse) -> Result<bool, String> {
         Ok(true)
     }
+
+    fn verify_chained(
+        _beacon_config: BeaconConfiguration,
+        _pulse: Pulse,
+        _previous_signature: &[u8],
+    ) -> Result<bool, String> {
+        Ok(true)
+    }
 }
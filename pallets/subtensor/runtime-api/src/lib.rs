@@ -2,6 +2,9 @@
 extern crate alloc;
 use alloc::vec::Vec;
 use codec::Compact;
+use pallet_admin_utils::PendingHyperparameterChange;
+use pallet_commitments::{CommitmentFieldChange, CommitmentSchemaField, Data, StructuredField};
+use pallet_crowdloan::CrowdloanId;
 use pallet_subtensor::rpc_info::{
     delegate_info::DelegateInfo,
     dynamic_info::DynamicInfo,
@@ -11,6 +14,7 @@ use pallet_subtensor::rpc_info::{
     stake_info::StakeInfo,
     subnet_info::{SubnetHyperparams, SubnetInfo, SubnetInfov2},
 };
+use pallet_subtensor::utils::hyperparameters::HyperparameterInfo;
 use sp_runtime::AccountId32;
 
 // Here we declare the runtime API. It is implemented it the `impl` block in
@@ -53,4 +57,28 @@ sp_api::decl_runtime_apis! {
     pub trait SubnetRegistrationRuntimeApi {
         fn get_network_registration_cost() -> u64;
     }
+
+    pub trait HyperparameterRuntimeApi {
+        fn get_subnet_hyperparameters(netuid: u16) -> Option<Vec<HyperparameterInfo>>;
+    }
+
+    pub trait HyperparameterAnnouncementRuntimeApi {
+        fn get_pending_hyperparameter_changes(netuid: u16) -> Vec<(u32, PendingHyperparameterChange<AccountId32, u32>)>;
+    }
+
+    pub trait CrowdloanRuntimeApi {
+        fn get_contributions(contributor: AccountId32) -> Vec<(CrowdloanId, u64)>;
+    }
+
+    pub trait DrandRuntimeApi {
+        fn get_retained_rounds() -> Vec<(u16, u64, u64)>;
+    }
+
+    pub trait CommitmentsRuntimeApi {
+        fn get_commitment_schema(netuid: u16) -> Option<Vec<CommitmentSchemaField>>;
+        fn get_structured_commitment(netuid: u16, account: AccountId32) -> Option<Vec<StructuredField>>;
+        fn get_structured_commitments(netuid: u16) -> Vec<(AccountId32, Vec<StructuredField>)>;
+        fn get_commitment_history(netuid: u16, account: AccountId32) -> Vec<(u64, Vec<Data>)>;
+        fn get_commitment_diffs(netuid: u16, account: AccountId32) -> Vec<(u64, Vec<CommitmentFieldChange>)>;
+    }
 }
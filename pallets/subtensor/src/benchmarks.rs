@@ -657,6 +657,60 @@ mod pallet_benchmarks {
         );
     }
 
+    #[benchmark]
+    fn execute_scheduled_coldkey_swap() {
+        let old_coldkey: T::AccountId = account("old_coldkey", 0, 0);
+        let new_coldkey: T::AccountId = account("new_coldkey", 0, 0);
+        let hotkey1: T::AccountId = account("hotkey1", 0, 0);
+        let netuid: u16 = 1;
+        let swap_cost: u64 = Subtensor::<T>::get_key_swap_cost();
+        let free_balance_old: u64 = 12345 + swap_cost;
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        Subtensor::<T>::set_network_registration_allowed(netuid, true);
+        Subtensor::<T>::set_network_pow_registration_allowed(netuid, true);
+
+        let block_number: u64 = Subtensor::<T>::get_current_block_as_u64();
+        let (nonce, work) =
+            Subtensor::<T>::create_work_for_block_number(netuid, block_number, 3, &hotkey1);
+        let _ = Subtensor::<T>::register(
+            RawOrigin::Signed(old_coldkey.clone()).into(),
+            netuid,
+            block_number,
+            nonce,
+            work.clone(),
+            hotkey1.clone(),
+            old_coldkey.clone(),
+        );
+
+        Subtensor::<T>::add_balance_to_coldkey_account(&old_coldkey, free_balance_old);
+        let name: Vec<u8> = b"The fourth Coolest Identity".to_vec();
+        let identity = ChainIdentity {
+            name,
+            url: vec![],
+            image: vec![],
+            discord: vec![],
+            description: vec![],
+            additional: vec![],
+        };
+        Identities::<T>::insert(&old_coldkey, identity);
+        ColdkeySwapScheduled::<T>::insert(
+            &old_coldkey,
+            (
+                frame_system::Pallet::<T>::block_number(),
+                new_coldkey.clone(),
+            ),
+        );
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Root,
+            old_coldkey.clone(),
+            new_coldkey.clone(),
+            swap_cost,
+        );
+    }
+
     #[benchmark]
     fn batch_reveal_weights() {
         let tempo: u16 = 0;
@@ -1490,4 +1544,300 @@ mod pallet_benchmarks {
         #[extrinsic_call]
         _(RawOrigin::Signed(coldkey.clone()), hotkey.clone());
     }
+
+    #[benchmark]
+    fn register_alpha_token() {
+        let caller: T::AccountId = whitelisted_caller();
+        let coldkey: T::AccountId = account("B", 0, 16);
+        let hotkey: T::AccountId = account("A", 0, 16);
+        let netuid: u16 = 1;
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        Subtensor::<T>::create_account_if_non_existent(&coldkey, &hotkey);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), hotkey.clone(), netuid);
+    }
+
+    #[benchmark]
+    fn approve_alpha() {
+        let coldkey: T::AccountId = whitelisted_caller();
+        let spender: T::AccountId = account("B", 0, 17);
+        let hotkey: T::AccountId = account("A", 0, 17);
+        let netuid: u16 = 1;
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        Subtensor::<T>::create_account_if_non_existent(&coldkey, &hotkey);
+        assert_ok!(Subtensor::<T>::register_alpha_token(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            hotkey.clone(),
+            netuid
+        ));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(coldkey.clone()),
+            0,
+            spender.clone(),
+            1_000_000,
+        );
+    }
+
+    #[benchmark]
+    fn transfer_alpha_from() {
+        let coldkey: T::AccountId = account("C", 0, 18);
+        let spender: T::AccountId = whitelisted_caller();
+        let dest: T::AccountId = account("B", 0, 18);
+        let hot: T::AccountId = account("A", 0, 18);
+        let netuid: u16 = 1;
+
+        SubtokenEnabled::<T>::insert(netuid, true);
+        Subtensor::<T>::init_new_network(netuid, 1);
+
+        let reg_fee = Subtensor::<T>::get_burn_as_u64(netuid);
+        let stake_tao: u64 = 1_000_000;
+        let deposit = reg_fee.saturating_mul(2).saturating_add(stake_tao);
+        Subtensor::<T>::add_balance_to_coldkey_account(&coldkey, deposit);
+
+        assert_ok!(Subtensor::<T>::burned_register(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            netuid,
+            hot.clone()
+        ));
+
+        SubnetTAO::<T>::insert(netuid, deposit);
+        SubnetAlphaIn::<T>::insert(netuid, deposit);
+        TotalStake::<T>::set(deposit);
+
+        assert_ok!(Subtensor::<T>::add_stake_limit(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            hot.clone(),
+            netuid,
+            stake_tao,
+            u64::MAX,
+            false
+        ));
+
+        let alpha_to_transfer: u64 =
+            Subtensor::<T>::get_stake_for_hotkey_and_coldkey_on_subnet(&hot, &coldkey, netuid);
+
+        Subtensor::<T>::create_account_if_non_existent(&dest, &hot);
+        assert_ok!(Subtensor::<T>::register_alpha_token(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            hot.clone(),
+            netuid
+        ));
+        assert_ok!(Subtensor::<T>::approve_alpha(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            0,
+            spender.clone(),
+            alpha_to_transfer
+        ));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(spender.clone()),
+            0,
+            coldkey.clone(),
+            dest.clone(),
+            alpha_to_transfer,
+        );
+    }
+
+    #[benchmark]
+    fn transfer_alpha() {
+        let coldkey: T::AccountId = whitelisted_caller();
+        let dest: T::AccountId = account("B", 0, 19);
+        let hot: T::AccountId = account("A", 0, 19);
+        let netuid: u16 = 1;
+
+        SubtokenEnabled::<T>::insert(netuid, true);
+        Subtensor::<T>::init_new_network(netuid, 1);
+
+        let reg_fee = Subtensor::<T>::get_burn_as_u64(netuid);
+        let stake_tao: u64 = 1_000_000;
+        let deposit = reg_fee.saturating_mul(2).saturating_add(stake_tao);
+        Subtensor::<T>::add_balance_to_coldkey_account(&coldkey, deposit);
+
+        assert_ok!(Subtensor::<T>::burned_register(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            netuid,
+            hot.clone()
+        ));
+
+        SubnetTAO::<T>::insert(netuid, deposit);
+        SubnetAlphaIn::<T>::insert(netuid, deposit);
+        TotalStake::<T>::set(deposit);
+
+        assert_ok!(Subtensor::<T>::add_stake_limit(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            hot.clone(),
+            netuid,
+            stake_tao,
+            u64::MAX,
+            false
+        ));
+
+        let alpha_to_transfer: u64 =
+            Subtensor::<T>::get_stake_for_hotkey_and_coldkey_on_subnet(&hot, &coldkey, netuid);
+
+        Subtensor::<T>::create_account_if_non_existent(&dest, &hot);
+        assert_ok!(Subtensor::<T>::register_alpha_token(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            hot.clone(),
+            netuid
+        ));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(coldkey.clone()),
+            0,
+            dest.clone(),
+            alpha_to_transfer,
+        );
+    }
+
+    #[benchmark]
+    fn accept_childkey() {
+        let coldkey: T::AccountId = whitelisted_caller();
+        let parent: T::AccountId = account("A", 0, 19);
+        let child: T::AccountId = account("B", 0, 19);
+        let netuid: u16 = 1;
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        Subtensor::<T>::create_account_if_non_existent(&coldkey, &child);
+        PendingChildKeys::<T>::insert(netuid, &parent, (vec![(u64::MAX, child.clone())], 0));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(coldkey.clone()),
+            child.clone(),
+            parent.clone(),
+            netuid,
+        );
+    }
+
+    #[benchmark]
+    fn resign_childkey() {
+        let coldkey: T::AccountId = whitelisted_caller();
+        let parent: T::AccountId = account("A", 0, 20);
+        let child: T::AccountId = account("B", 0, 20);
+        let netuid: u16 = 1;
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        Subtensor::<T>::create_account_if_non_existent(&coldkey, &child);
+        ChildKeys::<T>::insert(&parent, netuid, vec![(u64::MAX, child.clone())]);
+        ParentKeys::<T>::insert(&child, netuid, vec![(u64::MAX, parent.clone())]);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(coldkey.clone()),
+            child.clone(),
+            parent.clone(),
+            netuid,
+        );
+    }
+
+    #[benchmark]
+    fn set_coldkey_guardians() {
+        let coldkey: T::AccountId = whitelisted_caller();
+        let guardians: Vec<T::AccountId> = (0..MAX_COLDKEY_GUARDIANS as u32)
+            .map(|i| account("Guardian", i, 21))
+            .collect();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(coldkey.clone()), guardians, 2);
+    }
+
+    #[benchmark]
+    fn approve_coldkey_recovery() {
+        let coldkey: T::AccountId = account("A", 0, 22);
+        let new_coldkey: T::AccountId = account("B", 0, 22);
+        let guardian: T::AccountId = whitelisted_caller();
+
+        ColdkeyGuardians::<T>::insert(
+            &coldkey,
+            ColdkeyGuardianSet {
+                guardians: vec![guardian.clone()],
+                threshold: 1,
+            },
+        );
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(guardian.clone()),
+            coldkey.clone(),
+            new_coldkey.clone(),
+        );
+    }
+
+    #[benchmark]
+    fn cancel_coldkey_recovery() {
+        let coldkey: T::AccountId = whitelisted_caller();
+        let new_coldkey: T::AccountId = account("B", 0, 23);
+
+        PendingColdkeyRecoveries::<T>::insert(
+            &coldkey,
+            ColdkeyRecovery {
+                approvals: vec![(account("Guardian", 0, 23), new_coldkey)],
+                scheduled: None,
+            },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(coldkey.clone()));
+    }
+
+    #[benchmark]
+    fn withdraw_coldkey_recovery_approval() {
+        let coldkey: T::AccountId = account("A", 0, 25);
+        let new_coldkey: T::AccountId = account("B", 0, 25);
+        let guardian: T::AccountId = whitelisted_caller();
+
+        ColdkeyGuardians::<T>::insert(
+            &coldkey,
+            ColdkeyGuardianSet {
+                guardians: vec![guardian.clone()],
+                threshold: 1,
+            },
+        );
+        assert_ok!(Subtensor::<T>::approve_coldkey_recovery(
+            RawOrigin::Signed(guardian.clone()).into(),
+            coldkey.clone(),
+            new_coldkey
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(guardian.clone()), coldkey.clone());
+    }
+
+    #[benchmark]
+    fn cancel_coldkey_swap() {
+        let coldkey: T::AccountId = whitelisted_caller();
+        let new_coldkey: T::AccountId = account("B", 0, 24);
+
+        ColdkeySwapScheduled::<T>::insert(
+            &coldkey,
+            (frame_system::Pallet::<T>::block_number(), new_coldkey),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(coldkey.clone()));
+    }
+
+    #[benchmark]
+    fn contest_coldkey_swap() {
+        let coldkey: T::AccountId = whitelisted_caller();
+        let new_coldkey: T::AccountId = account("B", 0, 25);
+        let swap_cost: u64 = Subtensor::<T>::get_key_swap_cost();
+
+        Subtensor::<T>::add_balance_to_coldkey_account(&coldkey, swap_cost.saturating_mul(2));
+        ColdkeySwapScheduled::<T>::insert(
+            &coldkey,
+            (frame_system::Pallet::<T>::block_number(), new_coldkey),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(coldkey.clone()));
+    }
 }
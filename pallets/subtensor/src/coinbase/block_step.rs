@@ -26,7 +26,8 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
         let subnets: Vec<u16> = Self::get_all_subnet_netuids();
         for &netuid in subnets.iter() {
             if Self::should_run_epoch(netuid, block_number) {
-                // Set pending children on the epoch.
+                // Expire ended child-key terms and set pending children on the epoch.
+                Self::expire_child_keys(netuid);
                 Self::do_set_pending_children(netuid);
             }
         }
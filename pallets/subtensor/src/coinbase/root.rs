@@ -429,16 +429,20 @@ impl<T: Config> Pallet<T> {
 
     /// Facilitates the removal of a user's subnetwork.
     ///
+    /// The subnet is sunset rather than removed right away, so alpha holders get the unstake
+    /// window and their remaining alpha is unstaked before the subnet storage is removed.
+    ///
     /// # Args:
-    /// * 'origin': ('T::RuntimeOrigin'): The calling origin. Must be signed.
+    /// * 'coldkey': ('T::AccountId'): The owner of the subnet.
     /// * 'netuid': ('u16'): The unique identifier of the network to be removed.
     ///
     /// # Event:
-    /// * 'NetworkRemoved': Emitted when a network is successfully removed.
+    /// * 'SubnetSunsetStarted': Emitted when the sunset of the network starts.
     ///
     /// # Raises:
     /// * 'SubNetworkDoesNotExist': If the specified network does not exist.
     /// * 'NotSubnetOwner': If the caller does not own the specified subnet.
+    /// * 'SubnetSunsetting': If the network is already being sunset.
     ///
     pub fn user_remove_network(coldkey: T::AccountId, netuid: u16) -> dispatch::DispatchResult {
         // --- 1. Ensure this subnet exists.
@@ -453,20 +457,9 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotSubnetOwner
         );
 
-        // --- 4. Remove the subnet identity if it exists.
-        if SubnetIdentitiesV2::<T>::take(netuid).is_some() {
-            Self::deposit_event(Event::SubnetIdentityRemoved(netuid));
-        }
-
-        // --- 5. Explicitly erase the network and all its parameters.
-        Self::remove_network(netuid);
-
-        // --- 6. Emit the NetworkRemoved event.
-        log::debug!("NetworkRemoved( netuid:{:?} )", netuid);
-        Self::deposit_event(Event::NetworkRemoved(netuid));
-
-        // --- 7. Return success.
-        Ok(())
+        // --- 3. Sunset the network, which erases it and all its parameters once its alpha
+        // has been unstaked.
+        Self::do_start_subnet_sunset(netuid)
     }
 
     /// Removes a network (identified by netuid) and all associated parameters.
@@ -506,6 +499,11 @@ impl<T: Config> Pallet<T> {
         let keys = Keys::<T>::iter_prefix(netuid).collect::<Vec<_>>();
         let _ = Keys::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = Bonds::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = AssociatedEvmAddress::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = AssociatedEvmContract::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = PendingChildKeyTerms::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = ChildKeyAcceptances::<T>::clear_prefix((netuid,), u32::MAX, None);
+        let _ = ActiveChildKeyTerms::<T>::clear_prefix((netuid,), u32::MAX, None);
 
         // --- 8. Removes the weights for this subnet (do not remove).
         let _ = Weights::<T>::clear_prefix(netuid, u32::MAX, None);
@@ -554,6 +552,7 @@ impl<T: Config> Pallet<T> {
         ActivityCutoff::<T>::remove(netuid);
         MaxWeightsLimit::<T>::remove(netuid);
         MinAllowedWeights::<T>::remove(netuid);
+        HyperparameterAnnouncementDelay::<T>::remove(netuid);
         RegistrationsThisInterval::<T>::remove(netuid);
         POWRegistrationsThisInterval::<T>::remove(netuid);
         BurnRegistrationsThisInterval::<T>::remove(netuid);
@@ -562,6 +561,11 @@ impl<T: Config> Pallet<T> {
         Self::add_balance_to_coldkey_account(&owner_coldkey, reserved_amount);
         Self::set_subnet_locked_balance(netuid, 0);
         SubnetOwner::<T>::remove(netuid);
+        PendingSubnetOwner::<T>::remove(netuid);
+        SubnetCoOwners::<T>::remove(netuid);
+        SubnetSaleListings::<T>::remove(netuid);
+        SubnetSunset::<T>::remove(netuid);
+        Self::remove_subnet_lease(netuid);
 
         // --- 13. Remove subnet identity if it exists.
         if SubnetIdentitiesV2::<T>::contains_key(netuid) {
@@ -1,9 +1,8 @@
 use super::*;
 use alloc::collections::BTreeMap;
+use pallet_drand::types::BeaconId;
 use safe_math::*;
 use substrate_fixed::types::U96F32;
-use tle::stream_ciphers::AESGCMStreamCipherProvider;
-use tle::tlock::tld;
 
 /// Contains all necesarry information to set weights.
 ///
@@ -43,11 +42,14 @@ impl<T: Config> Pallet<T> {
             .filter(|netuid| *netuid != 0)
             .collect();
         log::debug!("All subnet netuids: {:?}", subnets);
-        // Filter out subnets with no first emission block number.
+        // Filter out subnets with no first emission block number and sunsetting subnets.
         let subnets_to_emit_to: Vec<u16> = subnets
             .clone()
             .into_iter()
-            .filter(|netuid| FirstEmissionBlockNumber::<T>::get(*netuid).is_some())
+            .filter(|netuid| {
+                FirstEmissionBlockNumber::<T>::get(*netuid).is_some()
+                    && !Self::is_subnet_sunsetting(*netuid)
+            })
             .collect();
         log::debug!("Subnets to emit to: {:?}", subnets_to_emit_to);
 
@@ -400,8 +402,10 @@ impl<T: Config> Pallet<T> {
         alpha_dividends: BTreeMap<T::AccountId, U96F32>,
         tao_dividends: BTreeMap<T::AccountId, U96F32>,
     ) {
-        // Distribute the owner cut.
-        if let Ok(owner_coldkey) = SubnetOwner::<T>::try_get(netuid) {
+        // Distribute the owner cut, to the lease contributors if the subnet is leased.
+        if let Some(lease_id) = SubnetUidToLeaseId::<T>::get(netuid) {
+            Self::distribute_leased_network_dividends(lease_id, owner_cut);
+        } else if let Ok(owner_coldkey) = SubnetOwner::<T>::try_get(netuid) {
             if let Ok(owner_hotkey) = SubnetOwnerHotkey::<T>::try_get(netuid) {
                 // Increase stake for owner hotkey and coldkey.
                 log::debug!(
@@ -643,8 +647,8 @@ impl<T: Config> Pallet<T> {
         combined_contribution.saturating_to_num::<u64>()
     }
 
-    /// Returns a list of tuples for each parent associated with this hotkey including self
-    /// Each tuples contains the dividends owed to that hotkey given their parent proportion
+    /// Returns a list of tuples for each ancestor within `MaxChildKeyDepth` hops of this hotkey including self
+    /// Each tuples contains the dividends owed to that hotkey given the share of its stake reaching the hotkey
     /// The hotkey child take proportion is removed from this and added to the tuples for self.
     /// The hotkey also gets a portion based on its own stake contribution, this is added to the childkey take.
     ///
@@ -654,7 +658,7 @@ impl<T: Config> Pallet<T> {
     /// * `dividends` - the dividends to distribute.
     ///
     /// # Returns
-    /// * dividend_tuples: `Vec<(T::AccountId, u64)>` - Vector of (hotkey, divs) for each parent including self.
+    /// * dividend_tuples: `Vec<(T::AccountId, u64)>` - Vector of (hotkey, divs) for each ancestor including self.
     ///
     pub fn get_parent_child_dividends_distribution(
         hotkey: &T::AccountId,
@@ -703,35 +707,39 @@ impl<T: Config> Pallet<T> {
         total_contribution =
             total_contribution.saturating_add(U96F32::saturating_from_num(self_contribution));
 
-        // Calculate total root and alpha (subnet-specific) stakes from all parents
-        for (proportion, parent) in Self::get_parents(hotkey, netuid) {
-            // Convert the parent's stake proportion to a fractional value
-            let parent_proportion: U96F32 = U96F32::saturating_from_num(proportion)
-                .safe_div(U96F32::saturating_from_num(u64::MAX));
-
-            // Get the parent's root and subnet-specific (alpha) stakes
+        // Calculate total root and alpha (subnet-specific) stakes from all ancestors
+        let max_depth: u16 = MaxChildKeyDepth::<T>::get();
+        let children_proportion: U96F32 =
+            Self::get_children_proportion(&Self::get_children(hotkey, netuid));
+        for (parent, parent_share, hops) in Self::get_ancestors(hotkey, netuid) {
+            // Get the ancestor's root and subnet-specific (alpha) stakes
             let parent_root: U96F32 = U96F32::saturating_from_num(
                 Self::get_stake_for_hotkey_on_subnet(&parent, Self::get_root_netuid()),
             );
             let parent_alpha: U96F32 =
                 U96F32::saturating_from_num(Self::get_stake_for_hotkey_on_subnet(&parent, netuid));
 
-            // Calculate the parent's contribution to the hotkey's stakes
-            let parent_alpha_contribution: U96F32 = parent_alpha.saturating_mul(parent_proportion);
+            // Calculate the ancestor's contribution to the hotkey's stakes
+            let parent_alpha_contribution: U96F32 = parent_alpha.saturating_mul(parent_share);
             let parent_root_contribution: U96F32 = parent_root
-                .saturating_mul(parent_proportion)
+                .saturating_mul(parent_share)
                 .saturating_mul(tao_weight);
-            let combined_contribution: U96F32 =
-                parent_alpha_contribution.saturating_add(parent_root_contribution);
+            let combined_contribution: U96F32 = Self::retained_from_ancestor(
+                parent_alpha_contribution.saturating_add(parent_root_contribution),
+                hops,
+                max_depth,
+                children_proportion,
+            );
 
             // Add to the total stakes
             total_contribution = total_contribution.saturating_add(combined_contribution);
-            // Store the parent's contributions for later use
+            // Store the ancestor's contributions for later use
             parent_contributions.push((parent.clone(), combined_contribution));
             log::debug!(
-                "Parent contribution for hotkey {:?} from parent {:?}: {:?}",
+                "Parent contribution for hotkey {:?} from ancestor {:?} ({:?} hops): {:?}",
                 hotkey,
                 parent,
+                hops,
                 combined_contribution
             );
         }
@@ -841,11 +849,7 @@ impl<T: Config> Pallet<T> {
 
     /// The `reveal_crv3_commits` function is run at the very beginning of epoch `n`,
     pub fn reveal_crv3_commits(netuid: u16) -> dispatch::DispatchResult {
-        use ark_serialize::CanonicalDeserialize;
         use frame_support::traits::OriginTrait;
-        use tle::curves::drand::TinyBLS381;
-        use tle::tlock::TLECiphertext;
-        use w3f_bls::EngineBLS;
 
         let cur_block = Self::get_current_block_as_u64();
         let cur_epoch = Self::get_epoch_index(netuid, cur_block);
@@ -854,10 +858,12 @@ impl<T: Config> Pallet<T> {
         let reveal_epoch =
             cur_epoch.saturating_sub(Self::get_reveal_period(netuid).saturating_sub(1));
 
-        // Clean expired commits
+        // Clean expired commits, releasing the drand rounds they were waiting on
         for (epoch, _) in CRV3WeightCommits::<T>::iter_prefix(netuid) {
             if epoch < reveal_epoch {
-                CRV3WeightCommits::<T>::remove(netuid, epoch);
+                for (_, _, beacon_id, round_number) in CRV3WeightCommits::<T>::take(netuid, epoch) {
+                    pallet_drand::Pallet::<T>::release_round(beacon_id, round_number);
+                }
             }
         }
 
@@ -868,61 +874,37 @@ impl<T: Config> Pallet<T> {
         }
 
         let mut entries = CRV3WeightCommits::<T>::take(netuid, reveal_epoch);
+        // The drand rounds are released once every commit is processed
+        let rounds: Vec<(BeaconId, u64)> = entries
+            .iter()
+            .map(|(_, _, beacon_id, round_number)| (*beacon_id, *round_number))
+            .collect();
 
         // Keep popping item off the end of the queue until we sucessfully reveal a commit.
-        while let Some((who, serialized_compresssed_commit, round_number)) = entries.pop_front() {
-            let reader = &mut &serialized_compresssed_commit[..];
-            let commit = match TLECiphertext::<TinyBLS381>::deserialize_compressed(reader) {
-                Ok(c) => c,
-                Err(e) => {
-                    log::warn!(
-                        "Failed to reveal commit for subnet {} submitted by {:?} due to error deserializing the commit: {:?}",
-                        netuid,
-                        who,
-                        e
-                    );
-                    continue;
-                }
-            };
-
+        while let Some((who, serialized_compresssed_commit, beacon_id, round_number)) =
+            entries.pop_front()
+        {
             // Try to get the round number from pallet_drand.
-            let pulse = match pallet_drand::Pulses::<T>::get(round_number) {
+            let pulse = match pallet_drand::Pallet::<T>::pulse(beacon_id, round_number) {
                 Some(p) => p,
                 None => {
                     // Round number used was not found on the chain. Skip this commit.
                     log::warn!(
-                        "Failed to reveal commit for subnet {} submitted by {:?} due to missing round number {} at time of reveal.",
-                        netuid,
-                        who,
-                        round_number
-                    );
-                    continue;
-                }
-            };
-
-            let signature_bytes = pulse
-                .signature
-                .strip_prefix(b"0x")
-                .unwrap_or(&pulse.signature);
-
-            let sig_reader = &mut &signature_bytes[..];
-            let sig = match <TinyBLS381 as EngineBLS>::SignatureGroup::deserialize_compressed(
-                sig_reader,
-            ) {
-                Ok(s) => s,
-                Err(e) => {
-                    log::error!(
-                        "Failed to reveal commit for subnet {} submitted by {:?} due to error deserializing signature from drand pallet: {:?}",
+                        "Failed to reveal commit for subnet {} submitted by {:?} due to missing round number {} of beacon {} at time of reveal.",
                         netuid,
                         who,
-                        e
+                        round_number,
+                        beacon_id
                     );
                     continue;
                 }
             };
 
-            let decrypted_bytes: Vec<u8> = match tld::<TinyBLS381, AESGCMStreamCipherProvider>(
-                commit, sig,
+            // Decrypted on the curve of the beacon the commit was encrypted to
+            let decrypted_bytes: Vec<u8> = match pallet_drand::Pallet::<T>::timelock_decrypt(
+                beacon_id,
+                &serialized_compresssed_commit,
+                &pulse,
             ) {
                 Ok(d) => d,
                 Err(e) => {
@@ -970,6 +952,10 @@ impl<T: Config> Pallet<T> {
             };
         }
 
+        for (beacon_id, round_number) in rounds {
+            pallet_drand::Pallet::<T>::release_round(beacon_id, round_number);
+        }
+
         Ok(())
     }
 }
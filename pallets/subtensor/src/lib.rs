@@ -77,7 +77,7 @@ pub mod pallet {
         },
     };
     use frame_system::pallet_prelude::*;
-    use pallet_drand::types::RoundNumber;
+    use pallet_drand::types::{BeaconId, RoundNumber};
     use sp_core::{ConstU32, H160, H256};
     use sp_runtime::traits::{Dispatchable, TrailingZeroInput};
     use sp_std::collections::vec_deque::VecDeque;
@@ -105,6 +105,24 @@ pub mod pallet {
     /// Minimum balance required to perform a coldkey swap
     pub const MIN_BALANCE_TO_PERFORM_COLDKEY_SWAP: u64 = 100_000_000; // 0.1 TAO in RAO
 
+    /// Maximum number of co-owners of a subnet
+    pub const MAX_SUBNET_CO_OWNERS: usize = 16;
+
+    /// Maximum number of storage entries visited per block while sunsetting a subnet
+    pub const SUBNET_SUNSET_CHUNK_SIZE: usize = 256;
+
+    /// Maximum number of lease contributors paid per block while distributing lease dividends
+    pub const SUBNET_LEASE_DIVIDENDS_CHUNK_SIZE: usize = 256;
+
+    /// Maximum number of Alpha entries indexed per block while backfilling SubnetAlphaPositions
+    pub const ALPHA_POSITIONS_INDEX_CHUNK_SIZE: usize = 1024;
+
+    /// Maximum number of hops stake and dividends can travel along child keys
+    pub const MAX_CHILDKEY_DEPTH: u16 = 5;
+
+    /// Maximum number of guardians of a coldkey
+    pub const MAX_COLDKEY_GUARDIANS: usize = 16;
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -270,6 +288,91 @@ pub mod pallet {
         pub additional: Vec<u8>,
     }
 
+    /// Data structure for a pending subnet ownership transfer.
+    #[crate::freeze_struct("a1a37a5916c3a59b")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct SubnetOwnershipOffer<AccountId> {
+        /// The account that may accept the ownership.
+        pub new_owner: AccountId,
+        /// The co-owners behind `new_owner`, empty if it is a single account.
+        pub co_owners: Vec<AccountId>,
+        /// The number of co-owners required to act as the owner.
+        pub threshold: u16,
+    }
+
+    /// Data structure for a subnet listed for sale.
+    #[crate::freeze_struct("f8951cb8055615db")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct SubnetSaleListing<AccountId> {
+        /// The subnet owner selling the subnet.
+        pub seller: AccountId,
+        /// The price in RAO.
+        pub price: u64,
+        /// The last block at which the subnet can be bought, if any.
+        pub deadline: Option<u64>,
+    }
+
+    /// Terms of a child-key relationship, agreed to by the child when it accepts.
+    #[crate::freeze_struct("1470c87361a7527d")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug, Default)]
+    pub struct ChildKeyTerms {
+        /// The number of blocks the relationship lasts, forever if none.
+        pub term: Option<u64>,
+        /// Whether the relationship is renewed for another term when it expires.
+        pub auto_renew: bool,
+    }
+
+    /// Data structure for the guardians able to recover a coldkey.
+    #[crate::freeze_struct("f4da5f7989988b8")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct ColdkeyGuardianSet<AccountId> {
+        /// The accounts able to recover the coldkey.
+        pub guardians: Vec<AccountId>,
+        /// The number of guardians required to recover the coldkey.
+        pub threshold: u16,
+    }
+
+    /// Data structure for a coldkey recovery initiated by guardians.
+    #[crate::freeze_struct("9db516010721127f")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct ColdkeyRecovery<AccountId, BlockNumber> {
+        /// The approving guardians, each with the coldkey it recovers the account to.
+        pub approvals: Vec<(AccountId, AccountId)>,
+        /// The coldkey receiving the recovered account and the block at which the swap
+        /// executes, once the threshold of guardians approved it.
+        pub scheduled: Option<(AccountId, BlockNumber)>,
+    }
+
+    /// Identifier of a subnet lease.
+    pub type LeaseId = u32;
+
+    /// Data structure for a subnet registered by a crowdloan and leased to its contributors.
+    #[crate::freeze_struct("468405032f21593")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct SubnetLease<AccountId> {
+        /// The account receiving the subnet ownership when the lease ends.
+        pub beneficiary: AccountId,
+        /// The coldkey owning the subnet during the lease.
+        pub coldkey: AccountId,
+        /// The subnet owner hotkey during the lease.
+        pub hotkey: AccountId,
+        /// The leased subnet.
+        pub netuid: u16,
+        /// The block at which the lease ends.
+        pub end_block: u64,
+    }
+
+    /// Phase of a subnet sunset.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub enum SubnetSunsetPhase {
+        /// Emissions and registrations are stopped, alpha holders may unstake until the block.
+        UnstakeWindow(u64),
+        /// Remaining alpha is being unstaked.
+        Liquidating,
+        /// The subnet storage is being removed.
+        CleaningUp,
+    }
+
     /// Data structure for stake related jobs.
     #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
     pub enum StakeJob<AccountId> {
@@ -859,6 +962,12 @@ pub mod pallet {
         b"\xF0\x9D\x9C\x8F".to_vec() // Unicode for tau (𝜏)
     }
 
+    #[pallet::type_value]
+    /// Default number of blocks alpha holders have to unstake from a sunsetting subnet
+    pub fn DefaultSubnetSunsetUnstakeWindow<T: Config>() -> u64 {
+        50400
+    }
+
     #[pallet::type_value]
     /// Default value for dissolve network schedule duration
     pub fn DefaultDissolveNetworkScheduleDuration<T: Config>() -> BlockNumberFor<T> {
@@ -1039,6 +1148,41 @@ pub mod pallet {
         ValueQuery,
         DefaultAccountLinkage<T>,
     >;
+    #[pallet::storage]
+    /// DMAP ( netuid, parent ) --> terms | The terms of the pending children of the parent.
+    pub type PendingChildKeyTerms<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        ChildKeyTerms,
+        ValueQuery,
+    >;
+    #[pallet::storage]
+    /// NMAP ( netuid, parent, child ) --> () | The child accepted the pending schedule of the parent.
+    pub type ChildKeyAcceptances<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Identity, u16>,                  // netuid
+            NMapKey<Blake2_128Concat, T::AccountId>, // parent
+            NMapKey<Blake2_128Concat, T::AccountId>, // child
+        ),
+        (),
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// NMAP ( netuid, parent, child ) --> (terms, expires_at) | The terms of an active relationship with a term.
+    pub type ActiveChildKeyTerms<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Identity, u16>,                  // netuid
+            NMapKey<Blake2_128Concat, T::AccountId>, // parent
+            NMapKey<Blake2_128Concat, T::AccountId>, // child
+        ),
+        (ChildKeyTerms, u64),
+        OptionQuery,
+    >;
     #[pallet::storage] // --- DMAP ( netuid, hotkey ) --> u64 | Last total dividend this hotkey got on tempo.
     pub type AlphaDividendsPerSubnet<T: Config> = StorageDoubleMap<
         _,
@@ -1140,6 +1284,28 @@ pub mod pallet {
         DefaultColdkeySwapScheduled<T>,
     >;
 
+    #[pallet::storage] // --- MAP ( cold ) --> escrow | Returns the swap cost held in escrow for a contested coldkey swap.
+    pub type ContestedColdkeySwaps<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+
+    #[pallet::storage] // --- MAP ( cold ) --> guardian_set | Returns the guardians able to recover the coldkey.
+    pub type ColdkeyGuardians<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        ColdkeyGuardianSet<T::AccountId>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage] // --- MAP ( cold ) --> recovery | Returns the pending recovery of the coldkey.
+    pub type PendingColdkeyRecoveries<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        ColdkeyRecovery<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
     #[pallet::storage] // --- DMAP ( hot, netuid ) --> alpha | Returns the total amount of alpha a hotkey owns.
     pub type TotalHotkeyAlpha<T: Config> = StorageDoubleMap<
         _,
@@ -1185,6 +1351,19 @@ pub mod pallet {
         U64F64, // Shares
         ValueQuery,
     >;
+    #[pallet::storage] // --- NMAP ( netuid, hot, cold ) --> () | Indexes the non-zero alpha positions of a subnet.
+    pub type SubnetAlphaPositions<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Identity, u16>,                  // subnet
+            NMapKey<Blake2_128Concat, T::AccountId>, // hot
+            NMapKey<Blake2_128Concat, T::AccountId>, // cold
+        ),
+        (),
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- ITEM ( last_raw_key ) | The Alpha key the SubnetAlphaPositions backfill resumes after, set while it runs.
+    pub type SubnetAlphaPositionsIndexCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
     #[pallet::storage] // --- MAP ( netuid ) --> token_symbol | Returns the token symbol for a subnet.
     pub type TokenSymbol<T: Config> =
         StorageMap<_, Identity, u16, Vec<u8>, ValueQuery, DefaultUnicodeVecU8<T>>;
@@ -1333,6 +1512,53 @@ pub mod pallet {
     pub type SubnetOwner<T: Config> =
         StorageMap<_, Identity, u16, T::AccountId, ValueQuery, DefaultSubnetOwner<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> pending subnet ownership offer
+    pub type PendingSubnetOwner<T: Config> =
+        StorageMap<_, Identity, u16, SubnetOwnershipOffer<T::AccountId>, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> ( co_owners, threshold ) of a subnet owned by a multisig
+    pub type SubnetCoOwners<T: Config> =
+        StorageMap<_, Identity, u16, (Vec<T::AccountId>, u16), OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> subnet sale listing
+    pub type SubnetSaleListings<T: Config> =
+        StorageMap<_, Identity, u16, SubnetSaleListing<T::AccountId>, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> sunset phase of the subnet
+    pub type SubnetSunset<T: Config> = StorageMap<_, Identity, u16, SubnetSunsetPhase, OptionQuery>;
+    #[pallet::storage]
+    /// --- ITEM ( unstake window in blocks of a subnet sunset )
+    pub type SubnetSunsetUnstakeWindow<T> =
+        StorageValue<_, u64, ValueQuery, DefaultSubnetSunsetUnstakeWindow<T>>;
+    #[pallet::storage]
+    /// --- MAP ( lease_id ) --> subnet lease
+    pub type SubnetLeases<T: Config> =
+        StorageMap<_, Twox64Concat, LeaseId, SubnetLease<T::AccountId>, OptionQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( lease_id, contributor ) --> share of the owner emissions
+    pub type SubnetLeaseShares<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        LeaseId,
+        Blake2_128Concat,
+        T::AccountId,
+        U64F64,
+        ValueQuery,
+    >;
+    #[pallet::storage]
+    /// --- MAP ( lease_id ) --> owner emissions accrued for the contributors
+    pub type SubnetLeaseAccruedDividends<T> = StorageMap<_, Twox64Concat, LeaseId, u64, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( lease_id ) --> ( alpha, distributed alpha, last raw key ) of a distribution in progress
+    pub type SubnetLeaseDistributions<T> =
+        StorageMap<_, Twox64Concat, LeaseId, (u64, u64, Vec<u8>), OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> lease_id
+    pub type SubnetUidToLeaseId<T: Config> = StorageMap<_, Identity, u16, LeaseId, OptionQuery>;
+    #[pallet::storage]
+    /// --- ITEM ( next lease_id )
+    pub type NextSubnetLeaseId<T> = StorageValue<_, LeaseId, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> subnet_owner_hotkey
     pub type SubnetOwnerHotkey<T: Config> =
         StorageMap<_, Identity, u16, T::AccountId, ValueQuery, DefaultSubnetOwner<T>>;
@@ -1348,6 +1574,9 @@ pub mod pallet {
     pub type AlphaSigmoidSteepness<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultAlphaSigmoidSteepness<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> blocks an announced owner hyperparameter change waits before it is applied
+    pub type HyperparameterAnnouncementDelay<T> = StorageMap<_, Identity, u16, u64, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> Kappa
     pub type Kappa<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultKappa<T>>;
     #[pallet::storage]
@@ -1670,7 +1899,7 @@ pub mod pallet {
         OptionQuery,
     >;
     #[pallet::storage]
-    /// --- MAP (netuid, commit_epoch) --> VecDeque<(who, serialized_compressed_commit, reveal_round)> | Stores a queue of v3 commits for an account on a given netuid.
+    /// --- MAP (netuid, commit_epoch) --> VecDeque<(who, serialized_compressed_commit, beacon_id, reveal_round)> | Stores a queue of v3 commits for an account on a given netuid.
     pub type CRV3WeightCommits<T: Config> = StorageDoubleMap<
         _,
         Twox64Concat,
@@ -1680,6 +1909,7 @@ pub mod pallet {
         VecDeque<(
             T::AccountId,
             BoundedVec<u8, ConstU32<MAX_CRV3_COMMIT_SIZE_BYTES>>,
+            BeaconId,
             RoundNumber,
         )>,
         ValueQuery,
@@ -1708,6 +1938,32 @@ pub mod pallet {
     /// --- DMAP (netuid, uid) --> (H160, last_block_where_ownership_was_proven)
     pub type AssociatedEvmAddress<T: Config> =
         StorageDoubleMap<_, Twox64Concat, u16, Twox64Concat, u16, (H160, u64), OptionQuery>;
+    #[pallet::storage]
+    /// --- DMAP (netuid, uid) --> contract | The contract the coldkey allowed to act as the hotkey.
+    pub type AssociatedEvmContract<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, u16, Twox64Concat, u16, H160, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP (token_id) --> (hotkey, netuid) | The stake position wrapped by an ERC-20 alpha token.
+    pub type AlphaTokens<T: Config> =
+        StorageMap<_, Identity, u32, (T::AccountId, u16), OptionQuery>;
+    #[pallet::storage]
+    /// --- DMAP (hotkey, netuid) --> token_id | Reverse lookup of the alpha token for a position.
+    pub type AlphaTokenIds<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Identity, u16, u32, OptionQuery>;
+    #[pallet::storage]
+    /// --- ITEM ( next_alpha_token_id )
+    pub type NextAlphaTokenId<T> = StorageValue<_, u32, ValueQuery>;
+    #[pallet::storage] // --- NMAP ( token_id, owner, spender ) --> alpha | Returns the alpha the spender may move out of the owner's position.
+    pub type AlphaAllowances<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Identity, u32>,                  // token_id
+            NMapKey<Blake2_128Concat, T::AccountId>, // owner
+            NMapKey<Blake2_128Concat, T::AccountId>, // spender
+        ),
+        u64,
+        ValueQuery,
+    >;
 
     /// ==================
     /// ==== Genesis =====
@@ -1726,6 +1982,17 @@ pub mod pallet {
     pub type PendingChildKeyCooldown<T: Config> =
         StorageValue<_, u64, ValueQuery, DefaultPendingChildKeyCooldown<T>>;
 
+    #[pallet::type_value]
+    /// Default value for the child-key depth (settable by root, default 1)
+    pub fn DefaultMaxChildKeyDepth<T: Config>() -> u16 {
+        1
+    }
+
+    #[pallet::storage]
+    /// Storage value for the number of hops stake and dividends travel along child keys.
+    pub type MaxChildKeyDepth<T: Config> =
+        StorageValue<_, u16, ValueQuery, DefaultMaxChildKeyDepth<T>>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         /// Stakes record in genesis.
@@ -2097,7 +2364,8 @@ where
                     .into())
                 }
             }
-            Some(Call::commit_crv3_weights { netuid, .. }) => {
+            Some(Call::commit_crv3_weights { netuid, .. })
+            | Some(Call::commit_crv3_weights_with_beacon { netuid, .. }) => {
                 if Self::check_weights_min_stake(who, *netuid) {
                     let priority: u64 = Pallet::<T>::get_priority_set_weights(who, *netuid);
                     Ok(ValidTransaction {
@@ -2400,6 +2668,30 @@ where
                     Self::get_priority_staking(who, hotkey, *alpha_amount),
                 )
             }
+            Some(Call::transfer_alpha { .. }) => {
+                if ColdkeySwapScheduled::<T>::contains_key(who) {
+                    return InvalidTransaction::Custom(
+                        CustomTransactionError::ColdkeyInSwapSchedule.into(),
+                    )
+                    .into();
+                }
+                Ok(ValidTransaction {
+                    priority: Self::get_priority_vanilla(),
+                    ..Default::default()
+                })
+            }
+            Some(Call::transfer_alpha_from { owner, .. }) => {
+                if ColdkeySwapScheduled::<T>::contains_key(owner) {
+                    return InvalidTransaction::Custom(
+                        CustomTransactionError::ColdkeyInSwapSchedule.into(),
+                    )
+                    .into();
+                }
+                Ok(ValidTransaction {
+                    priority: Self::get_priority_vanilla(),
+                    ..Default::default()
+                })
+            }
             Some(Call::swap_stake {
                 hotkey,
                 origin_netuid,
@@ -2731,6 +3023,64 @@ impl<T, H, P> CollectiveInterface<T, H, P> for () {
     }
 }
 
+/// A crowdloan being finalized, as needed to fund a subnet lease
+pub struct FinalizingCrowdloan<AccountId> {
+    /// The crowdloan ID
+    pub id: u32,
+    /// The creator of the crowdloan
+    pub creator: AccountId,
+    /// The account holding the raised funds
+    pub funds_account: AccountId,
+    /// The amount raised
+    pub raised: u64,
+}
+
+/// Trait for interacting with the crowdloan pallet
+pub trait CrowdloanInterface<AccountId> {
+    /// Get the crowdloan being finalized, if any
+    fn finalizing_crowdloan() -> Option<FinalizingCrowdloan<AccountId>>;
+
+    /// Get the contributions to a crowdloan
+    fn contributions(crowdloan_id: u32) -> Vec<(AccountId, u64)>;
+
+    /// Get the maximum number of contributors to a crowdloan
+    fn max_contributors() -> u32;
+}
+
+impl<T> CrowdloanInterface<T> for () {
+    fn finalizing_crowdloan() -> Option<FinalizingCrowdloan<T>> {
+        None
+    }
+
+    fn contributions(_: u32) -> Vec<(T, u64)> {
+        vec![]
+    }
+
+    fn max_contributors() -> u32 {
+        0
+    }
+}
+
+/// Trait for managing the proxies of subnet leases
+pub trait ProxyInterface<AccountId> {
+    /// Let the beneficiary of a lease act as the lease coldkey for subnet owner calls
+    fn add_lease_beneficiary_proxy(lease: &AccountId, beneficiary: &AccountId) -> DispatchResult;
+
+    /// Remove the proxy of the beneficiary of a lease
+    fn remove_lease_beneficiary_proxy(lease: &AccountId, beneficiary: &AccountId)
+    -> DispatchResult;
+}
+
+impl<T> ProxyInterface<T> for () {
+    fn add_lease_beneficiary_proxy(_: &T, _: &T) -> DispatchResult {
+        Ok(())
+    }
+
+    fn remove_lease_beneficiary_proxy(_: &T, _: &T) -> DispatchResult {
+        Ok(())
+    }
+}
+
 /// Enum that defines types of rate limited operations for
 /// storing last block when this operation occured
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
@@ -36,6 +36,12 @@ mod config {
         /// Interface to allow other pallets to control who can register identities
         type TriumvirateInterface: crate::CollectiveInterface<Self::AccountId, Self::Hash, u32>;
 
+        /// Interface to the crowdloans funding subnet leases.
+        type CrowdloanInterface: crate::CrowdloanInterface<Self::AccountId>;
+
+        /// Interface to manage the proxies of subnet lease beneficiaries.
+        type ProxyInterface: crate::ProxyInterface<Self::AccountId>;
+
         /// The scheduler type used for scheduling delayed calls.
         type Scheduler: ScheduleAnon<
                 BlockNumberFor<Self>,
@@ -13,6 +13,7 @@ mod dispatches {
     use sp_runtime::traits::Saturating;
 
     use crate::MAX_CRV3_COMMIT_SIZE_BYTES;
+    use pallet_drand::types::{BeaconId, DEFAULT_BEACON_ID};
     /// Dispatchable functions allow users to interact with the pallet and invoke state changes.
     /// These functions materialize as "extrinsics", which are often compared to transactions.
     /// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
@@ -288,7 +289,7 @@ mod dispatches {
             commit: BoundedVec<u8, ConstU32<MAX_CRV3_COMMIT_SIZE_BYTES>>,
             reveal_round: u64,
         ) -> DispatchResult {
-            Self::do_commit_crv3_weights(origin, netuid, commit, reveal_round)
+            Self::do_commit_crv3_weights(origin, netuid, commit, DEFAULT_BEACON_ID, reveal_round)
         }
 
         /// ---- The implementation for batch revealing committed weights.
@@ -1222,6 +1223,7 @@ mod dispatches {
 
         /// Remove a user's subnetwork
         /// The caller must be the owner of the network
+        /// The network is sunset, see `start_subnet_sunset`
         #[pallet::call_index(61)]
         #[pallet::weight((Weight::from_parts(119_000_000, 0)
 		.saturating_add(T::DbWeight::get().reads(6))
@@ -1337,6 +1339,12 @@ mod dispatches {
             let who = ensure_signed(origin)?;
             let current_block = <frame_system::Pallet<T>>::block_number();
 
+            // A contested swap waits for governance and cannot be rescheduled
+            ensure!(
+                !ContestedColdkeySwaps::<T>::contains_key(&who),
+                Error::<T>::ColdkeySwapContested
+            );
+
             // If the coldkey has a scheduled swap, check if we can reschedule it
             if ColdkeySwapScheduled::<T>::contains_key(&who) {
                 let (scheduled_block, _scheduled_coldkey) = ColdkeySwapScheduled::<T>::get(&who);
@@ -1356,13 +1364,13 @@ mod dispatches {
             let duration: BlockNumberFor<T> = ColdkeySwapScheduleDuration::<T>::get();
             let when: BlockNumberFor<T> = current_block.saturating_add(duration);
 
-            let call = Call::<T>::swap_coldkey {
+            let call = Call::<T>::execute_scheduled_coldkey_swap {
                 old_coldkey: who.clone(),
                 new_coldkey: new_coldkey.clone(),
                 swap_cost,
             };
 
-            let bound_call = T::Preimages::bound(LocalCallOf::<T>::from(call.clone()))
+            let bound_call = <T as Config>::Preimages::bound(LocalCallOf::<T>::from(call.clone()))
                 .map_err(|_| Error::<T>::FailedToSchedule)?;
 
             T::Scheduler::schedule(
@@ -1422,7 +1430,7 @@ mod dispatches {
             //     netuid,
             // };
 
-            // let bound_call = T::Preimages::bound(LocalCallOf::<T>::from(call.clone()))
+            // let bound_call = <T as Config>::Preimages::bound(LocalCallOf::<T>::from(call.clone()))
             //     .map_err(|_| Error::<T>::FailedToSchedule)?;
 
             // T::Scheduler::schedule(
@@ -2069,6 +2077,636 @@ mod dispatches {
             Ok(())
         }
 
+        /// Offers the ownership of a subnet to a new owner.
+        ///
+        /// The ownership is only transferred once the new owner accepts it with
+        /// `accept_subnet_ownership`. A new offer replaces any pending one.
+        ///
+        /// # Args:
+        /// * `origin` - The current subnet owner.
+        /// * `netuid` - The subnet to transfer.
+        /// * `new_owner` - The coldkey that may accept the ownership.
+        #[pallet::call_index(110)]
+        #[pallet::weight((
+            Weight::from_parts(15_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn offer_subnet_ownership(
+            origin: OriginFor<T>,
+            netuid: u16,
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_offer_subnet_ownership(origin, netuid, new_owner)
+        }
+
+        /// Offers the ownership of a subnet to a set of co-owners.
+        ///
+        /// The subnet will be owned by the multisig account of the co-owners, which must accept
+        /// the ownership with `accept_subnet_ownership`.
+        ///
+        /// # Args:
+        /// * `origin` - The current subnet owner.
+        /// * `netuid` - The subnet to transfer.
+        /// * `co_owners` - The coldkeys that will jointly own the subnet.
+        /// * `threshold` - The number of co-owners required to act as the owner.
+        #[pallet::call_index(111)]
+        #[pallet::weight((
+            Weight::from_parts(20_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn offer_subnet_ownership_to_co_owners(
+            origin: OriginFor<T>,
+            netuid: u16,
+            co_owners: Vec<T::AccountId>,
+            threshold: u16,
+        ) -> DispatchResult {
+            Self::do_offer_subnet_ownership_to_co_owners(origin, netuid, co_owners, threshold)
+        }
+
+        /// Accepts a pending ownership offer for a subnet.
+        ///
+        /// # Args:
+        /// * `origin` - The account the ownership was offered to.
+        /// * `netuid` - The subnet to take ownership of.
+        /// * `hotkey` - The hotkey of the new owner, which receives the owner cut.
+        #[pallet::call_index(112)]
+        #[pallet::weight((
+            Weight::from_parts(25_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(4, 6)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn accept_subnet_ownership(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_accept_subnet_ownership(origin, netuid, hotkey)
+        }
+
+        /// Cancels the pending ownership offer of a subnet.
+        ///
+        /// # Args:
+        /// * `origin` - The current subnet owner.
+        /// * `netuid` - The subnet with the pending offer.
+        #[pallet::call_index(113)]
+        #[pallet::weight((
+            Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn cancel_subnet_ownership_offer(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_cancel_subnet_ownership_offer(origin, netuid)
+        }
+
+        /// Lists a subnet for sale at a fixed TAO price.
+        ///
+        /// # Args:
+        /// * `origin` - The current subnet owner.
+        /// * `netuid` - The subnet to sell.
+        /// * `price` - The price in RAO.
+        /// * `deadline` - The last block at which the subnet can be bought, if any.
+        #[pallet::call_index(114)]
+        #[pallet::weight((
+            Weight::from_parts(15_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn list_subnet_for_sale(
+            origin: OriginFor<T>,
+            netuid: u16,
+            price: u64,
+            deadline: Option<u64>,
+        ) -> DispatchResult {
+            Self::do_list_subnet_for_sale(origin, netuid, price, deadline)
+        }
+
+        /// Removes the sale listing of a subnet.
+        ///
+        /// # Args:
+        /// * `origin` - The current subnet owner.
+        /// * `netuid` - The listed subnet.
+        #[pallet::call_index(115)]
+        #[pallet::weight((
+            Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn cancel_subnet_listing(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_cancel_subnet_listing(origin, netuid)
+        }
+
+        /// Buys a listed subnet.
+        ///
+        /// The price is paid through escrow and the subnet owner and owner hotkey switch to the
+        /// buyer atomically. The owner cut accrued before the sale is paid to the seller.
+        ///
+        /// # Args:
+        /// * `origin` - The buyer's coldkey.
+        /// * `netuid` - The subnet to buy.
+        /// * `hotkey` - The buyer's hotkey to become the subnet owner hotkey.
+        /// * `max_price` - The highest price the buyer accepts.
+        #[pallet::call_index(116)]
+        #[pallet::weight((
+            Weight::from_parts(60_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(12, 10)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn buy_subnet(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+            max_price: u64,
+        ) -> DispatchResult {
+            Self::do_buy_subnet(origin, netuid, hotkey, max_price)
+        }
+
+        /// Starts the sunset of a subnet.
+        ///
+        /// Emissions and registrations stop immediately, then alpha holders have
+        /// `SubnetSunsetUnstakeWindow` blocks to unstake at the pool price. Afterwards the
+        /// remaining alpha is unstaked and the subnet storage removed in chunks across blocks.
+        ///
+        /// # Args:
+        /// * `origin` - Root.
+        /// * `netuid` - The subnet to sunset.
+        #[pallet::call_index(117)]
+        #[pallet::weight((
+            Weight::from_parts(15_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(3, 1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn start_subnet_sunset(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_start_subnet_sunset(netuid)
+        }
+
+        /// Registers a subnet funded by the crowdloan being finalized and leases it to the
+        /// contributors.
+        ///
+        /// Must be the call of a crowdloan created without a target address. The owner
+        /// emissions are staked to the contributors pro rata to their contributions for `term`
+        /// blocks, after which the crowdloan creator can take over the subnet. Meanwhile the
+        /// creator manages the subnet through a proxy of the lease coldkey.
+        ///
+        /// # Args:
+        /// * `origin` - The crowdloan creator, dispatched by the crowdloan finalization.
+        /// * `term` - The number of blocks the lease lasts.
+        #[pallet::call_index(118)]
+        #[pallet::weight((
+            Weight::from_parts(300_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(40, 60))
+                .saturating_add(T::DbWeight::get().reads_writes(
+                    u64::from(T::CrowdloanInterface::max_contributors()),
+                    u64::from(T::CrowdloanInterface::max_contributors()).saturating_mul(3),
+                )),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn register_leased_network(origin: OriginFor<T>, term: u64) -> DispatchResult {
+            Self::do_register_leased_network(origin, term)
+        }
+
+        /// Ends a subnet lease and hands the subnet over to the beneficiary.
+        ///
+        /// # Args:
+        /// * `origin` - The lease beneficiary.
+        /// * `lease_id` - The lease to end.
+        /// * `hotkey` - The beneficiary's hotkey to become the subnet owner hotkey.
+        #[pallet::call_index(119)]
+        #[pallet::weight((
+            Weight::from_parts(50_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(6, 10))
+                .saturating_add(T::DbWeight::get().writes(
+                    u64::from(T::CrowdloanInterface::max_contributors()),
+                )),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn terminate_subnet_lease(
+            origin: OriginFor<T>,
+            lease_id: LeaseId,
+            hotkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_terminate_subnet_lease(origin, lease_id, hotkey)
+        }
+
+        /// Registers an ERC-20 alpha token wrapping the stake of `hotkey` on `netuid`.
+        ///
+        /// The token is exposed to the EVM by the alpha token precompile.
+        ///
+        /// # Args:
+        /// * `origin` - Any signed account.
+        /// * `hotkey` - The hotkey of the wrapped position.
+        /// * `netuid` - The subnet of the wrapped position.
+        #[pallet::call_index(120)]
+        #[pallet::weight((
+            Weight::from_parts(20_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(4, 3)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn register_alpha_token(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: u16,
+        ) -> DispatchResult {
+            Self::do_register_alpha_token(origin, hotkey, netuid)
+        }
+
+        /// Allows `spender` to move up to `amount` alpha of the caller's stake wrapped by
+        /// `token_id`, replacing any previous allowance.
+        ///
+        /// # Args:
+        /// * `origin` - The coldkey owning the stake.
+        /// * `token_id` - The alpha token.
+        /// * `spender` - The account allowed to move the stake.
+        /// * `amount` - The allowed amount of alpha.
+        #[pallet::call_index(121)]
+        #[pallet::weight((
+            Weight::from_parts(10_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn approve_alpha(
+            origin: OriginFor<T>,
+            token_id: u32,
+            spender: T::AccountId,
+            amount: u64,
+        ) -> DispatchResult {
+            Self::do_approve_alpha(origin, token_id, spender, amount)
+        }
+
+        /// Transfers `amount` alpha of `owner`'s stake wrapped by `token_id` to
+        /// `destination_coldkey`, spending the caller's allowance.
+        ///
+        /// # Args:
+        /// * `origin` - The spender.
+        /// * `token_id` - The alpha token.
+        /// * `owner` - The coldkey the stake is taken from.
+        /// * `destination_coldkey` - The coldkey receiving the stake.
+        /// * `amount` - The amount of alpha to transfer.
+        #[pallet::call_index(122)]
+        #[pallet::weight((
+            Weight::from_parts(220_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(18))
+                .saturating_add(T::DbWeight::get().writes(14)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn transfer_alpha_from(
+            origin: OriginFor<T>,
+            token_id: u32,
+            owner: T::AccountId,
+            destination_coldkey: T::AccountId,
+            amount: u64,
+        ) -> DispatchResult {
+            Self::do_transfer_alpha_from(origin, token_id, owner, destination_coldkey, amount)
+        }
+
+        /// Schedules the children of a hotkey under terms the children have to accept.
+        ///
+        /// # Args:
+        /// * `origin` - The coldkey owning the hotkey.
+        /// * `hotkey` - The parent hotkey.
+        /// * `netuid` - The subnet of the relationships.
+        /// * `children` - The children and their u64 normalized proportions.
+        /// * `terms` - The term of the relationships and whether they auto-renew.
+        #[pallet::call_index(123)]
+        #[pallet::weight((Weight::from_parts(119_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().writes(33)), DispatchClass::Operational, Pays::Yes))]
+        pub fn set_children_with_terms(
+            origin: T::RuntimeOrigin,
+            hotkey: T::AccountId,
+            netuid: u16,
+            children: Vec<(u64, T::AccountId)>,
+            terms: ChildKeyTerms,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_schedule_children_with_terms(origin, hotkey, netuid, children, terms)?;
+            Ok(().into())
+        }
+
+        /// Accepts the pending children schedule of `parent` on behalf of `child`.
+        ///
+        /// # Args:
+        /// * `origin` - The coldkey owning the child.
+        /// * `child` - The child hotkey.
+        /// * `parent` - The parent hotkey.
+        /// * `netuid` - The subnet of the relationship.
+        #[pallet::call_index(124)]
+        #[pallet::weight((
+            Weight::from_parts(15_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(2, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn accept_childkey(
+            origin: OriginFor<T>,
+            child: T::AccountId,
+            parent: T::AccountId,
+            netuid: u16,
+        ) -> DispatchResult {
+            Self::do_accept_childkey(origin, child, parent, netuid)
+        }
+
+        /// Ends the relationship of `child` with `parent`.
+        ///
+        /// # Args:
+        /// * `origin` - The coldkey owning the child.
+        /// * `child` - The child hotkey.
+        /// * `parent` - The parent hotkey.
+        /// * `netuid` - The subnet of the relationship.
+        #[pallet::call_index(125)]
+        #[pallet::weight((
+            Weight::from_parts(20_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(4, 4)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn resign_childkey(
+            origin: OriginFor<T>,
+            child: T::AccountId,
+            parent: T::AccountId,
+            netuid: u16,
+        ) -> DispatchResult {
+            Self::do_resign_childkey(origin, child, parent, netuid)
+        }
+
+        /// Sets the guardians able to recover the coldkey of the caller.
+        ///
+        /// # Args:
+        /// * `origin` - The guarded coldkey.
+        /// * `guardians` - The guardians, an empty list removes them.
+        /// * `threshold` - The number of guardians required to recover the coldkey.
+        #[pallet::call_index(127)]
+        #[pallet::weight((
+            Weight::from_parts(15_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(0, 2)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn set_coldkey_guardians(
+            origin: OriginFor<T>,
+            guardians: Vec<T::AccountId>,
+            threshold: u16,
+        ) -> DispatchResult {
+            Self::do_set_coldkey_guardians(origin, guardians, threshold)
+        }
+
+        /// Approves, as a guardian, the recovery of `coldkey` to `new_coldkey`. The swap is
+        /// scheduled once the threshold of guardians approved the same new coldkey.
+        ///
+        /// # Args:
+        /// * `origin` - A guardian of the coldkey.
+        /// * `coldkey` - The coldkey to recover.
+        /// * `new_coldkey` - The coldkey receiving the recovered account.
+        #[pallet::call_index(128)]
+        #[pallet::weight((
+            Weight::from_parts(30_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(5, 3)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn approve_coldkey_recovery(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            new_coldkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_approve_coldkey_recovery(origin, coldkey, new_coldkey)
+        }
+
+        /// Cancels the pending recovery of the coldkey of the caller.
+        #[pallet::call_index(129)]
+        #[pallet::weight((
+            Weight::from_parts(10_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn cancel_coldkey_recovery(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_cancel_coldkey_recovery(origin)
+        }
+
+        /// Executes the scheduled recovery of `coldkey` once its delay has passed. Dispatched
+        /// by the scheduler, and callable by anyone if the scheduled execution failed.
+        ///
+        /// # Args:
+        /// * `origin` - Root or any signed account.
+        /// * `coldkey` - The coldkey to recover.
+        #[pallet::call_index(130)]
+        #[pallet::weight((Weight::from_parts(179_500_000, 0)
+        .saturating_add(T::DbWeight::get().reads(17))
+        .saturating_add(T::DbWeight::get().writes(13)), DispatchClass::Operational, Pays::Yes))]
+        pub fn execute_coldkey_recovery(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed_or_root(origin)?;
+            Self::do_execute_coldkey_recovery(&coldkey)
+        }
+
+        /// Cancels the scheduled swap of the coldkey of the caller.
+        ///
+        /// # Args:
+        /// * `origin` - The coldkey with a scheduled swap.
+        #[pallet::call_index(131)]
+        #[pallet::weight((
+            Weight::from_parts(10_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(2, 1)),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn cancel_coldkey_swap(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_cancel_coldkey_swap(origin)
+        }
+
+        /// Contests the scheduled swap of the coldkey of the caller. The swap is frozen and the
+        /// swap cost is held in escrow until governance decides it.
+        ///
+        /// # Args:
+        /// * `origin` - The coldkey with a scheduled swap.
+        #[pallet::call_index(132)]
+        #[pallet::weight((
+            Weight::from_parts(20_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(4, 2)),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn contest_coldkey_swap(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_contest_coldkey_swap(origin)
+        }
+
+        /// Decides a contested coldkey swap. Root only.
+        ///
+        /// # Args:
+        /// * `origin` - Root.
+        /// * `coldkey` - The coldkey with a contested swap.
+        /// * `approve` - Executes the swap if true, otherwise cancels it and refunds the escrow.
+        #[pallet::call_index(133)]
+        #[pallet::weight((Weight::from_parts(179_500_000, 0)
+        .saturating_add(T::DbWeight::get().reads(16))
+        .saturating_add(T::DbWeight::get().writes(11)), DispatchClass::Operational, Pays::No))]
+        pub fn resolve_coldkey_swap_contest(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            approve: bool,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            Self::do_resolve_coldkey_swap_contest(&coldkey, approve)
+        }
+
+        /// Transfers `amount` alpha of the caller's stake wrapped by `token_id` to
+        /// `destination_coldkey`. The alpha is moved without going through the pool, so no
+        /// staking fee is charged.
+        ///
+        /// # Args:
+        /// * `origin` - The coldkey owning the stake.
+        /// * `token_id` - The alpha token.
+        /// * `destination_coldkey` - The coldkey receiving the stake.
+        /// * `amount` - The amount of alpha to transfer.
+        #[pallet::call_index(134)]
+        #[pallet::weight((
+            Weight::from_parts(120_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(14))
+                .saturating_add(T::DbWeight::get().writes(9)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn transfer_alpha(
+            origin: OriginFor<T>,
+            token_id: u32,
+            destination_coldkey: T::AccountId,
+            amount: u64,
+        ) -> DispatchResult {
+            Self::do_transfer_alpha(origin, token_id, destination_coldkey, amount)
+        }
+
+        /// Associates a contract with a hotkey without a signature, letting the contract act as
+        /// the hotkey through the neuron precompile.
+        ///
+        /// # Args:
+        /// * `origin` - The coldkey owning the `hotkey`.
+        /// * `netuid` - The netuid that the `hotkey` belongs to.
+        /// * `hotkey` - The hotkey the contract may act as.
+        /// * `contract` - The contract address.
+        ///
+        /// # Errors
+        /// Returns an error if:
+        /// * The transaction is not signed.
+        /// * The hotkey is not owned by the origin coldkey.
+        /// * The hotkey does not belong to the subnet identified by the netuid.
+        ///
+        /// # Events
+        /// Emits a `EvmContractAssociated` event on success
+        #[pallet::call_index(135)]
+        #[pallet::weight((
+            Weight::from_parts(3_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(2, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn associate_evm_contract(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+            contract: H160,
+        ) -> DispatchResult {
+            Self::do_associate_evm_contract(origin, netuid, hotkey, contract)
+        }
+
+        /// Withdraws the approval of the caller from the pending recovery of `coldkey`.
+        ///
+        /// # Args:
+        /// * `origin` - A guardian who approved the recovery.
+        /// * `coldkey` - The coldkey being recovered.
+        #[pallet::call_index(136)]
+        #[pallet::weight((
+            Weight::from_parts(30_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(5, 3)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn withdraw_coldkey_recovery_approval(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_withdraw_coldkey_recovery_approval(origin, coldkey)
+        }
+
+        /// Executes a swap scheduled by `schedule_swap_coldkey`. Dispatched by the scheduler,
+        /// it fails if the swap was cancelled, rescheduled or contested in the meantime.
+        ///
+        /// # Args:
+        /// * `origin` - Root.
+        /// * `old_coldkey` - The coldkey with a scheduled swap.
+        /// * `new_coldkey` - The coldkey the swap was scheduled to.
+        /// * `swap_cost` - The swap cost charged to the old coldkey.
+        #[pallet::call_index(137)]
+        #[pallet::weight((Weight::from_parts(179_500_000, 0)
+        .saturating_add(T::DbWeight::get().reads(16))
+        .saturating_add(T::DbWeight::get().writes(9)), DispatchClass::Operational, Pays::No))]
+        pub fn execute_scheduled_coldkey_swap(
+            origin: OriginFor<T>,
+            old_coldkey: T::AccountId,
+            new_coldkey: T::AccountId,
+            swap_cost: u64,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            // The swap may have been cancelled, rescheduled or contested since it was scheduled.
+            Self::ensure_scheduled_coldkey_swap(&old_coldkey, &new_coldkey)?;
+
+            Self::do_swap_coldkey(&old_coldkey, &new_coldkey, swap_cost)
+        }
+
+        /// ---- Used to commit encrypted commit-reveal v3 weight values, timelock-encrypted to a
+        /// round of an additional drand beacon, to later be revealed.
+        ///
+        /// # Args:
+        /// * `origin`: (`<T as frame_system::Config>::RuntimeOrigin`):
+        ///   - The committing hotkey.
+        ///
+        /// * `netuid` (`u16`):
+        ///   - The u16 network identifier.
+        ///
+        /// * `commit` (`Vec<u8>`):
+        ///   - The encrypted compressed commit, as for `commit_crv3_weights` but with the
+        ///     ciphertext type of the beacon curve, e.g. `TLECiphertext<ZBLS>` for the beacons
+        ///     signing in G2.
+        ///
+        /// * `beacon_id` (`u16`):
+        ///   - The drand beacon the commit is encrypted to.
+        ///
+        /// * reveal_round (`u64`):
+        ///    - The round of the beacon which will be avaliable during epoch `n+1` from the
+        ///      current epoch.
+        ///
+        /// # Raises:
+        /// * `CommitRevealV3Disabled`:
+        ///   - Attempting to commit when the commit-reveal mechanism is disabled.
+        ///
+        /// * `UnknownDrandBeacon`:
+        ///   - The beacon is not bridged.
+        ///
+        /// * `TooManyUnrevealedCommits`:
+        ///   - Attempting to commit when the user has more than the allowed limit of unrevealed commits.
+        ///
+        #[pallet::call_index(138)]
+        #[pallet::weight((Weight::from_parts(73_720_000, 0)
+		.saturating_add(T::DbWeight::get().reads(7))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn commit_crv3_weights_with_beacon(
+            origin: T::RuntimeOrigin,
+            netuid: u16,
+            commit: BoundedVec<u8, ConstU32<MAX_CRV3_COMMIT_SIZE_BYTES>>,
+            beacon_id: BeaconId,
+            reveal_round: u64,
+        ) -> DispatchResult {
+            Self::do_commit_crv3_weights(origin, netuid, commit, beacon_id, reveal_round)
+        }
+
         // /// --- Adds stake to a hotkey on a subnet with a price limit.
         // /// This extrinsic allows to specify the limit price for alpha token
         // /// at which or better (lower) the staking should execute.
@@ -216,5 +216,75 @@ mod errors {
         ZeroMaxStakeAmount,
         /// Invalid netuid duplication
         SameNetuid,
+        /// The hyperparameter value is outside of its allowed bounds.
+        HyperparameterOutOfBounds,
+        /// There is no pending subnet ownership offer for the caller.
+        NoSubnetOwnershipOffer,
+        /// Co-owners must be between two and the maximum number of distinct accounts.
+        InvalidSubnetCoOwners,
+        /// The co-owner threshold must be between one and the number of co-owners.
+        InvalidSubnetCoOwnerThreshold,
+        /// The subnet is not listed for sale.
+        SubnetListingNotFound,
+        /// The subnet sale listing deadline has passed.
+        SubnetListingExpired,
+        /// The subnet sale price is above the buyer's limit.
+        SubnetPriceAboveLimit,
+        /// Not enough balance to pay the subnet sale price.
+        NotEnoughBalanceToBuySubnet,
+        /// The subnet is being sunset.
+        SubnetSunsetting,
+        /// The call must be dispatched by the crowdloan being finalized.
+        NotCalledFromCrowdloan,
+        /// The caller is not the beneficiary of the subnet lease.
+        NotSubnetLeaseBeneficiary,
+        /// The subnet lease does not exist.
+        SubnetLeaseDoesNotExist,
+        /// The subnet lease has not ended yet.
+        SubnetLeaseNotEnded,
+        /// The owner emissions of the subnet lease have not all been distributed yet.
+        SubnetLeaseDividendsPending,
+        /// The alpha token does not exist.
+        AlphaTokenNotFound,
+        /// An alpha token is already registered for this hotkey and subnet.
+        AlphaTokenAlreadyRegistered,
+        /// Alpha tokens cannot wrap root subnet stake.
+        AlphaTokenOnRootSubnet,
+        /// No alpha token ids are left.
+        AlphaTokenIdsExhausted,
+        /// The spender's alpha allowance is lower than the transferred amount.
+        InsufficientAlphaAllowance,
+        /// The EVM key is not associated with the hotkey on this subnet.
+        EvmKeyNotAssociated,
+        /// The child-key terms are invalid.
+        InvalidChildKeyTerms,
+        /// The child is not in the pending children of the parent.
+        NoPendingChildKey,
+        /// The hotkey is not a child of the parent.
+        NotAChildOfParent,
+        /// The child is an ancestor of the parent.
+        ChildKeyCycle,
+        /// The child-key depth is out of bounds.
+        InvalidChildKeyDepth,
+        /// The guardians or threshold of the coldkey are invalid.
+        InvalidColdkeyGuardians,
+        /// The caller is not a guardian of the coldkey.
+        NotColdkeyGuardian,
+        /// The coldkey has no pending recovery.
+        NoPendingColdkeyRecovery,
+        /// The guardian has not approved the recovery.
+        ColdkeyRecoveryNotApproved,
+        /// The guardian already approved the recovery.
+        ColdkeyRecoveryAlreadyApproved,
+        /// The recovery is not due yet.
+        ColdkeyRecoveryNotDue,
+        /// The coldkey has no scheduled swap matching the call.
+        ColdkeySwapNotScheduled,
+        /// The scheduled coldkey swap is contested and awaits a governance decision.
+        ColdkeySwapContested,
+        /// The scheduled coldkey swap is not contested.
+        ColdkeySwapNotContested,
+        /// The drand beacon is not bridged.
+        UnknownDrandBeacon,
     }
 }
@@ -334,6 +334,16 @@ mod events {
             block_associated: u64,
         },
 
+        /// A contract has been associated with a hotkey by its coldkey.
+        EvmContractAssociated {
+            /// The subnet that the hotkey belongs to.
+            netuid: u16,
+            /// The hotkey the contract may act as.
+            hotkey: T::AccountId,
+            /// The contract address.
+            contract: H160,
+        },
+
         /// CRV3 Weights have been successfully revealed.
         ///
         /// - **netuid**: The network identifier.
@@ -363,5 +373,309 @@ mod events {
             /// the subnet ID
             netuid: u16,
         },
+
+        /// The ownership of a subnet has been offered to a new owner.
+        SubnetOwnershipOffered {
+            /// The subnet ID
+            netuid: u16,
+            /// The account that may accept the ownership
+            new_owner: T::AccountId,
+        },
+
+        /// A pending subnet ownership offer has been cancelled.
+        SubnetOwnershipOfferCancelled {
+            /// The subnet ID
+            netuid: u16,
+        },
+
+        /// The ownership of a subnet has been transferred.
+        SubnetOwnershipTransferred {
+            /// The subnet ID
+            netuid: u16,
+            /// The previous owner
+            old_owner: T::AccountId,
+            /// The new owner
+            new_owner: T::AccountId,
+        },
+
+        /// A subnet is now owned by a set of co-owners.
+        SubnetCoOwnersSet {
+            /// The subnet ID
+            netuid: u16,
+            /// The co-owners of the subnet
+            co_owners: Vec<T::AccountId>,
+            /// The number of co-owners required to act as the owner
+            threshold: u16,
+        },
+
+        /// A subnet has been listed for sale.
+        SubnetListedForSale {
+            /// The subnet ID
+            netuid: u16,
+            /// The subnet owner selling the subnet
+            seller: T::AccountId,
+            /// The price in RAO
+            price: u64,
+            /// The last block at which the subnet can be bought, if any
+            deadline: Option<u64>,
+        },
+
+        /// A subnet sale listing has been cancelled.
+        SubnetListingCancelled {
+            /// The subnet ID
+            netuid: u16,
+        },
+
+        /// A listed subnet has been sold.
+        SubnetSold {
+            /// The subnet ID
+            netuid: u16,
+            /// The previous owner
+            seller: T::AccountId,
+            /// The new owner
+            buyer: T::AccountId,
+            /// The new subnet owner hotkey
+            hotkey: T::AccountId,
+            /// The price paid in RAO
+            price: u64,
+        },
+
+        /// A subnet sunset has started: emissions and registrations are stopped.
+        SubnetSunsetStarted {
+            /// The subnet ID
+            netuid: u16,
+            /// The last block at which alpha holders can unstake at the pool price
+            unstake_window_ends: u64,
+        },
+
+        /// The unstake window of a sunsetting subnet has closed and the remaining alpha is being unstaked.
+        SubnetUnstakeWindowClosed {
+            /// The subnet ID
+            netuid: u16,
+        },
+
+        /// All alpha of a sunsetting subnet has been unstaked and its storage is being removed.
+        SubnetLiquidated {
+            /// The subnet ID
+            netuid: u16,
+        },
+
+        /// A subnet sunset has completed and the subnet has been removed.
+        SubnetSunsetCompleted {
+            /// The subnet ID
+            netuid: u16,
+        },
+
+        /// A crowdloan has registered a subnet leased to its contributors.
+        SubnetLeaseCreated {
+            /// The lease ID
+            lease_id: LeaseId,
+            /// The crowdloan that funded the subnet
+            crowdloan_id: u32,
+            /// The subnet ID
+            netuid: u16,
+            /// The account receiving the subnet when the lease ends
+            beneficiary: T::AccountId,
+            /// The block at which the lease ends
+            end_block: u64,
+        },
+
+        /// The owner emissions of a leased subnet have been distributed to the contributors.
+        SubnetLeaseDividendsDistributed {
+            /// The lease ID
+            lease_id: LeaseId,
+            /// The distributed alpha
+            alpha: u64,
+        },
+
+        /// A subnet lease has ended and the ownership has passed to the beneficiary.
+        SubnetLeaseTerminated {
+            /// The lease ID
+            lease_id: LeaseId,
+            /// The subnet ID
+            netuid: u16,
+            /// The new subnet owner
+            beneficiary: T::AccountId,
+        },
+
+        /// An ERC-20 alpha token has been registered for a stake position.
+        AlphaTokenRegistered {
+            /// The token ID
+            token_id: u32,
+            /// The hotkey of the wrapped position
+            hotkey: T::AccountId,
+            /// The subnet of the wrapped position
+            netuid: u16,
+        },
+
+        /// An alpha allowance has been set.
+        AlphaAllowanceSet {
+            /// The token ID
+            token_id: u32,
+            /// The coldkey owning the stake
+            owner: T::AccountId,
+            /// The account allowed to move the stake
+            spender: T::AccountId,
+            /// The allowed amount of alpha
+            amount: u64,
+        },
+
+        /// Alpha has been moved between two coldkeys on an alpha token position.
+        AlphaTransferred {
+            /// The token ID
+            token_id: u32,
+            /// The coldkey the stake was taken from
+            from: T::AccountId,
+            /// The coldkey receiving the stake
+            to: T::AccountId,
+            /// The amount of alpha received
+            amount: u64,
+        },
+
+        /// The terms of the pending children of a parent have been set.
+        ChildKeyTermsSet {
+            /// The parent hotkey
+            parent: T::AccountId,
+            /// The subnet ID
+            netuid: u16,
+            /// The proposed terms
+            terms: ChildKeyTerms,
+        },
+
+        /// A child hotkey has accepted the pending schedule of a parent.
+        ChildKeyAccepted {
+            /// The parent hotkey
+            parent: T::AccountId,
+            /// The child hotkey
+            child: T::AccountId,
+            /// The subnet ID
+            netuid: u16,
+        },
+
+        /// A child hotkey has left its parent.
+        ChildKeyResigned {
+            /// The parent hotkey
+            parent: T::AccountId,
+            /// The child hotkey
+            child: T::AccountId,
+            /// The subnet ID
+            netuid: u16,
+        },
+
+        /// A child-key relationship has reached the end of its term.
+        ChildKeyExpired {
+            /// The parent hotkey
+            parent: T::AccountId,
+            /// The child hotkey
+            child: T::AccountId,
+            /// The subnet ID
+            netuid: u16,
+        },
+
+        /// A child-key relationship has been renewed for another term.
+        ChildKeyRenewed {
+            /// The parent hotkey
+            parent: T::AccountId,
+            /// The child hotkey
+            child: T::AccountId,
+            /// The subnet ID
+            netuid: u16,
+            /// The block at which the new term ends
+            expires_at: u64,
+        },
+
+        /// The guardians of a coldkey have been set.
+        ColdkeyGuardiansSet {
+            /// The guarded coldkey
+            coldkey: T::AccountId,
+            /// The guardians, empty if they were removed
+            guardians: Vec<T::AccountId>,
+            /// The number of guardians required to recover the coldkey
+            threshold: u16,
+        },
+
+        /// A guardian has approved the recovery of a coldkey.
+        ColdkeyRecoveryApproved {
+            /// The coldkey being recovered
+            coldkey: T::AccountId,
+            /// The approving guardian
+            guardian: T::AccountId,
+            /// The coldkey receiving the recovered account
+            new_coldkey: T::AccountId,
+        },
+
+        /// The guardians of a coldkey have reached the threshold and scheduled its swap.
+        ColdkeyRecoveryScheduled {
+            /// The coldkey being recovered
+            coldkey: T::AccountId,
+            /// The coldkey receiving the recovered account
+            new_coldkey: T::AccountId,
+            /// The block at which the swap executes
+            execution_block: BlockNumberFor<T>,
+        },
+
+        /// A guardian has withdrawn its approval of the recovery of a coldkey.
+        ColdkeyRecoveryApprovalWithdrawn {
+            /// The coldkey being recovered
+            coldkey: T::AccountId,
+            /// The withdrawing guardian
+            guardian: T::AccountId,
+        },
+
+        /// The scheduled recovery of a coldkey has been dropped, because its guardians no longer
+        /// reach the threshold or the new coldkey has become a hotkey.
+        ColdkeyRecoveryUnscheduled {
+            /// The coldkey being recovered
+            coldkey: T::AccountId,
+            /// The coldkey the account was to be recovered to
+            new_coldkey: T::AccountId,
+        },
+
+        /// The recovery of a coldkey has been cancelled by the coldkey.
+        ColdkeyRecoveryCancelled {
+            /// The coldkey
+            coldkey: T::AccountId,
+        },
+
+        /// A coldkey has been recovered by its guardians.
+        ColdkeyRecovered {
+            /// The recovered coldkey
+            old_coldkey: T::AccountId,
+            /// The coldkey receiving the recovered account
+            new_coldkey: T::AccountId,
+            /// The swap cost
+            swap_cost: u64,
+        },
+
+        /// A scheduled coldkey swap has been cancelled by the coldkey.
+        ColdkeySwapCancelled {
+            /// The account ID of the old coldkey
+            old_coldkey: T::AccountId,
+            /// The account ID of the new coldkey
+            new_coldkey: T::AccountId,
+        },
+
+        /// A scheduled coldkey swap has been contested and frozen until a governance decision.
+        ColdkeySwapContested {
+            /// The account ID of the old coldkey
+            old_coldkey: T::AccountId,
+            /// The account ID of the new coldkey
+            new_coldkey: T::AccountId,
+            /// The swap cost held in escrow
+            escrow: u64,
+        },
+
+        /// Governance has decided a contested coldkey swap.
+        ColdkeySwapContestResolved {
+            /// The account ID of the old coldkey
+            old_coldkey: T::AccountId,
+            /// The account ID of the new coldkey
+            new_coldkey: T::AccountId,
+            /// Whether the swap was approved and executed, or rejected and the escrow refunded
+            approved: bool,
+        },
+
+        /// maximum childkey depth set
+        MaxChildKeyDepthSet(u16),
     }
 }
@@ -68,6 +68,7 @@ mod genesis {
                     (hotkey.clone(), hotkey.clone(), netuid),
                     U64F64::saturating_from_num(1_000_000_000),
                 );
+                SubnetAlphaPositions::<T>::insert((netuid, hotkey.clone(), hotkey.clone()), ());
                 TotalHotkeyAlpha::<T>::insert(hotkey.clone(), netuid, 1_000_000_000);
                 TotalHotkeyShares::<T>::insert(
                     hotkey.clone(),
@@ -16,6 +16,9 @@ mod hooks {
         // 		- The number of the block we are initializing.
         fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
             let hotkey_swap_clean_up_weight = Self::clean_up_hotkey_swap_records(block_number);
+            let subnet_sunset_weight =
+                Self::index_subnet_alpha_positions().saturating_add(Self::sunset_subnets());
+            let lease_dividends_weight = Self::distribute_accrued_lease_dividends();
 
             let block_step_result = Self::block_step();
             match block_step_result {
@@ -26,6 +29,8 @@ mod hooks {
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
                         .saturating_add(hotkey_swap_clean_up_weight)
+                        .saturating_add(subnet_sunset_weight)
+                        .saturating_add(lease_dividends_weight)
                 }
                 Err(e) => {
                     // --- If the block step was unsuccessful, return the weight anyway.
@@ -34,6 +39,8 @@ mod hooks {
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
                         .saturating_add(hotkey_swap_clean_up_weight)
+                        .saturating_add(subnet_sunset_weight)
+                        .saturating_add(lease_dividends_weight)
                 }
             }
         }
@@ -119,7 +126,15 @@ mod hooks {
                 // Reset max burn
                 .saturating_add(migrations::migrate_reset_max_burn::migrate_reset_max_burn::<T>())
                 // Migrate ColdkeySwapScheduled structure to new format
-                .saturating_add(migrations::migrate_coldkey_swap_scheduled::migrate_coldkey_swap_scheduled::<T>());
+                .saturating_add(migrations::migrate_coldkey_swap_scheduled::migrate_coldkey_swap_scheduled::<T>())
+                // Index the Alpha positions of each subnet
+                .saturating_add(migrations::migrate_populate_subnet_alpha_positions::migrate_populate_subnet_alpha_positions::<T>())
+                // Record the drand beacon of pending CRV3 commits
+                .saturating_add(migrations::migrate_crv3_commits_add_beacon_id::migrate_crv3_commits_add_beacon_id::<T>())
+                // Keep the drand pulses pending CRV3 commits are revealed with
+                .saturating_add(migrations::migrate_reference_crv3_reveal_rounds::migrate_reference_crv3_reveal_rounds::<T>())
+                // Grandfather the children schedules pending before children had to accept them
+                .saturating_add(migrations::migrate_accept_pending_child_keys::migrate_accept_pending_child_keys::<T>());
             weight
         }
 
@@ -0,0 +1,64 @@
+use super::*;
+use frame_support::{traits::Get, weights::Weight};
+use log;
+use scale_info::prelude::string::String;
+
+/// Grandfathers the children schedules pending at upgrade, which were scheduled before children
+/// had to accept them, by recording the acceptance of every pending child.
+pub fn migrate_accept_pending_child_keys<T: Config>() -> Weight {
+    let migration_name = b"migrate_accept_pending_child_keys".to_vec();
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // ------------------------------
+    // Step 0: Check if already run
+    // ------------------------------
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // ------------------------------
+    // Step 1: Accept every pending child on its behalf
+    // ------------------------------
+
+    let mut schedules_count = 0u64;
+    let mut accepted_count = 0u64;
+
+    for (netuid, parent, (children, _)) in PendingChildKeys::<T>::iter() {
+        schedules_count = schedules_count.saturating_add(1);
+        for (_, child) in children {
+            ChildKeyAcceptances::<T>::insert((netuid, &parent, child), ());
+            accepted_count = accepted_count.saturating_add(1);
+        }
+    }
+
+    weight =
+        weight.saturating_add(T::DbWeight::get().reads_writes(schedules_count, accepted_count));
+
+    log::info!(
+        "Accepted {} children of {} pending schedules.",
+        accepted_count,
+        schedules_count
+    );
+
+    // ------------------------------
+    // Step 2: Mark Migration as Completed
+    // ------------------------------
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed successfully.",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    weight
+}
@@ -0,0 +1,74 @@
+use super::*;
+use frame_support::{storage::StoragePrefixedMap, traits::Get, weights::Weight};
+use log;
+use pallet_drand::types::{DEFAULT_BEACON_ID, RoundNumber};
+use scale_info::prelude::string::String;
+use sp_core::ConstU32;
+use sp_runtime::BoundedVec;
+use sp_std::collections::vec_deque::VecDeque;
+
+/// The CRV3 commits, before they recorded the drand beacon they are encrypted to
+type OldCRV3Commits<T> = VecDeque<(
+    <T as frame_system::Config>::AccountId,
+    BoundedVec<u8, ConstU32<MAX_CRV3_COMMIT_SIZE_BYTES>>,
+    RoundNumber,
+)>;
+
+pub fn migrate_crv3_commits_add_beacon_id<T: Config>() -> Weight {
+    let migration_name = b"migrate_crv3_commits_add_beacon_id".to_vec();
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // ------------------------------
+    // Step 0: Check if already run
+    // ------------------------------
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // ------------------------------
+    // Step 1: Record the default beacon on every pending CRV3 commit
+    // ------------------------------
+
+    let mut translated_entries_count = 0u64;
+
+    CRV3WeightCommits::<T>::translate_values::<OldCRV3Commits<T>, _>(|commits| {
+        translated_entries_count = translated_entries_count.saturating_add(1);
+        Some(
+            commits
+                .into_iter()
+                .map(|(who, commit, reveal_round)| (who, commit, DEFAULT_BEACON_ID, reveal_round))
+                .collect(),
+        )
+    });
+
+    weight = weight.saturating_add(
+        T::DbWeight::get().reads_writes(translated_entries_count, translated_entries_count),
+    );
+
+    log::info!(
+        "Recorded the default beacon on {} CRV3 commit queues.",
+        translated_entries_count
+    );
+
+    // ------------------------------
+    // Step 2: Mark Migration as Completed
+    // ------------------------------
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed successfully.",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    weight
+}
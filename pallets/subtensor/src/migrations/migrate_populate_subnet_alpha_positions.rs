@@ -0,0 +1,48 @@
+use super::*;
+use frame_support::{storage::StoragePrefixedMap, traits::Get, weights::Weight};
+use log;
+use scale_info::prelude::string::String;
+
+/// Starts indexing the Alpha positions of each subnet.
+///
+/// `Alpha` is too large to walk in a single block, so this only points the index cursor at the
+/// start of the map. `index_subnet_alpha_positions` then indexes it in chunks on each block.
+pub fn migrate_populate_subnet_alpha_positions<T: Config>() -> Weight {
+    let migration_name = b"migrate_populate_subnet_alpha_positions".to_vec();
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // ------------------------------
+    // Step 0: Check if already run
+    // ------------------------------
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // ------------------------------
+    // Step 1: Start the index backfill from the first Alpha entry
+    // ------------------------------
+    SubnetAlphaPositionsIndexCursor::<T>::put(Alpha::<T>::final_prefix().to_vec());
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    // ------------------------------
+    // Step 2: Mark Migration as Completed
+    // ------------------------------
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed successfully.",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    weight
+}
@@ -0,0 +1,63 @@
+use super::*;
+use frame_support::{traits::Get, weights::Weight};
+use log;
+use scale_info::prelude::string::String;
+
+pub fn migrate_reference_crv3_reveal_rounds<T: Config>() -> Weight {
+    let migration_name = b"migrate_reference_crv3_reveal_rounds".to_vec();
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // ------------------------------
+    // Step 0: Check if already run
+    // ------------------------------
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // ------------------------------
+    // Step 1: Reference the drand round of every pending CRV3 commit
+    // ------------------------------
+
+    let mut read_entries_count = 0u64;
+    let mut referenced_rounds_count = 0u64;
+
+    for commits in CRV3WeightCommits::<T>::iter_values() {
+        read_entries_count = read_entries_count.saturating_add(1);
+        for (_, _, beacon_id, reveal_round) in commits {
+            pallet_drand::Pallet::<T>::reference_round(beacon_id, reveal_round);
+            referenced_rounds_count = referenced_rounds_count.saturating_add(1);
+        }
+    }
+
+    weight = weight.saturating_add(T::DbWeight::get().reads(read_entries_count));
+    weight = weight.saturating_add(
+        T::DbWeight::get().reads_writes(referenced_rounds_count, referenced_rounds_count),
+    );
+
+    log::info!(
+        "Referenced {} drand rounds of pending CRV3 commits.",
+        referenced_rounds_count
+    );
+
+    // ------------------------------
+    // Step 2: Mark Migration as Completed
+    // ------------------------------
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed successfully.",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    weight
+}
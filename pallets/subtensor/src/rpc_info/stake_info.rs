@@ -4,18 +4,18 @@ extern crate alloc;
 use codec::Compact;
 use substrate_fixed::types::U96F32;
 
-#[freeze_struct("5cfb3c84c3af3116")]
+#[freeze_struct("f51b002669e36be7")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct StakeInfo<AccountId: TypeInfo + Encode + Decode> {
-    hotkey: AccountId,
-    coldkey: AccountId,
-    netuid: Compact<u16>,
-    stake: Compact<u64>,
-    locked: Compact<u64>,
-    emission: Compact<u64>,
-    tao_emission: Compact<u64>,
-    drain: Compact<u64>,
-    is_registered: bool,
+    pub hotkey: AccountId,
+    pub coldkey: AccountId,
+    pub netuid: Compact<u16>,
+    pub stake: Compact<u64>,
+    pub locked: Compact<u64>,
+    pub emission: Compact<u64>,
+    pub tao_emission: Compact<u64>,
+    pub drain: Compact<u64>,
+    pub is_registered: bool,
 }
 
 impl<T: Config> Pallet<T> {
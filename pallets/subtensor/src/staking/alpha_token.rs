@@ -0,0 +1,264 @@
+use super::*;
+use crate::{Error, system::ensure_signed};
+
+impl<T: Config> Pallet<T> {
+    /// Registers an ERC-20 style alpha token wrapping the `(hotkey, netuid)` stake position.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - Any signed origin; registration is permissionless.
+    /// * `hotkey` - The hotkey whose alpha is wrapped.
+    /// * `netuid` - The subnet of the wrapped alpha.
+    ///
+    /// # Returns
+    ///
+    /// * `DispatchResult` - Success or error
+    pub(crate) fn do_register_alpha_token(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: u16,
+    ) -> DispatchResult {
+        ensure_signed(origin)?;
+
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::AlphaTokenOnRootSubnet
+        );
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(
+            !AlphaTokenIds::<T>::contains_key(&hotkey, netuid),
+            Error::<T>::AlphaTokenAlreadyRegistered
+        );
+
+        let token_id = NextAlphaTokenId::<T>::get();
+        NextAlphaTokenId::<T>::put(
+            token_id
+                .checked_add(1)
+                .ok_or(Error::<T>::AlphaTokenIdsExhausted)?,
+        );
+        AlphaTokens::<T>::insert(token_id, (hotkey.clone(), netuid));
+        AlphaTokenIds::<T>::insert(&hotkey, netuid, token_id);
+
+        log::debug!(
+            "AlphaTokenRegistered( token_id:{:?}, hotkey:{:?}, netuid:{:?} )",
+            token_id,
+            hotkey,
+            netuid
+        );
+        Self::deposit_event(Event::AlphaTokenRegistered {
+            token_id,
+            hotkey,
+            netuid,
+        });
+
+        Ok(())
+    }
+
+    /// Sets the amount of alpha `spender` may move out of the caller's position on `token_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The coldkey owning the stake.
+    /// * `token_id` - The alpha token.
+    /// * `spender` - The account allowed to move the stake.
+    /// * `amount` - The new allowance, replacing any previous one.
+    ///
+    /// # Returns
+    ///
+    /// * `DispatchResult` - Success or error
+    pub(crate) fn do_approve_alpha(
+        origin: T::RuntimeOrigin,
+        token_id: u32,
+        spender: T::AccountId,
+        amount: u64,
+    ) -> DispatchResult {
+        let owner = ensure_signed(origin)?;
+
+        ensure!(
+            AlphaTokens::<T>::contains_key(token_id),
+            Error::<T>::AlphaTokenNotFound
+        );
+
+        if amount == 0 {
+            AlphaAllowances::<T>::remove((token_id, &owner, &spender));
+        } else {
+            AlphaAllowances::<T>::insert((token_id, &owner, &spender), amount);
+        }
+
+        Self::deposit_event(Event::AlphaAllowanceSet {
+            token_id,
+            owner,
+            spender,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Moves `amount` alpha of the `token_id` position from `owner` to `destination_coldkey`,
+    /// spending the caller's allowance. The alpha moves like with `transfer_alpha`, without going
+    /// through the pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The spender.
+    /// * `token_id` - The alpha token.
+    /// * `owner` - The coldkey the stake is taken from.
+    /// * `destination_coldkey` - The coldkey receiving the stake.
+    /// * `amount` - The amount of alpha to move.
+    ///
+    /// # Returns
+    ///
+    /// * `DispatchResult` - Success or error
+    pub(crate) fn do_transfer_alpha_from(
+        origin: T::RuntimeOrigin,
+        token_id: u32,
+        owner: T::AccountId,
+        destination_coldkey: T::AccountId,
+        amount: u64,
+    ) -> DispatchResult {
+        let spender = ensure_signed(origin)?;
+
+        let (hotkey, netuid) =
+            AlphaTokens::<T>::get(token_id).ok_or(Error::<T>::AlphaTokenNotFound)?;
+
+        let allowance = AlphaAllowances::<T>::get((token_id, &owner, &spender));
+        let remaining = allowance
+            .checked_sub(amount)
+            .ok_or(Error::<T>::InsufficientAlphaAllowance)?;
+
+        if remaining == 0 {
+            AlphaAllowances::<T>::remove((token_id, &owner, &spender));
+        } else {
+            AlphaAllowances::<T>::insert((token_id, &owner, &spender), remaining);
+        }
+
+        Self::transfer_alpha_position(
+            token_id,
+            &owner,
+            &destination_coldkey,
+            &hotkey,
+            netuid,
+            amount,
+        )
+    }
+
+    /// Moves `amount` alpha of the caller's `token_id` position to `destination_coldkey`.
+    ///
+    /// Unlike `transfer_stake`, the alpha is moved between the two coldkeys directly instead of
+    /// being swapped through the pool, so no staking fee is charged and the total alpha of the
+    /// hotkey is unchanged. The received amount may still be lower than `amount` by the share
+    /// pool rounding.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The coldkey owning the stake.
+    /// * `token_id` - The alpha token.
+    /// * `destination_coldkey` - The coldkey receiving the stake.
+    /// * `amount` - The amount of alpha to move.
+    ///
+    /// # Returns
+    ///
+    /// * `DispatchResult` - Success or error
+    pub(crate) fn do_transfer_alpha(
+        origin: T::RuntimeOrigin,
+        token_id: u32,
+        destination_coldkey: T::AccountId,
+        amount: u64,
+    ) -> DispatchResult {
+        let owner = ensure_signed(origin)?;
+
+        let (hotkey, netuid) =
+            AlphaTokens::<T>::get(token_id).ok_or(Error::<T>::AlphaTokenNotFound)?;
+
+        Self::transfer_alpha_position(
+            token_id,
+            &owner,
+            &destination_coldkey,
+            &hotkey,
+            netuid,
+            amount,
+        )
+    }
+
+    /// Moves `amount` alpha staked to `hotkey` on `netuid` from `owner` to `destination_coldkey`
+    /// without touching the subnet pool.
+    fn transfer_alpha_position(
+        token_id: u32,
+        owner: &T::AccountId,
+        destination_coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        netuid: u16,
+        amount: u64,
+    ) -> DispatchResult {
+        Self::validate_stake_transition(
+            owner,
+            destination_coldkey,
+            hotkey,
+            hotkey,
+            netuid,
+            netuid,
+            amount,
+            amount,
+            None,
+            true,
+        )?;
+
+        let removed =
+            Self::decrease_stake_for_hotkey_and_coldkey_on_subnet(hotkey, owner, netuid, amount);
+
+        // If the coldkey is not the owner, make the hotkey a delegate.
+        if Self::get_owning_coldkey_for_hotkey(hotkey) != *destination_coldkey {
+            Self::maybe_become_delegate(hotkey);
+        }
+
+        let received = Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            hotkey,
+            destination_coldkey,
+            netuid,
+            removed,
+        );
+
+        let current_block = Self::get_current_block_as_u64();
+        LastColdkeyHotkeyStakeBlock::<T>::insert(owner, hotkey, current_block);
+        LastColdkeyHotkeyStakeBlock::<T>::insert(destination_coldkey, hotkey, current_block);
+
+        log::debug!(
+            "AlphaTransferred( token_id:{:?}, from:{:?}, to:{:?}, amount:{:?} )",
+            token_id,
+            owner,
+            destination_coldkey,
+            received
+        );
+        Self::deposit_event(Event::AlphaTransferred {
+            token_id,
+            from: owner.clone(),
+            to: destination_coldkey.clone(),
+            amount: received,
+        });
+
+        Ok(())
+    }
+
+    /// Returns the `(hotkey, netuid)` position wrapped by `token_id`, if registered.
+    pub fn get_alpha_token(token_id: u32) -> Option<(T::AccountId, u16)> {
+        AlphaTokens::<T>::get(token_id)
+    }
+
+    /// Returns the token id wrapping the `(hotkey, netuid)` position, if registered.
+    pub fn get_alpha_token_id(hotkey: &T::AccountId, netuid: u16) -> Option<u32> {
+        AlphaTokenIds::<T>::get(hotkey, netuid)
+    }
+
+    /// Returns the alpha `spender` may still move out of `owner`'s `token_id` position.
+    pub fn get_alpha_allowance(token_id: u32, owner: &T::AccountId, spender: &T::AccountId) -> u64 {
+        AlphaAllowances::<T>::get((token_id, owner, spender))
+    }
+}
@@ -1,4 +1,6 @@
 use super::*;
+use safe_math::*;
+use substrate_fixed::types::U96F32;
 
 impl<T: Config> Pallet<T> {
     /// ---- The implementation for the extrinsic do_set_child_singular: Sets a single child.
@@ -37,6 +39,31 @@ impl<T: Config> Pallet<T> {
         hotkey: T::AccountId,
         netuid: u16,
         children: Vec<(u64, T::AccountId)>,
+    ) -> DispatchResult {
+        Self::do_schedule_children_with_terms(
+            origin,
+            hotkey,
+            netuid,
+            children,
+            ChildKeyTerms::default(),
+        )
+    }
+
+    /// Schedules the children of a hotkey under the given terms.
+    ///
+    /// Performs the checks of `do_schedule_children` and records the terms the children must
+    /// accept before the schedule is applied. Acceptances of a previous schedule are dropped.
+    ///
+    /// # Errors:
+    /// * `InvalidChildKeyTerms`:
+    ///     - The term is zero, or the relationship auto-renews without a term.
+    ///
+    pub fn do_schedule_children_with_terms(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: u16,
+        children: Vec<(u64, T::AccountId)>,
+        terms: ChildKeyTerms,
     ) -> DispatchResult {
         // Check that the caller has signed the transaction. (the coldkey of the pairing)
         let coldkey = ensure_signed(origin)?;
@@ -48,6 +75,12 @@ impl<T: Config> Pallet<T> {
             children
         );
 
+        // Ensure the terms are valid.
+        ensure!(
+            terms.term != Some(0) && (terms.term.is_some() || !terms.auto_renew),
+            Error::<T>::InvalidChildKeyTerms
+        );
+
         // Ensure the hotkey passes the rate limit.
         ensure!(
             Self::passes_rate_limit_on_subnet(
@@ -99,6 +132,12 @@ impl<T: Config> Pallet<T> {
             unique_children.push(child_i.clone());
         }
 
+        // Ensure no child is an ancestor of the hotkey, which would create a cycle.
+        ensure!(
+            !Self::creates_child_key_cycle(&hotkey, netuid, &children),
+            Error::<T>::ChildKeyCycle
+        );
+
         // Check that the parent key has at least the minimum own stake
         // if children vector is not empty
         // (checking with check_weights_min_stake wouldn't work because it considers
@@ -127,6 +166,15 @@ impl<T: Config> Pallet<T> {
         // Insert or update PendingChildKeys
         PendingChildKeys::<T>::insert(netuid, hotkey.clone(), (children.clone(), cooldown_block));
 
+        // The children accept this schedule anew.
+        let _ = ChildKeyAcceptances::<T>::clear_prefix((netuid, &hotkey), u32::MAX, None);
+        PendingChildKeyTerms::<T>::insert(netuid, &hotkey, terms.clone());
+        Self::deposit_event(Event::ChildKeyTermsSet {
+            parent: hotkey.clone(),
+            netuid,
+            terms,
+        });
+
         // --- 8. Log and return.
         log::trace!(
             "SetChildrenScheduled( netuid:{:?}, cooldown_block:{:?}, hotkey:{:?}, children:{:?} )",
@@ -168,6 +216,10 @@ impl<T: Config> Pallet<T> {
     /// # Detailed Explanation of actions:
     /// 1. **Old Children Cleanup**: Removes the hotkey from the parent list of its old children.
     /// 2. **New Children Assignment**: Assigns the new child to the hotkey and updates the parent list for the new child.
+    /// 3. **Terms**: Records the expiry of the new relationships and clears the acceptances.
+    ///
+    /// A schedule is only applied once every child has accepted it and while it does not create
+    /// a cycle, it stays pending otherwise.
     ///
     pub fn do_set_pending_children(netuid: u16) {
         let current_block = Self::get_current_block_as_u64();
@@ -175,7 +227,12 @@ impl<T: Config> Pallet<T> {
         // Iterate over all pending children of this subnet and set as needed
         PendingChildKeys::<T>::iter_prefix(netuid).for_each(
             |(hotkey, (children, cool_down_block))| {
-                if cool_down_block < current_block {
+                if cool_down_block < current_block
+                    && Self::pending_children_accepted(netuid, &hotkey, &children)
+                    && !Self::creates_child_key_cycle(&hotkey, netuid, &children)
+                {
+                    let terms = PendingChildKeyTerms::<T>::get(netuid, &hotkey);
+
                     // Erase myself from old children's parents.
                     let old_children: Vec<(u64, T::AccountId)> =
                         ChildKeys::<T>::get(hotkey.clone(), netuid);
@@ -194,6 +251,11 @@ impl<T: Config> Pallet<T> {
 
                         // Update the parent list in storage
                         ParentKeys::<T>::insert(old_child_i, netuid, filtered_parents);
+
+                        // Drop the terms of the children that are no longer kept.
+                        if !children.iter().any(|(_, child)| child == old_child_i) {
+                            ActiveChildKeyTerms::<T>::remove((netuid, &hotkey, old_child_i));
+                        }
                     }
 
                     // Insert my new children + proportion list into the map.
@@ -215,6 +277,23 @@ impl<T: Config> Pallet<T> {
                             netuid,
                             new_child_previous_parents,
                         );
+
+                        // Start the term, unless the child already serves under these terms.
+                        match terms.term {
+                            Some(term) => {
+                                if ActiveChildKeyTerms::<T>::get((netuid, &hotkey, new_child_i))
+                                    .is_none_or(|(active_terms, _)| active_terms != terms)
+                                {
+                                    ActiveChildKeyTerms::<T>::insert(
+                                        (netuid, &hotkey, new_child_i),
+                                        (terms.clone(), current_block.saturating_add(term)),
+                                    );
+                                }
+                            }
+                            None => {
+                                ActiveChildKeyTerms::<T>::remove((netuid, &hotkey, new_child_i))
+                            }
+                        }
                     }
 
                     // Log and emit event.
@@ -231,12 +310,203 @@ impl<T: Config> Pallet<T> {
                     ));
 
                     // Remove pending children
+                    let _ =
+                        ChildKeyAcceptances::<T>::clear_prefix((netuid, &hotkey), u32::MAX, None);
+                    PendingChildKeyTerms::<T>::remove(netuid, &hotkey);
                     PendingChildKeys::<T>::remove(netuid, hotkey);
                 }
             },
         );
     }
 
+    /// Returns true if every pending child of the parent consents to the pending schedule.
+    ///
+    /// A child consents if it accepted the schedule, or if it already is a child of the parent
+    /// under the same terms.
+    fn pending_children_accepted(
+        netuid: u16,
+        parent: &T::AccountId,
+        children: &[(u64, T::AccountId)],
+    ) -> bool {
+        let terms = PendingChildKeyTerms::<T>::get(netuid, parent);
+        let current_children = ChildKeys::<T>::get(parent, netuid);
+
+        children.iter().all(|(_, child)| {
+            ChildKeyAcceptances::<T>::contains_key((netuid, parent, child))
+                || (current_children.iter().any(|(_, c)| c == child)
+                    && ActiveChildKeyTerms::<T>::get((netuid, parent, child))
+                        .map(|(active_terms, _)| active_terms)
+                        .unwrap_or_default()
+                        == terms)
+        })
+    }
+
+    /// ---- The implementation for the extrinsic accept_childkey.
+    ///
+    /// The coldkey of the child accepts the pending children schedule of the parent, including
+    /// its terms. The schedule is applied once all of its children have accepted.
+    ///
+    /// # Events:
+    /// * `ChildKeyAccepted`:
+    ///     - The child accepted the schedule.
+    ///
+    /// # Errors:
+    /// * `NonAssociatedColdKey`:
+    ///     - The coldkey does not own the child.
+    /// * `NoPendingChildKey`:
+    ///     - The child is not in the pending children of the parent.
+    ///
+    pub fn do_accept_childkey(
+        origin: T::RuntimeOrigin,
+        child: T::AccountId,
+        parent: T::AccountId,
+        netuid: u16,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &child),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        let (pending_children, _) = PendingChildKeys::<T>::get(netuid, &parent);
+        ensure!(
+            pending_children.iter().any(|(_, c)| *c == child),
+            Error::<T>::NoPendingChildKey
+        );
+
+        ChildKeyAcceptances::<T>::insert((netuid, &parent, &child), ());
+
+        log::debug!(
+            "ChildKeyAccepted( parent:{:?}, child:{:?}, netuid:{:?} )",
+            parent,
+            child,
+            netuid
+        );
+        Self::deposit_event(Event::ChildKeyAccepted {
+            parent,
+            child,
+            netuid,
+        });
+
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic resign_childkey.
+    ///
+    /// The coldkey of the child ends the relationship with the parent. The child has to accept
+    /// again if the parent still has it in a pending schedule.
+    ///
+    /// # Events:
+    /// * `ChildKeyResigned`:
+    ///     - The child has left the parent.
+    ///
+    /// # Errors:
+    /// * `NonAssociatedColdKey`:
+    ///     - The coldkey does not own the child.
+    /// * `NotAChildOfParent`:
+    ///     - The child is not a child of the parent.
+    ///
+    pub fn do_resign_childkey(
+        origin: T::RuntimeOrigin,
+        child: T::AccountId,
+        parent: T::AccountId,
+        netuid: u16,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &child),
+            Error::<T>::NonAssociatedColdKey
+        );
+        ensure!(
+            ChildKeys::<T>::get(&parent, netuid)
+                .iter()
+                .any(|(_, c)| *c == child),
+            Error::<T>::NotAChildOfParent
+        );
+
+        Self::remove_child_relationship(netuid, &parent, &child);
+        ChildKeyAcceptances::<T>::remove((netuid, &parent, &child));
+
+        log::debug!(
+            "ChildKeyResigned( parent:{:?}, child:{:?}, netuid:{:?} )",
+            parent,
+            child,
+            netuid
+        );
+        Self::deposit_event(Event::ChildKeyResigned {
+            parent,
+            child,
+            netuid,
+        });
+
+        Ok(())
+    }
+
+    /// Expires the child-key relationships of the subnet whose term has ended, renewing those
+    /// that auto-renew.
+    pub fn expire_child_keys(netuid: u16) {
+        let current_block = Self::get_current_block_as_u64();
+
+        let ended: Vec<(T::AccountId, T::AccountId, ChildKeyTerms)> =
+            ActiveChildKeyTerms::<T>::iter_prefix((netuid,))
+                .filter(|(_, (_, expires_at))| *expires_at <= current_block)
+                .map(|((parent, child), (terms, _))| (parent, child, terms))
+                .collect();
+
+        for (parent, child, terms) in ended {
+            match terms.term {
+                Some(term) if terms.auto_renew => {
+                    let expires_at = current_block.saturating_add(term);
+                    ActiveChildKeyTerms::<T>::insert(
+                        (netuid, &parent, &child),
+                        (terms, expires_at),
+                    );
+                    Self::deposit_event(Event::ChildKeyRenewed {
+                        parent,
+                        child,
+                        netuid,
+                        expires_at,
+                    });
+                }
+                _ => {
+                    Self::remove_child_relationship(netuid, &parent, &child);
+                    Self::deposit_event(Event::ChildKeyExpired {
+                        parent,
+                        child,
+                        netuid,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Removes a single child from its parent, keeping the other children of the parent.
+    fn remove_child_relationship(netuid: u16, parent: &T::AccountId, child: &T::AccountId) {
+        let children: Vec<(u64, T::AccountId)> = ChildKeys::<T>::get(parent, netuid)
+            .into_iter()
+            .filter(|(_, c)| c != child)
+            .collect();
+        if children.is_empty() {
+            ChildKeys::<T>::remove(parent, netuid);
+        } else {
+            ChildKeys::<T>::insert(parent, netuid, children);
+        }
+
+        let parents: Vec<(u64, T::AccountId)> = ParentKeys::<T>::get(child, netuid)
+            .into_iter()
+            .filter(|(_, p)| p != parent)
+            .collect();
+        if parents.is_empty() {
+            ParentKeys::<T>::remove(child, netuid);
+        } else {
+            ParentKeys::<T>::insert(child, netuid, parents);
+        }
+
+        ActiveChildKeyTerms::<T>::remove((netuid, parent, child));
+    }
+
     /* Retrieves the list of children for a given hotkey and network.
     ///
     /// # Arguments
@@ -271,6 +541,65 @@ impl<T: Config> Pallet<T> {
         ParentKeys::<T>::get(child, netuid)
     }
 
+    /// Returns true if one of the children is the hotkey or one of its ancestors at any depth.
+    pub fn creates_child_key_cycle(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        children: &[(u64, T::AccountId)],
+    ) -> bool {
+        let mut visited: Vec<T::AccountId> = vec![hotkey.clone()];
+        let mut frontier: Vec<T::AccountId> = vec![hotkey.clone()];
+        while let Some(key) = frontier.pop() {
+            for (_, parent) in Self::get_parents(&key, netuid) {
+                if !visited.contains(&parent) {
+                    visited.push(parent.clone());
+                    frontier.push(parent);
+                }
+            }
+        }
+        children.iter().any(|(_, child)| visited.contains(child))
+    }
+
+    /// Retrieves the ancestors of a hotkey within `MaxChildKeyDepth` hops.
+    ///
+    /// # Returns
+    /// * `Vec<(T::AccountId, U96F32, u16)>` - The ancestor, the share of its stake that reaches
+    ///   the hotkey, and the number of hops it travels. Shares of an ancestor reached by several
+    ///   paths of the same length are summed.
+    pub fn get_ancestors(hotkey: &T::AccountId, netuid: u16) -> Vec<(T::AccountId, U96F32, u16)> {
+        let mut ancestors: Vec<(T::AccountId, U96F32, u16)> = Vec::new();
+        let mut frontier: Vec<(T::AccountId, U96F32)> =
+            vec![(hotkey.clone(), U96F32::saturating_from_num(1))];
+
+        for hops in 1..=MaxChildKeyDepth::<T>::get() {
+            let mut next: Vec<(T::AccountId, U96F32)> = Vec::new();
+            for (key, share) in frontier {
+                for (proportion, parent) in Self::get_parents(&key, netuid) {
+                    let parent_share: U96F32 = share.saturating_mul(
+                        U96F32::saturating_from_num(proportion)
+                            .safe_div(U96F32::saturating_from_num(u64::MAX)),
+                    );
+                    match next.iter_mut().find(|(account, _)| *account == parent) {
+                        Some((_, total_share)) => {
+                            *total_share = total_share.saturating_add(parent_share)
+                        }
+                        None => next.push((parent, parent_share)),
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            ancestors.extend(
+                next.iter()
+                    .map(|(account, share)| (account.clone(), *share, hops)),
+            );
+            frontier = next;
+        }
+
+        ancestors
+    }
+
     /// Sets the childkey take for a given hotkey.
     ///
     /// This function allows a coldkey to set the childkey take for a given hotkey.
@@ -227,17 +227,18 @@ impl<T: Config> Pallet<T> {
     ///
     /// This function performs the following steps:
     /// 1. Retrieves the initial alpha (stake) for the hotkey on the specified subnet.
-    /// 2. Retrieves the list of children and parents for the hotkey on the subnet.
+    /// 2. Retrieves the list of children and the ancestors within `MaxChildKeyDepth` hops.
     /// 3. Calculates the alpha allocated to children:
     ///    a. For each child, computes the proportion of alpha to be allocated.
     ///    b. Accumulates the total alpha allocated to all children.
-    /// 4. Calculates the alpha received from parents:
-    ///    a. For each parent, retrieves the parent's stake on the subnet.
-    ///    b. Computes the proportion of the parent's stake to be inherited.
-    ///    c. Accumulates the total alpha inherited from all parents.
+    /// 4. Calculates the alpha received from ancestors:
+    ///    a. For each ancestor, retrieves the ancestor's stake on the subnet.
+    ///    b. Computes the share of the ancestor's stake reaching the hotkey, less the part passed
+    ///       on to the children if it has not travelled `MaxChildKeyDepth` hops yet.
+    ///    c. Accumulates the total alpha inherited from all ancestors.
     /// 5. Computes the final inherited alpha by adjusting the initial alpha:
     ///    a. Subtracts the alpha allocated to children.
-    ///    b. Adds the alpha inherited from parents.
+    ///    b. Adds the alpha inherited from ancestors.
     /// 6. Returns the final inherited alpha value.
     ///
     /// # Arguments
@@ -255,18 +256,18 @@ impl<T: Config> Pallet<T> {
             Self::get_stake_for_hotkey_on_subnet(hotkey, Self::get_root_netuid()),
         );
 
-        // Initialize variables to track alpha allocated to children and inherited from parents.
+        // Initialize variables to track tao allocated to children and inherited from ancestors.
         let mut tao_to_children: U96F32 = U96F32::saturating_from_num(0);
         let mut tao_from_parents: U96F32 = U96F32::saturating_from_num(0);
 
-        // Step 2: Retrieve the lists of parents and children for the hotkey on the subnet.
-        let parents: Vec<(u64, T::AccountId)> = Self::get_parents(hotkey, netuid);
+        // Step 2: Retrieve the lists of ancestors and children for the hotkey on the subnet.
+        let ancestors: Vec<(T::AccountId, U96F32, u16)> = Self::get_ancestors(hotkey, netuid);
         let children: Vec<(u64, T::AccountId)> = Self::get_children(hotkey, netuid);
         log::trace!(
-            "Parents for hotkey {:?} on subnet {}: {:?}",
+            "Ancestors for hotkey {:?} on subnet {}: {:?}",
             hotkey,
             netuid,
-            parents
+            ancestors
         );
         log::trace!(
             "Children for hotkey {:?} on subnet {}: {:?}",
@@ -276,6 +277,7 @@ impl<T: Config> Pallet<T> {
         );
 
         // Step 3: Calculate the total tao allocated to children.
+        let children_proportion: U96F32 = Self::get_children_proportion(&children);
         for (proportion, _) in children {
             // Convert the proportion to a normalized value between 0 and 1.
             let normalized_proportion: U96F32 = U96F32::saturating_from_num(proportion)
@@ -295,44 +297,39 @@ impl<T: Config> Pallet<T> {
         }
         log::trace!("Total tao allocated to children: {:?}", tao_to_children);
 
-        // Step 4: Calculate the total tao inherited from parents.
-        for (proportion, parent) in parents {
-            // Retrieve the parent's total stake on this subnet.
-            let parent_tao: U96F32 = U96F32::saturating_from_num(
-                Self::get_stake_for_hotkey_on_subnet(&parent, Self::get_root_netuid()),
+        // Step 4: Calculate the total tao inherited from ancestors.
+        let max_depth: u16 = MaxChildKeyDepth::<T>::get();
+        for (ancestor, share, hops) in ancestors {
+            // Retrieve the ancestor's root stake.
+            let ancestor_tao: U96F32 = U96F32::saturating_from_num(
+                Self::get_stake_for_hotkey_on_subnet(&ancestor, Self::get_root_netuid()),
             );
             log::trace!(
-                "Parent tao for parent {:?} on subnet {}: {:?}",
-                parent,
+                "Ancestor tao for ancestor {:?} on subnet {}: {:?}",
+                ancestor,
                 netuid,
-                parent_tao
+                ancestor_tao
             );
 
-            // Convert the proportion to a normalized value between 0 and 1.
-            let normalized_proportion: U96F32 = U96F32::saturating_from_num(proportion)
-                .safe_div(U96F32::saturating_from_num(u64::MAX));
-            log::trace!(
-                "Normalized proportion from parent: {:?}",
-                normalized_proportion
-            );
-
-            // Calculate the amount of tao to be inherited from this parent.
-            let tao_proportion_from_parent: U96F32 =
-                U96F32::saturating_from_num(parent_tao).saturating_mul(normalized_proportion);
-            log::trace!(
-                "Tao proportion from parent: {:?}",
-                tao_proportion_from_parent
+            // Calculate the amount of tao reaching the hotkey from this ancestor, less the
+            // part passed on to the children while it may still travel.
+            let tao_from_ancestor: U96F32 = Self::retained_from_ancestor(
+                ancestor_tao.saturating_mul(share),
+                hops,
+                max_depth,
+                children_proportion,
             );
+            log::trace!("Tao proportion from ancestor: {:?}", tao_from_ancestor);
 
-            // Add this parent's contribution to the total tao inherited from parents.
-            tao_from_parents = tao_from_parents.saturating_add(tao_proportion_from_parent);
+            // Add this ancestor's contribution to the total tao inherited.
+            tao_from_parents = tao_from_parents.saturating_add(tao_from_ancestor);
         }
-        log::trace!("Total tao inherited from parents: {:?}", tao_from_parents);
+        log::trace!("Total tao inherited from ancestors: {:?}", tao_from_parents);
 
         // Step 5: Calculate the final inherited tao for the hotkey.
         let finalized_tao: U96F32 = initial_tao
             .saturating_sub(tao_to_children) // Subtract tao allocated to children
-            .saturating_add(tao_from_parents); // Add tao inherited from parents
+            .saturating_add(tao_from_parents); // Add tao inherited from ancestors
         log::trace!(
             "Finalized tao for hotkey {:?} on subnet {}: {:?}",
             hotkey,
@@ -358,18 +355,18 @@ impl<T: Config> Pallet<T> {
             return initial_alpha.saturating_to_num::<u64>();
         }
 
-        // Initialize variables to track alpha allocated to children and inherited from parents.
+        // Initialize variables to track alpha allocated to children and inherited from ancestors.
         let mut alpha_to_children: U96F32 = U96F32::saturating_from_num(0);
         let mut alpha_from_parents: U96F32 = U96F32::saturating_from_num(0);
 
-        // Step 2: Retrieve the lists of parents and children for the hotkey on the subnet.
-        let parents: Vec<(u64, T::AccountId)> = Self::get_parents(hotkey, netuid);
+        // Step 2: Retrieve the lists of ancestors and children for the hotkey on the subnet.
+        let ancestors: Vec<(T::AccountId, U96F32, u16)> = Self::get_ancestors(hotkey, netuid);
         let children: Vec<(u64, T::AccountId)> = Self::get_children(hotkey, netuid);
         log::debug!(
-            "Parents for hotkey {:?} on subnet {}: {:?}",
+            "Ancestors for hotkey {:?} on subnet {}: {:?}",
             hotkey,
             netuid,
-            parents
+            ancestors
         );
         log::debug!(
             "Children for hotkey {:?} on subnet {}: {:?}",
@@ -379,6 +376,7 @@ impl<T: Config> Pallet<T> {
         );
 
         // Step 3: Calculate the total alpha allocated to children.
+        let children_proportion: U96F32 = Self::get_children_proportion(&children);
         for (proportion, _) in children {
             // Convert the proportion to a normalized value between 0 and 1.
             let normalized_proportion: U96F32 = U96F32::saturating_from_num(proportion)
@@ -398,46 +396,42 @@ impl<T: Config> Pallet<T> {
         }
         log::debug!("Total alpha allocated to children: {:?}", alpha_to_children);
 
-        // Step 4: Calculate the total alpha inherited from parents.
-        for (proportion, parent) in parents {
-            // Retrieve the parent's total stake on this subnet.
-            let parent_alpha: U96F32 =
-                U96F32::saturating_from_num(Self::get_stake_for_hotkey_on_subnet(&parent, netuid));
-            log::trace!(
-                "Parent alpha for parent {:?} on subnet {}: {:?}",
-                parent,
-                netuid,
-                parent_alpha
+        // Step 4: Calculate the total alpha inherited from ancestors.
+        let max_depth: u16 = MaxChildKeyDepth::<T>::get();
+        for (ancestor, share, hops) in ancestors {
+            // Retrieve the ancestor's total stake on this subnet.
+            let ancestor_alpha: U96F32 = U96F32::saturating_from_num(
+                Self::get_stake_for_hotkey_on_subnet(&ancestor, netuid),
             );
-
-            // Convert the proportion to a normalized value between 0 and 1.
-            let normalized_proportion: U96F32 = U96F32::saturating_from_num(proportion)
-                .safe_div(U96F32::saturating_from_num(u64::MAX));
             log::trace!(
-                "Normalized proportion from parent: {:?}",
-                normalized_proportion
+                "Ancestor alpha for ancestor {:?} on subnet {}: {:?}",
+                ancestor,
+                netuid,
+                ancestor_alpha
             );
 
-            // Calculate the amount of alpha to be inherited from this parent.
-            let alpha_proportion_from_parent: U96F32 =
-                U96F32::saturating_from_num(parent_alpha).saturating_mul(normalized_proportion);
-            log::trace!(
-                "Alpha proportion from parent: {:?}",
-                alpha_proportion_from_parent
+            // Calculate the amount of alpha reaching the hotkey from this ancestor, less the
+            // part passed on to the children while it may still travel.
+            let alpha_from_ancestor: U96F32 = Self::retained_from_ancestor(
+                ancestor_alpha.saturating_mul(share),
+                hops,
+                max_depth,
+                children_proportion,
             );
+            log::trace!("Alpha proportion from ancestor: {:?}", alpha_from_ancestor);
 
-            // Add this parent's contribution to the total alpha inherited from parents.
-            alpha_from_parents = alpha_from_parents.saturating_add(alpha_proportion_from_parent);
+            // Add this ancestor's contribution to the total alpha inherited.
+            alpha_from_parents = alpha_from_parents.saturating_add(alpha_from_ancestor);
         }
         log::debug!(
-            "Total alpha inherited from parents: {:?}",
+            "Total alpha inherited from ancestors: {:?}",
             alpha_from_parents
         );
 
         // Step 5: Calculate the final inherited alpha for the hotkey.
         let finalized_alpha: U96F32 = initial_alpha
             .saturating_sub(alpha_to_children) // Subtract alpha allocated to children
-            .saturating_add(alpha_from_parents); // Add alpha inherited from parents
+            .saturating_add(alpha_from_parents); // Add alpha inherited from ancestors
         log::trace!(
             "Finalized alpha for hotkey {:?} on subnet {}: {:?}",
             hotkey,
@@ -449,6 +443,35 @@ impl<T: Config> Pallet<T> {
         finalized_alpha.saturating_to_num::<u64>()
     }
 
+    /// Returns the sum of the normalized proportions of the children.
+    pub fn get_children_proportion(children: &[(u64, T::AccountId)]) -> U96F32 {
+        children
+            .iter()
+            .fold(U96F32::saturating_from_num(0), |acc, (proportion, _)| {
+                acc.saturating_add(
+                    U96F32::saturating_from_num(*proportion)
+                        .safe_div(U96F32::saturating_from_num(u64::MAX)),
+                )
+            })
+            .min(U96F32::saturating_from_num(1))
+    }
+
+    /// Returns the part of the stake reaching a hotkey from an ancestor `hops` away that the
+    /// hotkey keeps. Stake that has not travelled `max_depth` hops yet is passed on to the
+    /// children of the hotkey in their proportions.
+    pub fn retained_from_ancestor(
+        stake: U96F32,
+        hops: u16,
+        max_depth: u16,
+        children_proportion: U96F32,
+    ) -> U96F32 {
+        if hops < max_depth {
+            stake.saturating_sub(stake.saturating_mul(children_proportion))
+        } else {
+            stake
+        }
+    }
+
     /// Checks if a specific hotkey-coldkey pair has enough stake on a subnet to fulfill a given decrement.
     ///
     /// This function performs the following steps:
@@ -914,6 +937,9 @@ impl<T: Config> Pallet<T> {
         // Ensure that the subnet is enabled.
         Self::ensure_subtoken_enabled(netuid)?;
 
+        // Ensure that the subnet is not being sunset.
+        Self::ensure_subnet_not_sunsetting(netuid)?;
+
         // Get the minimum balance (and amount) that satisfies the transaction
         let min_amount = DefaultMinStake::<T>::get().saturating_add(DefaultStakingFee::<T>::get());
 
@@ -1079,6 +1105,9 @@ impl<T: Config> Pallet<T> {
             Error::<T>::SubtokenDisabled
         );
 
+        // Ensure that no stake enters or moves within a sunsetting subnet
+        Self::ensure_subnet_not_sunsetting(destination_netuid)?;
+
         // Ensure that the origin hotkey account exists
         ensure!(
             Self::hotkey_account_exists(origin_hotkey),
@@ -1632,8 +1661,10 @@ impl<T: Config> SharePoolDataOperations<AlphaShareKey<T>>
     fn set_share(&mut self, key: &AlphaShareKey<T>, share: U64F64) {
         if share != 0 {
             crate::Alpha::<T>::insert((&self.hotkey, key, self.netuid), share);
+            crate::SubnetAlphaPositions::<T>::insert((self.netuid, &self.hotkey, key), ());
         } else {
             crate::Alpha::<T>::remove((&self.hotkey, key, self.netuid));
+            crate::SubnetAlphaPositions::<T>::remove((self.netuid, &self.hotkey, key));
         }
     }
 
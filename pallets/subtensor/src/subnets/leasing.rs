@@ -0,0 +1,313 @@
+use super::*;
+use frame_support::{
+    ensure,
+    traits::{fungible::Mutate, tokens::Preservation},
+};
+use frame_system::RawOrigin;
+use safe_math::*;
+use sp_core::hashing::blake2_256;
+use sp_runtime::traits::TrailingZeroInput;
+use sp_std::vec::Vec;
+use substrate_fixed::types::U64F64;
+
+impl<T: Config> Pallet<T> {
+    /// Derives the coldkey or hotkey owning a leased subnet.
+    pub fn get_subnet_lease_account_id(lease_id: LeaseId, hotkey: bool) -> T::AccountId {
+        let entropy = (b"subtensor/lease/", lease_id, hotkey).using_encoded(blake2_256);
+        T::AccountId::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+            .expect("infinite length input; no invalid inputs for type; qed")
+    }
+
+    /// Registers a subnet with the funds of the crowdloan being finalized and leases it to the
+    /// contributors.
+    ///
+    /// The call must be the crowdloan call, created without a target address, so it is
+    /// dispatched by `finalize` with the creator origin. The raised funds pay the lock cost
+    /// of a subnet owned by a lease coldkey and the proxy deposit letting the beneficiary
+    /// manage the subnet through it, and the remainder is refunded pro rata to the
+    /// contributors. Until the end of the lease the owner emissions are staked to the
+    /// contributors pro rata to their contributions, after which the creator can take over
+    /// the subnet.
+    ///
+    /// # Args:
+    /// * 'origin': The creator of the crowdloan, who becomes the lease beneficiary.
+    /// * 'term': The number of blocks the lease lasts.
+    ///
+    /// # Raises:
+    /// * 'NotCalledFromCrowdloan': No crowdloan is being finalized or the caller is not its creator.
+    ///
+    /// # Event:
+    /// * SubnetLeaseCreated;
+    pub fn do_register_leased_network(origin: T::RuntimeOrigin, term: u64) -> DispatchResult {
+        let beneficiary = ensure_signed(origin)?;
+
+        let crowdloan = T::CrowdloanInterface::finalizing_crowdloan()
+            .filter(|crowdloan| crowdloan.creator == beneficiary)
+            .ok_or(Error::<T>::NotCalledFromCrowdloan)?;
+        let crowdloan_id = crowdloan.id;
+
+        let lease_id = NextSubnetLeaseId::<T>::get();
+        NextSubnetLeaseId::<T>::put(lease_id.saturating_add(1));
+        let coldkey = Self::get_subnet_lease_account_id(lease_id, false);
+        let hotkey = Self::get_subnet_lease_account_id(lease_id, true);
+
+        // Move the raised funds to the lease coldkey and register the subnet with them.
+        frame_system::Pallet::<T>::inc_providers(&coldkey);
+        T::Currency::transfer(
+            &crowdloan.funds_account,
+            &coldkey,
+            crowdloan.raised,
+            Preservation::Expendable,
+        )?;
+        let netuid = Self::get_next_netuid();
+        Self::do_register_network(RawOrigin::Signed(coldkey.clone()).into(), &hotkey, 1, None)?;
+        T::ProxyInterface::add_lease_beneficiary_proxy(&coldkey, &beneficiary)?;
+
+        // Record the contributor shares and refund what was left after the lock.
+        let leftover = Self::get_coldkey_balance(&coldkey);
+        let mut refunded: u64 = 0;
+        for (contributor, amount) in T::CrowdloanInterface::contributions(crowdloan_id) {
+            let share = U64F64::saturating_from_num(amount)
+                .safe_div(U64F64::saturating_from_num(crowdloan.raised));
+            SubnetLeaseShares::<T>::insert(lease_id, &contributor, share);
+
+            let refund = U64F64::saturating_from_num(leftover)
+                .saturating_mul(share)
+                .saturating_to_num::<u64>();
+            if refund > 0 {
+                T::Currency::transfer(&coldkey, &contributor, refund, Preservation::Expendable)?;
+                refunded = refunded.saturating_add(refund);
+            }
+        }
+        let dust = leftover.saturating_sub(refunded);
+        if dust > 0 {
+            T::Currency::transfer(&coldkey, &beneficiary, dust, Preservation::Expendable)?;
+        }
+
+        let end_block = Self::get_current_block_as_u64().saturating_add(term);
+        SubnetLeases::<T>::insert(
+            lease_id,
+            SubnetLease {
+                beneficiary: beneficiary.clone(),
+                coldkey,
+                hotkey,
+                netuid,
+                end_block,
+            },
+        );
+        SubnetUidToLeaseId::<T>::insert(netuid, lease_id);
+
+        log::debug!(
+            "SubnetLeaseCreated( lease_id: {:?}, crowdloan_id: {:?}, netuid: {:?}, beneficiary: {:?}, end_block: {:?} )",
+            lease_id,
+            crowdloan_id,
+            netuid,
+            beneficiary,
+            end_block
+        );
+        Self::deposit_event(Event::SubnetLeaseCreated {
+            lease_id,
+            crowdloan_id,
+            netuid,
+            beneficiary,
+            end_block,
+        });
+        Ok(())
+    }
+
+    /// Ends a subnet lease and hands the subnet over to the beneficiary.
+    ///
+    /// # Args:
+    /// * 'origin': The lease beneficiary.
+    /// * 'lease_id': The lease to end.
+    /// * 'hotkey': The beneficiary's hotkey to become the subnet owner hotkey.
+    ///
+    /// # Raises:
+    /// * 'SubnetLeaseDoesNotExist': The lease does not exist.
+    /// * 'NotSubnetLeaseBeneficiary': The caller is not the lease beneficiary.
+    /// * 'SubnetLeaseNotEnded': The lease end block has not been reached.
+    /// * 'SubnetLeaseDividendsPending': The contributors have not been paid all their dividends yet.
+    /// * 'NonAssociatedColdKey': The hotkey is owned by another coldkey.
+    /// * 'SubnetSunsetting': The subnet is being sunset.
+    ///
+    /// # Event:
+    /// * SubnetLeaseTerminated;
+    pub fn do_terminate_subnet_lease(
+        origin: T::RuntimeOrigin,
+        lease_id: LeaseId,
+        hotkey: T::AccountId,
+    ) -> DispatchResult {
+        let who = ensure_signed(origin)?;
+
+        let lease = SubnetLeases::<T>::get(lease_id).ok_or(Error::<T>::SubnetLeaseDoesNotExist)?;
+        ensure!(
+            lease.beneficiary == who,
+            Error::<T>::NotSubnetLeaseBeneficiary
+        );
+        ensure!(
+            Self::get_current_block_as_u64() >= lease.end_block,
+            Error::<T>::SubnetLeaseNotEnded
+        );
+        ensure!(
+            !SubnetLeaseAccruedDividends::<T>::contains_key(lease_id)
+                && !SubnetLeaseDistributions::<T>::contains_key(lease_id),
+            Error::<T>::SubnetLeaseDividendsPending
+        );
+        ensure!(
+            !Self::hotkey_account_exists(&hotkey) || Self::coldkey_owns_hotkey(&who, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        Self::ensure_subnet_not_sunsetting(lease.netuid)?;
+
+        Self::create_account_if_non_existent(&who, &hotkey);
+        Self::transfer_subnet_ownership(lease.netuid, who.clone());
+        Self::set_subnet_owner_hotkey(lease.netuid, &hotkey);
+        Self::remove_subnet_lease(lease.netuid);
+
+        Self::deposit_event(Event::SubnetLeaseTerminated {
+            lease_id,
+            netuid: lease.netuid,
+            beneficiary: who,
+        });
+        Ok(())
+    }
+
+    /// Distributes the owner cut of a leased subnet.
+    ///
+    /// During the lease the cut accrues for the contributors and is staked to them by
+    /// `distribute_accrued_lease_dividends`. After the lease end block it is all staked to the
+    /// beneficiary until the lease is terminated.
+    pub fn distribute_leased_network_dividends(lease_id: LeaseId, owner_cut: u64) {
+        let Some(lease) = SubnetLeases::<T>::get(lease_id) else {
+            return;
+        };
+
+        if Self::get_current_block_as_u64() < lease.end_block {
+            SubnetLeaseAccruedDividends::<T>::mutate(lease_id, |accrued| {
+                *accrued = accrued.saturating_add(owner_cut)
+            });
+            return;
+        }
+
+        Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &lease.hotkey,
+            &lease.beneficiary,
+            lease.netuid,
+            owner_cut,
+        );
+        Self::deposit_event(Event::SubnetLeaseDividendsDistributed {
+            lease_id,
+            alpha: owner_cut,
+        });
+    }
+
+    /// Stakes the accrued owner emissions of the leased subnets to their contributors.
+    ///
+    /// Called on each block, paying at most `SUBNET_LEASE_DIVIDENDS_CHUNK_SIZE` contributors
+    /// and resuming from the last paid one on the next block. A distribution covers what had
+    /// accrued when it started, each contributor receiving it pro rata to their share and the
+    /// rounding dust going to the beneficiary.
+    pub fn distribute_accrued_lease_dividends() -> Weight {
+        let mut weight = Weight::zero();
+        let mut budget = SUBNET_LEASE_DIVIDENDS_CHUNK_SIZE;
+
+        for (lease_id, lease) in SubnetLeases::<T>::iter() {
+            weight.saturating_accrue(T::DbWeight::get().reads(2));
+            if budget == 0 {
+                break;
+            }
+
+            let (alpha, mut distributed, last_raw_key) =
+                match SubnetLeaseDistributions::<T>::get(lease_id) {
+                    Some(distribution) => distribution,
+                    None => {
+                        let alpha = SubnetLeaseAccruedDividends::<T>::take(lease_id);
+                        if alpha == 0 {
+                            continue;
+                        }
+                        (alpha, 0, Vec::new())
+                    }
+                };
+
+            let mut shares = if last_raw_key.is_empty() {
+                SubnetLeaseShares::<T>::iter_prefix(lease_id)
+            } else {
+                SubnetLeaseShares::<T>::iter_prefix_from(lease_id, last_raw_key)
+            };
+            let mut exhausted = false;
+            while budget > 0 {
+                let Some((contributor, share)) = shares.next() else {
+                    exhausted = true;
+                    break;
+                };
+                budget = budget.saturating_sub(1);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(4, 4));
+
+                let amount = U64F64::saturating_from_num(alpha)
+                    .saturating_mul(share)
+                    .saturating_to_num::<u64>();
+                if amount > 0 {
+                    Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                        &lease.hotkey,
+                        &contributor,
+                        lease.netuid,
+                        amount,
+                    );
+                    distributed = distributed.saturating_add(amount);
+                }
+            }
+
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+            if !exhausted {
+                SubnetLeaseDistributions::<T>::insert(
+                    lease_id,
+                    (alpha, distributed, shares.last_raw_key().to_vec()),
+                );
+                continue;
+            }
+
+            SubnetLeaseDistributions::<T>::remove(lease_id);
+            let remainder = alpha.saturating_sub(distributed);
+            if remainder > 0 {
+                Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                    &lease.hotkey,
+                    &lease.beneficiary,
+                    lease.netuid,
+                    remainder,
+                );
+            }
+            Self::deposit_event(Event::SubnetLeaseDividendsDistributed { lease_id, alpha });
+        }
+
+        weight
+    }
+
+    /// Removes the lease of a subnet, if any.
+    ///
+    /// The beneficiary proxy is removed and what is left on the lease coldkey goes to the
+    /// beneficiary.
+    pub fn remove_subnet_lease(netuid: u16) {
+        let Some(lease_id) = SubnetUidToLeaseId::<T>::take(netuid) else {
+            return;
+        };
+        if let Some(lease) = SubnetLeases::<T>::take(lease_id) {
+            let _ = T::ProxyInterface::remove_lease_beneficiary_proxy(
+                &lease.coldkey,
+                &lease.beneficiary,
+            );
+            let balance = Self::get_coldkey_balance(&lease.coldkey);
+            if balance > 0 {
+                let _ = T::Currency::transfer(
+                    &lease.coldkey,
+                    &lease.beneficiary,
+                    balance,
+                    Preservation::Expendable,
+                );
+            }
+            let _ = frame_system::Pallet::<T>::dec_providers(&lease.coldkey);
+        }
+        SubnetLeaseAccruedDividends::<T>::remove(lease_id);
+        SubnetLeaseDistributions::<T>::remove(lease_id);
+        let _ = SubnetLeaseShares::<T>::clear_prefix(lease_id, u32::MAX, None);
+    }
+}
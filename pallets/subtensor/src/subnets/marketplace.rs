@@ -0,0 +1,170 @@
+use super::*;
+use frame_support::ensure;
+
+impl<T: Config> Pallet<T> {
+    /// Lists a subnet for sale at a fixed TAO price.
+    ///
+    /// A new listing replaces any existing one. The listing is dropped if the ownership of the
+    /// subnet changes by other means.
+    ///
+    /// # Args:
+    /// * 'origin': The current subnet owner.
+    /// * 'netuid': The subnet to sell.
+    /// * 'price': The price in RAO the buyer pays to the seller.
+    /// * 'deadline': The last block at which the subnet can be bought, if any.
+    ///
+    /// # Raises:
+    /// * 'SubnetListingExpired': The deadline is in the past.
+    /// * 'SubnetSunsetting': The subnet is being sunset.
+    ///
+    /// # Event:
+    /// * SubnetListedForSale;
+    pub fn do_list_subnet_for_sale(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        price: u64,
+        deadline: Option<u64>,
+    ) -> DispatchResult {
+        let seller = ensure_signed(origin.clone())?;
+        Self::ensure_subnet_owner(origin, netuid)?;
+        Self::ensure_subnet_not_sunsetting(netuid)?;
+
+        let current_block = Self::get_current_block_as_u64();
+        ensure!(
+            deadline.is_none_or(|deadline| deadline >= current_block),
+            Error::<T>::SubnetListingExpired
+        );
+
+        SubnetSaleListings::<T>::insert(
+            netuid,
+            SubnetSaleListing {
+                seller: seller.clone(),
+                price,
+                deadline,
+            },
+        );
+
+        Self::deposit_event(Event::SubnetListedForSale {
+            netuid,
+            seller,
+            price,
+            deadline,
+        });
+        Ok(())
+    }
+
+    /// Removes the sale listing of a subnet.
+    ///
+    /// # Raises:
+    /// * 'SubnetListingNotFound': The subnet is not listed.
+    ///
+    /// # Event:
+    /// * SubnetListingCancelled;
+    pub fn do_cancel_subnet_listing(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+
+        ensure!(
+            SubnetSaleListings::<T>::take(netuid).is_some(),
+            Error::<T>::SubnetListingNotFound
+        );
+
+        Self::deposit_event(Event::SubnetListingCancelled { netuid });
+        Ok(())
+    }
+
+    /// Buys a listed subnet.
+    ///
+    /// The price is taken from the buyer into escrow, the owner cut accrued so far is paid out
+    /// to the seller, and the subnet owner and owner hotkey are switched to the buyer before the
+    /// escrowed price is released to the seller. Either all of this happens or none of it does.
+    ///
+    /// # Args:
+    /// * 'origin': The buyer's coldkey.
+    /// * 'netuid': The subnet to buy.
+    /// * 'hotkey': The buyer's hotkey to become the subnet owner hotkey.
+    /// * 'max_price': The highest price the buyer accepts.
+    ///
+    /// # Raises:
+    /// * 'SubnetListingNotFound': The subnet is not listed, or the buyer is the seller.
+    /// * 'SubnetListingExpired': The listing deadline has passed.
+    /// * 'SubnetPriceAboveLimit': The listing price exceeds `max_price`.
+    /// * 'NonAssociatedColdKey': The hotkey is owned by another coldkey.
+    /// * 'NotEnoughBalanceToBuySubnet': The buyer cannot pay the price.
+    /// * 'SubnetSunsetting': The subnet is being sunset.
+    ///
+    /// # Event:
+    /// * SubnetSold;
+    pub fn do_buy_subnet(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        hotkey: T::AccountId,
+        max_price: u64,
+    ) -> DispatchResult {
+        let buyer = ensure_signed(origin)?;
+        Self::ensure_subnet_not_sunsetting(netuid)?;
+
+        let listing = SubnetSaleListings::<T>::get(netuid)
+            .filter(|listing| {
+                listing.seller == SubnetOwner::<T>::get(netuid) && listing.seller != buyer
+            })
+            .ok_or(Error::<T>::SubnetListingNotFound)?;
+
+        let current_block = Self::get_current_block_as_u64();
+        ensure!(
+            listing
+                .deadline
+                .is_none_or(|deadline| deadline >= current_block),
+            Error::<T>::SubnetListingExpired
+        );
+        ensure!(
+            listing.price <= max_price,
+            Error::<T>::SubnetPriceAboveLimit
+        );
+        ensure!(
+            !Self::hotkey_account_exists(&hotkey) || Self::coldkey_owns_hotkey(&buyer, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(&buyer, listing.price),
+            Error::<T>::NotEnoughBalanceToBuySubnet
+        );
+
+        // Take the price from the buyer into escrow.
+        let escrowed = Self::remove_balance_from_coldkey_account(&buyer, listing.price)?;
+
+        // Pay the owner cut accrued before the sale to the seller.
+        let owner_cut = PendingOwnerCut::<T>::take(netuid);
+        if let Ok(seller_hotkey) = SubnetOwnerHotkey::<T>::try_get(netuid) {
+            Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &seller_hotkey,
+                &listing.seller,
+                netuid,
+                owner_cut,
+            );
+        }
+
+        // Switch the ownership to the buyer.
+        Self::create_account_if_non_existent(&buyer, &hotkey);
+        Self::transfer_subnet_ownership(netuid, buyer.clone());
+        Self::set_subnet_owner_hotkey(netuid, &hotkey);
+
+        // Release the escrow to the seller.
+        Self::add_balance_to_coldkey_account(&listing.seller, escrowed);
+
+        log::debug!(
+            "SubnetSold( netuid: {:?}, seller: {:?}, buyer: {:?}, price: {:?} )",
+            netuid,
+            listing.seller,
+            buyer,
+            escrowed
+        );
+        Self::deposit_event(Event::SubnetSold {
+            netuid,
+            seller: listing.seller,
+            buyer,
+            hotkey,
+            price: escrowed,
+        });
+        Ok(())
+    }
+}
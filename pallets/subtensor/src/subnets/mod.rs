@@ -1,7 +1,11 @@
 use super::*;
+pub mod leasing;
+pub mod marketplace;
+pub mod ownership;
 pub mod registration;
 pub mod serving;
 pub mod subnet;
+pub mod sunset;
 pub mod symbols;
 pub mod uids;
 pub mod weights;
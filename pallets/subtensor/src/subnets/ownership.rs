@@ -0,0 +1,191 @@
+use super::*;
+use frame_support::ensure;
+use sp_core::hashing::blake2_256;
+use sp_runtime::traits::TrailingZeroInput;
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+    /// Derives the account controlled by a set of co-owners with a threshold.
+    ///
+    /// The derivation matches `pallet_multisig::Pallet::multi_account_id`, so the co-owners can
+    /// act as the subnet owner through the multisig pallet. `co_owners` must be sorted.
+    pub fn get_co_owners_account_id(co_owners: &[T::AccountId], threshold: u16) -> T::AccountId {
+        let entropy = (b"modlpy/utilisuba", co_owners, threshold).using_encoded(blake2_256);
+        T::AccountId::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+            .expect("infinite length input; no invalid inputs for type; qed")
+    }
+
+    /// Offers the ownership of a subnet to a new owner, who must accept it.
+    ///
+    /// # Args:
+    /// * 'origin': The current subnet owner.
+    /// * 'netuid': The subnet to transfer.
+    /// * 'new_owner': The coldkey that may accept the ownership.
+    ///
+    /// # Raises:
+    /// * 'SubnetSunsetting': The subnet is being sunset.
+    ///
+    /// # Event:
+    /// * SubnetOwnershipOffered;
+    pub fn do_offer_subnet_ownership(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        new_owner: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+        Self::ensure_subnet_not_sunsetting(netuid)?;
+
+        PendingSubnetOwner::<T>::insert(
+            netuid,
+            SubnetOwnershipOffer {
+                new_owner: new_owner.clone(),
+                co_owners: Vec::new(),
+                threshold: 0,
+            },
+        );
+
+        Self::deposit_event(Event::SubnetOwnershipOffered { netuid, new_owner });
+        Ok(())
+    }
+
+    /// Offers the ownership of a subnet to a set of co-owners.
+    ///
+    /// The offer is made to the multisig account of the co-owners, so at least `threshold` of
+    /// them must approve the acceptance.
+    ///
+    /// # Args:
+    /// * 'origin': The current subnet owner.
+    /// * 'netuid': The subnet to transfer.
+    /// * 'co_owners': The coldkeys that will jointly own the subnet.
+    /// * 'threshold': The number of co-owners required to act as the owner.
+    ///
+    /// # Raises:
+    /// * 'InvalidSubnetCoOwners': There are fewer than two or more than `MAX_SUBNET_CO_OWNERS`
+    ///   distinct co-owners.
+    /// * 'InvalidSubnetCoOwnerThreshold': The threshold is zero or exceeds the co-owner count.
+    /// * 'SubnetSunsetting': The subnet is being sunset.
+    ///
+    /// # Event:
+    /// * SubnetOwnershipOffered;
+    pub fn do_offer_subnet_ownership_to_co_owners(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        mut co_owners: Vec<T::AccountId>,
+        threshold: u16,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+        Self::ensure_subnet_not_sunsetting(netuid)?;
+
+        let count = co_owners.len();
+        co_owners.sort();
+        co_owners.dedup();
+        ensure!(
+            co_owners.len() == count && count >= 2 && count <= MAX_SUBNET_CO_OWNERS,
+            Error::<T>::InvalidSubnetCoOwners
+        );
+        ensure!(
+            threshold > 0 && usize::from(threshold) <= count,
+            Error::<T>::InvalidSubnetCoOwnerThreshold
+        );
+
+        let new_owner = Self::get_co_owners_account_id(&co_owners, threshold);
+        PendingSubnetOwner::<T>::insert(
+            netuid,
+            SubnetOwnershipOffer {
+                new_owner: new_owner.clone(),
+                co_owners,
+                threshold,
+            },
+        );
+
+        Self::deposit_event(Event::SubnetOwnershipOffered { netuid, new_owner });
+        Ok(())
+    }
+
+    /// Accepts a pending subnet ownership offer.
+    ///
+    /// # Args:
+    /// * 'origin': The account the ownership was offered to.
+    /// * 'netuid': The subnet to take ownership of.
+    /// * 'hotkey': The hotkey of the new owner, which receives the owner cut.
+    ///
+    /// # Raises:
+    /// * 'NoSubnetOwnershipOffer': There is no offer for the caller on this subnet.
+    /// * 'NonAssociatedColdKey': The hotkey is owned by another coldkey.
+    /// * 'SubnetSunsetting': The subnet is being sunset.
+    ///
+    /// # Event:
+    /// * SubnetOwnershipTransferred;
+    pub fn do_accept_subnet_ownership(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        hotkey: T::AccountId,
+    ) -> DispatchResult {
+        let who = ensure_signed(origin)?;
+        Self::ensure_subnet_not_sunsetting(netuid)?;
+
+        let offer = PendingSubnetOwner::<T>::get(netuid)
+            .filter(|offer| offer.new_owner == who)
+            .ok_or(Error::<T>::NoSubnetOwnershipOffer)?;
+        ensure!(
+            !Self::hotkey_account_exists(&hotkey) || Self::coldkey_owns_hotkey(&who, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        Self::create_account_if_non_existent(&who, &hotkey);
+        Self::transfer_subnet_ownership(netuid, who);
+        Self::set_subnet_owner_hotkey(netuid, &hotkey);
+        if !offer.co_owners.is_empty() {
+            SubnetCoOwners::<T>::insert(netuid, (offer.co_owners.clone(), offer.threshold));
+            Self::deposit_event(Event::SubnetCoOwnersSet {
+                netuid,
+                co_owners: offer.co_owners,
+                threshold: offer.threshold,
+            });
+        }
+        Ok(())
+    }
+
+    /// Cancels the pending ownership offer of a subnet.
+    ///
+    /// # Raises:
+    /// * 'NoSubnetOwnershipOffer': There is no pending offer on this subnet.
+    ///
+    /// # Event:
+    /// * SubnetOwnershipOfferCancelled;
+    pub fn do_cancel_subnet_ownership_offer(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+
+        ensure!(
+            PendingSubnetOwner::<T>::take(netuid).is_some(),
+            Error::<T>::NoSubnetOwnershipOffer
+        );
+
+        Self::deposit_event(Event::SubnetOwnershipOfferCancelled { netuid });
+        Ok(())
+    }
+
+    /// Sets the owner of a subnet, dropping any pending offer, co-owner set and sale listing.
+    pub fn transfer_subnet_ownership(netuid: u16, new_owner: T::AccountId) {
+        let old_owner = SubnetOwner::<T>::get(netuid);
+        SubnetOwner::<T>::insert(netuid, new_owner.clone());
+        PendingSubnetOwner::<T>::remove(netuid);
+        SubnetCoOwners::<T>::remove(netuid);
+        SubnetSaleListings::<T>::remove(netuid);
+
+        log::debug!(
+            "SubnetOwnershipTransferred( netuid: {:?}, old_owner: {:?}, new_owner: {:?} )",
+            netuid,
+            old_owner,
+            new_owner
+        );
+        Self::deposit_event(Event::SubnetOwnershipTransferred {
+            netuid,
+            old_owner,
+            new_owner,
+        });
+    }
+}
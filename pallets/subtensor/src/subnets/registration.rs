@@ -91,6 +91,7 @@ impl<T: Config> Pallet<T> {
             Self::get_network_registration_allowed(netuid),
             Error::<T>::SubNetRegistrationDisabled
         );
+        Self::ensure_subnet_not_sunsetting(netuid)?;
 
         // --- 4. Ensure we are not exceeding the max allowed registrations per block.
         ensure!(
@@ -250,6 +251,7 @@ impl<T: Config> Pallet<T> {
             Self::get_network_pow_registration_allowed(netuid),
             Error::<T>::SubNetRegistrationDisabled
         );
+        Self::ensure_subnet_not_sunsetting(netuid)?;
 
         // --- 4. Ensure we are not exceeding the max allowed registrations per block.
         ensure!(
@@ -0,0 +1,213 @@
+use super::*;
+use frame_support::{ensure, weights::Weight};
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+    /// Starts the sunset of a subnet.
+    ///
+    /// The subnet stops receiving emissions and accepting registrations and new stake right
+    /// away. Alpha holders can unstake at the pool price until the unstake window ends, after
+    /// which `sunset_subnets` unstakes the remaining alpha and removes the subnet in chunks.
+    ///
+    /// # Args:
+    /// * 'netuid': The subnet to sunset.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist': The subnet does not exist or is the root subnet.
+    /// * 'SubnetSunsetting': The subnet is already being sunset.
+    ///
+    /// # Event:
+    /// * SubnetSunsetStarted;
+    pub fn do_start_subnet_sunset(netuid: u16) -> DispatchResult {
+        ensure!(
+            netuid != Self::get_root_netuid() && Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            !SubnetSunset::<T>::contains_key(netuid),
+            Error::<T>::SubnetSunsetting
+        );
+
+        let unstake_window_ends =
+            Self::get_current_block_as_u64().saturating_add(SubnetSunsetUnstakeWindow::<T>::get());
+        SubnetSunset::<T>::insert(
+            netuid,
+            SubnetSunsetPhase::UnstakeWindow(unstake_window_ends),
+        );
+        SubnetSaleListings::<T>::remove(netuid);
+        PendingSubnetOwner::<T>::remove(netuid);
+
+        log::debug!(
+            "SubnetSunsetStarted( netuid: {:?}, unstake_window_ends: {:?} )",
+            netuid,
+            unstake_window_ends
+        );
+        Self::deposit_event(Event::SubnetSunsetStarted {
+            netuid,
+            unstake_window_ends,
+        });
+        Ok(())
+    }
+
+    /// Returns true if the subnet is being sunset.
+    pub fn is_subnet_sunsetting(netuid: u16) -> bool {
+        SubnetSunset::<T>::contains_key(netuid)
+    }
+
+    /// Ensures the subnet is not being sunset.
+    pub fn ensure_subnet_not_sunsetting(netuid: u16) -> Result<(), Error<T>> {
+        ensure!(
+            !Self::is_subnet_sunsetting(netuid),
+            Error::<T>::SubnetSunsetting
+        );
+        Ok(())
+    }
+
+    /// Advances every sunsetting subnet by one bounded step.
+    ///
+    /// Called on each block, returns the weight consumed.
+    pub fn sunset_subnets() -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        let current_block = Self::get_current_block_as_u64();
+
+        for (netuid, phase) in SubnetSunset::<T>::iter().collect::<Vec<_>>() {
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            match phase {
+                SubnetSunsetPhase::UnstakeWindow(ends_at) => {
+                    if current_block > ends_at {
+                        SubnetSunset::<T>::insert(netuid, SubnetSunsetPhase::Liquidating);
+                        weight.saturating_accrue(T::DbWeight::get().writes(1));
+                        Self::deposit_event(Event::SubnetUnstakeWindowClosed { netuid });
+                    }
+                }
+                SubnetSunsetPhase::Liquidating => {
+                    // Liquidation walks the position index, so it waits for the backfill.
+                    if !SubnetAlphaPositionsIndexCursor::<T>::exists() {
+                        weight.saturating_accrue(Self::liquidate_sunset_subnet(netuid));
+                    }
+                }
+                SubnetSunsetPhase::CleaningUp => {
+                    weight.saturating_accrue(Self::clean_up_sunset_subnet(netuid));
+                }
+            }
+        }
+
+        weight
+    }
+
+    /// Indexes the next chunk of `Alpha` entries into `SubnetAlphaPositions`.
+    ///
+    /// Runs on each block while `SubnetAlphaPositionsIndexCursor` is set. Entries the cursor
+    /// has passed are kept up to date by the share pool, so the index is complete once the
+    /// cursor reaches the end of `Alpha`.
+    pub fn index_subnet_alpha_positions() -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        let Some(cursor) = SubnetAlphaPositionsIndexCursor::<T>::get() else {
+            return weight;
+        };
+
+        let mut entries = Alpha::<T>::iter_from(cursor);
+        let mut exhausted = false;
+        for _ in 0..ALPHA_POSITIONS_INDEX_CHUNK_SIZE {
+            let Some(((hotkey, coldkey, netuid), share)) = entries.next() else {
+                exhausted = true;
+                break;
+            };
+            if share != 0 {
+                SubnetAlphaPositions::<T>::insert((netuid, &hotkey, &coldkey), ());
+                weight.saturating_accrue(T::DbWeight::get().writes(1));
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+        }
+
+        if exhausted {
+            SubnetAlphaPositionsIndexCursor::<T>::kill();
+            log::info!("Finished indexing the Alpha positions of each subnet.");
+        } else {
+            SubnetAlphaPositionsIndexCursor::<T>::put(entries.last_raw_key().to_vec());
+        }
+        weight.saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Unstakes the next chunk of alpha positions of a sunsetting subnet.
+    ///
+    /// The positions are taken from `SubnetAlphaPositions`, unstaked at the pool price without
+    /// fee and the TAO is credited to the coldkeys. No stake can enter the subnet once it is
+    /// sunsetting, so the subnet moves on to the cleanup once the index is empty.
+    fn liquidate_sunset_subnet(netuid: u16) -> Weight {
+        let mut weight = Weight::zero();
+
+        let positions: Vec<(T::AccountId, T::AccountId)> =
+            SubnetAlphaPositions::<T>::iter_key_prefix((netuid,))
+                .take(SUBNET_SUNSET_CHUNK_SIZE)
+                .collect();
+        weight.saturating_accrue(
+            T::DbWeight::get().reads(u64::try_from(positions.len()).unwrap_or(u64::MAX)),
+        );
+        let exhausted = positions.len() < SUBNET_SUNSET_CHUNK_SIZE;
+
+        for (hotkey, coldkey) in positions {
+            let alpha = Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+            if alpha > 0 {
+                let tao = Self::unstake_from_subnet(&hotkey, &coldkey, netuid, alpha, 0);
+                Self::add_balance_to_coldkey_account(&coldkey, tao);
+            }
+            Self::clear_small_nomination_if_required(&hotkey, &coldkey, netuid);
+
+            // Drop any share left by rounding so the position is fully removed.
+            Alpha::<T>::remove((&hotkey, &coldkey, netuid));
+            SubnetAlphaPositions::<T>::remove((netuid, &hotkey, &coldkey));
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(10, 10));
+        }
+
+        if exhausted {
+            SubnetSunset::<T>::insert(netuid, SubnetSunsetPhase::CleaningUp);
+            Self::deposit_event(Event::SubnetLiquidated { netuid });
+        }
+        weight.saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Removes the next chunk of the per-neuron storage of a sunsetting subnet.
+    ///
+    /// Once these are empty the rest of the subnet is removed with `remove_network`, whose
+    /// prefix removals then have nothing left to clear.
+    fn clean_up_sunset_subnet(netuid: u16) -> Weight {
+        let limit = u32::try_from(SUBNET_SUNSET_CHUNK_SIZE).unwrap_or(u32::MAX);
+        let mut weight = Weight::zero();
+
+        // Keys are removed in key order, so the chunk cleared below is the one iterated here.
+        for (_uid, hotkey) in Keys::<T>::iter_prefix(netuid).take(SUBNET_SUNSET_CHUNK_SIZE) {
+            Uids::<T>::remove(netuid, &hotkey);
+            IsNetworkMember::<T>::remove(&hotkey, netuid);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+        let results = [
+            Keys::<T>::clear_prefix(netuid, limit, None),
+            Uids::<T>::clear_prefix(netuid, limit, None),
+            Bonds::<T>::clear_prefix(netuid, limit, None),
+            Weights::<T>::clear_prefix(netuid, limit, None),
+            AssociatedEvmAddress::<T>::clear_prefix(netuid, limit, None),
+            AssociatedEvmContract::<T>::clear_prefix(netuid, limit, None),
+            PendingChildKeyTerms::<T>::clear_prefix(netuid, limit, None),
+            ChildKeyAcceptances::<T>::clear_prefix((netuid,), limit, None),
+            ActiveChildKeyTerms::<T>::clear_prefix((netuid,), limit, None),
+        ];
+        let mut done = true;
+        for result in results.iter() {
+            weight.saturating_accrue(
+                T::DbWeight::get().reads_writes(u64::from(result.loops), u64::from(result.unique)),
+            );
+            done = done && result.maybe_cursor.is_none();
+        }
+
+        if done {
+            Self::remove_network(netuid);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(60, 50));
+
+            log::debug!("NetworkRemoved( netuid:{:?} )", netuid);
+            Self::deposit_event(Event::NetworkRemoved(netuid));
+            Self::deposit_event(Event::SubnetSunsetCompleted { netuid });
+        }
+        weight
+    }
+}
@@ -79,6 +79,10 @@ impl<T: Config> Pallet<T> {
 
         // 5a. reset axon info for the new uid.
         Axons::<T>::remove(netuid, old_hotkey);
+
+        // 6. Drop the EVM key and contract associated with the old hotkey.
+        AssociatedEvmAddress::<T>::remove(netuid, uid_to_replace);
+        AssociatedEvmContract::<T>::remove(netuid, uid_to_replace);
     }
 
     /// Appends the uid to the network.
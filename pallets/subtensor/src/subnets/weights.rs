@@ -1,6 +1,7 @@
 use super::*;
 use crate::epoch::math::*;
 use codec::Compact;
+use pallet_drand::types::BeaconId;
 use safe_math::*;
 use sp_core::{ConstU32, H256};
 use sp_runtime::{
@@ -53,6 +54,10 @@ impl<T: Config> Pallet<T> {
             Self::get_commit_reveal_weights_enabled(netuid),
             Error::<T>::CommitRevealDisabled
         );
+        ensure!(
+            pallet_drand::Pallet::<T>::beacon_config(beacon_id).is_some(),
+            Error::<T>::UnknownDrandBeacon
+        );
 
         // 3. Ensure the hotkey is registered on the network.
         ensure!(
@@ -206,6 +211,9 @@ impl<T: Config> Pallet<T> {
     ///        to produce a [`TLECiphertext<TinyBLS381>`] type.
     ///     4. Serialize and compress using the `ark-serialize` `CanonicalSerialize` trait.
     ///
+    /// * beacon_id (`u16`):
+    ///    - The drand beacon the commit is encrypted to.
+    ///
     /// * reveal_round (`u64`):
     ///    - The drand reveal round which will be avaliable during epoch `n+1` from the current
     ///      epoch.
@@ -214,6 +222,9 @@ impl<T: Config> Pallet<T> {
     /// * `CommitRevealDisabled`:
     ///   - Raised if commit-reveal v3 is disabled for the specified network.
     ///
+    /// * `UnknownDrandBeacon`:
+    ///   - Raised if the drand beacon is not bridged.
+    ///
     /// * `HotKeyNotRegisteredInSubNet`:
     ///   - Raised if the hotkey is not registered on the specified network.
     ///
@@ -230,6 +241,7 @@ impl<T: Config> Pallet<T> {
         origin: T::RuntimeOrigin,
         netuid: u16,
         commit: BoundedVec<u8, ConstU32<MAX_CRV3_COMMIT_SIZE_BYTES>>,
+        beacon_id: BeaconId,
         reveal_round: u64,
     ) -> DispatchResult {
         // 1. Verify the caller's signature (hotkey).
@@ -246,6 +258,10 @@ impl<T: Config> Pallet<T> {
             Self::get_commit_reveal_weights_enabled(netuid),
             Error::<T>::CommitRevealDisabled
         );
+        ensure!(
+            pallet_drand::Pallet::<T>::beacon_config(beacon_id).is_some(),
+            Error::<T>::UnknownDrandBeacon
+        );
 
         // 3. Ensure the hotkey is registered on the network.
         ensure!(
@@ -273,7 +289,7 @@ impl<T: Config> Pallet<T> {
 
             let unrevealed_commits_for_who = commits
                 .iter()
-                .filter(|(account, _, _)| account == &who)
+                .filter(|(account, _, _, _)| account == &who)
                 .count();
             ensure!(
                 unrevealed_commits_for_who < 10,
@@ -283,7 +299,9 @@ impl<T: Config> Pallet<T> {
             // 7. Append the new commit with calculated reveal blocks.
             // Hash the commit before it is moved, for the event
             let commit_hash = BlakeTwo256::hash(&commit);
-            commits.push_back((who.clone(), commit, reveal_round));
+            commits.push_back((who.clone(), commit, beacon_id, reveal_round));
+            // Keep the drand pulse the commit is revealed with
+            pallet_drand::Pallet::<T>::reference_round(beacon_id, reveal_round);
 
             // 8. Emit the WeightsCommitted event
             Self::deposit_event(Event::CRV3WeightsCommitted(
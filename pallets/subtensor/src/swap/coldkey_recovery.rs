@@ -0,0 +1,358 @@
+use super::*;
+use frame_support::traits::schedule::{DispatchTime, v3::Anon as ScheduleAnon};
+use frame_support::weights::Weight;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_core::Get;
+use sp_runtime::Saturating;
+
+impl<T: Config> Pallet<T> {
+    /// Sets the guardians able to recover the coldkey of the caller, replacing any previous
+    /// ones and dropping a pending recovery. An empty list of guardians removes them.
+    ///
+    /// # Errors
+    /// * `InvalidColdkeyGuardians` - If the threshold is zero or above the number of guardians,
+    ///   a guardian is repeated or is the coldkey itself, or there are too many guardians.
+    ///
+    /// # Events
+    /// Emits a `ColdkeyGuardiansSet` event on success.
+    pub fn do_set_coldkey_guardians(
+        origin: T::RuntimeOrigin,
+        guardians: Vec<T::AccountId>,
+        threshold: u16,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        if guardians.is_empty() {
+            ColdkeyGuardians::<T>::remove(&coldkey);
+        } else {
+            ensure!(
+                guardians.len() <= MAX_COLDKEY_GUARDIANS
+                    && threshold > 0
+                    && usize::from(threshold) <= guardians.len()
+                    && !guardians.contains(&coldkey),
+                Error::<T>::InvalidColdkeyGuardians
+            );
+            for (i, guardian) in guardians.iter().enumerate() {
+                ensure!(
+                    !guardians
+                        .iter()
+                        .skip(i.saturating_add(1))
+                        .any(|g| g == guardian),
+                    Error::<T>::InvalidColdkeyGuardians
+                );
+            }
+
+            ColdkeyGuardians::<T>::insert(
+                &coldkey,
+                ColdkeyGuardianSet {
+                    guardians: guardians.clone(),
+                    threshold,
+                },
+            );
+        }
+        PendingColdkeyRecoveries::<T>::remove(&coldkey);
+
+        Self::deposit_event(Event::ColdkeyGuardiansSet {
+            coldkey,
+            guardians,
+            threshold,
+        });
+        Ok(())
+    }
+
+    /// Approves, as a guardian, the recovery of `coldkey` to `new_coldkey`. Approvals are
+    /// counted per new coldkey, and a guardian approving another new coldkey moves its
+    /// approval. Once the approvals for a new coldkey reach the threshold, the swap is scheduled
+    /// after the coldkey swap delay, during which the coldkey can still cancel it.
+    ///
+    /// # Errors
+    /// * `NotColdkeyGuardian` - If the caller is not a guardian of the coldkey.
+    /// * `NewColdKeyIsHotkey` - If the new coldkey is a hotkey.
+    /// * `ColdkeyRecoveryAlreadyApproved` - If the guardian already approved this new coldkey.
+    /// * `FailedToSchedule` - If the swap could not be scheduled.
+    ///
+    /// # Events
+    /// Emits a `ColdkeyRecoveryApproved` event, and a `ColdkeyRecoveryScheduled` event once the
+    /// threshold is reached.
+    pub fn do_approve_coldkey_recovery(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+        new_coldkey: T::AccountId,
+    ) -> DispatchResult {
+        let guardian = ensure_signed(origin)?;
+
+        let guardian_set =
+            ColdkeyGuardians::<T>::get(&coldkey).ok_or(Error::<T>::NotColdkeyGuardian)?;
+        ensure!(
+            guardian_set.guardians.contains(&guardian),
+            Error::<T>::NotColdkeyGuardian
+        );
+        ensure!(
+            !Self::hotkey_account_exists(&new_coldkey),
+            Error::<T>::NewColdKeyIsHotkey
+        );
+
+        let mut recovery =
+            PendingColdkeyRecoveries::<T>::get(&coldkey).unwrap_or_else(|| ColdkeyRecovery {
+                approvals: Vec::new(),
+                scheduled: None,
+            });
+        ensure!(
+            !recovery
+                .approvals
+                .contains(&(guardian.clone(), new_coldkey.clone())),
+            Error::<T>::ColdkeyRecoveryAlreadyApproved
+        );
+        recovery
+            .approvals
+            .retain(|(approver, _)| *approver != guardian);
+        recovery
+            .approvals
+            .push((guardian.clone(), new_coldkey.clone()));
+
+        Self::deposit_event(Event::ColdkeyRecoveryApproved {
+            coldkey: coldkey.clone(),
+            guardian,
+            new_coldkey,
+        });
+
+        Self::update_coldkey_recovery_schedule(&coldkey, &mut recovery, guardian_set.threshold)?;
+
+        PendingColdkeyRecoveries::<T>::insert(&coldkey, recovery);
+        Ok(())
+    }
+
+    /// Withdraws the approval of the caller, as a guardian, from the pending recovery of
+    /// `coldkey`. The scheduled swap is dropped if its new coldkey falls below the threshold.
+    ///
+    /// # Errors
+    /// * `NoPendingColdkeyRecovery` - If the coldkey has no pending recovery.
+    /// * `ColdkeyRecoveryNotApproved` - If the caller has not approved the recovery.
+    /// * `NotColdkeyGuardian` - If the coldkey has no guardians.
+    /// * `FailedToSchedule` - If another new coldkey reaching the threshold could not be
+    ///   scheduled.
+    ///
+    /// # Events
+    /// Emits a `ColdkeyRecoveryApprovalWithdrawn` event, and a `ColdkeyRecoveryUnscheduled`
+    /// event if the scheduled swap is dropped.
+    pub fn do_withdraw_coldkey_recovery_approval(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+    ) -> DispatchResult {
+        let guardian = ensure_signed(origin)?;
+
+        let mut recovery = PendingColdkeyRecoveries::<T>::get(&coldkey)
+            .ok_or(Error::<T>::NoPendingColdkeyRecovery)?;
+        let approvals = recovery.approvals.len();
+        recovery
+            .approvals
+            .retain(|(approver, _)| *approver != guardian);
+        ensure!(
+            recovery.approvals.len() < approvals,
+            Error::<T>::ColdkeyRecoveryNotApproved
+        );
+        let guardian_set =
+            ColdkeyGuardians::<T>::get(&coldkey).ok_or(Error::<T>::NotColdkeyGuardian)?;
+
+        Self::deposit_event(Event::ColdkeyRecoveryApprovalWithdrawn {
+            coldkey: coldkey.clone(),
+            guardian,
+        });
+
+        Self::update_coldkey_recovery_schedule(&coldkey, &mut recovery, guardian_set.threshold)?;
+
+        if recovery.approvals.is_empty() {
+            PendingColdkeyRecoveries::<T>::remove(&coldkey);
+        } else {
+            PendingColdkeyRecoveries::<T>::insert(&coldkey, recovery);
+        }
+        Ok(())
+    }
+
+    /// Cancels the pending recovery of the coldkey of the caller.
+    ///
+    /// # Errors
+    /// * `NoPendingColdkeyRecovery` - If the coldkey has no pending recovery.
+    ///
+    /// # Events
+    /// Emits a `ColdkeyRecoveryCancelled` event on success.
+    pub fn do_cancel_coldkey_recovery(origin: T::RuntimeOrigin) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            PendingColdkeyRecoveries::<T>::take(&coldkey).is_some(),
+            Error::<T>::NoPendingColdkeyRecovery
+        );
+
+        Self::deposit_event(Event::ColdkeyRecoveryCancelled { coldkey });
+        Ok(())
+    }
+
+    /// Executes a scheduled coldkey recovery once its delay has passed. The swap cost is paid
+    /// by the recovered coldkey, and the guardians move with the account. Stake already held by
+    /// the new coldkey is merged with the recovered one.
+    ///
+    /// Anyone can register the new coldkey as a hotkey during the delay. The recovery is then
+    /// unscheduled and the approvals for that coldkey dropped, so the guardians can approve
+    /// another one instead of the execution failing forever.
+    ///
+    /// A contested coldkey swap waits for governance, so the recovery cannot execute until the
+    /// contest is resolved. The recovery drops any swap the coldkey still has scheduled.
+    ///
+    /// # Errors
+    /// * `NoPendingColdkeyRecovery` - If the coldkey has no pending recovery.
+    /// * `ColdkeyRecoveryNotDue` - If the threshold was not reached or the delay has not passed.
+    /// * `ColdkeySwapContested` - If the coldkey has a contested swap.
+    /// * `NotEnoughBalanceToPaySwapColdKey` - If the coldkey cannot pay the swap cost.
+    ///
+    /// # Events
+    /// Emits a `ColdkeyRecovered` event on success, or a `ColdkeyRecoveryUnscheduled` event if
+    /// the new coldkey has become a hotkey.
+    pub fn do_execute_coldkey_recovery(coldkey: &T::AccountId) -> DispatchResultWithPostInfo {
+        let mut weight: Weight = T::DbWeight::get().reads(1);
+
+        let mut recovery = PendingColdkeyRecoveries::<T>::get(coldkey)
+            .ok_or(Error::<T>::NoPendingColdkeyRecovery)?;
+        let (new_coldkey, execution_block) = recovery
+            .scheduled
+            .clone()
+            .ok_or(Error::<T>::ColdkeyRecoveryNotDue)?;
+        ensure!(
+            <frame_system::Pallet<T>>::block_number() >= execution_block,
+            Error::<T>::ColdkeyRecoveryNotDue
+        );
+        ensure!(
+            !ContestedColdkeySwaps::<T>::contains_key(coldkey),
+            Error::<T>::ColdkeySwapContested
+        );
+
+        weight.saturating_accrue(T::DbWeight::get().reads(2));
+        if Self::hotkey_account_exists(&new_coldkey) {
+            recovery
+                .approvals
+                .retain(|(_, approved_coldkey)| *approved_coldkey != new_coldkey);
+            recovery.scheduled = None;
+            Self::deposit_event(Event::ColdkeyRecoveryUnscheduled {
+                coldkey: coldkey.clone(),
+                new_coldkey,
+            });
+
+            if let Some(guardian_set) = ColdkeyGuardians::<T>::get(coldkey) {
+                Self::update_coldkey_recovery_schedule(
+                    coldkey,
+                    &mut recovery,
+                    guardian_set.threshold,
+                )?;
+            }
+            if recovery.approvals.is_empty() {
+                PendingColdkeyRecoveries::<T>::remove(coldkey);
+            } else {
+                PendingColdkeyRecoveries::<T>::insert(coldkey, recovery);
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+            return Ok(Some(weight).into());
+        }
+
+        // Pay the swap cost from the recovered coldkey.
+        let swap_cost = Self::get_key_swap_cost();
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(coldkey, swap_cost),
+            Error::<T>::NotEnoughBalanceToPaySwapColdKey
+        );
+        let actual_burn_amount = Self::remove_balance_from_coldkey_account(coldkey, swap_cost)?;
+        Self::burn_tokens(actual_burn_amount);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+        PendingColdkeyRecoveries::<T>::remove(coldkey);
+        ColdkeySwapScheduled::<T>::remove(coldkey);
+        if let Some(identity) = IdentitiesV2::<T>::take(coldkey) {
+            IdentitiesV2::<T>::insert(&new_coldkey, identity);
+        }
+        if let Some(guardian_set) = ColdkeyGuardians::<T>::take(coldkey) {
+            ColdkeyGuardians::<T>::insert(&new_coldkey, guardian_set);
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 6));
+
+        Self::perform_swap_coldkey(coldkey, &new_coldkey, &mut weight)?;
+
+        Self::set_last_tx_block(&new_coldkey, Self::get_current_block_as_u64());
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+        Self::deposit_event(Event::ColdkeyRecovered {
+            old_coldkey: coldkey.clone(),
+            new_coldkey,
+            swap_cost,
+        });
+
+        Ok(Some(weight).into())
+    }
+
+    /// Unschedules the swap of `recovery` if its new coldkey no longer reaches the `threshold`,
+    /// then schedules the first new coldkey reaching it if no swap is scheduled.
+    fn update_coldkey_recovery_schedule(
+        coldkey: &T::AccountId,
+        recovery: &mut ColdkeyRecovery<T::AccountId, BlockNumberFor<T>>,
+        threshold: u16,
+    ) -> DispatchResult {
+        let approvals_for = |approvals: &[(T::AccountId, T::AccountId)],
+                             new_coldkey: &T::AccountId| {
+            approvals
+                .iter()
+                .filter(|(_, approved_coldkey)| approved_coldkey == new_coldkey)
+                .count()
+        };
+
+        if let Some((new_coldkey, _)) = recovery.scheduled.clone() {
+            if approvals_for(&recovery.approvals, &new_coldkey) < usize::from(threshold) {
+                recovery.scheduled = None;
+                Self::deposit_event(Event::ColdkeyRecoveryUnscheduled {
+                    coldkey: coldkey.clone(),
+                    new_coldkey,
+                });
+            }
+        }
+        if recovery.scheduled.is_some() {
+            return Ok(());
+        }
+
+        let Some(new_coldkey) = recovery
+            .approvals
+            .iter()
+            .map(|(_, approved_coldkey)| approved_coldkey)
+            .find(|approved_coldkey| {
+                approvals_for(&recovery.approvals, approved_coldkey) >= usize::from(threshold)
+            })
+            .cloned()
+        else {
+            return Ok(());
+        };
+
+        let current_block: BlockNumberFor<T> = <frame_system::Pallet<T>>::block_number();
+        let when: BlockNumberFor<T> =
+            current_block.saturating_add(ColdkeySwapScheduleDuration::<T>::get());
+
+        let call = Call::<T>::execute_coldkey_recovery {
+            coldkey: coldkey.clone(),
+        };
+        let bound_call = <T as Config>::Preimages::bound(LocalCallOf::<T>::from(call))
+            .map_err(|_| Error::<T>::FailedToSchedule)?;
+        T::Scheduler::schedule(
+            DispatchTime::At(when),
+            None,
+            63,
+            frame_system::RawOrigin::Root.into(),
+            bound_call,
+        )
+        .map_err(|_| Error::<T>::FailedToSchedule)?;
+
+        recovery.scheduled = Some((new_coldkey.clone(), when));
+        Self::deposit_event(Event::ColdkeyRecoveryScheduled {
+            coldkey: coldkey.clone(),
+            new_coldkey,
+            execution_block: when,
+        });
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
 use super::*;
+pub mod coldkey_recovery;
 pub mod swap_coldkey;
 pub mod swap_hotkey;
@@ -172,6 +172,10 @@ impl<T: Config> Pallet<T> {
                 );
                 // Remove the value from the old account.
                 Alpha::<T>::remove((&hotkey, old_coldkey, netuid));
+                // Move the position in the subnet index.
+                if SubnetAlphaPositions::<T>::take((netuid, &hotkey, old_coldkey)).is_some() {
+                    SubnetAlphaPositions::<T>::insert((netuid, &hotkey, new_coldkey), ());
+                }
             }
             // Add the weight for the read and write.
             weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
@@ -236,4 +240,134 @@ impl<T: Config> Pallet<T> {
         // Return ok.
         Ok(())
     }
+
+    /// Ensures the scheduled swap of `old_coldkey` to `new_coldkey` can execute: it is still
+    /// scheduled, not contested, and its execution block has been reached.
+    ///
+    /// # Errors
+    /// * `ColdkeySwapNotScheduled` - If the swap was cancelled, rescheduled or is not due yet.
+    /// * `ColdkeySwapContested` - If the swap is contested.
+    pub fn ensure_scheduled_coldkey_swap(
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+    ) -> DispatchResult {
+        ensure!(
+            ColdkeySwapScheduled::<T>::contains_key(old_coldkey),
+            Error::<T>::ColdkeySwapNotScheduled
+        );
+        let (execution_block, scheduled_coldkey) = ColdkeySwapScheduled::<T>::get(old_coldkey);
+        ensure!(
+            scheduled_coldkey == *new_coldkey
+                && <frame_system::Pallet<T>>::block_number() >= execution_block,
+            Error::<T>::ColdkeySwapNotScheduled
+        );
+        ensure!(
+            !ContestedColdkeySwaps::<T>::contains_key(old_coldkey),
+            Error::<T>::ColdkeySwapContested
+        );
+        Ok(())
+    }
+
+    /// Cancels the scheduled swap of the coldkey of the caller, lifting the restrictions on
+    /// the coldkey. A contested swap can only be decided by governance.
+    ///
+    /// # Errors
+    /// * `ColdkeySwapNotScheduled` - If the coldkey has no scheduled swap.
+    /// * `ColdkeySwapContested` - If the swap is contested.
+    ///
+    /// # Events
+    /// Emits a `ColdkeySwapCancelled` event on success.
+    pub fn do_cancel_coldkey_swap(origin: T::RuntimeOrigin) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            ColdkeySwapScheduled::<T>::contains_key(&coldkey),
+            Error::<T>::ColdkeySwapNotScheduled
+        );
+        ensure!(
+            !ContestedColdkeySwaps::<T>::contains_key(&coldkey),
+            Error::<T>::ColdkeySwapContested
+        );
+        let (_, new_coldkey) = ColdkeySwapScheduled::<T>::take(&coldkey);
+
+        Self::deposit_event(Event::ColdkeySwapCancelled {
+            old_coldkey: coldkey,
+            new_coldkey,
+        });
+        Ok(())
+    }
+
+    /// Contests the scheduled swap of the coldkey of the caller. The swap is frozen, the swap
+    /// cost is held in escrow and the swap waits for a governance decision.
+    ///
+    /// # Errors
+    /// * `ColdkeySwapNotScheduled` - If the coldkey has no scheduled swap.
+    /// * `ColdkeySwapContested` - If the swap is already contested.
+    /// * `NotEnoughBalanceToPaySwapColdKey` - If the coldkey cannot pay the swap cost.
+    ///
+    /// # Events
+    /// Emits a `ColdkeySwapContested` event on success.
+    pub fn do_contest_coldkey_swap(origin: T::RuntimeOrigin) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            ColdkeySwapScheduled::<T>::contains_key(&coldkey),
+            Error::<T>::ColdkeySwapNotScheduled
+        );
+        ensure!(
+            !ContestedColdkeySwaps::<T>::contains_key(&coldkey),
+            Error::<T>::ColdkeySwapContested
+        );
+
+        let swap_cost = Self::get_key_swap_cost();
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(&coldkey, swap_cost),
+            Error::<T>::NotEnoughBalanceToPaySwapColdKey
+        );
+        let escrow = Self::remove_balance_from_coldkey_account(&coldkey, swap_cost)?;
+        ContestedColdkeySwaps::<T>::insert(&coldkey, escrow);
+
+        let (_, new_coldkey) = ColdkeySwapScheduled::<T>::get(&coldkey);
+        Self::deposit_event(Event::ColdkeySwapContested {
+            old_coldkey: coldkey,
+            new_coldkey,
+            escrow,
+        });
+        Ok(())
+    }
+
+    /// Decides a contested coldkey swap. An approved swap executes immediately and the escrow
+    /// pays its cost. A rejected swap is removed and the escrow is refunded to the coldkey.
+    ///
+    /// # Errors
+    /// * `ColdkeySwapNotContested` - If the swap of the coldkey is not contested.
+    /// * Any error of `do_swap_coldkey` if the approved swap cannot execute.
+    ///
+    /// # Events
+    /// Emits a `ColdkeySwapContestResolved` event on success, and a `ColdkeySwapped` event if
+    /// the swap was approved.
+    pub fn do_resolve_coldkey_swap_contest(
+        coldkey: &T::AccountId,
+        approve: bool,
+    ) -> DispatchResultWithPostInfo {
+        let escrow =
+            ContestedColdkeySwaps::<T>::take(coldkey).ok_or(Error::<T>::ColdkeySwapNotContested)?;
+        let (_, new_coldkey) = ColdkeySwapScheduled::<T>::get(coldkey);
+
+        // The escrow goes back to the coldkey, which pays the swap cost if approved.
+        Self::add_balance_to_coldkey_account(coldkey, escrow);
+        let post_info = if approve {
+            Self::do_swap_coldkey(coldkey, &new_coldkey, escrow)?
+        } else {
+            ColdkeySwapScheduled::<T>::remove(coldkey);
+            ().into()
+        };
+
+        Self::deposit_event(Event::ColdkeySwapContestResolved {
+            old_coldkey: coldkey.clone(),
+            new_coldkey,
+            approved: approve,
+        });
+        Ok(post_info)
+    }
 }
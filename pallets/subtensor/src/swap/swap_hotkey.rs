@@ -520,6 +520,50 @@ impl<T: Config> Pallet<T> {
             }
         }
 
+        // 6.1. Swap the child-key terms and acceptances.
+        // PendingChildKeyTerms( netuid, parent ) --> terms
+        if let Ok(terms) = PendingChildKeyTerms::<T>::try_get(netuid, old_hotkey) {
+            PendingChildKeyTerms::<T>::remove(netuid, old_hotkey);
+            PendingChildKeyTerms::<T>::insert(netuid, new_hotkey, terms);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+
+        // ChildKeyAcceptances( netuid, parent, child ) --> ()
+        let acceptances: Vec<(T::AccountId, T::AccountId)> =
+            ChildKeyAcceptances::<T>::iter_key_prefix((netuid,))
+                .filter(|(parent, child)| parent == old_hotkey || child == old_hotkey)
+                .collect();
+        for (parent, child) in acceptances {
+            ChildKeyAcceptances::<T>::remove((netuid, &parent, &child));
+            ChildKeyAcceptances::<T>::insert(
+                (
+                    netuid,
+                    Self::swapped_key(parent, old_hotkey, new_hotkey),
+                    Self::swapped_key(child, old_hotkey, new_hotkey),
+                ),
+                (),
+            );
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+
+        // ActiveChildKeyTerms( netuid, parent, child ) --> (terms, expires_at)
+        let active_terms: Vec<((T::AccountId, T::AccountId), (ChildKeyTerms, u64))> =
+            ActiveChildKeyTerms::<T>::iter_prefix((netuid,))
+                .filter(|((parent, child), _)| parent == old_hotkey || child == old_hotkey)
+                .collect();
+        for ((parent, child), value) in active_terms {
+            ActiveChildKeyTerms::<T>::remove((netuid, &parent, &child));
+            ActiveChildKeyTerms::<T>::insert(
+                (
+                    netuid,
+                    Self::swapped_key(parent, old_hotkey, new_hotkey),
+                    Self::swapped_key(child, old_hotkey, new_hotkey),
+                ),
+                value,
+            );
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+
         // 7. Swap SubnetOwnerHotkey
         // SubnetOwnerHotkey( netuid ) --> hotkey -- the hotkey that is the owner of the subnet.
         if let Ok(old_subnet_owner_hotkey) = SubnetOwnerHotkey::<T>::try_get(netuid) {
@@ -579,7 +623,9 @@ impl<T: Config> Pallet<T> {
                     (new_hotkey, &coldkey, netuid),
                     alpha.saturating_add(new_alpha),
                 );
-                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+                SubnetAlphaPositions::<T>::remove((netuid, old_hotkey, &coldkey));
+                SubnetAlphaPositions::<T>::insert((netuid, new_hotkey, &coldkey), ());
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 4));
 
                 // Swap StakingHotkeys.
                 // StakingHotkeys( coldkey ) --> Vec<hotkey> -- the hotkeys that the coldkey stakes.
@@ -592,5 +638,29 @@ impl<T: Config> Pallet<T> {
                 }
             }
         }
+
+        // 10. Swap the alpha token wrapping the position, unless the new hotkey has one already.
+        // AlphaTokenIds( hotkey, netuid ) --> token_id
+        weight.saturating_accrue(T::DbWeight::get().reads(2));
+        if !AlphaTokenIds::<T>::contains_key(new_hotkey, netuid) {
+            if let Some(token_id) = AlphaTokenIds::<T>::take(old_hotkey, netuid) {
+                AlphaTokenIds::<T>::insert(new_hotkey, netuid, token_id);
+                AlphaTokens::<T>::insert(token_id, (new_hotkey.clone(), netuid));
+                weight.saturating_accrue(T::DbWeight::get().writes(3));
+            }
+        }
+    }
+
+    /// Returns `new_hotkey` if `key` is the swapped hotkey, `key` otherwise.
+    fn swapped_key(
+        key: T::AccountId,
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+    ) -> T::AccountId {
+        if key == *old_hotkey {
+            new_hotkey.clone()
+        } else {
+            key
+        }
     }
 }
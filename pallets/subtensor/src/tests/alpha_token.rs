@@ -0,0 +1,276 @@
+use frame_support::weights::Weight;
+use frame_support::{assert_noop, assert_ok};
+use sp_core::U256;
+
+use super::mock::*;
+use crate::*;
+
+fn setup_position() -> (U256, U256, u16, u64) {
+    let owner_coldkey = U256::from(1001);
+    let owner_hotkey = U256::from(1002);
+    let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+
+    let coldkey = U256::from(1);
+    let hotkey = U256::from(2);
+    SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+    SubtensorModule::stake_into_subnet(
+        &hotkey,
+        &coldkey,
+        netuid,
+        DefaultMinStake::<Test>::get() * 10,
+        0,
+    );
+    let alpha =
+        SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+
+    (coldkey, hotkey, netuid, alpha)
+}
+
+#[test]
+fn test_register_alpha_token() {
+    new_test_ext(1).execute_with(|| {
+        let (coldkey, hotkey, netuid, _) = setup_position();
+
+        assert_ok!(SubtensorModule::register_alpha_token(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid
+        ));
+        assert_eq!(
+            SubtensorModule::get_alpha_token_id(&hotkey, netuid),
+            Some(0)
+        );
+        assert_eq!(SubtensorModule::get_alpha_token(0), Some((hotkey, netuid)));
+        assert_eq!(NextAlphaTokenId::<Test>::get(), 1);
+
+        assert_noop!(
+            SubtensorModule::register_alpha_token(RuntimeOrigin::signed(coldkey), hotkey, netuid),
+            Error::<Test>::AlphaTokenAlreadyRegistered
+        );
+        assert_noop!(
+            SubtensorModule::register_alpha_token(
+                RuntimeOrigin::signed(coldkey),
+                U256::from(12345),
+                netuid
+            ),
+            Error::<Test>::HotKeyAccountNotExists
+        );
+        assert_noop!(
+            SubtensorModule::register_alpha_token(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                SubtensorModule::get_root_netuid()
+            ),
+            Error::<Test>::AlphaTokenOnRootSubnet
+        );
+    });
+}
+
+#[test]
+fn test_transfer_alpha_from_spends_allowance() {
+    new_test_ext(1).execute_with(|| {
+        let (coldkey, hotkey, netuid, alpha) = setup_position();
+        let spender = U256::from(3);
+        let destination = U256::from(4);
+
+        assert_ok!(SubtensorModule::register_alpha_token(
+            RuntimeOrigin::signed(spender),
+            hotkey,
+            netuid
+        ));
+        assert_ok!(SubtensorModule::approve_alpha(
+            RuntimeOrigin::signed(coldkey),
+            0,
+            spender,
+            alpha
+        ));
+        assert_eq!(
+            SubtensorModule::get_alpha_allowance(0, &coldkey, &spender),
+            alpha
+        );
+
+        let total_hotkey_alpha = TotalHotkeyAlpha::<Test>::get(hotkey, netuid);
+        let subnet_tao = SubnetTAO::<Test>::get(netuid);
+
+        let amount = alpha / 2;
+        assert_ok!(SubtensorModule::transfer_alpha_from(
+            RuntimeOrigin::signed(spender),
+            0,
+            coldkey,
+            destination,
+            amount
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_alpha_allowance(0, &coldkey, &spender),
+            alpha - amount
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey,
+                &destination,
+                netuid
+            ),
+            amount
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid),
+            alpha - amount
+        );
+        assert_eq!(
+            TotalHotkeyAlpha::<Test>::get(hotkey, netuid),
+            total_hotkey_alpha
+        );
+        assert_eq!(SubnetTAO::<Test>::get(netuid), subnet_tao);
+    });
+}
+
+#[test]
+fn test_transfer_alpha_moves_stake_without_fee() {
+    new_test_ext(1).execute_with(|| {
+        let (coldkey, hotkey, netuid, alpha) = setup_position();
+        let destination = U256::from(4);
+
+        assert_ok!(SubtensorModule::register_alpha_token(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid
+        ));
+
+        let total_hotkey_alpha = TotalHotkeyAlpha::<Test>::get(hotkey, netuid);
+        let subnet_tao = SubnetTAO::<Test>::get(netuid);
+        let subnet_alpha_in = SubnetAlphaIn::<Test>::get(netuid);
+
+        let amount = alpha / 2;
+        assert_ok!(SubtensorModule::transfer_alpha(
+            RuntimeOrigin::signed(coldkey),
+            0,
+            destination,
+            amount
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey,
+                &destination,
+                netuid
+            ),
+            amount
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid),
+            alpha - amount
+        );
+
+        // Nothing went through the pool.
+        assert_eq!(
+            TotalHotkeyAlpha::<Test>::get(hotkey, netuid),
+            total_hotkey_alpha
+        );
+        assert_eq!(SubnetTAO::<Test>::get(netuid), subnet_tao);
+        assert_eq!(SubnetAlphaIn::<Test>::get(netuid), subnet_alpha_in);
+
+        System::assert_last_event(
+            Event::AlphaTransferred {
+                token_id: 0,
+                from: coldkey,
+                to: destination,
+                amount,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            SubtensorModule::transfer_alpha(RuntimeOrigin::signed(coldkey), 0, coldkey, amount),
+            Error::<Test>::SameNetuid
+        );
+        assert_noop!(
+            SubtensorModule::transfer_alpha(RuntimeOrigin::signed(coldkey), 1, destination, amount),
+            Error::<Test>::AlphaTokenNotFound
+        );
+        assert_noop!(
+            SubtensorModule::transfer_alpha(RuntimeOrigin::signed(coldkey), 0, destination, alpha),
+            Error::<Test>::NotEnoughStakeToWithdraw
+        );
+    });
+}
+
+#[test]
+fn test_transfer_alpha_from_insufficient_allowance() {
+    new_test_ext(1).execute_with(|| {
+        let (coldkey, hotkey, netuid, alpha) = setup_position();
+        let spender = U256::from(3);
+
+        assert_ok!(SubtensorModule::register_alpha_token(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid
+        ));
+        assert_ok!(SubtensorModule::approve_alpha(
+            RuntimeOrigin::signed(coldkey),
+            0,
+            spender,
+            alpha / 4
+        ));
+
+        assert_noop!(
+            SubtensorModule::transfer_alpha_from(
+                RuntimeOrigin::signed(spender),
+                0,
+                coldkey,
+                spender,
+                alpha / 2
+            ),
+            Error::<Test>::InsufficientAlphaAllowance
+        );
+        assert_noop!(
+            SubtensorModule::approve_alpha(RuntimeOrigin::signed(coldkey), 1, spender, alpha),
+            Error::<Test>::AlphaTokenNotFound
+        );
+
+        // A zero approval clears the allowance.
+        assert_ok!(SubtensorModule::approve_alpha(
+            RuntimeOrigin::signed(coldkey),
+            0,
+            spender,
+            0
+        ));
+        assert!(!AlphaAllowances::<Test>::contains_key((
+            0, coldkey, spender
+        )));
+    });
+}
+
+#[test]
+fn test_swap_hotkey_moves_alpha_token() {
+    new_test_ext(1).execute_with(|| {
+        let (coldkey, old_hotkey, netuid, _) = setup_position();
+        let new_hotkey = U256::from(5);
+        let mut weight = Weight::zero();
+
+        assert_ok!(SubtensorModule::register_alpha_token(
+            RuntimeOrigin::signed(coldkey),
+            old_hotkey,
+            netuid
+        ));
+        assert_ok!(SubtensorModule::perform_hotkey_swap_on_all_subnets(
+            &old_hotkey,
+            &new_hotkey,
+            &coldkey,
+            &mut weight
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_alpha_token_id(&old_hotkey, netuid),
+            None
+        );
+        assert_eq!(
+            SubtensorModule::get_alpha_token_id(&new_hotkey, netuid),
+            Some(0)
+        );
+        assert_eq!(
+            SubtensorModule::get_alpha_token(0),
+            Some((new_hotkey, netuid))
+        );
+    });
+}
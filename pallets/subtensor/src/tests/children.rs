@@ -1460,7 +1460,7 @@ fn test_children_stake_values() {
         );
 
         // Set multiple children with proportions.
-        mock_set_children_no_epochs(
+        mock_set_accepted_children_no_epochs(
             netuid,
             &hotkey,
             &[
@@ -1556,6 +1556,10 @@ fn test_get_parents_chain() {
                 proportion
             );
         }
+        // Accept each relationship on behalf of the children
+        for i in 0..num_keys - 1 {
+            mock_accept_children(netuid, &hotkeys[i], &[(proportion, hotkeys[i + 1])]);
+        }
         // Wait for children to be set
         wait_and_set_pending_children(netuid);
 
@@ -1725,7 +1729,7 @@ fn test_get_stake_for_hotkey_on_subnet_single_parent_child() {
             1_000_000_000,
         );
 
-        mock_set_children_no_epochs(netuid, &parent, &[(u64::MAX, child)]);
+        mock_set_accepted_children_no_epochs(netuid, &parent, &[(u64::MAX, child)]);
 
         assert_eq!(
             SubtensorModule::get_inherited_for_hotkey_on_subnet(&parent, netuid),
@@ -1769,8 +1773,8 @@ fn test_get_stake_for_hotkey_on_subnet_multiple_parents_single_child() {
             &parent2, &coldkey, netuid, 2000,
         );
 
-        mock_set_children_no_epochs(netuid, &parent1, &[(u64::MAX / 2, child)]);
-        mock_set_children_no_epochs(netuid, &parent2, &[(u64::MAX / 2, child)]);
+        mock_set_accepted_children_no_epochs(netuid, &parent1, &[(u64::MAX / 2, child)]);
+        mock_set_accepted_children_no_epochs(netuid, &parent2, &[(u64::MAX / 2, child)]);
 
         close(
             SubtensorModule::get_inherited_for_hotkey_on_subnet(&parent1, netuid),
@@ -1825,7 +1829,7 @@ fn test_get_stake_for_hotkey_on_subnet_single_parent_multiple_children() {
             total_stake,
         );
 
-        mock_set_children_no_epochs(
+        mock_set_accepted_children_no_epochs(
             netuid,
             &parent,
             &[(u64::MAX / 3, child1), (u64::MAX / 3, child2)],
@@ -1893,7 +1897,7 @@ fn test_get_stake_for_hotkey_on_subnet_edge_cases() {
         );
 
         // Test with 0% and 100% stake allocation
-        mock_set_children_no_epochs(netuid, &parent, &[(0, child1), (u64::MAX, child2)]);
+        mock_set_accepted_children_no_epochs(netuid, &parent, &[(0, child1), (u64::MAX, child2)]);
 
         let parent_stake = SubtensorModule::get_inherited_for_hotkey_on_subnet(&parent, netuid);
         let child1_stake = SubtensorModule::get_inherited_for_hotkey_on_subnet(&child1, netuid);
@@ -1979,7 +1983,7 @@ fn test_get_stake_for_hotkey_on_subnet_complex_hierarchy() {
         );
 
         // Step 1: Set children for parent
-        mock_set_children_no_epochs(
+        mock_set_accepted_children_no_epochs(
             netuid,
             &parent,
             &[(u64::MAX / 2, child1), (u64::MAX / 2, child2)],
@@ -2015,7 +2019,7 @@ fn test_get_stake_for_hotkey_on_subnet_complex_hierarchy() {
         close(child2_stake_1, 499, 10, "Child2 should have 499 stake");
 
         // Step 2: Set children for child1
-        mock_set_children_no_epochs(netuid, &child1, &[(u64::MAX, grandchild)]);
+        mock_set_accepted_children_no_epochs(netuid, &child1, &[(u64::MAX, grandchild)]);
 
         log::info!("After setting child1's children:");
         log::info!(
@@ -2294,6 +2298,9 @@ fn test_do_set_child_cooldown_period() {
             "Children vector should be empty before cooldown",
         );
 
+        // Accept the schedule on behalf of the child
+        mock_accept_children(netuid, &parent, &[(proportion, child)]);
+
         wait_and_set_pending_children(netuid);
         SubtensorModule::decrease_stake_for_hotkey_and_coldkey_on_subnet(
             &parent,
@@ -2367,6 +2374,9 @@ fn test_do_set_pending_children_runs_in_epoch() {
             "Children vector should be empty before cooldown",
         );
 
+        // Accept the schedule on behalf of the child
+        mock_accept_children(netuid, &parent, &[(proportion, child)]);
+
         wait_set_pending_children_cooldown(netuid);
 
         // Verify child assignment
@@ -2430,6 +2440,9 @@ fn test_revoke_child_no_min_stake_check() {
         let children_before = SubtensorModule::get_children(&parent, netuid);
         assert_eq!(children_before, vec![]);
 
+        // Accept the schedule on behalf of the child
+        mock_accept_children(netuid, &parent, &[(proportion, child)]);
+
         wait_and_set_pending_children(netuid);
         SubtensorModule::decrease_stake_for_hotkey_and_coldkey_on_subnet(
             &parent,
@@ -2501,6 +2514,9 @@ fn test_do_set_child_registration_disabled() {
             vec![(proportion, child)],
         ));
 
+        // Accept the schedule on behalf of the child
+        mock_accept_children(netuid, &parent, &[(proportion, child)]);
+
         wait_and_set_pending_children(netuid);
         SubtensorModule::decrease_stake_for_hotkey_and_coldkey_on_subnet(
             &parent,
@@ -2628,7 +2644,7 @@ fn test_childkey_set_weights_single_parent() {
         SubtensorModule::set_weights_set_rate_limit(netuid, 0);
 
         // Set parent-child relationship
-        mock_set_children_no_epochs(netuid, &parent, &[(u64::MAX, child)]);
+        mock_set_accepted_children_no_epochs(netuid, &parent, &[(u64::MAX, child)]);
 
         // Set weights on the child using the weight_setter account
         let origin = RuntimeOrigin::signed(weight_setter);
@@ -2830,7 +2846,11 @@ fn test_childkey_take_drain() {
             SubnetOwnerCut::<Test>::set(0);
 
             // Set children
-            mock_set_children_no_epochs(netuid, &parent_hotkey, &[(proportion, child_hotkey)]);
+            mock_set_accepted_children_no_epochs(
+                netuid,
+                &parent_hotkey,
+                &[(proportion, child_hotkey)],
+            );
 
             // Set 20% childkey take
             let max_take: u16 = 0xFFFF / 5;
@@ -3002,10 +3022,10 @@ fn test_parent_child_chain_emission() {
 
         // Set parent-child relationships
         // A -> B (50% of A's stake)
-        mock_set_children_no_epochs(netuid, &hotkey_a, &[(u64::MAX / 2, hotkey_b)]);
+        mock_set_accepted_children_no_epochs(netuid, &hotkey_a, &[(u64::MAX / 2, hotkey_b)]);
 
         // B -> C (50% of B's stake)
-        mock_set_children_no_epochs(netuid, &hotkey_b, &[(u64::MAX / 2, hotkey_c)]);
+        mock_set_accepted_children_no_epochs(netuid, &hotkey_b, &[(u64::MAX / 2, hotkey_c)]);
 
         // Get old stakes after children are scheduled
         let stake_a_old: u64 = SubtensorModule::get_total_stake_for_hotkey(&hotkey_a);
@@ -4095,3 +4115,469 @@ fn test_do_set_childkey_take_rate_limit_exceeded() {
         ));
     });
 }
+
+// Schedules `parent -> child` under `terms` and applies it once the child has accepted.
+fn set_child_with_terms(
+    coldkey: &U256,
+    parent: &U256,
+    child_coldkey: &U256,
+    child: &U256,
+    netuid: u16,
+    terms: ChildKeyTerms,
+) {
+    StakeThreshold::<Test>::put(0);
+    assert_ok!(SubtensorModule::do_schedule_children_with_terms(
+        RuntimeOrigin::signed(*coldkey),
+        *parent,
+        netuid,
+        vec![(u64::MAX, *child)],
+        terms,
+    ));
+    assert_ok!(SubtensorModule::accept_childkey(
+        RuntimeOrigin::signed(*child_coldkey),
+        *child,
+        *parent,
+        netuid,
+    ));
+    System::set_block_number(
+        SubtensorModule::get_current_block_as_u64() + PendingChildKeyCooldown::<Test>::get() + 1,
+    );
+    SubtensorModule::do_set_pending_children(netuid);
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::children::test_pending_children_wait_for_acceptance --exact --show-output --nocapture
+#[test]
+fn test_pending_children_wait_for_acceptance() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let parent = U256::from(2);
+        let child_coldkey = U256::from(3);
+        let child = U256::from(4);
+        let netuid: u16 = 1;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, parent, coldkey, 0);
+        SubtensorModule::create_account_if_non_existent(&child_coldkey, &child);
+
+        mock_schedule_children(&coldkey, &parent, netuid, &[(u64::MAX, child)]);
+
+        // The cooldown has passed, but the child has not accepted yet.
+        step_block(PendingChildKeyCooldown::<Test>::get() as u16 + 1);
+        SubtensorModule::do_set_pending_children(netuid);
+        assert!(SubtensorModule::get_children(&parent, netuid).is_empty());
+        assert!(PendingChildKeys::<Test>::contains_key(netuid, parent));
+
+        // Only the coldkey of the child can accept.
+        assert_noop!(
+            SubtensorModule::accept_childkey(RuntimeOrigin::signed(coldkey), child, parent, netuid),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_ok!(SubtensorModule::accept_childkey(
+            RuntimeOrigin::signed(child_coldkey),
+            child,
+            parent,
+            netuid
+        ));
+
+        SubtensorModule::do_set_pending_children(netuid);
+        assert_eq!(
+            SubtensorModule::get_children(&parent, netuid),
+            vec![(u64::MAX, child)]
+        );
+        assert!(!PendingChildKeys::<Test>::contains_key(netuid, parent));
+        assert!(!ChildKeyAcceptances::<Test>::contains_key((
+            netuid, parent, child
+        )));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::children::test_accept_childkey_not_pending --exact --show-output --nocapture
+#[test]
+fn test_accept_childkey_not_pending() {
+    new_test_ext(1).execute_with(|| {
+        let parent = U256::from(2);
+        let child_coldkey = U256::from(3);
+        let child = U256::from(4);
+        let netuid: u16 = 1;
+
+        add_network(netuid, 13, 0);
+        SubtensorModule::create_account_if_non_existent(&child_coldkey, &child);
+
+        assert_noop!(
+            SubtensorModule::accept_childkey(
+                RuntimeOrigin::signed(child_coldkey),
+                child,
+                parent,
+                netuid
+            ),
+            Error::<Test>::NoPendingChildKey
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::children::test_rescheduling_drops_acceptances --exact --show-output --nocapture
+#[test]
+fn test_rescheduling_drops_acceptances() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let parent = U256::from(2);
+        let child_coldkey = U256::from(3);
+        let child = U256::from(4);
+        let netuid: u16 = 1;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, parent, coldkey, 0);
+        SubtensorModule::create_account_if_non_existent(&child_coldkey, &child);
+
+        mock_schedule_children(&coldkey, &parent, netuid, &[(u64::MAX, child)]);
+        assert_ok!(SubtensorModule::accept_childkey(
+            RuntimeOrigin::signed(child_coldkey),
+            child,
+            parent,
+            netuid
+        ));
+
+        // New terms need a new acceptance.
+        step_rate_limit(&TransactionType::SetChildren, netuid);
+        assert_ok!(SubtensorModule::do_schedule_children_with_terms(
+            RuntimeOrigin::signed(coldkey),
+            parent,
+            netuid,
+            vec![(u64::MAX, child)],
+            ChildKeyTerms {
+                term: Some(100),
+                auto_renew: false,
+            },
+        ));
+        assert!(!ChildKeyAcceptances::<Test>::contains_key((
+            netuid, parent, child
+        )));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::children::test_invalid_childkey_terms --exact --show-output --nocapture
+#[test]
+fn test_invalid_childkey_terms() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let parent = U256::from(2);
+        let child = U256::from(4);
+        let netuid: u16 = 1;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, parent, coldkey, 0);
+        StakeThreshold::<Test>::put(0);
+
+        for terms in [
+            ChildKeyTerms {
+                term: Some(0),
+                auto_renew: false,
+            },
+            ChildKeyTerms {
+                term: None,
+                auto_renew: true,
+            },
+        ] {
+            assert_noop!(
+                SubtensorModule::do_schedule_children_with_terms(
+                    RuntimeOrigin::signed(coldkey),
+                    parent,
+                    netuid,
+                    vec![(u64::MAX, child)],
+                    terms,
+                ),
+                Error::<Test>::InvalidChildKeyTerms
+            );
+        }
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::children::test_resign_childkey --exact --show-output --nocapture
+#[test]
+fn test_resign_childkey() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let parent = U256::from(2);
+        let child_coldkey = U256::from(3);
+        let child = U256::from(4);
+        let other_child = U256::from(5);
+        let netuid: u16 = 1;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, parent, coldkey, 0);
+        SubtensorModule::create_account_if_non_existent(&child_coldkey, &child);
+
+        mock_set_children(
+            &coldkey,
+            &parent,
+            netuid,
+            &[(u64::MAX / 2, child), (u64::MAX / 2, other_child)],
+        );
+
+        // The parent cannot resign in place of the child.
+        assert_noop!(
+            SubtensorModule::resign_childkey(RuntimeOrigin::signed(coldkey), child, parent, netuid),
+            Error::<Test>::NonAssociatedColdKey
+        );
+
+        assert_ok!(SubtensorModule::resign_childkey(
+            RuntimeOrigin::signed(child_coldkey),
+            child,
+            parent,
+            netuid
+        ));
+        assert_eq!(
+            SubtensorModule::get_children(&parent, netuid),
+            vec![(u64::MAX / 2, other_child)]
+        );
+        assert!(SubtensorModule::get_parents(&child, netuid).is_empty());
+        System::assert_last_event(
+            Event::ChildKeyResigned {
+                parent,
+                child,
+                netuid,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            SubtensorModule::resign_childkey(
+                RuntimeOrigin::signed(child_coldkey),
+                child,
+                parent,
+                netuid
+            ),
+            Error::<Test>::NotAChildOfParent
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::children::test_childkey_expires_after_term --exact --show-output --nocapture
+#[test]
+fn test_childkey_expires_after_term() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let parent = U256::from(2);
+        let child_coldkey = U256::from(3);
+        let child = U256::from(4);
+        let netuid: u16 = 1;
+        let term: u64 = 100;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, parent, coldkey, 0);
+        SubtensorModule::create_account_if_non_existent(&child_coldkey, &child);
+
+        set_child_with_terms(
+            &coldkey,
+            &parent,
+            &child_coldkey,
+            &child,
+            netuid,
+            ChildKeyTerms {
+                term: Some(term),
+                auto_renew: false,
+            },
+        );
+        assert_eq!(
+            SubtensorModule::get_children(&parent, netuid),
+            vec![(u64::MAX, child)]
+        );
+        let (_, expires_at) = ActiveChildKeyTerms::<Test>::get((netuid, parent, child)).unwrap();
+        assert_eq!(
+            expires_at,
+            SubtensorModule::get_current_block_as_u64() + term
+        );
+
+        // Not expired before the end of the term.
+        System::set_block_number(expires_at - 1);
+        SubtensorModule::expire_child_keys(netuid);
+        assert_eq!(SubtensorModule::get_children(&parent, netuid).len(), 1);
+
+        System::set_block_number(expires_at);
+        SubtensorModule::expire_child_keys(netuid);
+        assert!(SubtensorModule::get_children(&parent, netuid).is_empty());
+        assert!(SubtensorModule::get_parents(&child, netuid).is_empty());
+        assert!(ActiveChildKeyTerms::<Test>::get((netuid, parent, child)).is_none());
+        System::assert_last_event(
+            Event::ChildKeyExpired {
+                parent,
+                child,
+                netuid,
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::children::test_childkey_auto_renews --exact --show-output --nocapture
+#[test]
+fn test_childkey_auto_renews() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let parent = U256::from(2);
+        let child_coldkey = U256::from(3);
+        let child = U256::from(4);
+        let netuid: u16 = 1;
+        let term: u64 = 100;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, parent, coldkey, 0);
+        SubtensorModule::create_account_if_non_existent(&child_coldkey, &child);
+
+        set_child_with_terms(
+            &coldkey,
+            &parent,
+            &child_coldkey,
+            &child,
+            netuid,
+            ChildKeyTerms {
+                term: Some(term),
+                auto_renew: true,
+            },
+        );
+        let (_, expires_at) = ActiveChildKeyTerms::<Test>::get((netuid, parent, child)).unwrap();
+
+        System::set_block_number(expires_at);
+        SubtensorModule::expire_child_keys(netuid);
+        assert_eq!(
+            SubtensorModule::get_children(&parent, netuid),
+            vec![(u64::MAX, child)]
+        );
+        let (_, renewed_expires_at) =
+            ActiveChildKeyTerms::<Test>::get((netuid, parent, child)).unwrap();
+        assert_eq!(renewed_expires_at, expires_at + term);
+        System::assert_last_event(
+            Event::ChildKeyRenewed {
+                parent,
+                child,
+                netuid,
+                expires_at: renewed_expires_at,
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::children::test_schedule_children_rejects_cycles --exact --show-output --nocapture
+#[test]
+fn test_schedule_children_rejects_cycles() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey_a = U256::from(2);
+        let hotkey_b = U256::from(3);
+        let hotkey_c = U256::from(4);
+        let netuid: u16 = 1;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_a, coldkey, 0);
+        register_ok_neuron(netuid, hotkey_b, coldkey, 0);
+        register_ok_neuron(netuid, hotkey_c, coldkey, 0);
+
+        // A -> B -> C
+        mock_set_accepted_children_no_epochs(netuid, &hotkey_a, &[(u64::MAX, hotkey_b)]);
+        mock_set_accepted_children_no_epochs(netuid, &hotkey_b, &[(u64::MAX, hotkey_c)]);
+
+        StakeThreshold::<Test>::put(0);
+        assert_noop!(
+            SubtensorModule::do_schedule_children(
+                RuntimeOrigin::signed(coldkey),
+                hotkey_b,
+                netuid,
+                vec![(u64::MAX, hotkey_a)]
+            ),
+            Error::<Test>::ChildKeyCycle
+        );
+        assert_noop!(
+            SubtensorModule::do_schedule_children(
+                RuntimeOrigin::signed(coldkey),
+                hotkey_c,
+                netuid,
+                vec![(u64::MAX, hotkey_a)]
+            ),
+            Error::<Test>::ChildKeyCycle
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::children::test_multi_level_stake_inheritance --exact --show-output --nocapture
+#[test]
+fn test_multi_level_stake_inheritance() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey_a = U256::from(2);
+        let hotkey_b = U256::from(3);
+        let hotkey_c = U256::from(4);
+        let netuid: u16 = 1;
+        let stake: u64 = 1_000_000;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_a, coldkey, 0);
+        register_ok_neuron(netuid, hotkey_b, coldkey, 0);
+        register_ok_neuron(netuid, hotkey_c, coldkey, 0);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey_a, &coldkey, netuid, stake,
+        );
+
+        // A gives half to B, B gives everything to C.
+        mock_set_accepted_children_no_epochs(netuid, &hotkey_a, &[(u64::MAX / 2, hotkey_b)]);
+        mock_set_accepted_children_no_epochs(netuid, &hotkey_b, &[(u64::MAX, hotkey_c)]);
+
+        // A single hop: B keeps the stake of A, C only inherits the own stake of B.
+        let inherited_a = SubtensorModule::get_inherited_for_hotkey_on_subnet(&hotkey_a, netuid);
+        let inherited_b = SubtensorModule::get_inherited_for_hotkey_on_subnet(&hotkey_b, netuid);
+        let inherited_c = SubtensorModule::get_inherited_for_hotkey_on_subnet(&hotkey_c, netuid);
+        assert_abs_diff_eq!(inherited_a, stake / 2, epsilon = 10);
+        assert_abs_diff_eq!(inherited_b, stake / 2, epsilon = 10);
+        assert_eq!(inherited_c, 0);
+
+        // Two hops: the stake of A is passed on by B to C.
+        MaxChildKeyDepth::<Test>::put(2);
+        let inherited_a = SubtensorModule::get_inherited_for_hotkey_on_subnet(&hotkey_a, netuid);
+        let inherited_b = SubtensorModule::get_inherited_for_hotkey_on_subnet(&hotkey_b, netuid);
+        let inherited_c = SubtensorModule::get_inherited_for_hotkey_on_subnet(&hotkey_c, netuid);
+        assert_abs_diff_eq!(inherited_a, stake / 2, epsilon = 10);
+        assert_abs_diff_eq!(inherited_b, 0, epsilon = 10);
+        assert_abs_diff_eq!(inherited_c, stake / 2, epsilon = 10);
+        assert_abs_diff_eq!(inherited_a + inherited_b + inherited_c, stake, epsilon = 10);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::children::test_multi_level_dividends_distribution --exact --show-output --nocapture
+#[test]
+fn test_multi_level_dividends_distribution() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey_a = U256::from(2);
+        let hotkey_b = U256::from(3);
+        let hotkey_c = U256::from(4);
+        let netuid: u16 = 1;
+        let dividends: u64 = 1_000_000;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_a, coldkey, 0);
+        register_ok_neuron(netuid, hotkey_b, coldkey, 0);
+        register_ok_neuron(netuid, hotkey_c, coldkey, 0);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey_a, &coldkey, netuid, 1_000_000,
+        );
+
+        mock_set_accepted_children_no_epochs(netuid, &hotkey_a, &[(u64::MAX, hotkey_b)]);
+        mock_set_accepted_children_no_epochs(netuid, &hotkey_b, &[(u64::MAX, hotkey_c)]);
+
+        // With a single hop, A is not an ancestor of C.
+        let distribution =
+            SubtensorModule::get_parent_child_dividends_distribution(&hotkey_c, netuid, dividends);
+        assert!(!distribution.iter().any(|(hotkey, _)| *hotkey == hotkey_a));
+
+        // With two hops, the dividends of C are owed to A, whose stake C validates with.
+        MaxChildKeyDepth::<Test>::put(2);
+        let distribution =
+            SubtensorModule::get_parent_child_dividends_distribution(&hotkey_c, netuid, dividends);
+        let to_a: u64 = distribution
+            .iter()
+            .filter(|(hotkey, _)| *hotkey == hotkey_a)
+            .map(|(_, divs)| *divs)
+            .sum();
+        assert_abs_diff_eq!(to_a, dividends, epsilon = 10);
+    });
+}
@@ -819,7 +819,7 @@ fn test_drain_alpha_childkey_parentkey() {
             netuid,
             stake_before,
         );
-        mock_set_children_no_epochs(netuid, &parent, &[(u64::MAX, child)]);
+        mock_set_accepted_children_no_epochs(netuid, &parent, &[(u64::MAX, child)]);
 
         // Childkey take is 10%
         ChildkeyTake::<Test>::insert(child, netuid, u16::MAX / 10);
@@ -907,7 +907,7 @@ fn test_get_root_children() {
 
         // Set Bob as 100% child of Alice on root.
         // mock_set_children_no_epochs( root, &alice, &[(u64::MAX, bob)]);
-        mock_set_children_no_epochs(alpha, &alice, &[(u64::MAX, bob)]);
+        mock_set_accepted_children_no_epochs(alpha, &alice, &[(u64::MAX, bob)]);
 
         // Assert Alice and Bob stake on root and netuid
         assert_eq!(
@@ -1025,7 +1025,7 @@ fn test_get_root_children_drain() {
             bob_alpha_stake,
         );
         // Set Bob as 100% child of Alice on root.
-        mock_set_children_no_epochs(alpha, &alice, &[(u64::MAX, bob)]);
+        mock_set_accepted_children_no_epochs(alpha, &alice, &[(u64::MAX, bob)]);
         // Set Bob childkey take to zero.
         ChildkeyTake::<Test>::insert(bob, alpha, 0);
         Delegates::<Test>::insert(alice, 0);
@@ -1149,7 +1149,7 @@ fn test_get_root_children_drain_half_proportion() {
             bob_alpha_stake,
         );
         // Set Bob as 100% child of Alice on root.
-        mock_set_children_no_epochs(alpha, &alice, &[(u64::MAX / 2, bob)]);
+        mock_set_accepted_children_no_epochs(alpha, &alice, &[(u64::MAX / 2, bob)]);
 
         // Set Bob childkey take to zero.
         ChildkeyTake::<Test>::insert(bob, alpha, 0);
@@ -1233,7 +1233,7 @@ fn test_get_root_children_drain_with_take() {
         );
         // Set Bob as 100% child of Alice on root.
         ChildkeyTake::<Test>::insert(bob, alpha, u16::MAX);
-        mock_set_children_no_epochs(alpha, &alice, &[(u64::MAX, bob)]);
+        mock_set_accepted_children_no_epochs(alpha, &alice, &[(u64::MAX, bob)]);
         // Set Bob validator take to zero.
         Delegates::<Test>::insert(alice, 0);
         Delegates::<Test>::insert(bob, 0);
@@ -1311,7 +1311,7 @@ fn test_get_root_children_drain_with_half_take() {
         );
         // Set Bob as 100% child of Alice on root.
         ChildkeyTake::<Test>::insert(bob, alpha, u16::MAX / 2);
-        mock_set_children_no_epochs(alpha, &alice, &[(u64::MAX, bob)]);
+        mock_set_accepted_children_no_epochs(alpha, &alice, &[(u64::MAX, bob)]);
         // Set Bob childkey take to zero.
         Delegates::<Test>::insert(alice, 0);
         Delegates::<Test>::insert(bob, 0);
@@ -1391,7 +1391,7 @@ fn test_get_root_children_drain_with_half_take() {
 //             bob_alpha_stake,
 //         );
 //         // Set Bob as 100% child of Alice on root.
-//         mock_set_children_no_epochs(alpha, &alice, &[(u64::MAX, bob)]);
+//         mock_set_accepted_children_no_epochs(alpha, &alice, &[(u64::MAX, bob)]);
 
 //         // Set Bob childkey take to zero.
 //         ChildkeyTake::<Test>::insert(bob, alpha, 0);
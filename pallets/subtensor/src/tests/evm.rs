@@ -240,3 +240,128 @@ fn test_associate_evm_key_using_wrong_hash_function() {
         );
     });
 }
+
+#[test]
+fn test_ensure_evm_key_associated() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 2, 2);
+
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+
+        let pair = ecdsa::Pair::generate().0;
+        let evm_key = public_to_evm_key(&pair.public());
+        let block_number = frame_system::Pallet::<Test>::block_number();
+        let hashed_block_number = keccak_256(block_number.encode().as_ref());
+        let message = [hotkey.encode().as_ref(), hashed_block_number.as_ref()].concat();
+        let signature = sign_evm_message(&pair, message);
+
+        assert_err!(
+            SubtensorModule::ensure_evm_key_associated(netuid, &hotkey, evm_key),
+            Error::<Test>::EvmKeyNotAssociated
+        );
+
+        assert_ok!(SubtensorModule::associate_evm_key(
+            RuntimeOrigin::signed(coldkey),
+            netuid,
+            hotkey,
+            evm_key,
+            block_number,
+            signature,
+        ));
+
+        assert_ok!(SubtensorModule::ensure_evm_key_associated(
+            netuid, &hotkey, evm_key
+        ));
+        assert_err!(
+            SubtensorModule::ensure_evm_key_associated(netuid, &hotkey, H160::repeat_byte(1)),
+            Error::<Test>::EvmKeyNotAssociated
+        );
+
+        // The association does not carry over to the hotkey taking the uid.
+        let new_hotkey = U256::from(3);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        SubtensorModule::replace_neuron(netuid, uid, &new_hotkey, block_number);
+
+        assert_err!(
+            SubtensorModule::ensure_evm_key_associated(netuid, &new_hotkey, evm_key),
+            Error::<Test>::EvmKeyNotAssociated
+        );
+        assert!(AssociatedEvmAddress::<Test>::get(netuid, uid).is_none());
+    });
+}
+
+#[test]
+fn test_associate_evm_contract() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 2, 2);
+
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+
+        let contract = H160::repeat_byte(7);
+
+        assert_err!(
+            SubtensorModule::associate_evm_contract(
+                RuntimeOrigin::signed(U256::from(3)),
+                netuid,
+                hotkey,
+                contract,
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_err!(
+            SubtensorModule::ensure_evm_key_associated(netuid, &hotkey, contract),
+            Error::<Test>::EvmKeyNotAssociated
+        );
+
+        assert_ok!(SubtensorModule::associate_evm_contract(
+            RuntimeOrigin::signed(coldkey),
+            netuid,
+            hotkey,
+            contract,
+        ));
+
+        assert_eq!(
+            AssociatedEvmContract::<Test>::get(netuid, uid),
+            Some(contract)
+        );
+        assert_ok!(SubtensorModule::ensure_evm_key_associated(
+            netuid, &hotkey, contract
+        ));
+        // The contract is not a proven EVM key of the hotkey.
+        assert!(AssociatedEvmAddress::<Test>::get(netuid, uid).is_none());
+        assert!(SubtensorModule::uid_lookup(netuid, contract, 1024).is_empty());
+
+        // A new contract replaces the previous one.
+        let new_contract = H160::repeat_byte(8);
+        assert_ok!(SubtensorModule::associate_evm_contract(
+            RuntimeOrigin::signed(coldkey),
+            netuid,
+            hotkey,
+            new_contract,
+        ));
+        assert_err!(
+            SubtensorModule::ensure_evm_key_associated(netuid, &hotkey, contract),
+            Error::<Test>::EvmKeyNotAssociated
+        );
+
+        // The association does not carry over to the hotkey taking the uid.
+        let new_hotkey = U256::from(4);
+        let block_number = frame_system::Pallet::<Test>::block_number();
+        SubtensorModule::replace_neuron(netuid, uid, &new_hotkey, block_number);
+
+        assert_err!(
+            SubtensorModule::ensure_evm_key_associated(netuid, &new_hotkey, new_contract),
+            Error::<Test>::EvmKeyNotAssociated
+        );
+        assert!(AssociatedEvmContract::<Test>::get(netuid, uid).is_none());
+    });
+}
@@ -0,0 +1,271 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::arithmetic_side_effects)]
+
+use super::mock::*;
+use crate::*;
+use approx::assert_abs_diff_eq;
+use frame_support::{assert_noop, assert_ok};
+use sp_core::U256;
+use substrate_fixed::types::U64F64;
+
+fn create_leasing_crowdloan(
+    beneficiary: U256,
+    contributors: &[(U256, u64)],
+    deposit: u64,
+    term: u64,
+    end: u64,
+) -> pallet_crowdloan::CrowdloanId {
+    let crowdloan_id = pallet_crowdloan::NextCrowdloanId::<Test>::get();
+    let cap = deposit + contributors.iter().map(|(_, amount)| amount).sum::<u64>();
+    let call = RuntimeCall::SubtensorModule(SubtensorCall::register_leased_network { term });
+
+    SubtensorModule::add_balance_to_coldkey_account(&beneficiary, deposit + 1);
+    assert_ok!(Crowdloan::create(
+        RuntimeOrigin::signed(beneficiary),
+        deposit,
+        10,
+        cap,
+        end,
+        Some(Box::new(call)),
+        None
+    ));
+    for (contributor, amount) in contributors {
+        SubtensorModule::add_balance_to_coldkey_account(contributor, amount + 1);
+        assert_ok!(Crowdloan::contribute(
+            RuntimeOrigin::signed(*contributor),
+            crowdloan_id,
+            *amount
+        ));
+    }
+    crowdloan_id
+}
+
+#[test]
+fn test_register_leased_network_ok() {
+    new_test_ext(1).execute_with(|| {
+        let beneficiary = U256::from(1);
+        let contributor1 = U256::from(2);
+        let contributor2 = U256::from(3);
+        let lock_cost = SubtensorModule::get_network_lock_cost();
+        let deposit = lock_cost / 2;
+        let extra = 1_000_000;
+
+        let crowdloan_id = create_leasing_crowdloan(
+            beneficiary,
+            &[
+                (contributor1, lock_cost / 4 + extra / 2),
+                (
+                    contributor2,
+                    lock_cost - deposit - lock_cost / 4 + extra / 2,
+                ),
+            ],
+            deposit,
+            100,
+            50,
+        );
+        let netuid = SubtensorModule::get_next_netuid();
+        let balance_before = SubtensorModule::get_coldkey_balance(&contributor1);
+
+        run_to_block(50);
+        assert_ok!(Crowdloan::finalize(
+            RuntimeOrigin::signed(beneficiary),
+            crowdloan_id
+        ));
+
+        let lease_id = SubnetUidToLeaseId::<Test>::get(netuid).unwrap();
+        let lease = SubnetLeases::<Test>::get(lease_id).unwrap();
+        assert_eq!(lease.beneficiary, beneficiary);
+        assert_eq!(lease.end_block, 150);
+        assert_eq!(SubnetOwner::<Test>::get(netuid), lease.coldkey);
+        assert_eq!(SubnetOwnerHotkey::<Test>::get(netuid), lease.hotkey);
+        System::assert_has_event(
+            Event::SubnetLeaseCreated {
+                lease_id,
+                crowdloan_id,
+                netuid,
+                beneficiary,
+                end_block: 150,
+            }
+            .into(),
+        );
+
+        // What was raised above the lock is refunded pro rata.
+        assert_abs_diff_eq!(
+            SubtensorModule::get_coldkey_balance(&contributor1),
+            balance_before + extra / 4,
+            epsilon = extra / 100
+        );
+
+        // The owner cut accrues and is then staked to the contributors pro rata.
+        let owner_cut = 1_000_000_000;
+        SubtensorModule::distribute_leased_network_dividends(lease_id, owner_cut);
+        assert_eq!(
+            SubnetLeaseAccruedDividends::<Test>::get(lease_id),
+            owner_cut
+        );
+        SubtensorModule::distribute_accrued_lease_dividends();
+        assert!(!SubnetLeaseAccruedDividends::<Test>::contains_key(lease_id));
+        assert!(!SubnetLeaseDistributions::<Test>::contains_key(lease_id));
+        let share1 = SubnetLeaseShares::<Test>::get(lease_id, contributor1).to_num::<f64>();
+        assert_abs_diff_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &lease.hotkey,
+                &contributor1,
+                netuid
+            ),
+            (owner_cut as f64 * share1) as u64,
+            epsilon = 10
+        );
+        assert_abs_diff_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &lease.hotkey,
+                &beneficiary,
+                netuid
+            ),
+            owner_cut / 2,
+            epsilon = owner_cut / 100
+        );
+    });
+}
+
+#[test]
+fn test_register_leased_network_outside_crowdloan_fails() {
+    new_test_ext(1).execute_with(|| {
+        assert_noop!(
+            SubtensorModule::register_leased_network(RuntimeOrigin::signed(U256::from(1)), 100),
+            Error::<Test>::NotCalledFromCrowdloan
+        );
+    });
+}
+
+#[test]
+fn test_terminate_subnet_lease() {
+    new_test_ext(1).execute_with(|| {
+        let beneficiary = U256::from(1);
+        let contributor = U256::from(2);
+        let new_hotkey = U256::from(10);
+        let lock_cost = SubtensorModule::get_network_lock_cost();
+
+        let crowdloan_id =
+            create_leasing_crowdloan(beneficiary, &[(contributor, lock_cost)], 100, 100, 50);
+        let netuid = SubtensorModule::get_next_netuid();
+        run_to_block(50);
+        assert_ok!(Crowdloan::finalize(
+            RuntimeOrigin::signed(beneficiary),
+            crowdloan_id
+        ));
+        let lease_id = SubnetUidToLeaseId::<Test>::get(netuid).unwrap();
+
+        assert_noop!(
+            SubtensorModule::terminate_subnet_lease(
+                RuntimeOrigin::signed(contributor),
+                lease_id,
+                new_hotkey
+            ),
+            Error::<Test>::NotSubnetLeaseBeneficiary
+        );
+        assert_noop!(
+            SubtensorModule::terminate_subnet_lease(
+                RuntimeOrigin::signed(beneficiary),
+                lease_id,
+                new_hotkey
+            ),
+            Error::<Test>::SubnetLeaseNotEnded
+        );
+
+        run_to_block(150);
+        SubnetLeaseAccruedDividends::<Test>::insert(lease_id, 1_000);
+        assert_noop!(
+            SubtensorModule::terminate_subnet_lease(
+                RuntimeOrigin::signed(beneficiary),
+                lease_id,
+                new_hotkey
+            ),
+            Error::<Test>::SubnetLeaseDividendsPending
+        );
+        SubtensorModule::distribute_accrued_lease_dividends();
+
+        assert_ok!(SubtensorModule::terminate_subnet_lease(
+            RuntimeOrigin::signed(beneficiary),
+            lease_id,
+            new_hotkey
+        ));
+        assert_eq!(SubnetOwner::<Test>::get(netuid), beneficiary);
+        assert_eq!(SubnetOwnerHotkey::<Test>::get(netuid), new_hotkey);
+        assert!(!SubnetLeases::<Test>::contains_key(lease_id));
+        assert!(!SubnetUidToLeaseId::<Test>::contains_key(netuid));
+        assert_eq!(SubnetLeaseShares::<Test>::iter_prefix(lease_id).count(), 0);
+        System::assert_last_event(
+            Event::SubnetLeaseTerminated {
+                lease_id,
+                netuid,
+                beneficiary,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_distribute_accrued_lease_dividends_in_chunks() {
+    new_test_ext(1).execute_with(|| {
+        let beneficiary = U256::from(1);
+        let lease_id: LeaseId = 0;
+        let lease_coldkey = SubtensorModule::get_subnet_lease_account_id(lease_id, false);
+        let lease_hotkey = SubtensorModule::get_subnet_lease_account_id(lease_id, true);
+        let netuid = add_dynamic_network(&lease_hotkey, &lease_coldkey);
+        SubnetLeases::<Test>::insert(
+            lease_id,
+            SubnetLease {
+                beneficiary,
+                coldkey: lease_coldkey,
+                hotkey: lease_hotkey,
+                netuid,
+                end_block: 1_000,
+            },
+        );
+        SubnetUidToLeaseId::<Test>::insert(netuid, lease_id);
+
+        let contributors = SUBNET_LEASE_DIVIDENDS_CHUNK_SIZE as u64 + 10;
+        let share = U64F64::from_num(1).saturating_div(U64F64::from_num(contributors));
+        for i in 0..contributors {
+            SubnetLeaseShares::<Test>::insert(lease_id, U256::from(100 + i), share);
+        }
+
+        let owner_cut = contributors * 1_000;
+        SubtensorModule::distribute_leased_network_dividends(lease_id, owner_cut);
+        let stake_of = |i: u64| {
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &lease_hotkey,
+                &U256::from(100 + i),
+                netuid,
+            )
+        };
+        let paid = || (0..contributors).filter(|i| stake_of(*i) > 0).count();
+
+        // The first block pays a chunk of the contributors and remembers where it stopped.
+        SubtensorModule::distribute_accrued_lease_dividends();
+        assert_eq!(paid(), SUBNET_LEASE_DIVIDENDS_CHUNK_SIZE);
+        assert!(SubnetLeaseDistributions::<Test>::contains_key(lease_id));
+
+        // Emissions accrued meanwhile wait for the next distribution.
+        SubtensorModule::distribute_leased_network_dividends(lease_id, owner_cut);
+        SubtensorModule::distribute_accrued_lease_dividends();
+        assert_eq!(paid() as u64, contributors);
+        assert!(!SubnetLeaseDistributions::<Test>::contains_key(lease_id));
+        assert_eq!(
+            SubnetLeaseAccruedDividends::<Test>::get(lease_id),
+            owner_cut
+        );
+        System::assert_has_event(
+            Event::SubnetLeaseDividendsDistributed {
+                lease_id,
+                alpha: owner_cut,
+            }
+            .into(),
+        );
+        for i in 0..contributors {
+            assert_abs_diff_eq!(stake_of(i), 1_000, epsilon = 10);
+        }
+    });
+}
@@ -2,7 +2,7 @@
 
 use super::mock::*;
 use crate::*;
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, VecDeque};
 use approx::assert_abs_diff_eq;
 use codec::{Decode, Encode};
 use frame_support::{
@@ -14,11 +14,11 @@ use frame_support::{
 
 use crate::migrations::migrate_storage;
 use frame_system::Config;
-use sp_core::{H256, U256, crypto::Ss58Codec};
+use sp_core::{ConstU32, H256, U256, crypto::Ss58Codec};
 use sp_io::hashing::twox_128;
-use sp_runtime::traits::Zero;
-use substrate_fixed::types::I96F32;
+use sp_runtime::{BoundedVec, traits::Zero};
 use substrate_fixed::types::extra::U2;
+use substrate_fixed::types::{I96F32, U64F64};
 
 #[allow(clippy::arithmetic_side_effects)]
 fn close(value: u64, target: u64, eps: u64) {
@@ -820,3 +820,177 @@ fn test_migrate_remove_commitments_rate_limit() {
         assert!(!weight.is_zero(), "Migration weight should be non-zero");
     });
 }
+
+#[test]
+fn test_migrate_populate_subnet_alpha_positions() {
+    new_test_ext(1).execute_with(|| {
+        const MIGRATION_NAME: &str = "migrate_populate_subnet_alpha_positions";
+        let netuid = 1u16;
+        let hotkey = U256::from(100u64);
+        let coldkey = U256::from(101u64);
+        let coldkey_zero = U256::from(102u64);
+
+        Alpha::<Test>::insert((hotkey, coldkey, netuid), U64F64::from_num(123));
+        Alpha::<Test>::insert((hotkey, coldkey_zero, netuid), U64F64::from_num(0));
+
+        let weight = crate::migrations::migrate_populate_subnet_alpha_positions::migrate_populate_subnet_alpha_positions::<Test>();
+
+        assert!(HasMigrationRun::<Test>::get(
+            MIGRATION_NAME.as_bytes().to_vec()
+        ));
+        assert!(SubnetAlphaPositionsIndexCursor::<Test>::exists());
+        assert!(!SubnetAlphaPositions::<Test>::contains_key((
+            netuid, hotkey, coldkey
+        )));
+
+        // The positions are indexed in chunks on the following blocks.
+        SubtensorModule::index_subnet_alpha_positions();
+        assert!(!SubnetAlphaPositionsIndexCursor::<Test>::exists());
+        assert!(SubnetAlphaPositions::<Test>::contains_key((
+            netuid, hotkey, coldkey
+        )));
+        assert!(!SubnetAlphaPositions::<Test>::contains_key((
+            netuid,
+            hotkey,
+            coldkey_zero
+        )));
+        assert!(!weight.is_zero());
+    });
+}
+
+#[test]
+fn test_index_subnet_alpha_positions_in_chunks() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = 1u16;
+        let count = ALPHA_POSITIONS_INDEX_CHUNK_SIZE + 10;
+        for i in 0..count {
+            Alpha::<Test>::insert(
+                (U256::from(1_000 + i), U256::from(100_000 + i), netuid),
+                U64F64::from_num(1),
+            );
+        }
+
+        crate::migrations::migrate_populate_subnet_alpha_positions::migrate_populate_subnet_alpha_positions::<Test>();
+
+        SubtensorModule::index_subnet_alpha_positions();
+        assert!(SubnetAlphaPositionsIndexCursor::<Test>::exists());
+        assert_eq!(
+            SubnetAlphaPositions::<Test>::iter_key_prefix((netuid,)).count(),
+            ALPHA_POSITIONS_INDEX_CHUNK_SIZE
+        );
+
+        SubtensorModule::index_subnet_alpha_positions();
+        assert!(!SubnetAlphaPositionsIndexCursor::<Test>::exists());
+        assert_eq!(
+            SubnetAlphaPositions::<Test>::iter_key_prefix((netuid,)).count(),
+            count
+        );
+    });
+}
+
+#[test]
+fn test_migrate_reference_crv3_reveal_rounds() {
+    new_test_ext(1).execute_with(|| {
+        const MIGRATION_NAME: &str = "migrate_reference_crv3_reveal_rounds";
+        let netuid = 1u16;
+        let commit = || vec![1u8, 2, 3].try_into().expect("commit fits");
+
+        CRV3WeightCommits::<Test>::insert(
+            netuid,
+            5,
+            vec![
+                (U256::from(1), commit(), 0, 1000),
+                (U256::from(2), commit(), 0, 1000),
+            ]
+            .into_iter()
+            .collect::<VecDeque<_>>(),
+        );
+        CRV3WeightCommits::<Test>::insert(
+            netuid,
+            6,
+            vec![(U256::from(1), commit(), 1, 1010)]
+                .into_iter()
+                .collect::<VecDeque<_>>(),
+        );
+
+        let weight = crate::migrations::migrate_reference_crv3_reveal_rounds::migrate_reference_crv3_reveal_rounds::<Test>();
+
+        assert!(HasMigrationRun::<Test>::get(
+            MIGRATION_NAME.as_bytes().to_vec()
+        ));
+        assert_eq!(pallet_drand::ReferencedRounds::<Test>::get(0, 1000), Some(2));
+        assert_eq!(pallet_drand::ReferencedRounds::<Test>::get(1, 1010), Some(1));
+        assert!(!weight.is_zero());
+
+        // Running it again does not reference the rounds twice
+        crate::migrations::migrate_reference_crv3_reveal_rounds::migrate_reference_crv3_reveal_rounds::<Test>();
+        assert_eq!(pallet_drand::ReferencedRounds::<Test>::get(0, 1000), Some(2));
+    });
+}
+
+#[test]
+fn test_migrate_crv3_commits_add_beacon_id() {
+    new_test_ext(1).execute_with(|| {
+        const MIGRATION_NAME: &str = "migrate_crv3_commits_add_beacon_id";
+        let netuid = 1u16;
+        let commit: BoundedVec<u8, ConstU32<MAX_CRV3_COMMIT_SIZE_BYTES>> =
+            vec![1u8, 2, 3].try_into().expect("commit fits");
+
+        // Commits stored before the beacon id was recorded
+        let old_commits: VecDeque<(U256, BoundedVec<u8, ConstU32<MAX_CRV3_COMMIT_SIZE_BYTES>>, u64)> =
+            vec![(U256::from(1), commit.clone(), 1000)].into_iter().collect();
+        let key = CRV3WeightCommits::<Test>::hashed_key_for(netuid, 5);
+        put_raw(&key, &old_commits.encode());
+
+        let weight = crate::migrations::migrate_crv3_commits_add_beacon_id::migrate_crv3_commits_add_beacon_id::<Test>();
+
+        assert!(HasMigrationRun::<Test>::get(
+            MIGRATION_NAME.as_bytes().to_vec()
+        ));
+        assert_eq!(
+            CRV3WeightCommits::<Test>::get(netuid, 5),
+            vec![(U256::from(1), commit, 0, 1000)]
+                .into_iter()
+                .collect::<VecDeque<_>>()
+        );
+        assert!(!weight.is_zero());
+    });
+}
+
+#[test]
+fn test_migrate_accept_pending_child_keys() {
+    new_test_ext(1).execute_with(|| {
+        const MIGRATION_NAME: &str = "migrate_accept_pending_child_keys";
+        let netuid = 1u16;
+        let parent = U256::from(1);
+        let child1 = U256::from(2);
+        let child2 = U256::from(3);
+
+        // A schedule pending before children had to accept it
+        PendingChildKeys::<Test>::insert(
+            netuid,
+            parent,
+            (vec![(u64::MAX / 2, child1), (u64::MAX / 2, child2)], 0),
+        );
+
+        let weight = crate::migrations::migrate_accept_pending_child_keys::migrate_accept_pending_child_keys::<Test>();
+
+        assert!(HasMigrationRun::<Test>::get(
+            MIGRATION_NAME.as_bytes().to_vec()
+        ));
+        assert!(ChildKeyAcceptances::<Test>::contains_key((
+            netuid, parent, child1
+        )));
+        assert!(ChildKeyAcceptances::<Test>::contains_key((
+            netuid, parent, child2
+        )));
+        assert!(!weight.is_zero());
+
+        // The schedule applies once its cooldown has passed
+        SubtensorModule::do_set_pending_children(netuid);
+        assert_eq!(
+            ChildKeys::<Test>::get(parent, netuid),
+            vec![(u64::MAX / 2, child1), (u64::MAX / 2, child2)]
+        );
+    });
+}
@@ -12,7 +12,7 @@ use frame_support::{
 use frame_system as system;
 use frame_system::{EnsureNever, EnsureRoot, RawOrigin, limits};
 use pallet_collective::MemberCount;
-use sp_core::{ConstU64, Get, H256, U256, offchain::KeyTypeId};
+use sp_core::{ConstU32, ConstU64, Get, H256, U256, offchain::KeyTypeId};
 use sp_runtime::Perbill;
 use sp_runtime::{
     BuildStorage,
@@ -39,6 +39,7 @@ frame_support::construct_runtime!(
         Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>} = 9,
         Preimage: pallet_preimage::{Pallet, Call, Storage, Event<T>} = 10,
         Drand: pallet_drand::{Pallet, Call, Storage, Event<T>} = 11,
+        Crowdloan: pallet_crowdloan::{Pallet, Call, Storage, Event<T>} = 12,
     }
 );
 
@@ -386,6 +387,8 @@ impl crate::Config for Test {
     type CouncilOrigin = frame_system::EnsureSigned<AccountId>;
     type SenateMembers = ManageSenateMembers;
     type TriumvirateInterface = TriumvirateVotes;
+    type CrowdloanInterface = CrowdloanAdapter;
+    type ProxyInterface = ();
     type Scheduler = Scheduler;
     type InitialMinAllowedWeights = InitialMinAllowedWeights;
     type InitialEmissionValue = InitialEmissionValue;
@@ -539,12 +542,61 @@ where
     type OverarchingCall = RuntimeCall;
 }
 
+parameter_types! {
+    pub const CrowdloanPalletId: frame_support::PalletId = frame_support::PalletId(*b"bt/cloan");
+    pub const CrowdloanMinimumDeposit: u64 = 50;
+    pub const CrowdloanAbsoluteMinimumContribution: u64 = 10;
+    pub const CrowdloanMinimumBlockDuration: u64 = 20;
+    pub const CrowdloanMaximumBlockDuration: u64 = 1000;
+    pub const CrowdloanRefundContributorsLimit: u32 = 5;
+    pub const CrowdloanMaxContributors: u32 = 10;
+    pub const CrowdloanMaxContributionTiers: u32 = 4;
+}
+
+impl pallet_crowdloan::Config for Test {
+    type PalletId = CrowdloanPalletId;
+    type Currency = Balances;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_crowdloan::weights::SubstrateWeight<Test>;
+    type Preimages = Preimage;
+    type MinimumDeposit = CrowdloanMinimumDeposit;
+    type AbsoluteMinimumContribution = CrowdloanAbsoluteMinimumContribution;
+    type MinimumBlockDuration = CrowdloanMinimumBlockDuration;
+    type MaximumBlockDuration = CrowdloanMaximumBlockDuration;
+    type RefundContributorsLimit = CrowdloanRefundContributorsLimit;
+    type MaxContributors = CrowdloanMaxContributors;
+    type MaxContributionTiers = CrowdloanMaxContributionTiers;
+}
+
+pub struct CrowdloanAdapter;
+impl crate::CrowdloanInterface<U256> for CrowdloanAdapter {
+    fn finalizing_crowdloan() -> Option<crate::FinalizingCrowdloan<U256>> {
+        let id = pallet_crowdloan::CurrentCrowdloanId::<Test>::get()?;
+        pallet_crowdloan::Crowdloans::<Test>::get(id).map(|crowdloan| crate::FinalizingCrowdloan {
+            id,
+            creator: crowdloan.creator,
+            funds_account: crowdloan.funds_account,
+            raised: crowdloan.raised,
+        })
+    }
+
+    fn contributions(crowdloan_id: u32) -> Vec<(U256, u64)> {
+        pallet_crowdloan::Contributions::<Test>::iter_prefix(crowdloan_id).collect()
+    }
+
+    fn max_contributors() -> u32 {
+        CrowdloanMaxContributors::get()
+    }
+}
+
 impl pallet_drand::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type AuthorityId = TestAuthId;
-    type Verifier = pallet_drand::verifier::QuicknetVerifier;
+    type Verifier = pallet_drand::verifier::SchemeVerifier;
     type UnsignedPriority = ConstU64<{ 1 << 20 }>;
     type HttpFetchTimeout = ConstU64<1_000>;
+    type MaxPulsesPrunedPerBlock = ConstU32<64>;
 }
 
 impl frame_system::offchain::SigningTypes for Test {
@@ -795,6 +847,21 @@ pub fn wait_set_pending_children_cooldown(netuid: u16) {
     step_epochs(1, netuid); // Run next epoch
 }
 
+/// Accepts the pending children schedule of `parent` through `accept_childkey`, signed by the
+/// coldkey of each child. Children without an owner are made their own coldkey.
+#[allow(dead_code)]
+pub fn mock_accept_children(netuid: u16, parent: &U256, child_vec: &[(u64, U256)]) {
+    for (_, child) in child_vec {
+        SubtensorModule::create_account_if_non_existent(child, child);
+        assert_ok!(SubtensorModule::accept_childkey(
+            RuntimeOrigin::signed(SubtensorModule::get_owning_coldkey_for_hotkey(child)),
+            *child,
+            *parent,
+            netuid
+        ));
+    }
+}
+
 #[allow(dead_code)]
 pub fn wait_and_set_pending_children(netuid: u16) {
     let original_block = System::block_number();
@@ -825,6 +892,7 @@ pub fn mock_schedule_children(
 #[allow(dead_code)]
 pub fn mock_set_children(coldkey: &U256, parent: &U256, netuid: u16, child_vec: &[(u64, U256)]) {
     mock_schedule_children(coldkey, parent, netuid, child_vec);
+    mock_accept_children(netuid, parent, child_vec);
     wait_and_set_pending_children(netuid);
 }
 
@@ -837,6 +905,13 @@ pub fn mock_set_children_no_epochs(netuid: u16, parent: &U256, child_vec: &[(u64
     System::set_block_number(backup_block);
 }
 
+#[allow(dead_code)]
+pub fn mock_set_accepted_children_no_epochs(netuid: u16, parent: &U256, child_vec: &[(u64, U256)]) {
+    PendingChildKeys::<Test>::insert(netuid, parent, (child_vec, 0));
+    mock_accept_children(netuid, parent, child_vec);
+    mock_set_children_no_epochs(netuid, parent, child_vec);
+}
+
 // Helper function to wait for the rate limit
 #[allow(dead_code)]
 pub fn step_rate_limit(transaction_type: &TransactionType, netuid: u16) {
@@ -1,3 +1,4 @@
+mod alpha_token;
 mod batch_tx;
 mod children;
 mod coinbase;
@@ -7,6 +8,7 @@ mod difficulty;
 mod emission;
 mod epoch;
 mod evm;
+mod leasing;
 mod math;
 mod migration;
 mod mock;
@@ -1,6 +1,6 @@
 use super::mock::*;
 use crate::*;
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 use frame_system::Config;
 use sp_core::U256;
 
@@ -32,11 +32,14 @@ fn test_registration_ok() {
             coldkey_account_id
         ));
 
+        SubnetSunsetUnstakeWindow::<Test>::put(0);
         assert_ok!(SubtensorModule::user_remove_network(
             coldkey_account_id,
             netuid
         ));
+        assert!(SubtensorModule::is_subnet_sunsetting(netuid));
 
+        step_block(3);
         assert!(!SubtensorModule::if_subnet_exist(netuid))
     })
 }
@@ -342,3 +345,116 @@ fn test_tempo_greater_than_weight_set_rate_limit() {
         assert!(tempo as u64 >= weights_set_rate_limit);
     })
 }
+
+#[test]
+fn test_subnet_sunset_phases() {
+    new_test_ext(1).execute_with(|| {
+        let owner_hotkey = U256::from(1);
+        let owner_coldkey = U256::from(2);
+        let staker_coldkey = U256::from(3);
+        let new_hotkey = U256::from(4);
+        let amount = DefaultMinStake::<Test>::get() * 10;
+
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        SubtensorModule::add_balance_to_coldkey_account(&staker_coldkey, amount * 2);
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(staker_coldkey),
+            owner_hotkey,
+            netuid,
+            amount
+        ));
+        let balance_before = SubtensorModule::get_coldkey_balance(&staker_coldkey);
+
+        SubnetSunsetUnstakeWindow::<Test>::put(2);
+        assert_ok!(SubtensorModule::start_subnet_sunset(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid
+        ));
+        System::assert_last_event(
+            Event::SubnetSunsetStarted {
+                netuid,
+                unstake_window_ends: 3,
+            }
+            .into(),
+        );
+
+        // Emissions, registrations and new stake are stopped.
+        assert_noop!(
+            SubtensorModule::add_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(staker_coldkey),
+                owner_hotkey,
+                netuid,
+                amount
+            ),
+            Error::<Test>::SubnetSunsetting
+        );
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(staker_coldkey),
+                netuid,
+                new_hotkey
+            ),
+            Error::<Test>::SubnetSunsetting
+        );
+
+        // Stake cannot be moved within the subnet and the subnet cannot be sold.
+        let alpha = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &owner_hotkey,
+            &staker_coldkey,
+            netuid,
+        );
+        assert_noop!(
+            SubtensorModule::transfer_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(staker_coldkey),
+                new_hotkey,
+                owner_hotkey,
+                netuid,
+                netuid,
+                alpha
+            ),
+            Error::<Test>::SubnetSunsetting
+        );
+        assert_noop!(
+            SubtensorModule::list_subnet_for_sale(
+                <<Test as Config>::RuntimeOrigin>::signed(owner_coldkey),
+                netuid,
+                amount,
+                None
+            ),
+            Error::<Test>::SubnetSunsetting
+        );
+
+        // The unstake window closes, then the remaining alpha is unstaked.
+        step_block(3);
+        assert_eq!(
+            SubnetSunset::<Test>::get(netuid),
+            Some(SubnetSunsetPhase::Liquidating)
+        );
+        System::assert_has_event(Event::SubnetUnstakeWindowClosed { netuid }.into());
+
+        step_block(1);
+        assert_eq!(
+            SubnetSunset::<Test>::get(netuid),
+            Some(SubnetSunsetPhase::CleaningUp)
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &owner_hotkey,
+                &staker_coldkey,
+                netuid
+            ),
+            0
+        );
+        assert!(SubtensorModule::get_coldkey_balance(&staker_coldkey) > balance_before);
+        assert_eq!(
+            SubnetAlphaPositions::<Test>::iter_key_prefix((netuid,)).count(),
+            0
+        );
+
+        // Finally the subnet storage is removed.
+        step_block(1);
+        assert!(!SubtensorModule::if_subnet_exist(netuid));
+        assert!(!SubnetSunset::<Test>::contains_key(netuid));
+        System::assert_has_event(Event::SubnetSunsetCompleted { netuid }.into());
+    })
+}
@@ -565,3 +565,329 @@ fn test_subtoken_enable_ok_for_burn_register_before_enable() {
         ));
     });
 }
+
+/***************************
+  subnet ownership transfer tests
+*****************************/
+
+#[test]
+fn test_offer_and_accept_subnet_ownership() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(0);
+        let new_owner = U256::from(1);
+        let new_owner_hotkey = U256::from(100);
+        let other = U256::from(2);
+        add_network(netuid, 13, 0);
+
+        // Only the owner can offer the subnet
+        assert_noop!(
+            SubtensorModule::offer_subnet_ownership(
+                <<Test as Config>::RuntimeOrigin>::signed(other),
+                netuid,
+                other
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(SubtensorModule::offer_subnet_ownership(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            new_owner
+        ));
+        assert_eq!(SubnetOwner::<Test>::get(netuid), owner);
+
+        // Only the recipient of the offer can accept it
+        assert_noop!(
+            SubtensorModule::accept_subnet_ownership(
+                <<Test as Config>::RuntimeOrigin>::signed(other),
+                netuid,
+                new_owner_hotkey
+            ),
+            Error::<Test>::NoSubnetOwnershipOffer
+        );
+
+        // The new owner must name a hotkey they own
+        SubtensorModule::create_account_if_non_existent(&other, &U256::from(200));
+        assert_noop!(
+            SubtensorModule::accept_subnet_ownership(
+                <<Test as Config>::RuntimeOrigin>::signed(new_owner),
+                netuid,
+                U256::from(200)
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_ok!(SubtensorModule::accept_subnet_ownership(
+            <<Test as Config>::RuntimeOrigin>::signed(new_owner),
+            netuid,
+            new_owner_hotkey
+        ));
+
+        assert_eq!(SubnetOwner::<Test>::get(netuid), new_owner);
+        assert_eq!(SubnetOwnerHotkey::<Test>::get(netuid), new_owner_hotkey);
+        assert_eq!(Owner::<Test>::get(new_owner_hotkey), new_owner);
+        assert!(PendingSubnetOwner::<Test>::get(netuid).is_none());
+        System::assert_has_event(
+            Event::SubnetOwnershipTransferred {
+                netuid,
+                old_owner: owner,
+                new_owner,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_cancel_subnet_ownership_offer() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(0);
+        let new_owner = U256::from(1);
+        add_network(netuid, 13, 0);
+
+        assert_noop!(
+            SubtensorModule::cancel_subnet_ownership_offer(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid
+            ),
+            Error::<Test>::NoSubnetOwnershipOffer
+        );
+        assert_ok!(SubtensorModule::offer_subnet_ownership(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            new_owner
+        ));
+        assert_ok!(SubtensorModule::cancel_subnet_ownership_offer(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid
+        ));
+
+        assert_noop!(
+            SubtensorModule::accept_subnet_ownership(
+                <<Test as Config>::RuntimeOrigin>::signed(new_owner),
+                netuid,
+                U256::from(100)
+            ),
+            Error::<Test>::NoSubnetOwnershipOffer
+        );
+        assert_eq!(SubnetOwner::<Test>::get(netuid), owner);
+    });
+}
+
+#[test]
+fn test_offer_subnet_ownership_to_co_owners() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(0);
+        let co_owners = vec![U256::from(3), U256::from(1), U256::from(2)];
+        add_network(netuid, 13, 0);
+
+        assert_noop!(
+            SubtensorModule::offer_subnet_ownership_to_co_owners(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                vec![U256::from(1), U256::from(1)],
+                1
+            ),
+            Error::<Test>::InvalidSubnetCoOwners
+        );
+        assert_noop!(
+            SubtensorModule::offer_subnet_ownership_to_co_owners(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                co_owners.clone(),
+                4
+            ),
+            Error::<Test>::InvalidSubnetCoOwnerThreshold
+        );
+
+        assert_ok!(SubtensorModule::offer_subnet_ownership_to_co_owners(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            co_owners,
+            2
+        ));
+
+        let sorted = vec![U256::from(1), U256::from(2), U256::from(3)];
+        let multisig = SubtensorModule::get_co_owners_account_id(&sorted, 2);
+
+        // A single co-owner cannot accept on behalf of the group
+        assert_noop!(
+            SubtensorModule::accept_subnet_ownership(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                U256::from(100)
+            ),
+            Error::<Test>::NoSubnetOwnershipOffer
+        );
+        assert_ok!(SubtensorModule::accept_subnet_ownership(
+            <<Test as Config>::RuntimeOrigin>::signed(multisig),
+            netuid,
+            U256::from(100)
+        ));
+
+        assert_eq!(SubnetOwner::<Test>::get(netuid), multisig);
+        assert_eq!(SubnetCoOwners::<Test>::get(netuid), Some((sorted, 2)));
+
+        // Transferring to a single owner drops the co-owner set
+        assert_ok!(SubtensorModule::offer_subnet_ownership(
+            <<Test as Config>::RuntimeOrigin>::signed(multisig),
+            netuid,
+            owner
+        ));
+        assert_ok!(SubtensorModule::accept_subnet_ownership(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            U256::from(101)
+        ));
+        assert!(SubnetCoOwners::<Test>::get(netuid).is_none());
+        assert_eq!(SubnetOwnerHotkey::<Test>::get(netuid), U256::from(101));
+    });
+}
+
+/***************************
+  subnet marketplace tests
+*****************************/
+
+#[test]
+fn test_buy_subnet_switches_ownership_atomically() {
+    new_test_ext(0).execute_with(|| {
+        let seller = U256::from(10);
+        let seller_hotkey = U256::from(11);
+        let buyer = U256::from(20);
+        let buyer_hotkey = U256::from(21);
+        let price: u64 = 1_000_000_000;
+        let netuid = add_dynamic_network(&seller_hotkey, &seller);
+
+        assert_ok!(SubtensorModule::list_subnet_for_sale(
+            <<Test as Config>::RuntimeOrigin>::signed(seller),
+            netuid,
+            price,
+            None
+        ));
+
+        // Owner cut accrued before the sale
+        let owner_cut: u64 = 5_000;
+        PendingOwnerCut::<Test>::insert(netuid, owner_cut);
+        let seller_stake_before = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &seller_hotkey,
+            &seller,
+            netuid,
+        );
+        let seller_balance_before = SubtensorModule::get_coldkey_balance(&seller);
+
+        SubtensorModule::add_balance_to_coldkey_account(&buyer, price + 1_000);
+        assert_noop!(
+            SubtensorModule::buy_subnet(
+                <<Test as Config>::RuntimeOrigin>::signed(buyer),
+                netuid,
+                buyer_hotkey,
+                price - 1
+            ),
+            Error::<Test>::SubnetPriceAboveLimit
+        );
+        assert_ok!(SubtensorModule::buy_subnet(
+            <<Test as Config>::RuntimeOrigin>::signed(buyer),
+            netuid,
+            buyer_hotkey,
+            price
+        ));
+
+        assert_eq!(SubnetOwner::<Test>::get(netuid), buyer);
+        assert_eq!(SubnetOwnerHotkey::<Test>::get(netuid), buyer_hotkey);
+        assert!(SubnetSaleListings::<Test>::get(netuid).is_none());
+        assert_eq!(SubtensorModule::get_coldkey_balance(&buyer), 1_000);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&seller),
+            seller_balance_before + price
+        );
+        assert_eq!(PendingOwnerCut::<Test>::get(netuid), 0);
+        assert!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &seller_hotkey,
+                &seller,
+                netuid
+            ) > seller_stake_before
+        );
+    });
+}
+
+#[test]
+fn test_buy_subnet_listing_checks() {
+    new_test_ext(0).execute_with(|| {
+        let seller = U256::from(10);
+        let seller_hotkey = U256::from(11);
+        let buyer = U256::from(20);
+        let buyer_hotkey = U256::from(21);
+        let price: u64 = 1_000;
+        let netuid = add_dynamic_network(&seller_hotkey, &seller);
+        SubtensorModule::add_balance_to_coldkey_account(&buyer, 1_000_000);
+
+        // Not listed
+        assert_noop!(
+            SubtensorModule::buy_subnet(
+                <<Test as Config>::RuntimeOrigin>::signed(buyer),
+                netuid,
+                buyer_hotkey,
+                price
+            ),
+            Error::<Test>::SubnetListingNotFound
+        );
+
+        // Only the owner can list
+        assert_noop!(
+            SubtensorModule::list_subnet_for_sale(
+                <<Test as Config>::RuntimeOrigin>::signed(buyer),
+                netuid,
+                price,
+                None
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        let deadline = System::block_number() + 10;
+        assert_ok!(SubtensorModule::list_subnet_for_sale(
+            <<Test as Config>::RuntimeOrigin>::signed(seller),
+            netuid,
+            price,
+            Some(deadline)
+        ));
+
+        // The hotkey must not belong to another coldkey
+        assert_noop!(
+            SubtensorModule::buy_subnet(
+                <<Test as Config>::RuntimeOrigin>::signed(buyer),
+                netuid,
+                seller_hotkey,
+                price
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+
+        // Expired listings cannot be bought
+        System::set_block_number(deadline + 1);
+        assert_noop!(
+            SubtensorModule::buy_subnet(
+                <<Test as Config>::RuntimeOrigin>::signed(buyer),
+                netuid,
+                buyer_hotkey,
+                price
+            ),
+            Error::<Test>::SubnetListingExpired
+        );
+
+        // Cancelled listings cannot be bought
+        assert_ok!(SubtensorModule::cancel_subnet_listing(
+            <<Test as Config>::RuntimeOrigin>::signed(seller),
+            netuid
+        ));
+        assert_noop!(
+            SubtensorModule::cancel_subnet_listing(
+                <<Test as Config>::RuntimeOrigin>::signed(seller),
+                netuid
+            ),
+            Error::<Test>::SubnetListingNotFound
+        );
+        assert_eq!(SubnetOwner::<Test>::get(netuid), seller);
+    });
+}
@@ -2429,3 +2429,676 @@ fn test_coldkey_in_swap_schedule_prevents_critical_calls() {
         );
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --package pallet-subtensor --lib -- tests::swap_coldkey::test_set_coldkey_guardians --exact --show-output --nocapture
+#[test]
+fn test_set_coldkey_guardians() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let guardians = vec![U256::from(10), U256::from(11), U256::from(12)];
+
+        for (invalid_guardians, threshold) in [
+            (guardians.clone(), 0),
+            (guardians.clone(), 4),
+            (vec![U256::from(10), U256::from(10)], 1),
+            (vec![coldkey, U256::from(10)], 1),
+            (
+                (0..=MAX_COLDKEY_GUARDIANS as u64).map(U256::from).collect(),
+                1,
+            ),
+        ] {
+            assert_noop!(
+                SubtensorModule::set_coldkey_guardians(
+                    RuntimeOrigin::signed(coldkey),
+                    invalid_guardians,
+                    threshold
+                ),
+                Error::<Test>::InvalidColdkeyGuardians
+            );
+        }
+
+        assert_ok!(SubtensorModule::set_coldkey_guardians(
+            RuntimeOrigin::signed(coldkey),
+            guardians.clone(),
+            2
+        ));
+        assert_eq!(
+            ColdkeyGuardians::<Test>::get(coldkey),
+            Some(ColdkeyGuardianSet {
+                guardians: guardians.clone(),
+                threshold: 2
+            })
+        );
+        System::assert_last_event(
+            Event::ColdkeyGuardiansSet {
+                coldkey,
+                guardians,
+                threshold: 2,
+            }
+            .into(),
+        );
+
+        // An empty list removes the guardians.
+        assert_ok!(SubtensorModule::set_coldkey_guardians(
+            RuntimeOrigin::signed(coldkey),
+            vec![],
+            0
+        ));
+        assert!(ColdkeyGuardians::<Test>::get(coldkey).is_none());
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --package pallet-subtensor --lib -- tests::swap_coldkey::test_coldkey_recovery_by_guardians --exact --show-output --nocapture
+#[test]
+fn test_coldkey_recovery_by_guardians() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let guardian_1 = U256::from(10);
+        let guardian_2 = U256::from(11);
+        let guardian_3 = U256::from(12);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        assert_ok!(SubtensorModule::set_coldkey_guardians(
+            RuntimeOrigin::signed(old_coldkey),
+            vec![guardian_1, guardian_2, guardian_3],
+            2
+        ));
+
+        // Only guardians can approve.
+        assert_noop!(
+            SubtensorModule::approve_coldkey_recovery(
+                RuntimeOrigin::signed(new_coldkey),
+                old_coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::NotColdkeyGuardian
+        );
+
+        assert_ok!(SubtensorModule::approve_coldkey_recovery(
+            RuntimeOrigin::signed(guardian_1),
+            old_coldkey,
+            new_coldkey
+        ));
+        assert_eq!(
+            PendingColdkeyRecoveries::<Test>::get(old_coldkey)
+                .unwrap()
+                .scheduled,
+            None
+        );
+        assert_noop!(
+            SubtensorModule::approve_coldkey_recovery(
+                RuntimeOrigin::signed(guardian_1),
+                old_coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::ColdkeyRecoveryAlreadyApproved
+        );
+
+        // Approvals for another new coldkey are counted apart.
+        assert_ok!(SubtensorModule::approve_coldkey_recovery(
+            RuntimeOrigin::signed(guardian_2),
+            old_coldkey,
+            U256::from(4)
+        ));
+        assert_eq!(
+            PendingColdkeyRecoveries::<Test>::get(old_coldkey)
+                .unwrap()
+                .scheduled,
+            None
+        );
+
+        // The second guardian moves its approval, reaching the threshold and scheduling the swap.
+        assert_ok!(SubtensorModule::approve_coldkey_recovery(
+            RuntimeOrigin::signed(guardian_2),
+            old_coldkey,
+            new_coldkey
+        ));
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+        System::assert_last_event(
+            Event::ColdkeyRecoveryScheduled {
+                coldkey: old_coldkey,
+                new_coldkey,
+                execution_block,
+            }
+            .into(),
+        );
+        assert_noop!(
+            SubtensorModule::execute_coldkey_recovery(RuntimeOrigin::root(), old_coldkey),
+            Error::<Test>::ColdkeyRecoveryNotDue
+        );
+
+        run_to_block(execution_block);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+        assert!(PendingColdkeyRecoveries::<Test>::get(old_coldkey).is_none());
+        assert!(ColdkeyGuardians::<Test>::get(old_coldkey).is_none());
+        assert!(ColdkeyGuardians::<Test>::get(new_coldkey).is_some());
+        System::assert_has_event(
+            Event::ColdkeyRecovered {
+                old_coldkey,
+                new_coldkey,
+                swap_cost: SubtensorModule::get_key_swap_cost(),
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --package pallet-subtensor --lib -- tests::swap_coldkey::test_coldkey_recovery_approval_withdrawn --exact --show-output --nocapture
+#[test]
+fn test_coldkey_recovery_approval_withdrawn() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let guardian_1 = U256::from(10);
+        let guardian_2 = U256::from(11);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        assert_ok!(SubtensorModule::set_coldkey_guardians(
+            RuntimeOrigin::signed(old_coldkey),
+            vec![guardian_1, guardian_2],
+            2
+        ));
+        assert_noop!(
+            SubtensorModule::withdraw_coldkey_recovery_approval(
+                RuntimeOrigin::signed(guardian_1),
+                old_coldkey
+            ),
+            Error::<Test>::NoPendingColdkeyRecovery
+        );
+
+        for guardian in [guardian_1, guardian_2] {
+            assert_ok!(SubtensorModule::approve_coldkey_recovery(
+                RuntimeOrigin::signed(guardian),
+                old_coldkey,
+                new_coldkey
+            ));
+        }
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+        assert_eq!(
+            PendingColdkeyRecoveries::<Test>::get(old_coldkey)
+                .unwrap()
+                .scheduled,
+            Some((new_coldkey, execution_block))
+        );
+
+        // Withdrawing an approval below the threshold unschedules the swap.
+        assert_ok!(SubtensorModule::withdraw_coldkey_recovery_approval(
+            RuntimeOrigin::signed(guardian_2),
+            old_coldkey
+        ));
+        System::assert_has_event(
+            Event::ColdkeyRecoveryUnscheduled {
+                coldkey: old_coldkey,
+                new_coldkey,
+            }
+            .into(),
+        );
+        assert_noop!(
+            SubtensorModule::withdraw_coldkey_recovery_approval(
+                RuntimeOrigin::signed(guardian_2),
+                old_coldkey
+            ),
+            Error::<Test>::ColdkeyRecoveryNotApproved
+        );
+
+        run_to_block(execution_block);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+
+        // The last withdrawal drops the recovery.
+        assert_ok!(SubtensorModule::withdraw_coldkey_recovery_approval(
+            RuntimeOrigin::signed(guardian_1),
+            old_coldkey
+        ));
+        assert!(PendingColdkeyRecoveries::<Test>::get(old_coldkey).is_none());
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --package pallet-subtensor --lib -- tests::swap_coldkey::test_coldkey_recovery_not_blocked_by_new_coldkey_stake --exact --show-output --nocapture
+#[test]
+fn test_coldkey_recovery_not_blocked_by_new_coldkey_stake() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let other_coldkey = U256::from(4);
+        let other_hotkey = U256::from(5);
+        let guardian = U256::from(10);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        assert_ok!(SubtensorModule::set_coldkey_guardians(
+            RuntimeOrigin::signed(old_coldkey),
+            vec![guardian],
+            1
+        ));
+        assert_ok!(SubtensorModule::approve_coldkey_recovery(
+            RuntimeOrigin::signed(guardian),
+            old_coldkey,
+            new_coldkey
+        ));
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+
+        // Someone stakes to the new coldkey during the delay, the stake is merged.
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &new_coldkey,
+            netuid,
+            1_000,
+        );
+
+        run_to_block(execution_block);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+        assert!(PendingColdkeyRecoveries::<Test>::get(old_coldkey).is_none());
+
+        // Registering the next new coldkey as a hotkey unschedules the recovery instead of
+        // blocking it.
+        let next_coldkey = U256::from(6);
+        assert_ok!(SubtensorModule::approve_coldkey_recovery(
+            RuntimeOrigin::signed(guardian),
+            new_coldkey,
+            next_coldkey
+        ));
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+        register_ok_neuron(netuid, next_coldkey, other_coldkey, 1);
+
+        run_to_block(execution_block);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+        assert!(PendingColdkeyRecoveries::<Test>::get(new_coldkey).is_none());
+        System::assert_has_event(
+            Event::ColdkeyRecoveryUnscheduled {
+                coldkey: new_coldkey,
+                new_coldkey: next_coldkey,
+            }
+            .into(),
+        );
+
+        // The guardian can approve another coldkey.
+        assert_ok!(SubtensorModule::approve_coldkey_recovery(
+            RuntimeOrigin::signed(guardian),
+            new_coldkey,
+            other_hotkey
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --package pallet-subtensor --lib -- tests::swap_coldkey::test_coldkey_recovery_cancelled_by_coldkey --exact --show-output --nocapture
+#[test]
+fn test_coldkey_recovery_cancelled_by_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let guardian = U256::from(10);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        assert_ok!(SubtensorModule::set_coldkey_guardians(
+            RuntimeOrigin::signed(old_coldkey),
+            vec![guardian],
+            1
+        ));
+        assert_ok!(SubtensorModule::approve_coldkey_recovery(
+            RuntimeOrigin::signed(guardian),
+            old_coldkey,
+            new_coldkey
+        ));
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+
+        // The coldkey cancels during the delay.
+        assert_ok!(SubtensorModule::cancel_coldkey_recovery(
+            RuntimeOrigin::signed(old_coldkey)
+        ));
+        System::assert_last_event(
+            Event::ColdkeyRecoveryCancelled {
+                coldkey: old_coldkey,
+            }
+            .into(),
+        );
+        assert_noop!(
+            SubtensorModule::cancel_coldkey_recovery(RuntimeOrigin::signed(old_coldkey)),
+            Error::<Test>::NoPendingColdkeyRecovery
+        );
+
+        run_to_block(execution_block);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+
+        // The scheduled execution finds no recovery and the coldkey keeps its hotkey.
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+        assert_noop!(
+            SubtensorModule::execute_coldkey_recovery(RuntimeOrigin::root(), old_coldkey),
+            Error::<Test>::NoPendingColdkeyRecovery
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --package pallet-subtensor --lib -- tests::swap_coldkey::test_cancel_coldkey_swap --exact --show-output --nocapture
+#[test]
+fn test_cancel_coldkey_swap() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        assert_noop!(
+            SubtensorModule::cancel_coldkey_swap(RuntimeOrigin::signed(old_coldkey)),
+            Error::<Test>::ColdkeySwapNotScheduled
+        );
+
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            RuntimeOrigin::signed(old_coldkey),
+            new_coldkey
+        ));
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+
+        assert_ok!(SubtensorModule::cancel_coldkey_swap(RuntimeOrigin::signed(
+            old_coldkey
+        )));
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+        System::assert_last_event(
+            Event::ColdkeySwapCancelled {
+                old_coldkey,
+                new_coldkey,
+            }
+            .into(),
+        );
+
+        // The scheduled call no longer swaps the coldkey.
+        run_to_block(execution_block);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+        assert_noop!(
+            SubtensorModule::execute_scheduled_coldkey_swap(
+                RuntimeOrigin::root(),
+                old_coldkey,
+                new_coldkey,
+                0
+            ),
+            Error::<Test>::ColdkeySwapNotScheduled
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --package pallet-subtensor --lib -- tests::swap_coldkey::test_root_swap_coldkey_without_schedule --exact --show-output --nocapture
+#[test]
+fn test_root_swap_coldkey_without_schedule() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        // Root can still swap a coldkey directly, without a scheduled swap.
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+        assert_ok!(SubtensorModule::swap_coldkey(
+            RuntimeOrigin::root(),
+            old_coldkey,
+            new_coldkey,
+            0
+        ));
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --package pallet-subtensor --lib -- tests::swap_coldkey::test_coldkey_recovery_waits_for_contested_swap --exact --show-output --nocapture
+#[test]
+fn test_coldkey_recovery_waits_for_contested_swap() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let swap_coldkey = U256::from(4);
+        let guardian = U256::from(10);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            RuntimeOrigin::signed(old_coldkey),
+            swap_coldkey
+        ));
+        assert_ok!(SubtensorModule::contest_coldkey_swap(
+            RuntimeOrigin::signed(old_coldkey)
+        ));
+
+        assert_ok!(SubtensorModule::set_coldkey_guardians(
+            RuntimeOrigin::signed(old_coldkey),
+            vec![guardian],
+            1
+        ));
+        assert_ok!(SubtensorModule::approve_coldkey_recovery(
+            RuntimeOrigin::signed(guardian),
+            old_coldkey,
+            new_coldkey
+        ));
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+
+        // The recovery does not execute while the swap is contested.
+        run_to_block(execution_block);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+        assert_noop!(
+            SubtensorModule::execute_coldkey_recovery(RuntimeOrigin::root(), old_coldkey),
+            Error::<Test>::ColdkeySwapContested
+        );
+
+        // Once the contest is rejected, the recovery executes and the swap is gone.
+        assert_ok!(SubtensorModule::resolve_coldkey_swap_contest(
+            RuntimeOrigin::root(),
+            old_coldkey,
+            false
+        ));
+        assert_ok!(SubtensorModule::execute_coldkey_recovery(
+            RuntimeOrigin::signed(guardian),
+            old_coldkey
+        ));
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --package pallet-subtensor --lib -- tests::swap_coldkey::test_contested_coldkey_swap_rejected --exact --show-output --nocapture
+#[test]
+fn test_contested_coldkey_swap_rejected() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let netuid = 1u16;
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            RuntimeOrigin::signed(old_coldkey),
+            new_coldkey
+        ));
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+        let balance_before = SubtensorModule::get_coldkey_balance(&old_coldkey);
+
+        // Contesting holds the swap cost in escrow.
+        assert_ok!(SubtensorModule::contest_coldkey_swap(
+            RuntimeOrigin::signed(old_coldkey)
+        ));
+        assert_eq!(
+            ContestedColdkeySwaps::<Test>::get(old_coldkey),
+            Some(swap_cost)
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&old_coldkey),
+            balance_before - swap_cost
+        );
+        System::assert_last_event(
+            Event::ColdkeySwapContested {
+                old_coldkey,
+                new_coldkey,
+                escrow: swap_cost,
+            }
+            .into(),
+        );
+
+        // The swap is frozen.
+        assert_noop!(
+            SubtensorModule::contest_coldkey_swap(RuntimeOrigin::signed(old_coldkey)),
+            Error::<Test>::ColdkeySwapContested
+        );
+        assert_noop!(
+            SubtensorModule::cancel_coldkey_swap(RuntimeOrigin::signed(old_coldkey)),
+            Error::<Test>::ColdkeySwapContested
+        );
+        run_to_block(execution_block);
+        <pallet_scheduler::Pallet<Test> as OnInitialize<BlockNumber>>::on_initialize(
+            execution_block,
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+        assert!(ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+        run_to_block(execution_block + ColdkeySwapRescheduleDuration::<Test>::get());
+        assert_noop!(
+            SubtensorModule::schedule_swap_coldkey(RuntimeOrigin::signed(old_coldkey), new_coldkey),
+            Error::<Test>::ColdkeySwapContested
+        );
+
+        // Only governance decides the contest.
+        assert_noop!(
+            SubtensorModule::resolve_coldkey_swap_contest(
+                RuntimeOrigin::signed(old_coldkey),
+                old_coldkey,
+                false
+            ),
+            BadOrigin
+        );
+        assert_ok!(SubtensorModule::resolve_coldkey_swap_contest(
+            RuntimeOrigin::root(),
+            old_coldkey,
+            false
+        ));
+        System::assert_last_event(
+            Event::ColdkeySwapContestResolved {
+                old_coldkey,
+                new_coldkey,
+                approved: false,
+            }
+            .into(),
+        );
+
+        // The swap is removed and the escrow refunded.
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+        assert!(ContestedColdkeySwaps::<Test>::get(old_coldkey).is_none());
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&old_coldkey),
+            balance_before
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+        assert_noop!(
+            SubtensorModule::resolve_coldkey_swap_contest(
+                RuntimeOrigin::root(),
+                old_coldkey,
+                false
+            ),
+            Error::<Test>::ColdkeySwapNotContested
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --package pallet-subtensor --lib -- tests::swap_coldkey::test_contested_coldkey_swap_approved --exact --show-output --nocapture
+#[test]
+fn test_contested_coldkey_swap_approved() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let netuid = 1u16;
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            RuntimeOrigin::signed(old_coldkey),
+            new_coldkey
+        ));
+        let balance_before = SubtensorModule::get_coldkey_balance(&old_coldkey);
+        assert_ok!(SubtensorModule::contest_coldkey_swap(
+            RuntimeOrigin::signed(old_coldkey)
+        ));
+
+        // An approved swap executes immediately, paid from the escrow.
+        assert_ok!(SubtensorModule::resolve_coldkey_swap_contest(
+            RuntimeOrigin::root(),
+            old_coldkey,
+            true
+        ));
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+        assert!(ContestedColdkeySwaps::<Test>::get(old_coldkey).is_none());
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&new_coldkey),
+            balance_before - swap_cost
+        );
+        System::assert_has_event(
+            Event::ColdkeySwapped {
+                old_coldkey,
+                new_coldkey,
+                swap_cost,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::ColdkeySwapContestResolved {
+                old_coldkey,
+                new_coldkey,
+                approved: true,
+            }
+            .into(),
+        );
+    });
+}
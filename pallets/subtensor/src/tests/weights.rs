@@ -26,7 +26,7 @@ use tle::{
 };
 use w3f_bls::EngineBLS;
 
-use pallet_drand::types::Pulse;
+use pallet_drand::types::{BeaconConfiguration, DEFAULT_BEACON_ID, Pulse};
 use sp_core::Encode;
 
 /***************************
@@ -4724,6 +4724,7 @@ fn test_reveal_crv3_commits_success() {
             RuntimeOrigin::signed(hotkey1),
             netuid,
             commit_bytes.clone().try_into().expect("Failed to convert commit bytes into bounded vector"),
+            DEFAULT_BEACON_ID,
             reveal_round
         ));
 
@@ -4784,6 +4785,9 @@ fn test_reveal_crv3_commits_success() {
                 uid_a, expected_weight, rounded_actual_weight
             );
         }
+
+        // The drand round is released once the commit is revealed
+        assert!(pallet_drand::ReferencedRounds::<Test>::get(DEFAULT_BEACON_ID, reveal_round).is_none());
     });
 }
 
@@ -4858,6 +4862,7 @@ fn test_reveal_crv3_commits_cannot_reveal_after_reveal_epoch() {
                 .clone()
                 .try_into()
                 .expect("Failed to convert commit bytes into bounded vector"),
+            DEFAULT_BEACON_ID,
             reveal_round
         ));
 
@@ -4935,6 +4940,7 @@ fn test_do_commit_crv3_weights_success() {
                 .clone()
                 .try_into()
                 .expect("Failed to convert commit data into bounded vector"),
+            DEFAULT_BEACON_ID,
             reveal_round
         ));
 
@@ -4944,7 +4950,12 @@ fn test_do_commit_crv3_weights_success() {
         assert_eq!(commits.len(), 1);
         assert_eq!(commits[0].0, hotkey);
         assert_eq!(commits[0].1, commit_data);
-        assert_eq!(commits[0].2, reveal_round);
+        assert_eq!(commits[0].2, DEFAULT_BEACON_ID);
+        assert_eq!(commits[0].3, reveal_round);
+        assert_eq!(
+            pallet_drand::ReferencedRounds::<Test>::get(DEFAULT_BEACON_ID, reveal_round),
+            Some(1)
+        );
     });
 }
 
@@ -4969,6 +4980,7 @@ fn test_do_commit_crv3_weights_disabled() {
                 commit_data
                     .try_into()
                     .expect("Failed to convert commit data into bounded vector"),
+                DEFAULT_BEACON_ID,
                 reveal_round
             ),
             Error::<Test>::CommitRevealDisabled
@@ -4976,6 +4988,62 @@ fn test_do_commit_crv3_weights_disabled() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_do_commit_crv3_weights_with_beacon --exact --show-output --nocapture
+#[test]
+fn test_do_commit_crv3_weights_with_beacon() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: AccountId = U256::from(1);
+        let commit_data: BoundedVec<u8, ConstU32<MAX_CRV3_COMMIT_SIZE_BYTES>> = vec![1, 2, 3]
+            .try_into()
+            .expect("Failed to convert commit data into bounded vector");
+        let beacon_id = 1;
+        let reveal_round: u64 = 1000;
+
+        add_network(netuid, 5, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100_000);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        // The beacon must be bridged
+        assert_err!(
+            SubtensorModule::commit_crv3_weights_with_beacon(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                commit_data.clone(),
+                beacon_id,
+                reveal_round
+            ),
+            Error::<Test>::UnknownDrandBeacon
+        );
+
+        pallet_drand::Beacons::<Test>::insert(beacon_id, BeaconConfiguration::default());
+        assert_ok!(SubtensorModule::commit_crv3_weights_with_beacon(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_data.clone(),
+            beacon_id,
+            reveal_round
+        ));
+
+        let cur_epoch =
+            SubtensorModule::get_epoch_index(netuid, SubtensorModule::get_current_block_as_u64());
+        let commits = CRV3WeightCommits::<Test>::get(netuid, cur_epoch);
+        assert_eq!(
+            commits.front(),
+            Some(&(hotkey, commit_data, beacon_id, reveal_round))
+        );
+        // The round of the beacon is kept, not the round of the default beacon
+        assert_eq!(
+            pallet_drand::ReferencedRounds::<Test>::get(beacon_id, reveal_round),
+            Some(1)
+        );
+        assert!(
+            pallet_drand::ReferencedRounds::<Test>::get(DEFAULT_BEACON_ID, reveal_round).is_none()
+        );
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_do_commit_crv3_weights_hotkey_not_registered --exact --show-output --nocapture
 #[test]
 fn test_do_commit_crv3_weights_hotkey_not_registered() {
@@ -4998,6 +5066,7 @@ fn test_do_commit_crv3_weights_hotkey_not_registered() {
                 commit_data
                     .try_into()
                     .expect("Failed to convert commit data into bounded vector"),
+                DEFAULT_BEACON_ID,
                 reveal_round
             ),
             Error::<Test>::HotKeyNotRegisteredInSubNet
@@ -5030,6 +5099,7 @@ fn test_do_commit_crv3_weights_committing_too_fast() {
                 .clone()
                 .try_into()
                 .expect("Failed to convert commit data into bounded vector"),
+            DEFAULT_BEACON_ID,
             reveal_round
         ));
 
@@ -5041,6 +5111,7 @@ fn test_do_commit_crv3_weights_committing_too_fast() {
                     .clone()
                     .try_into()
                     .expect("Failed to convert commit data into bounded vector"),
+                DEFAULT_BEACON_ID,
                 reveal_round
             ),
             Error::<Test>::CommittingWeightsTooFast
@@ -5056,6 +5127,7 @@ fn test_do_commit_crv3_weights_committing_too_fast() {
                     .clone()
                     .try_into()
                     .expect("Failed to convert commit data into bounded vector"),
+                DEFAULT_BEACON_ID,
                 reveal_round
             ),
             Error::<Test>::CommittingWeightsTooFast
@@ -5069,6 +5141,7 @@ fn test_do_commit_crv3_weights_committing_too_fast() {
             commit_data_2
                 .try_into()
                 .expect("Failed to convert commit data into bounded vector"),
+            DEFAULT_BEACON_ID,
             reveal_round
         ));
     });
@@ -5100,6 +5173,7 @@ fn test_do_commit_crv3_weights_too_many_unrevealed_commits() {
                 RuntimeOrigin::signed(hotkey1),
                 netuid,
                 bounded_commit_data,
+                DEFAULT_BEACON_ID,
                 reveal_round
             ));
         }
@@ -5115,6 +5189,7 @@ fn test_do_commit_crv3_weights_too_many_unrevealed_commits() {
                 RuntimeOrigin::signed(hotkey1),
                 netuid,
                 bounded_new_commit_data,
+                DEFAULT_BEACON_ID,
                 reveal_round
             ),
             Error::<Test>::TooManyUnrevealedCommits
@@ -5130,6 +5205,7 @@ fn test_do_commit_crv3_weights_too_many_unrevealed_commits() {
             RuntimeOrigin::signed(hotkey2),
             netuid,
             bounded_commit_data_hotkey2,
+            DEFAULT_BEACON_ID,
             reveal_round
         ));
 
@@ -5144,6 +5220,7 @@ fn test_do_commit_crv3_weights_too_many_unrevealed_commits() {
                 RuntimeOrigin::signed(hotkey2),
                 netuid,
                 bounded_commit_data,
+                DEFAULT_BEACON_ID,
                 reveal_round
             ));
         }
@@ -5159,6 +5236,7 @@ fn test_do_commit_crv3_weights_too_many_unrevealed_commits() {
                 RuntimeOrigin::signed(hotkey2),
                 netuid,
                 bounded_new_commit_data,
+                DEFAULT_BEACON_ID,
                 reveal_round
             ),
             Error::<Test>::TooManyUnrevealedCommits
@@ -5174,6 +5252,7 @@ fn test_do_commit_crv3_weights_too_many_unrevealed_commits() {
             RuntimeOrigin::signed(hotkey1),
             netuid,
             bounded_new_commit_data,
+            DEFAULT_BEACON_ID,
             reveal_round
         ));
     });
@@ -5202,6 +5281,7 @@ fn test_reveal_crv3_commits_decryption_failure() {
             RuntimeOrigin::signed(hotkey),
             netuid,
             bounded_commit_bytes,
+            DEFAULT_BEACON_ID,
             reveal_round
         ));
 
@@ -5309,12 +5389,14 @@ fn test_reveal_crv3_commits_multiple_commits_some_fail_some_succeed() {
             RuntimeOrigin::signed(hotkey1),
             netuid,
             commit_bytes_valid.try_into().expect("Failed to convert valid commit data"),
+            DEFAULT_BEACON_ID,
             reveal_round
         ));
         assert_ok!(SubtensorModule::do_commit_crv3_weights(
             RuntimeOrigin::signed(hotkey2),
             netuid,
             commit_bytes_invalid.try_into().expect("Failed to convert invalid commit data"),
+            DEFAULT_BEACON_ID,
             reveal_round
         ));
 
@@ -5411,6 +5493,7 @@ fn test_reveal_crv3_commits_do_set_weights_failure() {
             RuntimeOrigin::signed(hotkey),
             netuid,
             commit_bytes.try_into().expect("Failed to convert commit data into bounded vector"),
+            DEFAULT_BEACON_ID,
             reveal_round
         ));
 
@@ -5490,6 +5573,7 @@ fn test_reveal_crv3_commits_payload_decoding_failure() {
             RuntimeOrigin::signed(hotkey),
             netuid,
             commit_bytes.try_into().expect("Failed to convert commit data into bounded vector"),
+            DEFAULT_BEACON_ID,
             reveal_round
         ));
 
@@ -5575,6 +5659,7 @@ fn test_reveal_crv3_commits_signature_deserialization_failure() {
             RuntimeOrigin::signed(hotkey),
             netuid,
             commit_bytes.try_into().expect("Failed to convert commit data into bounded vector"),
+            DEFAULT_BEACON_ID,
             reveal_round
         ));
 
@@ -5639,6 +5724,7 @@ fn test_do_commit_crv3_weights_commit_size_exceeds_limit() {
             RuntimeOrigin::signed(hotkey),
             netuid,
             bounded_commit_data,
+            DEFAULT_BEACON_ID,
             reveal_round
         ));
     });
@@ -5724,6 +5810,7 @@ fn test_reveal_crv3_commits_with_incorrect_identity_message() {
             RuntimeOrigin::signed(hotkey),
             netuid,
             commit_bytes.try_into().expect("Failed to convert commit data into bounded vector"),
+            DEFAULT_BEACON_ID,
             reveal_round
         ));
 
@@ -5774,6 +5861,7 @@ fn test_multiple_commits_by_same_hotkey_within_limit() {
                 commit_data
                     .try_into()
                     .expect("Failed to convert commit data into bounded vector"),
+                DEFAULT_BEACON_ID,
                 reveal_round + i as u64
             ));
         }
@@ -5819,7 +5907,12 @@ fn test_reveal_crv3_commits_removes_past_epoch_commits() {
                 netuid,
                 *epoch,
                 |commits| -> DispatchResult {
-                    commits.push_back((hotkey, bounded_commit_data, reveal_round));
+                    commits.push_back((
+                        hotkey,
+                        bounded_commit_data,
+                        DEFAULT_BEACON_ID,
+                        reveal_round,
+                    ));
                     Ok(())
                 }
             ));
@@ -5937,6 +6030,7 @@ fn test_reveal_crv3_commits_multiple_valid_commits_all_processed() {
                 commit_bytes
                     .try_into()
                     .expect("Failed to convert commit data"),
+                DEFAULT_BEACON_ID,
                 reveal_round
             ));
 
@@ -6124,6 +6218,7 @@ fn test_reveal_crv3_commits_max_neurons() {
                 commit_bytes
                     .try_into()
                     .expect("Failed to convert commit data"),
+                DEFAULT_BEACON_ID,
                 reveal_round
             ));
 
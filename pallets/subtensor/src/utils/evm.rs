@@ -91,6 +91,68 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Associate a contract with a hotkey.
+    ///
+    /// Contracts cannot sign the message `do_associate_evm_key` expects, so the coldkey owning the
+    /// hotkey registers the contract address directly. The contract may then act as the hotkey
+    /// through the `...ForHotkey` calls of the neuron precompile, like an associated EVM key.
+    ///
+    /// The address is taken on trust from the coldkey and is not a proof of ownership, so it is
+    /// kept apart from `AssociatedEvmAddress` and is not returned by `uid_lookup`. A new call
+    /// replaces the previous contract of the hotkey.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call, which should be the coldkey that owns the hotkey.
+    /// * `netuid` - The unique identifier for the subnet that the hotkey belongs to.
+    /// * `hotkey` - The hotkey associated with the `origin` coldkey.
+    /// * `contract` - The contract address allowed to act as the `hotkey`.
+    pub fn do_associate_evm_contract(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        hotkey: T::AccountId,
+        contract: H160,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::get_owning_coldkey_for_hotkey(&hotkey) == coldkey,
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        let uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)?;
+
+        AssociatedEvmContract::<T>::insert(netuid, uid, contract);
+
+        Self::deposit_event(Event::EvmContractAssociated {
+            netuid,
+            hotkey,
+            contract,
+        });
+
+        Ok(())
+    }
+
+    /// Ensures `evm_key` is the EVM key or the contract associated with `hotkey` on `netuid`.
+    ///
+    /// The association belongs to the uid of the hotkey, so it ends when the uid is replaced.
+    pub fn ensure_evm_key_associated(
+        netuid: u16,
+        hotkey: &T::AccountId,
+        evm_key: H160,
+    ) -> dispatch::DispatchResult {
+        let uid = Self::get_uid_for_net_and_hotkey(netuid, hotkey)?;
+
+        ensure!(
+            AssociatedEvmAddress::<T>::get(netuid, uid)
+                .is_some_and(|(associated_key, _)| associated_key == evm_key)
+                || AssociatedEvmContract::<T>::get(netuid, uid) == Some(evm_key),
+            Error::<T>::EvmKeyNotAssociated
+        );
+
+        Ok(())
+    }
+
     pub fn uid_lookup(netuid: u16, evm_key: H160, limit: u16) -> Vec<(u16, u64)> {
         let mut ret_val = AssociatedEvmAddress::<T>::iter_prefix(netuid)
             .take(limit as usize)
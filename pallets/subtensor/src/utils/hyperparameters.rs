@@ -0,0 +1,394 @@
+use super::*;
+use crate::utils::rate_limiting::TransactionType;
+use codec::Compact;
+use frame_support::ensure;
+use frame_support::pallet_prelude::{Decode, Encode};
+use sp_std::vec::Vec;
+
+/// Identifier of a subnet hyperparameter in the registry.
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HyperparameterId {
+    Rho,
+    Kappa,
+    ImmunityPeriod,
+    MinAllowedWeights,
+    MaxWeightsLimit,
+    Tempo,
+    MinDifficulty,
+    MaxDifficulty,
+    WeightsVersionKey,
+    WeightsSetRateLimit,
+    AdjustmentInterval,
+    ActivityCutoff,
+    RegistrationAllowed,
+    PowRegistrationAllowed,
+    TargetRegistrationsPerInterval,
+    MinBurn,
+    MaxBurn,
+    BondsMovingAverage,
+    BondsPenalty,
+    MaxRegistrationsPerBlock,
+    ServingRateLimit,
+    MaxAllowedValidators,
+    MaxAllowedUids,
+    AdjustmentAlpha,
+    Difficulty,
+    CommitRevealPeriod,
+    CommitRevealWeightsEnabled,
+    LiquidAlphaEnabled,
+    Yuma3Enabled,
+    TransferEnabled,
+    AlphaSigmoidSteepness,
+    AnnouncementDelay,
+}
+
+impl HyperparameterId {
+    /// All hyperparameters in the registry, ordered by their numeric id.
+    pub const ALL: [HyperparameterId; 32] = [
+        HyperparameterId::Rho,
+        HyperparameterId::Kappa,
+        HyperparameterId::ImmunityPeriod,
+        HyperparameterId::MinAllowedWeights,
+        HyperparameterId::MaxWeightsLimit,
+        HyperparameterId::Tempo,
+        HyperparameterId::MinDifficulty,
+        HyperparameterId::MaxDifficulty,
+        HyperparameterId::WeightsVersionKey,
+        HyperparameterId::WeightsSetRateLimit,
+        HyperparameterId::AdjustmentInterval,
+        HyperparameterId::ActivityCutoff,
+        HyperparameterId::RegistrationAllowed,
+        HyperparameterId::PowRegistrationAllowed,
+        HyperparameterId::TargetRegistrationsPerInterval,
+        HyperparameterId::MinBurn,
+        HyperparameterId::MaxBurn,
+        HyperparameterId::BondsMovingAverage,
+        HyperparameterId::BondsPenalty,
+        HyperparameterId::MaxRegistrationsPerBlock,
+        HyperparameterId::ServingRateLimit,
+        HyperparameterId::MaxAllowedValidators,
+        HyperparameterId::MaxAllowedUids,
+        HyperparameterId::AdjustmentAlpha,
+        HyperparameterId::Difficulty,
+        HyperparameterId::CommitRevealPeriod,
+        HyperparameterId::CommitRevealWeightsEnabled,
+        HyperparameterId::LiquidAlphaEnabled,
+        HyperparameterId::Yuma3Enabled,
+        HyperparameterId::TransferEnabled,
+        HyperparameterId::AlphaSigmoidSteepness,
+        HyperparameterId::AnnouncementDelay,
+    ];
+
+    /// The type of the value stored for this hyperparameter.
+    pub fn value_type(&self) -> HyperparameterType {
+        match self {
+            HyperparameterId::RegistrationAllowed
+            | HyperparameterId::PowRegistrationAllowed
+            | HyperparameterId::CommitRevealWeightsEnabled
+            | HyperparameterId::LiquidAlphaEnabled
+            | HyperparameterId::Yuma3Enabled
+            | HyperparameterId::TransferEnabled => HyperparameterType::Bool,
+            HyperparameterId::MinDifficulty
+            | HyperparameterId::MaxDifficulty
+            | HyperparameterId::WeightsVersionKey
+            | HyperparameterId::WeightsSetRateLimit
+            | HyperparameterId::MinBurn
+            | HyperparameterId::MaxBurn
+            | HyperparameterId::BondsMovingAverage
+            | HyperparameterId::ServingRateLimit
+            | HyperparameterId::AdjustmentAlpha
+            | HyperparameterId::Difficulty
+            | HyperparameterId::CommitRevealPeriod
+            | HyperparameterId::AnnouncementDelay => HyperparameterType::U64,
+            _ => HyperparameterType::U16,
+        }
+    }
+
+    /// Whether the subnet owner may set this hyperparameter. Root may always set it.
+    pub fn owner_settable(&self) -> bool {
+        !matches!(
+            self,
+            HyperparameterId::Tempo
+                | HyperparameterId::MinDifficulty
+                | HyperparameterId::WeightsSetRateLimit
+                | HyperparameterId::AdjustmentInterval
+                | HyperparameterId::TargetRegistrationsPerInterval
+                | HyperparameterId::MinBurn
+                | HyperparameterId::MaxBurn
+                | HyperparameterId::MaxRegistrationsPerBlock
+                | HyperparameterId::MaxAllowedValidators
+                | HyperparameterId::MaxAllowedUids
+                | HyperparameterId::Difficulty
+                | HyperparameterId::AlphaSigmoidSteepness
+        )
+    }
+
+    /// The transaction type used to rate limit subnet owner changes, if any.
+    pub fn owner_rate_limit(&self) -> Option<TransactionType> {
+        match self {
+            HyperparameterId::WeightsVersionKey => Some(TransactionType::SetWeightsVersionKey),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<u16> for HyperparameterId {
+    type Error = ();
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        HyperparameterId::ALL
+            .get(usize::from(value))
+            .copied()
+            .ok_or(())
+    }
+}
+
+impl From<HyperparameterId> for u16 {
+    fn from(id: HyperparameterId) -> Self {
+        HyperparameterId::ALL
+            .iter()
+            .position(|known| *known == id)
+            .and_then(|index| u16::try_from(index).ok())
+            .unwrap_or(u16::MAX)
+    }
+}
+
+/// Type of a hyperparameter value. All values are exchanged as `u64`.
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HyperparameterType {
+    Bool,
+    U16,
+    U64,
+}
+
+impl HyperparameterType {
+    /// The largest value representable by this type.
+    pub fn max_value(&self) -> u64 {
+        match self {
+            HyperparameterType::Bool => 1,
+            HyperparameterType::U16 => u64::from(u16::MAX),
+            HyperparameterType::U64 => u64::MAX,
+        }
+    }
+}
+
+#[freeze_struct("dfc009e882ca8a46")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct HyperparameterInfo {
+    id: HyperparameterId,
+    value_type: HyperparameterType,
+    value: Compact<u64>,
+    min: Compact<u64>,
+    max: Compact<u64>,
+    owner_max: Compact<u64>,
+    owner_settable: bool,
+    owner_rate_limit: Compact<u64>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the bounds ( min, max ) a new value of `id` must fall within on `netuid`.
+    /// Subnet owners may be held to a tighter maximum than root.
+    pub fn get_hyperparameter_bounds(netuid: u16, id: HyperparameterId, owner: bool) -> (u64, u64) {
+        let type_max = id.value_type().max_value();
+        match id {
+            HyperparameterId::ActivityCutoff => {
+                (u64::from(MinActivityCutoff::<T>::get()), type_max)
+            }
+            HyperparameterId::MaxAllowedUids => (
+                u64::from(Self::get_subnetwork_n(netuid)).saturating_add(1),
+                type_max,
+            ),
+            HyperparameterId::MaxAllowedValidators => {
+                (0, u64::from(Self::get_max_allowed_uids(netuid)))
+            }
+            HyperparameterId::BondsMovingAverage if owner => (0, 975_000),
+            _ => (0, type_max),
+        }
+    }
+
+    /// Returns the current value of a hyperparameter as `u64`.
+    pub fn get_hyperparameter(netuid: u16, id: HyperparameterId) -> u64 {
+        match id {
+            HyperparameterId::Rho => u64::from(Self::get_rho(netuid)),
+            HyperparameterId::Kappa => u64::from(Self::get_kappa(netuid)),
+            HyperparameterId::ImmunityPeriod => u64::from(Self::get_immunity_period(netuid)),
+            HyperparameterId::MinAllowedWeights => u64::from(Self::get_min_allowed_weights(netuid)),
+            HyperparameterId::MaxWeightsLimit => u64::from(Self::get_max_weight_limit(netuid)),
+            HyperparameterId::Tempo => u64::from(Self::get_tempo(netuid)),
+            HyperparameterId::MinDifficulty => Self::get_min_difficulty(netuid),
+            HyperparameterId::MaxDifficulty => Self::get_max_difficulty(netuid),
+            HyperparameterId::WeightsVersionKey => Self::get_weights_version_key(netuid),
+            HyperparameterId::WeightsSetRateLimit => Self::get_weights_set_rate_limit(netuid),
+            HyperparameterId::AdjustmentInterval => {
+                u64::from(Self::get_adjustment_interval(netuid))
+            }
+            HyperparameterId::ActivityCutoff => u64::from(Self::get_activity_cutoff(netuid)),
+            HyperparameterId::RegistrationAllowed => {
+                u64::from(Self::get_network_registration_allowed(netuid))
+            }
+            HyperparameterId::PowRegistrationAllowed => {
+                u64::from(Self::get_network_pow_registration_allowed(netuid))
+            }
+            HyperparameterId::TargetRegistrationsPerInterval => {
+                u64::from(Self::get_target_registrations_per_interval(netuid))
+            }
+            HyperparameterId::MinBurn => Self::get_min_burn_as_u64(netuid),
+            HyperparameterId::MaxBurn => Self::get_max_burn_as_u64(netuid),
+            HyperparameterId::BondsMovingAverage => Self::get_bonds_moving_average(netuid),
+            HyperparameterId::BondsPenalty => u64::from(Self::get_bonds_penalty(netuid)),
+            HyperparameterId::MaxRegistrationsPerBlock => {
+                u64::from(Self::get_max_registrations_per_block(netuid))
+            }
+            HyperparameterId::ServingRateLimit => Self::get_serving_rate_limit(netuid),
+            HyperparameterId::MaxAllowedValidators => {
+                u64::from(Self::get_max_allowed_validators(netuid))
+            }
+            HyperparameterId::MaxAllowedUids => u64::from(Self::get_max_allowed_uids(netuid)),
+            HyperparameterId::AdjustmentAlpha => Self::get_adjustment_alpha(netuid),
+            HyperparameterId::Difficulty => Self::get_difficulty_as_u64(netuid),
+            HyperparameterId::CommitRevealPeriod => Self::get_reveal_period(netuid),
+            HyperparameterId::CommitRevealWeightsEnabled => {
+                u64::from(Self::get_commit_reveal_weights_enabled(netuid))
+            }
+            HyperparameterId::LiquidAlphaEnabled => {
+                u64::from(Self::get_liquid_alpha_enabled(netuid))
+            }
+            HyperparameterId::Yuma3Enabled => u64::from(Self::get_yuma3_enabled(netuid)),
+            HyperparameterId::TransferEnabled => u64::from(TransferToggle::<T>::get(netuid)),
+            HyperparameterId::AlphaSigmoidSteepness => {
+                u64::from(AlphaSigmoidSteepness::<T>::get(netuid))
+            }
+            HyperparameterId::AnnouncementDelay => {
+                Self::get_hyperparameter_announcement_delay(netuid)
+            }
+        }
+    }
+
+    /// Validates and sets a hyperparameter on a subnet.
+    ///
+    /// The caller is responsible for checking the origin. `owner` is the subnet owner making
+    /// the change, or `None` for root; owner changes are held to the owner bounds and rate limit.
+    ///
+    /// # Errors
+    /// * `SubNetworkDoesNotExist` - If the subnet does not exist.
+    /// * `HyperparameterOutOfBounds` - If `value` is outside the bounds of the hyperparameter.
+    /// * `TxRateLimitExceeded` - If the owner changed the hyperparameter too recently.
+    pub fn do_set_hyperparameter(
+        netuid: u16,
+        id: HyperparameterId,
+        value: u64,
+        owner: Option<&T::AccountId>,
+    ) -> dispatch::DispatchResult {
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+
+        let (min, max) = Self::get_hyperparameter_bounds(netuid, id, owner.is_some());
+        ensure!(
+            value >= min && value <= max,
+            Error::<T>::HyperparameterOutOfBounds
+        );
+
+        if let (Some(who), Some(tx_type)) = (owner, id.owner_rate_limit()) {
+            ensure!(
+                Self::passes_rate_limit_on_subnet(&tx_type, who, netuid),
+                Error::<T>::TxRateLimitExceeded
+            );
+            let current_block = Self::get_current_block_as_u64();
+            Self::set_last_transaction_block_on_subnet(who, netuid, &tx_type, current_block);
+        }
+
+        // Bounds were checked above, so narrowing conversions cannot fail.
+        let as_u16 = u16::try_from(value).unwrap_or(u16::MAX);
+        let as_bool = value != 0;
+        match id {
+            HyperparameterId::Rho => Self::set_rho(netuid, as_u16),
+            HyperparameterId::Kappa => Self::set_kappa(netuid, as_u16),
+            HyperparameterId::ImmunityPeriod => Self::set_immunity_period(netuid, as_u16),
+            HyperparameterId::MinAllowedWeights => Self::set_min_allowed_weights(netuid, as_u16),
+            HyperparameterId::MaxWeightsLimit => Self::set_max_weight_limit(netuid, as_u16),
+            HyperparameterId::Tempo => Self::set_tempo(netuid, as_u16),
+            HyperparameterId::MinDifficulty => Self::set_min_difficulty(netuid, value),
+            HyperparameterId::MaxDifficulty => Self::set_max_difficulty(netuid, value),
+            HyperparameterId::WeightsVersionKey => Self::set_weights_version_key(netuid, value),
+            HyperparameterId::WeightsSetRateLimit => {
+                Self::set_weights_set_rate_limit(netuid, value)
+            }
+            HyperparameterId::AdjustmentInterval => Self::set_adjustment_interval(netuid, as_u16),
+            HyperparameterId::ActivityCutoff => Self::set_activity_cutoff(netuid, as_u16),
+            HyperparameterId::RegistrationAllowed => {
+                Self::set_network_registration_allowed(netuid, as_bool)
+            }
+            HyperparameterId::PowRegistrationAllowed => {
+                Self::set_network_pow_registration_allowed(netuid, as_bool)
+            }
+            HyperparameterId::TargetRegistrationsPerInterval => {
+                Self::set_target_registrations_per_interval(netuid, as_u16)
+            }
+            HyperparameterId::MinBurn => Self::set_min_burn(netuid, value),
+            HyperparameterId::MaxBurn => Self::set_max_burn(netuid, value),
+            HyperparameterId::BondsMovingAverage => Self::set_bonds_moving_average(netuid, value),
+            HyperparameterId::BondsPenalty => Self::set_bonds_penalty(netuid, as_u16),
+            HyperparameterId::MaxRegistrationsPerBlock => {
+                Self::set_max_registrations_per_block(netuid, as_u16)
+            }
+            HyperparameterId::ServingRateLimit => Self::set_serving_rate_limit(netuid, value),
+            HyperparameterId::MaxAllowedValidators => {
+                Self::set_max_allowed_validators(netuid, as_u16)
+            }
+            HyperparameterId::MaxAllowedUids => Self::set_max_allowed_uids(netuid, as_u16),
+            HyperparameterId::AdjustmentAlpha => Self::set_adjustment_alpha(netuid, value),
+            HyperparameterId::Difficulty => Self::set_difficulty(netuid, value),
+            HyperparameterId::CommitRevealPeriod => Self::set_reveal_period(netuid, value),
+            HyperparameterId::CommitRevealWeightsEnabled => {
+                Self::set_commit_reveal_weights_enabled(netuid, as_bool)
+            }
+            HyperparameterId::LiquidAlphaEnabled => Self::set_liquid_alpha_enabled(netuid, as_bool),
+            HyperparameterId::Yuma3Enabled => Self::set_yuma3_enabled(netuid, as_bool),
+            HyperparameterId::TransferEnabled => return Self::toggle_transfer(netuid, as_bool),
+            HyperparameterId::AlphaSigmoidSteepness => {
+                Self::set_alpha_sigmoid_steepness(netuid, as_u16)
+            }
+            HyperparameterId::AnnouncementDelay => {
+                Self::set_hyperparameter_announcement_delay(netuid, value)
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the registry entry and current value of a hyperparameter on a subnet.
+    pub fn get_hyperparameter_info(netuid: u16, id: HyperparameterId) -> HyperparameterInfo {
+        let (min, max) = Self::get_hyperparameter_bounds(netuid, id, false);
+        let (_, owner_max) = Self::get_hyperparameter_bounds(netuid, id, true);
+        let owner_rate_limit = id
+            .owner_rate_limit()
+            .map(|tx_type| Self::get_rate_limit_on_subnet(&tx_type, netuid))
+            .unwrap_or_default();
+
+        HyperparameterInfo {
+            id,
+            value_type: id.value_type(),
+            value: Self::get_hyperparameter(netuid, id).into(),
+            min: min.into(),
+            max: max.into(),
+            owner_max: owner_max.into(),
+            owner_settable: id.owner_settable(),
+            owner_rate_limit: owner_rate_limit.into(),
+        }
+    }
+
+    /// Returns the registry entries of all hyperparameters on a subnet.
+    pub fn get_hyperparameters_info(netuid: u16) -> Option<Vec<HyperparameterInfo>> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        Some(
+            HyperparameterId::ALL
+                .iter()
+                .map(|id| Self::get_hyperparameter_info(netuid, *id))
+                .collect(),
+        )
+    }
+}
@@ -344,6 +344,13 @@ impl<T: Config> Pallet<T> {
         MaxChildkeyTake::<T>::put(take);
         Self::deposit_event(Event::MaxChildKeyTakeSet(take));
     }
+    pub fn get_max_childkey_depth() -> u16 {
+        MaxChildKeyDepth::<T>::get()
+    }
+    pub fn set_max_childkey_depth(depth: u16) {
+        MaxChildKeyDepth::<T>::put(depth);
+        Self::deposit_event(Event::MaxChildKeyDepthSet(depth));
+    }
     pub fn get_min_childkey_take() -> u16 {
         MinChildkeyTake::<T>::get()
     }
@@ -719,6 +726,13 @@ impl<T: Config> Pallet<T> {
         Yuma3On::<T>::get(netuid)
     }
 
+    pub fn get_hyperparameter_announcement_delay(netuid: u16) -> u64 {
+        HyperparameterAnnouncementDelay::<T>::get(netuid)
+    }
+    pub fn set_hyperparameter_announcement_delay(netuid: u16, delay: u64) {
+        HyperparameterAnnouncementDelay::<T>::insert(netuid, delay);
+    }
+
     /// Set the duration for coldkey swap
     ///
     /// # Arguments
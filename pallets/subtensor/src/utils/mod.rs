@@ -1,5 +1,6 @@
 use super::*;
 pub mod evm;
+pub mod hyperparameters;
 pub mod identity;
 pub mod misc;
 pub mod rate_limiting;
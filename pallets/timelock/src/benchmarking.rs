@@ -0,0 +1,152 @@
+//! Benchmarks for Timelock Pallet
+#![cfg(feature = "runtime-benchmarks")]
+#![allow(
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing,
+    clippy::unwrap_used
+)]
+use crate::{pallet::*, *};
+use ark_serialize::CanonicalSerialize;
+use frame_benchmarking::{account, v2::*};
+use frame_support::traits::Hooks;
+use frame_system::RawOrigin;
+use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
+use sha2::Digest;
+use sp_runtime::traits::Bounded;
+use tle::{ibe::fullident::Identity, tlock::tle};
+
+use alloc::vec;
+
+const SEED: u32 = 0;
+
+// Drand quicknet public key and signature for round 1000
+const QUICKNET_PUBLIC_KEY: [u8; 96] = hex_literal::hex!(
+    "83cf0f2896adee7eb8b5f01fcad3912212c437e0073e911fb90022d3e760183c8c4b450b6a0a6c3ac6a5776a2d1064510d1fec758c921cc22b0e17e63aaf4bcb5ed66304de9cf809bd274ca73bab4af5a6e9c76a4bc09e76eae8991ef5ece45a"
+);
+const QUICKNET_SIGNATURE: [u8; 48] = hex_literal::hex!(
+    "b44679b9a59af2ec876b1a6b1ad52ea9b1615fc3982b19576350f93447cb1125e342b73a8dd2bacbe47e4b6b63ed5e39"
+);
+const REVEAL_ROUND: RoundNumber = 1000;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Timelock-encrypts `plaintext` to the drand quicknet reveal round.
+fn encrypt<T: Config>(plaintext: &[u8]) -> CiphertextOf<T> {
+    let public_key =
+        <TinyBLS381 as EngineBLS>::PublicKeyGroup::deserialize_compressed(&QUICKNET_PUBLIC_KEY[..])
+            .unwrap();
+    let message = sha2::Sha256::digest(REVEAL_ROUND.to_be_bytes()).to_vec();
+    let identity = Identity::new(b"", vec![message]);
+
+    let ciphertext = tle::<TinyBLS381, AESGCMStreamCipherProvider, ChaCha20Rng>(
+        public_key,
+        [2u8; 32],
+        plaintext,
+        identity,
+        ChaCha20Rng::seed_from_u64(0),
+    )
+    .unwrap();
+
+    let mut bytes = Vec::new();
+    ciphertext.serialize_compressed(&mut bytes).unwrap();
+    bytes.try_into().unwrap()
+}
+
+fn funded_account<T: Config>(name: &'static str) -> T::AccountId {
+    let who = account::<T::AccountId>(name, 0, SEED);
+    T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+    who
+}
+
+fn queue_task<T: Config>(who: &T::AccountId, plaintext: &[u8]) {
+    Pallet::<T>::submit(
+        RawOrigin::Signed(who.clone()).into(),
+        REVEAL_ROUND,
+        encrypt::<T>(plaintext),
+        T::MaxWeightPerBlock::get(),
+    )
+    .unwrap();
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn submit() {
+        let who = funded_account::<T>("who");
+        let call =
+            <T as Config>::RuntimeCall::from(frame_system::Call::<T>::remark { remark: vec![] });
+        let ciphertext = encrypt::<T>(&call.encode());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(who.clone()),
+            REVEAL_ROUND,
+            ciphertext,
+            T::MaxWeightPerBlock::get(),
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::Submitted {
+                id: 0,
+                who,
+                reveal_round: REVEAL_ROUND,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn cancel() {
+        let who = funded_account::<T>("who");
+        let call =
+            <T as Config>::RuntimeCall::from(frame_system::Call::<T>::remark { remark: vec![] });
+        queue_task::<T>(&who, &call.encode());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(who.clone()), 0);
+
+        assert_last_event::<T>(Event::<T>::Cancelled { id: 0, who }.into());
+    }
+
+    // the decryption of a call, without the weight of its dispatch which is paid by its limit
+    #[benchmark]
+    fn reveal_task() {
+        let who = funded_account::<T>("who");
+        let call =
+            <T as Config>::RuntimeCall::from(frame_system::Call::<T>::remark { remark: vec![] });
+        queue_task::<T>(&who, &call.encode());
+
+        pallet_drand::Pulses::<T>::insert(
+            REVEAL_ROUND,
+            Pulse {
+                round: REVEAL_ROUND,
+                randomness: vec![0u8; 32].try_into().unwrap(),
+                signature: QUICKNET_SIGNATURE.to_vec().try_into().unwrap(),
+            },
+        );
+        pallet_drand::LastStoredRound::<T>::put(REVEAL_ROUND);
+        NextRound::<T>::put(REVEAL_ROUND);
+        let block_number = frame_system::Pallet::<T>::block_number();
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(block_number);
+        }
+
+        assert!(Tasks::<T>::get(0).is_none());
+        assert_last_event::<T>(
+            Event::<T>::Dispatched {
+                id: 0,
+                who,
+                result: Ok(()),
+            }
+            .into(),
+        );
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
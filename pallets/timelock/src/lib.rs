@@ -0,0 +1,411 @@
+//! # Timelock Pallet
+//!
+//! A pallet queueing calls timelock-encrypted to a future drand round, an encrypted mempool
+//! for any extrinsic. The call is encrypted off-chain to the identity of the round, so its
+//! content stays hidden until drand publishes the signature of the round. Once the drand pallet
+//! stores that pulse, the call is decrypted and dispatched with the origin of its submitter.
+//!
+//! The decrypted call is first checked by `CallValidator`, which the runtime uses to run the
+//! validation of its transaction extensions, so a call cannot escape the checks it would go
+//! through as a signed transaction.
+//!
+//! This makes operations such as large stake swaps resistant to front-running: the call is
+//! committed now and only known, and executed, after its reveal round.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::{Decode, DecodeLimit, Encode};
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    pallet_prelude::*,
+    sp_runtime::{
+        RuntimeDebug,
+        traits::{Dispatchable, Saturating},
+        transaction_validity::TransactionValidityError,
+    },
+    traits::{Currency, ReservableCurrency},
+};
+use frame_system::pallet_prelude::*;
+use pallet_drand::{
+    RandomnessProvider,
+    types::{DEFAULT_BEACON_ID, Pulse, RoundNumber},
+};
+use scale_info::TypeInfo;
+use weights::WeightInfo;
+
+pub use pallet::*;
+use subtensor_macros::freeze_struct;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod weights;
+
+/// The identifier of a queued call.
+pub type TaskId = u64;
+
+/// The maximum depth of the decrypted calls, as for extrinsics.
+const MAX_CALL_DEPTH: u32 = 256;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type CiphertextOf<T> = BoundedVec<u8, <T as Config>::MaxCiphertextLen>;
+
+/// A call waiting for its reveal round.
+#[freeze_struct("6a95a04ff1f00292")]
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TimelockedTask<AccountId, Balance, Ciphertext> {
+    /// The account which submitted the call, and whose origin it is dispatched with.
+    pub who: AccountId,
+    /// The drand round the call is encrypted to.
+    pub reveal_round: RoundNumber,
+    /// The call, timelock-encrypted to the reveal round.
+    pub ciphertext: Ciphertext,
+    /// The maximum weight of the call, paid for on submission.
+    pub weight_limit: Weight,
+    /// The deposit reserved from the submitter until the call is dispatched or cancelled.
+    pub deposit: Balance,
+}
+
+pub type TimelockedTaskOf<T> =
+    TimelockedTask<<T as frame_system::Config>::AccountId, BalanceOf<T>, CiphertextOf<T>>;
+
+/// Checks a decrypted call before it is dispatched, as the transaction extensions check a
+/// signed transaction.
+pub trait CallValidator<AccountId, Call> {
+    /// Whether `who` may dispatch `call`.
+    fn validate(who: &AccountId, call: &Call) -> Result<(), TransactionValidityError>;
+}
+
+impl<AccountId, Call> CallValidator<AccountId, Call> for () {
+    fn validate(_who: &AccountId, _call: &Call) -> Result<(), TransactionValidityError> {
+        Ok(())
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_drand::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The overarching call type.
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo
+            + From<frame_system::Call<Self>>
+            + IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// The currency mechanism.
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// The weight information for the pallet.
+        type WeightInfo: WeightInfo;
+
+        /// The maximum length of an encrypted call.
+        #[pallet::constant]
+        type MaxCiphertextLen: Get<u32>;
+
+        /// The maximum number of calls encrypted to the same round.
+        #[pallet::constant]
+        type MaxCallsPerRound: Get<u32>;
+
+        /// The maximum weight of the calls dispatched in a block, which also bounds the weight
+        /// of each call.
+        #[pallet::constant]
+        type MaxWeightPerBlock: Get<Weight>;
+
+        /// The deposit reserved for each queued call.
+        #[pallet::constant]
+        type DepositBase: Get<BalanceOf<Self>>;
+
+        /// The deposit reserved for each byte of an encrypted call.
+        #[pallet::constant]
+        type DepositPerByte: Get<BalanceOf<Self>>;
+
+        /// Checks the decrypted calls before they are dispatched.
+        type CallValidator: CallValidator<Self::AccountId, <Self as Config>::RuntimeCall>;
+    }
+
+    /// The queued calls.
+    #[pallet::storage]
+    pub type Tasks<T: Config> =
+        StorageMap<_, Twox64Concat, TaskId, TimelockedTaskOf<T>, OptionQuery>;
+
+    /// The calls queued for each reveal round.
+    #[pallet::storage]
+    pub type Agenda<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        RoundNumber,
+        BoundedVec<TaskId, T::MaxCallsPerRound>,
+        ValueQuery,
+    >;
+
+    /// The next incrementing task id.
+    #[pallet::storage]
+    pub type NextTaskId<T> = StorageValue<_, TaskId, ValueQuery>;
+
+    /// The next round whose calls are dispatched, 0 until a call is first queued.
+    #[pallet::storage]
+    pub type NextRound<T> = StorageValue<_, RoundNumber, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// An encrypted call was queued.
+        Submitted {
+            id: TaskId,
+            who: T::AccountId,
+            reveal_round: RoundNumber,
+        },
+        /// An encrypted call was cancelled before its reveal round.
+        Cancelled { id: TaskId, who: T::AccountId },
+        /// A call was decrypted and dispatched.
+        Dispatched {
+            id: TaskId,
+            who: T::AccountId,
+            result: DispatchResult,
+        },
+        /// A call could not be decrypted or decoded at its reveal round, and was dropped.
+        DecryptionFailed { id: TaskId, who: T::AccountId },
+        /// A decrypted call was heavier than its weight limit, and was dropped.
+        WeightLimitExceeded { id: TaskId, who: T::AccountId },
+        /// A decrypted call was rejected by the call validator, and was dropped.
+        ValidationFailed {
+            id: TaskId,
+            who: T::AccountId,
+            error: TransactionValidityError,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The pulse of the reveal round is already stored.
+        RoundAlreadyRevealed,
+        /// The weight limit is above the maximum weight dispatched in a block.
+        WeightLimitTooHigh,
+        /// The maximum number of calls encrypted to the round is reached.
+        TooManyCallsForRound,
+        /// The call does not exist.
+        UnknownTask,
+        /// The call was submitted by another account.
+        NotSubmitter,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_block_number: BlockNumberFor<T>) -> Weight {
+            Self::dispatch_revealed_calls()
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Queue a call encrypted to a future drand round, to be dispatched with the origin of
+        /// the caller once the pulse of the round is stored.
+        ///
+        /// The weight limit of the call is paid for on submission. A deposit proportional to
+        /// the length of the ciphertext is reserved until the call is dispatched or cancelled.
+        ///
+        /// Parameters:
+        /// - `reveal_round`: The drand round the call is encrypted to.
+        /// - `ciphertext`: The SCALE-encoded call, timelock-encrypted to the round.
+        /// - `weight_limit`: The maximum weight of the call.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::submit().saturating_add(*weight_limit))]
+        pub fn submit(
+            origin: OriginFor<T>,
+            reveal_round: RoundNumber,
+            ciphertext: CiphertextOf<T>,
+            weight_limit: Weight,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let last_round = pallet_drand::Pallet::<T>::last_stored_round(DEFAULT_BEACON_ID);
+            ensure!(reveal_round > last_round, Error::<T>::RoundAlreadyRevealed);
+            ensure!(
+                weight_limit.all_lte(T::MaxWeightPerBlock::get()),
+                Error::<T>::WeightLimitTooHigh
+            );
+
+            let id = NextTaskId::<T>::get();
+            Agenda::<T>::try_mutate(reveal_round, |agenda| agenda.try_push(id))
+                .map_err(|_| Error::<T>::TooManyCallsForRound)?;
+
+            let length = BalanceOf::<T>::from(u32::try_from(ciphertext.len()).unwrap_or(u32::MAX));
+            let deposit = T::DepositBase::get()
+                .saturating_add(T::DepositPerByte::get().saturating_mul(length));
+            T::Currency::reserve(&who, deposit)?;
+
+            Tasks::<T>::insert(
+                id,
+                TimelockedTask {
+                    who: who.clone(),
+                    reveal_round,
+                    ciphertext,
+                    weight_limit,
+                    deposit,
+                },
+            );
+            pallet_drand::Pallet::<T>::reference_round(DEFAULT_BEACON_ID, reveal_round);
+            NextTaskId::<T>::put(id.saturating_add(1));
+            if NextRound::<T>::get() == 0 {
+                NextRound::<T>::put(last_round.saturating_add(1));
+            }
+
+            Self::deposit_event(Event::Submitted {
+                id,
+                who,
+                reveal_round,
+            });
+            Ok(())
+        }
+
+        /// Cancel a queued call and release its deposit.
+        ///
+        /// Calls can only be cancelled before the pulse of their reveal round is stored, once
+        /// their content can be known.
+        ///
+        /// Parameters:
+        /// - `id`: The id of the call.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::cancel())]
+        pub fn cancel(origin: OriginFor<T>, id: TaskId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let task = Tasks::<T>::get(id).ok_or(Error::<T>::UnknownTask)?;
+            ensure!(task.who == who, Error::<T>::NotSubmitter);
+            ensure!(
+                task.reveal_round > pallet_drand::Pallet::<T>::last_stored_round(DEFAULT_BEACON_ID),
+                Error::<T>::RoundAlreadyRevealed
+            );
+
+            Agenda::<T>::mutate(task.reveal_round, |agenda| {
+                agenda.retain(|task_id| *task_id != id)
+            });
+            Tasks::<T>::remove(id);
+            pallet_drand::Pallet::<T>::release_round(DEFAULT_BEACON_ID, task.reveal_round);
+            T::Currency::unreserve(&who, task.deposit);
+
+            Self::deposit_event(Event::Cancelled { id, who });
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Dispatch the calls of the revealed rounds, in round then submission order, until the
+    /// maximum weight per block is reached.
+    pub fn dispatch_revealed_calls() -> Weight {
+        let db_weight = T::DbWeight::get();
+        let mut weight = db_weight.reads(2);
+
+        let mut round = NextRound::<T>::get();
+        if round == 0 {
+            return weight;
+        }
+        let last_round = pallet_drand::Pallet::<T>::last_stored_round(DEFAULT_BEACON_ID);
+        let max_weight = T::MaxWeightPerBlock::get();
+        let start_round = round;
+        // the first call always fits, so calls as heavy as the maximum weight are not stuck
+        let mut dispatched_any = false;
+
+        'rounds: while round <= last_round {
+            weight.saturating_accrue(db_weight.reads_writes(1, 1));
+            if weight.any_gt(max_weight) {
+                break;
+            }
+
+            let mut agenda = Agenda::<T>::take(round).into_iter();
+            let pulse = pallet_drand::Pallet::<T>::pulse_at(DEFAULT_BEACON_ID, round);
+            weight.saturating_accrue(db_weight.reads(1));
+
+            while let Some(id) = agenda.next() {
+                let Some(task) = Tasks::<T>::get(id) else {
+                    continue;
+                };
+                let task_weight = T::WeightInfo::reveal_task().saturating_add(task.weight_limit);
+                if dispatched_any && weight.saturating_add(task_weight).any_gt(max_weight) {
+                    // the remaining calls of the round are dispatched in the next block
+                    let remaining: Vec<TaskId> = core::iter::once(id).chain(agenda).collect();
+                    Agenda::<T>::insert(round, BoundedVec::truncate_from(remaining));
+                    break 'rounds;
+                }
+                weight.saturating_accrue(T::WeightInfo::reveal_task());
+                dispatched_any = true;
+
+                Tasks::<T>::remove(id);
+                T::Currency::unreserve(&task.who, task.deposit);
+                weight.saturating_accrue(Self::dispatch_task(id, task, pulse.as_ref()));
+                pallet_drand::Pallet::<T>::release_round(DEFAULT_BEACON_ID, round);
+                weight.saturating_accrue(db_weight.reads_writes(2, 2));
+            }
+
+            round.saturating_inc();
+        }
+
+        if round != start_round {
+            NextRound::<T>::put(round);
+            weight.saturating_accrue(db_weight.writes(1));
+        }
+
+        weight
+    }
+
+    /// Decrypt and dispatch a call, returning the weight it consumed.
+    fn dispatch_task(id: TaskId, task: TimelockedTaskOf<T>, pulse: Option<&Pulse>) -> Weight {
+        let who = task.who;
+        let call = pulse
+            .and_then(|pulse| Self::decrypt(&task.ciphertext, pulse))
+            .and_then(|plaintext| {
+                <T as Config>::RuntimeCall::decode_all_with_depth_limit(
+                    MAX_CALL_DEPTH,
+                    &mut &plaintext[..],
+                )
+                .ok()
+            });
+        let Some(call) = call else {
+            Self::deposit_event(Event::DecryptionFailed { id, who });
+            return Weight::zero();
+        };
+
+        let call_weight = call.get_dispatch_info().weight;
+        if call_weight.any_gt(task.weight_limit) {
+            Self::deposit_event(Event::WeightLimitExceeded { id, who });
+            return Weight::zero();
+        }
+
+        if let Err(error) = T::CallValidator::validate(&who, &call) {
+            Self::deposit_event(Event::ValidationFailed { id, who, error });
+            return Weight::zero();
+        }
+
+        let origin = frame_system::RawOrigin::Signed(who.clone()).into();
+        let (result, actual_weight) = match call.dispatch(origin) {
+            Ok(post_info) => (Ok(()), post_info.actual_weight),
+            Err(error) => (Err(error.error), error.post_info.actual_weight),
+        };
+
+        Self::deposit_event(Event::Dispatched { id, who, result });
+        actual_weight.unwrap_or(call_weight)
+    }
+
+    /// Decrypt a ciphertext timelock-encrypted to the round of a pulse of the default beacon,
+    /// on the curve the beacon signs in.
+    fn decrypt(ciphertext: &[u8], pulse: &Pulse) -> Option<Vec<u8>> {
+        pallet_drand::Pallet::<T>::timelock_decrypt(DEFAULT_BEACON_ID, ciphertext, pulse)
+            .map_err(|e| log::warn!("Failed to decrypt timelocked call: {:?}", e))
+            .ok()
+    }
+}
@@ -0,0 +1,228 @@
+#![cfg(test)]
+#![allow(
+    clippy::arithmetic_side_effects,
+    clippy::unwrap_used,
+    clippy::indexing_slicing
+)]
+use ark_serialize::CanonicalSerialize;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, Hooks},
+    weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
+use sha2::Digest;
+use sp_core::H256;
+use sp_runtime::{
+    BuildStorage,
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+use tle::{
+    curves::drand::TinyBLS381, ibe::fullident::Identity,
+    stream_ciphers::AESGCMStreamCipherProvider, tlock::tle,
+};
+use w3f_bls::EngineBLS;
+
+use crate as pallet_timelock;
+use crate::{CallValidator, CiphertextOf};
+
+pub type Block = sp_runtime::generic::Block<Header, UncheckedExtrinsic>;
+pub type UncheckedExtrinsic =
+    sp_runtime::generic::UncheckedExtrinsic<AccountId, RuntimeCall, test_crypto::Signature, ()>;
+
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system = 1,
+        Balances: pallet_balances = 2,
+        Drand: pallet_drand = 3,
+        Timelock: pallet_timelock = 4,
+    }
+);
+
+pub type AccountId = u64;
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type Nonce = u32;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+impl pallet_drand::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type AuthorityId = test_crypto::TestAuthId;
+    type Verifier = pallet_drand::verifier::SchemeVerifier;
+    type UnsignedPriority = ConstU64<{ 1 << 20 }>;
+    type HttpFetchTimeout = ConstU64<1_000>;
+    type MaxPulsesPrunedPerBlock = ConstU32<64>;
+}
+
+parameter_types! {
+    pub const MaxWeightPerBlock: Weight = Weight::from_parts(10_000_000_000, 1_000_000);
+}
+
+impl pallet_timelock::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type Currency = Balances;
+    type WeightInfo = ();
+    type MaxCiphertextLen = ConstU32<1024>;
+    type MaxCallsPerRound = ConstU32<2>;
+    type MaxWeightPerBlock = MaxWeightPerBlock;
+    type DepositBase = ConstU64<10>;
+    type DepositPerByte = ConstU64<0>;
+    type CallValidator = MockCallValidator;
+}
+
+/// Rejects `remark`, standing in for the checks of the transaction extensions.
+pub struct MockCallValidator;
+
+impl CallValidator<AccountId, RuntimeCall> for MockCallValidator {
+    fn validate(_who: &AccountId, call: &RuntimeCall) -> Result<(), TransactionValidityError> {
+        match call {
+            RuntimeCall::System(frame_system::Call::remark { .. }) => {
+                Err(InvalidTransaction::Call.into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+pub mod test_crypto {
+    use sp_core::sr25519::{Public as Sr25519Public, Signature as Sr25519Signature};
+    use sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        traits::IdentifyAccount,
+    };
+
+    pub const KEY_TYPE: sp_runtime::KeyTypeId = sp_runtime::KeyTypeId(*b"test");
+
+    app_crypto!(sr25519, KEY_TYPE);
+
+    pub struct TestAuthId;
+
+    impl frame_system::offchain::AppCrypto<Public, Signature> for TestAuthId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = Sr25519Signature;
+        type GenericPublic = Sr25519Public;
+    }
+
+    impl IdentifyAccount for Public {
+        type AccountId = u64;
+
+        fn into_account(self) -> u64 {
+            let mut bytes = [0u8; 32];
+            bytes.copy_from_slice(self.as_ref());
+            u64::from_le_bytes(bytes[..8].try_into().expect("Expected to not panic"))
+        }
+    }
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = test_crypto::Public;
+    type Signature = test_crypto::Signature;
+}
+
+impl frame_system::offchain::CreateSignedTransaction<pallet_drand::Call<Test>> for Test {
+    fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        _public: Self::Public,
+        account: Self::AccountId,
+        _nonce: u32,
+    ) -> Option<(
+        RuntimeCall,
+        <UncheckedExtrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload,
+    )> {
+        let dummy_signature = sp_core::sr25519::Signature::from([0u8; 64]);
+        let signature = test_crypto::Signature::from(dummy_signature);
+        Some((call, (account, signature, ())))
+    }
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = UncheckedExtrinsic;
+    type OverarchingCall = RuntimeCall;
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100), (2, 100)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+// Drand quicknet public key and signature for round 1000
+pub const DRAND_QUICKNET_PUBKEY_HEX: &str = "83cf0f2896adee7eb8b5f01fcad3912212c437e0073e911fb90022d3e760183c8c4b450b6\
+     a0a6c3ac6a5776a2d1064510d1fec758c921cc22b0e17e63aaf4bcb5ed66304de9cf809b\
+     d274ca73bab4af5a6e9c76a4bc09e76eae8991ef5ece45a";
+pub const DRAND_QUICKNET_SIG_HEX: &str = "b44679b9a59af2ec876b1a6b1ad52ea9b1615fc3982b19576350f93447cb1125e342b73a8dd2bacbe47e4b6b63ed5e39";
+pub const REVEAL_ROUND: u64 = 1000;
+
+/// Stores the pulse of `round` with the given signature, as the last stored round.
+pub(crate) fn insert_drand_pulse(round: u64, signature_hex: &str) {
+    let signature = hex::decode(signature_hex).unwrap();
+    pallet_drand::Pulses::<Test>::insert(
+        round,
+        pallet_drand::types::Pulse {
+            round,
+            randomness: vec![0u8; 32].try_into().unwrap(),
+            signature: signature.try_into().unwrap(),
+        },
+    );
+    pallet_drand::LastStoredRound::<Test>::put(round);
+}
+
+/// Timelock-encrypts `plaintext` to the drand quicknet `round`.
+pub(crate) fn encrypt(plaintext: &[u8], round: u64) -> CiphertextOf<Test> {
+    let pub_key_bytes = hex::decode(DRAND_QUICKNET_PUBKEY_HEX).unwrap();
+    let pub_key =
+        <TinyBLS381 as EngineBLS>::PublicKeyGroup::deserialize_compressed(&pub_key_bytes[..])
+            .unwrap();
+
+    let message = sha2::Sha256::digest(round.to_be_bytes()).to_vec();
+    let identity = Identity::new(b"", vec![message]);
+
+    let ciphertext = tle::<TinyBLS381, AESGCMStreamCipherProvider, ChaCha20Rng>(
+        pub_key,
+        [2u8; 32],
+        plaintext,
+        identity,
+        ChaCha20Rng::seed_from_u64(0),
+    )
+    .unwrap();
+
+    let mut bytes = Vec::new();
+    ciphertext.serialize_compressed(&mut bytes).unwrap();
+    bytes.try_into().unwrap()
+}
+
+pub(crate) fn run_to_block(n: BlockNumberFor<Test>) {
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        Timelock::on_initialize(System::block_number());
+    }
+}
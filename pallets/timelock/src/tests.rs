@@ -0,0 +1,281 @@
+#![cfg(test)]
+#![allow(clippy::arithmetic_side_effects, clippy::unwrap_used)]
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, traits::ReservableCurrency, weights::Weight};
+use pallet_drand::{ReferencedRounds, types::DEFAULT_BEACON_ID};
+use sp_runtime::{
+    traits::{BlakeTwo256, Hash},
+    transaction_validity::InvalidTransaction,
+};
+
+use crate::{
+    Agenda, Error, Event, NextRound, Tasks,
+    mock::{
+        Balances, DRAND_QUICKNET_SIG_HEX, REVEAL_ROUND, RuntimeCall, RuntimeEvent, RuntimeOrigin,
+        System, Test, Timelock, encrypt, insert_drand_pulse, new_test_ext, run_to_block,
+    },
+};
+
+fn remark_call() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark_with_event {
+        remark: b"timelocked".to_vec(),
+    })
+}
+
+fn weight_limit() -> Weight {
+    Weight::from_parts(1_000_000_000, 10_000)
+}
+
+fn submit(who: u64, call: &RuntimeCall) {
+    assert_ok!(Timelock::submit(
+        RuntimeOrigin::signed(who),
+        REVEAL_ROUND,
+        encrypt(&call.encode(), REVEAL_ROUND),
+        weight_limit(),
+    ));
+}
+
+fn last_event() -> RuntimeEvent {
+    System::events()
+        .pop()
+        .expect("an event was deposited")
+        .event
+}
+
+#[test]
+fn submit_reserves_deposit_and_queues_call() {
+    new_test_ext().execute_with(|| {
+        submit(1, &remark_call());
+
+        let task = Tasks::<Test>::get(0).unwrap();
+        assert_eq!(task.who, 1);
+        assert_eq!(task.reveal_round, REVEAL_ROUND);
+        assert_eq!(task.deposit, 10);
+        assert_eq!(Balances::reserved_balance(1), 10);
+        assert_eq!(Agenda::<Test>::get(REVEAL_ROUND).into_inner(), vec![0]);
+        assert_eq!(NextRound::<Test>::get(), 1);
+        assert_eq!(
+            ReferencedRounds::<Test>::get(DEFAULT_BEACON_ID, REVEAL_ROUND),
+            Some(1)
+        );
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Timelock(Event::Submitted {
+                id: 0,
+                who: 1,
+                reveal_round: REVEAL_ROUND,
+            })
+        );
+    });
+}
+
+#[test]
+fn submit_rejects_revealed_round() {
+    new_test_ext().execute_with(|| {
+        insert_drand_pulse(REVEAL_ROUND, DRAND_QUICKNET_SIG_HEX);
+
+        assert_noop!(
+            Timelock::submit(
+                RuntimeOrigin::signed(1),
+                REVEAL_ROUND,
+                encrypt(&remark_call().encode(), REVEAL_ROUND),
+                weight_limit(),
+            ),
+            Error::<Test>::RoundAlreadyRevealed
+        );
+    });
+}
+
+#[test]
+fn submit_rejects_weight_limit_above_block_limit() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Timelock::submit(
+                RuntimeOrigin::signed(1),
+                REVEAL_ROUND,
+                encrypt(&remark_call().encode(), REVEAL_ROUND),
+                Weight::from_parts(u64::MAX, 0),
+            ),
+            Error::<Test>::WeightLimitTooHigh
+        );
+    });
+}
+
+#[test]
+fn submit_rejects_full_round() {
+    new_test_ext().execute_with(|| {
+        submit(1, &remark_call());
+        submit(2, &remark_call());
+
+        assert_noop!(
+            Timelock::submit(
+                RuntimeOrigin::signed(1),
+                REVEAL_ROUND,
+                encrypt(&remark_call().encode(), REVEAL_ROUND),
+                weight_limit(),
+            ),
+            Error::<Test>::TooManyCallsForRound
+        );
+    });
+}
+
+#[test]
+fn cancel_releases_deposit() {
+    new_test_ext().execute_with(|| {
+        submit(1, &remark_call());
+
+        assert_noop!(
+            Timelock::cancel(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::NotSubmitter
+        );
+        assert_noop!(
+            Timelock::cancel(RuntimeOrigin::signed(1), 1),
+            Error::<Test>::UnknownTask
+        );
+
+        assert_ok!(Timelock::cancel(RuntimeOrigin::signed(1), 0));
+        assert!(Tasks::<Test>::get(0).is_none());
+        assert!(Agenda::<Test>::get(REVEAL_ROUND).is_empty());
+        assert!(ReferencedRounds::<Test>::get(DEFAULT_BEACON_ID, REVEAL_ROUND).is_none());
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Timelock(Event::Cancelled { id: 0, who: 1 })
+        );
+    });
+}
+
+#[test]
+fn cancel_rejects_revealed_round() {
+    new_test_ext().execute_with(|| {
+        submit(1, &remark_call());
+        insert_drand_pulse(REVEAL_ROUND, DRAND_QUICKNET_SIG_HEX);
+
+        assert_noop!(
+            Timelock::cancel(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::RoundAlreadyRevealed
+        );
+    });
+}
+
+#[test]
+fn revealed_call_is_dispatched_with_submitter_origin() {
+    new_test_ext().execute_with(|| {
+        submit(1, &remark_call());
+
+        run_to_block(2);
+        assert!(Tasks::<Test>::get(0).is_some());
+
+        insert_drand_pulse(REVEAL_ROUND, DRAND_QUICKNET_SIG_HEX);
+        run_to_block(3);
+
+        assert!(Tasks::<Test>::get(0).is_none());
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(NextRound::<Test>::get(), REVEAL_ROUND + 1);
+        assert!(ReferencedRounds::<Test>::get(DEFAULT_BEACON_ID, REVEAL_ROUND).is_none());
+
+        let events: Vec<RuntimeEvent> = System::events().into_iter().map(|r| r.event).collect();
+        assert!(
+            events.contains(&RuntimeEvent::System(frame_system::Event::Remarked {
+                sender: 1,
+                hash: BlakeTwo256::hash(b"timelocked"),
+            }))
+        );
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Timelock(Event::Dispatched {
+                id: 0,
+                who: 1,
+                result: Ok(()),
+            })
+        );
+    });
+}
+
+#[test]
+fn undecryptable_call_is_dropped() {
+    new_test_ext().execute_with(|| {
+        // encrypted to another round, so the pulse of the reveal round cannot decrypt it
+        assert_ok!(Timelock::submit(
+            RuntimeOrigin::signed(1),
+            REVEAL_ROUND,
+            encrypt(&remark_call().encode(), REVEAL_ROUND + 1),
+            weight_limit(),
+        ));
+
+        insert_drand_pulse(REVEAL_ROUND, DRAND_QUICKNET_SIG_HEX);
+        run_to_block(2);
+
+        assert!(Tasks::<Test>::get(0).is_none());
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Timelock(Event::DecryptionFailed { id: 0, who: 1 })
+        );
+    });
+}
+
+#[test]
+fn call_above_weight_limit_is_dropped() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Timelock::submit(
+            RuntimeOrigin::signed(1),
+            REVEAL_ROUND,
+            encrypt(&remark_call().encode(), REVEAL_ROUND),
+            Weight::zero(),
+        ));
+
+        insert_drand_pulse(REVEAL_ROUND, DRAND_QUICKNET_SIG_HEX);
+        run_to_block(2);
+
+        assert!(Tasks::<Test>::get(0).is_none());
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Timelock(Event::WeightLimitExceeded { id: 0, who: 1 })
+        );
+    });
+}
+
+#[test]
+fn call_rejected_by_validator_is_dropped() {
+    new_test_ext().execute_with(|| {
+        submit(
+            1,
+            &RuntimeCall::System(frame_system::Call::remark {
+                remark: b"timelocked".to_vec(),
+            }),
+        );
+
+        insert_drand_pulse(REVEAL_ROUND, DRAND_QUICKNET_SIG_HEX);
+        run_to_block(2);
+
+        assert!(Tasks::<Test>::get(0).is_none());
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Timelock(Event::ValidationFailed {
+                id: 0,
+                who: 1,
+                error: InvalidTransaction::Call.into(),
+            })
+        );
+    });
+}
+
+#[test]
+fn deposit_is_reserved_from_free_balance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Balances::reserve(&2, 95));
+
+        assert!(
+            Timelock::submit(
+                RuntimeOrigin::signed(2),
+                REVEAL_ROUND,
+                encrypt(&remark_call().encode(), REVEAL_ROUND),
+                weight_limit(),
+            )
+            .is_err()
+        );
+        assert!(Agenda::<Test>::get(REVEAL_ROUND).is_empty());
+    });
+}
@@ -0,0 +1,148 @@
+
+//! Autogenerated weights for `pallet_timelock`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 43.0.0
+//! DATE: 2025-05-01, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `Ubuntu-2404-noble-amd64-base`, CPU: `AMD Ryzen 9 7950X3D 16-Core Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("local")`, DB CACHE: `1024`
+
+// Executed Command:
+// ./target/production/node-subtensor
+// benchmark
+// pallet
+// --chain=local
+// --wasm-execution=compiled
+// --pallet=pallet-timelock
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --output=pallets/timelock/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+// --allow-missing-host-functions
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_timelock`.
+pub trait WeightInfo {
+	fn submit() -> Weight;
+	fn cancel() -> Weight;
+	fn reveal_task() -> Weight;
+}
+
+/// Weights for `pallet_timelock` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Drand::LastStoredRound` (r:1 w:0)
+	/// Proof: `Drand::LastStoredRound` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timelock::NextTaskId` (r:1 w:1)
+	/// Proof: `Timelock::NextTaskId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timelock::Agenda` (r:1 w:1)
+	/// Proof: `Timelock::Agenda` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Timelock::NextRound` (r:1 w:1)
+	/// Proof: `Timelock::NextRound` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timelock::Tasks` (r:0 w:1)
+	/// Proof: `Timelock::Tasks` (`max_values`: None, `max_size`: Some(2122), added: 4597, mode: `MaxEncodedLen`)
+	fn submit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `226`
+		//  Estimated: `3743`
+		// Minimum execution time: 31_220_000 picoseconds.
+		Weight::from_parts(32_101_000, 3743)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Timelock::Tasks` (r:1 w:1)
+	/// Proof: `Timelock::Tasks` (`max_values`: None, `max_size`: Some(2122), added: 4597, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::LastStoredRound` (r:1 w:0)
+	/// Proof: `Drand::LastStoredRound` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timelock::Agenda` (r:1 w:1)
+	/// Proof: `Timelock::Agenda` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn cancel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1472`
+		//  Estimated: `5587`
+		// Minimum execution time: 30_485_000 picoseconds.
+		Weight::from_parts(31_397_000, 5587)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Timelock::Tasks` (r:1 w:1)
+	/// Proof: `Timelock::Tasks` (`max_values`: None, `max_size`: Some(2122), added: 4597, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn reveal_task() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1472`
+		//  Estimated: `5587`
+		// Minimum execution time: 3_012_731_000 picoseconds.
+		Weight::from_parts(3_058_402_000, 5587)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Drand::LastStoredRound` (r:1 w:0)
+	/// Proof: `Drand::LastStoredRound` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timelock::NextTaskId` (r:1 w:1)
+	/// Proof: `Timelock::NextTaskId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timelock::Agenda` (r:1 w:1)
+	/// Proof: `Timelock::Agenda` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Timelock::NextRound` (r:1 w:1)
+	/// Proof: `Timelock::NextRound` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timelock::Tasks` (r:0 w:1)
+	/// Proof: `Timelock::Tasks` (`max_values`: None, `max_size`: Some(2122), added: 4597, mode: `MaxEncodedLen`)
+	fn submit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `226`
+		//  Estimated: `3743`
+		// Minimum execution time: 31_220_000 picoseconds.
+		Weight::from_parts(32_101_000, 3743)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Timelock::Tasks` (r:1 w:1)
+	/// Proof: `Timelock::Tasks` (`max_values`: None, `max_size`: Some(2122), added: 4597, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::LastStoredRound` (r:1 w:0)
+	/// Proof: `Drand::LastStoredRound` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timelock::Agenda` (r:1 w:1)
+	/// Proof: `Timelock::Agenda` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn cancel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1472`
+		//  Estimated: `5587`
+		// Minimum execution time: 30_485_000 picoseconds.
+		Weight::from_parts(31_397_000, 5587)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Timelock::Tasks` (r:1 w:1)
+	/// Proof: `Timelock::Tasks` (`max_values`: None, `max_size`: Some(2122), added: 4597, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn reveal_task() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1472`
+		//  Estimated: `5587`
+		// Minimum execution time: 3_012_731_000 picoseconds.
+		Weight::from_parts(3_058_402_000, 5587)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
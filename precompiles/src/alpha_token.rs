@@ -0,0 +1,332 @@
+// Alpha token precompiles expose staked alpha to EVM tooling through the ERC-20 interface.
+//
+// Every `(hotkey, netuid)` stake position can be registered once, through the registry precompile
+// or the `register_alpha_token` extrinsic, and receives a token id. The token then lives at the
+// address `ALPHA_TOKEN_ADDRESS_BASE + token_id`, where:
+//   - `balanceOf` is the alpha the mapped coldkey holds on the position,
+//   - `totalSupply` is the total alpha staked to the hotkey on the subnet,
+//   - `transfer` / `transferFrom` move alpha between coldkeys without going through the pool, so
+//     no staking fee is charged,
+//   - `approve` / `transferFrom` keep their allowances in the subtensor pallet.
+//
+// The `Transfer` logs carry the alpha actually credited to the receiver, which can be slightly
+// lower than the requested amount because of the share pool rounding.
+//
+// EVM addresses are mapped to coldkeys with the runtime `AddressMapping`, like the caller of any
+// other precompile.
+
+use alloc::format;
+use core::marker::PhantomData;
+
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_system::RawOrigin;
+use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
+use precompile_utils::prelude::{Address, UnboundedString, keccak256};
+use precompile_utils::{EvmResult, solidity};
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{Dispatchable, StaticLookup, UniqueSaturatedInto};
+use sp_std::vec;
+
+use crate::{PrecompileExt, PrecompileHandleExt};
+
+/// The address of alpha token `n` is `ALPHA_TOKEN_ADDRESS_BASE + n`.
+pub(crate) const ALPHA_TOKEN_ADDRESS_BASE: u64 = 0x0A17_0000_0000;
+
+/// Alpha uses the same precision as TAO.
+const ALPHA_DECIMALS: u8 = 9;
+
+const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
+const SELECTOR_LOG_APPROVAL: [u8; 32] = keccak256!("Approval(address,address,uint256)");
+
+pub(crate) struct AlphaTokenRegistryPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for AlphaTokenRegistryPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
+{
+    const INDEX: u64 = 2056;
+}
+
+#[precompile_utils::precompile]
+impl<R> AlphaTokenRegistryPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
+{
+    #[precompile::public("registerAlphaToken(bytes32,uint16)")]
+    fn register_alpha_token(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+    ) -> EvmResult<Address> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(hotkey.0);
+        let call = pallet_subtensor::Call::<R>::register_alpha_token {
+            hotkey: hotkey.clone(),
+            netuid,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+
+        Ok(Address(
+            pallet_subtensor::Pallet::<R>::get_alpha_token_id(&hotkey, netuid)
+                .map(token_address)
+                .unwrap_or_default(),
+        ))
+    }
+
+    #[precompile::public("getAlphaToken(bytes32,uint16)")]
+    #[precompile::view]
+    fn get_alpha_token(
+        _handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+    ) -> EvmResult<Address> {
+        let hotkey = R::AccountId::from(hotkey.0);
+
+        Ok(Address(
+            pallet_subtensor::Pallet::<R>::get_alpha_token_id(&hotkey, netuid)
+                .map(token_address)
+                .unwrap_or_default(),
+        ))
+    }
+}
+
+pub(crate) struct AlphaTokenPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for AlphaTokenPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
+{
+    // Tokens are served from a range of addresses, `INDEX` is only its start.
+    const INDEX: u64 = ALPHA_TOKEN_ADDRESS_BASE;
+}
+
+impl<R> AlphaTokenPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+{
+    /// Returns the id of the registered alpha token served at `address`.
+    pub(crate) fn token_id(address: H160) -> Option<u32> {
+        let low = address.to_low_u64_be();
+        if H160::from_low_u64_be(low) != address {
+            return None;
+        }
+
+        let token_id = u32::try_from(low.checked_sub(ALPHA_TOKEN_ADDRESS_BASE)?).ok()?;
+        pallet_subtensor::AlphaTokens::<R>::contains_key(token_id).then_some(token_id)
+    }
+
+    fn position(handle: &impl PrecompileHandle) -> EvmResult<(u32, R::AccountId, u16)> {
+        let token_id = Self::token_id(handle.code_address()).ok_or(PrecompileFailure::Error {
+            exit_status: ExitError::Other("alpha token does not exist".into()),
+        })?;
+        let (hotkey, netuid) = pallet_subtensor::Pallet::<R>::get_alpha_token(token_id).ok_or(
+            PrecompileFailure::Error {
+                exit_status: ExitError::Other("alpha token does not exist".into()),
+            },
+        )?;
+
+        Ok((token_id, hotkey, netuid))
+    }
+
+    fn coldkey(address: Address) -> R::AccountId {
+        <R as pallet_evm::Config>::AddressMapping::into_account_id(address.0)
+    }
+
+    fn stake(hotkey: &R::AccountId, coldkey: &R::AccountId, netuid: u16) -> u64 {
+        pallet_subtensor::Pallet::<R>::get_stake_for_hotkey_and_coldkey_on_subnet(
+            hotkey, coldkey, netuid,
+        )
+    }
+}
+
+#[precompile_utils::precompile]
+impl<R> AlphaTokenPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
+{
+    #[precompile::public("name()")]
+    #[precompile::view]
+    fn name(handle: &mut impl PrecompileHandle) -> EvmResult<UnboundedString> {
+        let (_, _, netuid) = Self::position(handle)?;
+
+        Ok(format!("Subnet {} staked alpha", netuid)
+            .into_bytes()
+            .into())
+    }
+
+    #[precompile::public("symbol()")]
+    #[precompile::view]
+    fn symbol(handle: &mut impl PrecompileHandle) -> EvmResult<UnboundedString> {
+        let (_, _, netuid) = Self::position(handle)?;
+
+        Ok(pallet_subtensor::TokenSymbol::<R>::get(netuid).into())
+    }
+
+    #[precompile::public("decimals()")]
+    #[precompile::view]
+    fn decimals(_handle: &mut impl PrecompileHandle) -> EvmResult<u8> {
+        Ok(ALPHA_DECIMALS)
+    }
+
+    #[precompile::public("hotkey()")]
+    #[precompile::view]
+    fn hotkey(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+        let (_, hotkey, _) = Self::position(handle)?;
+        let hotkey: [u8; 32] = hotkey.into();
+
+        Ok(H256::from(hotkey))
+    }
+
+    #[precompile::public("netuid()")]
+    #[precompile::view]
+    fn netuid(handle: &mut impl PrecompileHandle) -> EvmResult<u16> {
+        let (_, _, netuid) = Self::position(handle)?;
+
+        Ok(netuid)
+    }
+
+    #[precompile::public("totalSupply()")]
+    #[precompile::view]
+    fn total_supply(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        let (_, hotkey, netuid) = Self::position(handle)?;
+
+        Ok(pallet_subtensor::TotalHotkeyAlpha::<R>::get(&hotkey, netuid).into())
+    }
+
+    #[precompile::public("balanceOf(address)")]
+    #[precompile::view]
+    fn balance_of(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+        let (_, hotkey, netuid) = Self::position(handle)?;
+
+        Ok(Self::stake(&hotkey, &Self::coldkey(owner), netuid).into())
+    }
+
+    #[precompile::public("allowance(address,address)")]
+    #[precompile::view]
+    fn allowance(
+        handle: &mut impl PrecompileHandle,
+        owner: Address,
+        spender: Address,
+    ) -> EvmResult<U256> {
+        let (token_id, _, _) = Self::position(handle)?;
+
+        Ok(pallet_subtensor::Pallet::<R>::get_alpha_allowance(
+            token_id,
+            &Self::coldkey(owner),
+            &Self::coldkey(spender),
+        )
+        .into())
+    }
+
+    #[precompile::public("transfer(address,uint256)")]
+    fn transfer(handle: &mut impl PrecompileHandle, to: Address, amount: U256) -> EvmResult<bool> {
+        let (token_id, hotkey, netuid) = Self::position(handle)?;
+        let account_id = handle.caller_account_id::<R>();
+        let destination_coldkey = Self::coldkey(to);
+        let received_before = Self::stake(&hotkey, &destination_coldkey, netuid);
+        let call = pallet_subtensor::Call::<R>::transfer_alpha {
+            token_id,
+            destination_coldkey: destination_coldkey.clone(),
+            amount: amount.unique_saturated_into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+
+        let received =
+            Self::stake(&hotkey, &destination_coldkey, netuid).saturating_sub(received_before);
+
+        let topics = vec![
+            H256(SELECTOR_LOG_TRANSFER),
+            handle.context().caller.into(),
+            to.0.into(),
+        ];
+        handle.record_event(topics, solidity::encode_event_data(U256::from(received)))?;
+
+        Ok(true)
+    }
+
+    #[precompile::public("approve(address,uint256)")]
+    fn approve(
+        handle: &mut impl PrecompileHandle,
+        spender: Address,
+        amount: U256,
+    ) -> EvmResult<bool> {
+        let (token_id, _, _) = Self::position(handle)?;
+        let account_id = handle.caller_account_id::<R>();
+        // `type(uint256).max` approvals saturate to an unlimited alpha allowance.
+        let amount: u64 = amount.unique_saturated_into();
+        let call = pallet_subtensor::Call::<R>::approve_alpha {
+            token_id,
+            spender: Self::coldkey(spender),
+            amount,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_APPROVAL),
+            handle.context().caller.into(),
+            spender.0.into(),
+        ];
+        handle.record_event(topics, solidity::encode_event_data(U256::from(amount)))?;
+
+        Ok(true)
+    }
+
+    #[precompile::public("transferFrom(address,address,uint256)")]
+    fn transfer_from(
+        handle: &mut impl PrecompileHandle,
+        from: Address,
+        to: Address,
+        amount: U256,
+    ) -> EvmResult<bool> {
+        let (token_id, hotkey, netuid) = Self::position(handle)?;
+        let account_id = handle.caller_account_id::<R>();
+        let destination_coldkey = Self::coldkey(to);
+        let received_before = Self::stake(&hotkey, &destination_coldkey, netuid);
+        let call = pallet_subtensor::Call::<R>::transfer_alpha_from {
+            token_id,
+            owner: Self::coldkey(from),
+            destination_coldkey: destination_coldkey.clone(),
+            amount: amount.unique_saturated_into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+
+        let received =
+            Self::stake(&hotkey, &destination_coldkey, netuid).saturating_sub(received_before);
+        let topics = vec![H256(SELECTOR_LOG_TRANSFER), from.0.into(), to.0.into()];
+        handle.record_event(topics, solidity::encode_event_data(U256::from(received)))?;
+
+        Ok(true)
+    }
+}
+
+fn token_address(token_id: u32) -> H160 {
+    H160::from_low_u64_be(ALPHA_TOKEN_ADDRESS_BASE.saturating_add(u64::from(token_id)))
+}
@@ -3,12 +3,16 @@ use core::marker::PhantomData;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use frame_system::RawOrigin;
 use pallet_evm::PrecompileHandle;
-use precompile_utils::EvmResult;
+use precompile_utils::prelude::keccak256;
+use precompile_utils::{EvmResult, solidity};
 use sp_core::{H256, U256};
 use sp_runtime::traits::{Dispatchable, StaticLookup, UniqueSaturatedInto};
+use sp_std::vec;
 
 use crate::{PrecompileExt, PrecompileHandleExt};
 
+const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,bytes32,uint256)");
+
 pub(crate) struct BalanceTransferPrecompile<R>(PhantomData<R>);
 
 impl<R> PrecompileExt<R::AccountId> for BalanceTransferPrecompile<R>
@@ -55,6 +59,13 @@ where
             value: amount_sub.unique_saturated_into(),
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(Self::account_id()))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(Self::account_id()))?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_TRANSFER),
+            handle.context().caller.into(),
+            address,
+        ];
+        handle.record_event(topics, solidity::encode_event_data(amount_sub))
     }
 }
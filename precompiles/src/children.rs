@@ -0,0 +1,270 @@
+use core::marker::PhantomData;
+
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_system::RawOrigin;
+use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
+use precompile_utils::prelude::keccak256;
+use precompile_utils::{EvmResult, solidity};
+use sp_core::{H256, crypto::ByteArray};
+use sp_runtime::traits::Dispatchable;
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+use crate::{PrecompileExt, PrecompileHandleExt, netuid_topic};
+
+const SELECTOR_LOG_CHILDREN_SET: [u8; 32] = keccak256!("ChildrenSet(bytes32,uint16)");
+const SELECTOR_LOG_CHILDKEY_TAKE_SET: [u8; 32] =
+    keccak256!("ChildkeyTakeSet(bytes32,uint16,uint16)");
+const SELECTOR_LOG_CHILDKEY_ACCEPTED: [u8; 32] =
+    keccak256!("ChildkeyAccepted(bytes32,bytes32,uint16)");
+const SELECTOR_LOG_CHILDKEY_RESIGNED: [u8; 32] =
+    keccak256!("ChildkeyResigned(bytes32,bytes32,uint16)");
+
+/// Child-key delegation of hotkeys. Relationships are returned as `(hotkey, proportion)` pairs,
+/// with proportions normalized to `u64::MAX`.
+pub struct ChildrenPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for ChildrenPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]> + ByteArray,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    const INDEX: u64 = 2058;
+}
+
+#[precompile_utils::precompile]
+impl<R> ChildrenPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]> + ByteArray,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    #[precompile::public("getChildren(bytes32,uint16)")]
+    #[precompile::view]
+    fn get_children(
+        _: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+    ) -> EvmResult<Vec<(H256, u64)>> {
+        let children =
+            pallet_subtensor::Pallet::<R>::get_children(&R::AccountId::from(hotkey.0), netuid);
+        Ok(Self::encode_relations(children))
+    }
+
+    #[precompile::public("getParents(bytes32,uint16)")]
+    #[precompile::view]
+    fn get_parents(
+        _: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+    ) -> EvmResult<Vec<(H256, u64)>> {
+        let parents =
+            pallet_subtensor::Pallet::<R>::get_parents(&R::AccountId::from(hotkey.0), netuid);
+        Ok(Self::encode_relations(parents))
+    }
+
+    #[precompile::public("getPendingChildren(bytes32,uint16)")]
+    #[precompile::view]
+    fn get_pending_children(
+        _: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+    ) -> EvmResult<(Vec<(H256, u64)>, u64)> {
+        let (children, cooldown_block) =
+            pallet_subtensor::PendingChildKeys::<R>::get(netuid, R::AccountId::from(hotkey.0));
+        Ok((Self::encode_relations(children), cooldown_block))
+    }
+
+    #[precompile::public("getChildkeyTake(bytes32,uint16)")]
+    #[precompile::view]
+    fn get_childkey_take(
+        _: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+    ) -> EvmResult<u16> {
+        Ok(pallet_subtensor::Pallet::<R>::get_childkey_take(
+            &R::AccountId::from(hotkey.0),
+            netuid,
+        ))
+    }
+
+    #[precompile::public("setChildren(bytes32,uint16,bytes32[],uint64[])")]
+    #[precompile::payable]
+    pub fn set_children(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        children: Vec<H256>,
+        proportions: Vec<u64>,
+    ) -> EvmResult<()> {
+        if children.len() != proportions.len() {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("children and proportions length mismatch".into()),
+            });
+        }
+
+        let call = pallet_subtensor::Call::<R>::set_children {
+            hotkey: R::AccountId::from(hotkey.0),
+            netuid,
+            children: proportions
+                .into_iter()
+                .zip(children)
+                .map(|(proportion, child)| (proportion, R::AccountId::from(child.0)))
+                .collect(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_CHILDREN_SET),
+            hotkey,
+            netuid_topic(netuid),
+        ];
+        handle.record_event(topics, Vec::new())
+    }
+
+    /// A `term` of zero means the relationships do not expire.
+    #[precompile::public("setChildrenWithTerms(bytes32,uint16,bytes32[],uint64[],uint64,bool)")]
+    #[precompile::payable]
+    fn set_children_with_terms(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        children: Vec<H256>,
+        proportions: Vec<u64>,
+        term: u64,
+        auto_renew: bool,
+    ) -> EvmResult<()> {
+        if children.len() != proportions.len() {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("children and proportions length mismatch".into()),
+            });
+        }
+
+        let call = pallet_subtensor::Call::<R>::set_children_with_terms {
+            hotkey: R::AccountId::from(hotkey.0),
+            netuid,
+            children: proportions
+                .into_iter()
+                .zip(children)
+                .map(|(proportion, child)| (proportion, R::AccountId::from(child.0)))
+                .collect(),
+            terms: pallet_subtensor::ChildKeyTerms {
+                term: (term != 0).then_some(term),
+                auto_renew,
+            },
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_CHILDREN_SET),
+            hotkey,
+            netuid_topic(netuid),
+        ];
+        handle.record_event(topics, Vec::new())
+    }
+
+    #[precompile::public("acceptChildkey(bytes32,bytes32,uint16)")]
+    #[precompile::payable]
+    fn accept_childkey(
+        handle: &mut impl PrecompileHandle,
+        child: H256,
+        parent: H256,
+        netuid: u16,
+    ) -> EvmResult<()> {
+        let call = pallet_subtensor::Call::<R>::accept_childkey {
+            child: R::AccountId::from(child.0),
+            parent: R::AccountId::from(parent.0),
+            netuid,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_CHILDKEY_ACCEPTED),
+            parent,
+            child,
+            netuid_topic(netuid),
+        ];
+        handle.record_event(topics, Vec::new())
+    }
+
+    #[precompile::public("resignChildkey(bytes32,bytes32,uint16)")]
+    #[precompile::payable]
+    fn resign_childkey(
+        handle: &mut impl PrecompileHandle,
+        child: H256,
+        parent: H256,
+        netuid: u16,
+    ) -> EvmResult<()> {
+        let call = pallet_subtensor::Call::<R>::resign_childkey {
+            child: R::AccountId::from(child.0),
+            parent: R::AccountId::from(parent.0),
+            netuid,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_CHILDKEY_RESIGNED),
+            parent,
+            child,
+            netuid_topic(netuid),
+        ];
+        handle.record_event(topics, Vec::new())
+    }
+
+    #[precompile::public("setChildkeyTake(bytes32,uint16,uint16)")]
+    #[precompile::payable]
+    pub fn set_childkey_take(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        take: u16,
+    ) -> EvmResult<()> {
+        let call = pallet_subtensor::Call::<R>::set_childkey_take {
+            hotkey: R::AccountId::from(hotkey.0),
+            netuid,
+            take,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_CHILDKEY_TAKE_SET),
+            hotkey,
+            netuid_topic(netuid),
+        ];
+        handle.record_event(topics, solidity::encode_event_data(take))
+    }
+
+    fn encode_relations(relations: Vec<(u64, R::AccountId)>) -> Vec<(H256, u64)> {
+        relations
+            .into_iter()
+            .map(|(proportion, hotkey)| (H256::from_slice(hotkey.as_slice()), proportion))
+            .collect()
+    }
+}
@@ -10,7 +10,7 @@ use pallet_evm::{
     PrecompileFailure, PrecompileHandle, PrecompileResult,
 };
 use precompile_utils::EvmResult;
-use sp_core::{H160, U256, blake2_256};
+use sp_core::{H160, H256, U256, blake2_256};
 use sp_runtime::traits::Dispatchable;
 use sp_std::vec::Vec;
 
@@ -106,10 +106,29 @@ pub(crate) trait PrecompileHandleExt: PrecompileHandle {
             }
         }
     }
+
+    /// Emits an EVM log from the executing precompile, charging the log gas first.
+    fn record_event(&mut self, topics: Vec<H256>, data: Vec<u8>) -> EvmResult<()> {
+        precompile_utils::evm::handle::PrecompileHandleExt::record_log_costs_manual(
+            self,
+            topics.len(),
+            data.len(),
+        )?;
+
+        let address = self.context().address;
+        self.log(address, topics, data)?;
+
+        Ok(())
+    }
 }
 
 impl<T> PrecompileHandleExt for T where T: PrecompileHandle {}
 
+/// Encodes a netuid as an indexed `uint16` event topic.
+pub(crate) fn netuid_topic(netuid: u16) -> H256 {
+    H256::from_low_u64_be(u64::from(netuid))
+}
+
 pub(crate) trait PrecompileExt<AccountId: From<[u8; 32]>>: Precompile {
     const INDEX: u64;
 
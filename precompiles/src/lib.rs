@@ -19,22 +19,30 @@ use subtensor_runtime_common::ProxyType;
 
 use pallet_admin_utils::PrecompileEnum;
 
+use crate::alpha_token::*;
 use crate::balance_transfer::*;
+use crate::children::*;
 use crate::ed25519::*;
 use crate::extensions::*;
 use crate::metagraph::*;
 use crate::neuron::*;
+use crate::randomness::*;
 use crate::staking::*;
 use crate::subnet::*;
+use crate::subnet_owner::*;
 use crate::uid_lookup::*;
 
+mod alpha_token;
 mod balance_transfer;
+mod children;
 mod ed25519;
 mod extensions;
 mod metagraph;
 mod neuron;
+mod randomness;
 mod staking;
 mod subnet;
+mod subnet_owner;
 mod uid_lookup;
 
 pub struct Precompiles<R>(PhantomData<R>);
@@ -86,7 +94,7 @@ where
         Self(Default::default())
     }
 
-    pub fn used_addresses() -> [H160; 15] {
+    pub fn used_addresses() -> [H160; 19] {
         [
             hash(1),
             hash(2),
@@ -103,6 +111,10 @@ where
             hash(NeuronPrecompile::<R>::INDEX),
             hash(StakingPrecompileV2::<R>::INDEX),
             hash(UidLookupPrecompile::<R>::INDEX),
+            hash(RandomnessPrecompile::<R>::INDEX),
+            hash(AlphaTokenRegistryPrecompile::<R>::INDEX),
+            hash(SubnetOwnerPrecompile::<R>::INDEX),
+            hash(ChildrenPrecompile::<R>::INDEX),
         ]
     }
 }
@@ -164,13 +176,32 @@ where
             a if a == hash(UidLookupPrecompile::<R>::INDEX) => {
                 UidLookupPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::UidLookup)
             }
+            a if a == hash(RandomnessPrecompile::<R>::INDEX) => {
+                RandomnessPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Randomness)
+            }
+            a if a == hash(AlphaTokenRegistryPrecompile::<R>::INDEX) => {
+                AlphaTokenRegistryPrecompile::<R>::try_execute::<R>(
+                    handle,
+                    PrecompileEnum::AlphaToken,
+                )
+            }
+            a if a == hash(SubnetOwnerPrecompile::<R>::INDEX) => {
+                SubnetOwnerPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::SubnetOwner)
+            }
+            a if a == hash(ChildrenPrecompile::<R>::INDEX) => {
+                ChildrenPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Children)
+            }
+            a if AlphaTokenPrecompile::<R>::token_id(a).is_some() => {
+                AlphaTokenPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::AlphaToken)
+            }
             _ => None,
         }
     }
 
     fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
         IsPrecompileResult::Answer {
-            is_precompile: Self::used_addresses().contains(&address),
+            is_precompile: Self::used_addresses().contains(&address)
+                || AlphaTokenPrecompile::<R>::token_id(address).is_some(),
             extra_cost: 0,
         }
     }
@@ -2,13 +2,24 @@ use core::marker::PhantomData;
 
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use frame_system::RawOrigin;
-use pallet_evm::{AddressMapping, PrecompileHandle};
-use precompile_utils::{EvmResult, prelude::UnboundedBytes};
+use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
+use precompile_utils::prelude::{UnboundedBytes, keccak256};
+use precompile_utils::{EvmResult, solidity};
 use sp_core::H256;
 use sp_runtime::traits::Dispatchable;
+use sp_std::vec;
 use sp_std::vec::Vec;
 
-use crate::{PrecompileExt, PrecompileHandleExt};
+use crate::{PrecompileExt, PrecompileHandleExt, netuid_topic};
+
+const SELECTOR_LOG_WEIGHTS_SET: [u8; 32] = keccak256!("WeightsSet(uint16,address)");
+const SELECTOR_LOG_WEIGHTS_COMMITTED: [u8; 32] =
+    keccak256!("WeightsCommitted(uint16,address,bytes32)");
+const SELECTOR_LOG_WEIGHTS_REVEALED: [u8; 32] = keccak256!("WeightsRevealed(uint16,address)");
+const SELECTOR_LOG_NEURON_REGISTERED: [u8; 32] =
+    keccak256!("NeuronRegistered(uint16,bytes32,uint16)");
+const SELECTOR_LOG_AXON_SERVED: [u8; 32] = keccak256!("AxonServed(uint16,address)");
+const SELECTOR_LOG_PROMETHEUS_SERVED: [u8; 32] = keccak256!("PrometheusServed(uint16,address)");
 
 pub struct NeuronPrecompile<R>(PhantomData<R>);
 
@@ -53,7 +64,9 @@ where
         handle.try_dispatch_runtime_call::<R, _>(
             call,
             RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        )?;
+
+        Self::record_neuron_event(handle, SELECTOR_LOG_WEIGHTS_SET, netuid, Vec::new())
     }
 
     #[precompile::public("commitWeights(uint16,bytes32)")]
@@ -71,6 +84,13 @@ where
         handle.try_dispatch_runtime_call::<R, _>(
             call,
             RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        Self::record_neuron_event(
+            handle,
+            SELECTOR_LOG_WEIGHTS_COMMITTED,
+            netuid,
+            solidity::encode_event_data(commit_hash),
         )
     }
 
@@ -95,7 +115,9 @@ where
         handle.try_dispatch_runtime_call::<R, _>(
             call,
             RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        )?;
+
+        Self::record_neuron_event(handle, SELECTOR_LOG_WEIGHTS_REVEALED, netuid, Vec::new())
     }
 
     #[precompile::public("burnedRegister(uint16,bytes32)")]
@@ -106,10 +128,22 @@ where
         hotkey: H256,
     ) -> EvmResult<()> {
         let coldkey = handle.caller_account_id::<R>();
-        let hotkey = R::AccountId::from(hotkey.0);
-        let call = pallet_subtensor::Call::<R>::burned_register { netuid, hotkey };
+        let hotkey_id = R::AccountId::from(hotkey.0);
+        let call = pallet_subtensor::Call::<R>::burned_register {
+            netuid,
+            hotkey: hotkey_id.clone(),
+        };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(coldkey))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(coldkey))?;
+
+        let uid = pallet_subtensor::Pallet::<R>::get_uid_for_net_and_hotkey(netuid, &hotkey_id)
+            .unwrap_or_default();
+        let topics = vec![
+            H256(SELECTOR_LOG_NEURON_REGISTERED),
+            netuid_topic(netuid),
+            hotkey,
+        ];
+        handle.record_event(topics, solidity::encode_event_data(uid))
     }
 
     #[precompile::public("serveAxon(uint16,uint32,uint128,uint16,uint8,uint8,uint8,uint8)")]
@@ -140,7 +174,9 @@ where
         handle.try_dispatch_runtime_call::<R, _>(
             call,
             RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        )?;
+
+        Self::record_neuron_event(handle, SELECTOR_LOG_AXON_SERVED, netuid, Vec::new())
     }
 
     #[precompile::public(
@@ -175,7 +211,9 @@ where
         handle.try_dispatch_runtime_call::<R, _>(
             call,
             RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        )?;
+
+        Self::record_neuron_event(handle, SELECTOR_LOG_AXON_SERVED, netuid, Vec::new())
     }
 
     #[precompile::public("servePrometheus(uint16,uint32,uint128,uint16,uint8)")]
@@ -200,6 +238,152 @@ where
         handle.try_dispatch_runtime_call::<R, _>(
             call,
             RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        Self::record_neuron_event(handle, SELECTOR_LOG_PROMETHEUS_SERVED, netuid, Vec::new())
+    }
+
+    #[precompile::public("setWeightsForHotkey(uint16,bytes32,uint16[],uint16[],uint64)")]
+    #[precompile::payable]
+    pub fn set_weights_for_hotkey(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        hotkey: H256,
+        dests: Vec<u16>,
+        weights: Vec<u16>,
+        version_key: u64,
+    ) -> EvmResult<()> {
+        let origin = Self::associated_hotkey_origin(handle, netuid, hotkey)?;
+        let call = pallet_subtensor::Call::<R>::set_weights {
+            netuid,
+            dests,
+            weights,
+            version_key,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, origin)?;
+
+        Self::record_neuron_event(handle, SELECTOR_LOG_WEIGHTS_SET, netuid, Vec::new())
+    }
+
+    #[precompile::public("commitWeightsForHotkey(uint16,bytes32,bytes32)")]
+    #[precompile::payable]
+    pub fn commit_weights_for_hotkey(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        hotkey: H256,
+        commit_hash: H256,
+    ) -> EvmResult<()> {
+        let origin = Self::associated_hotkey_origin(handle, netuid, hotkey)?;
+        let call = pallet_subtensor::Call::<R>::commit_weights {
+            netuid,
+            commit_hash,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, origin)?;
+
+        Self::record_neuron_event(
+            handle,
+            SELECTOR_LOG_WEIGHTS_COMMITTED,
+            netuid,
+            solidity::encode_event_data(commit_hash),
+        )
+    }
+
+    #[precompile::public(
+        "revealWeightsForHotkey(uint16,bytes32,uint16[],uint16[],uint16[],uint64)"
+    )]
+    #[precompile::payable]
+    #[allow(clippy::too_many_arguments)]
+    pub fn reveal_weights_for_hotkey(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        hotkey: H256,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        salt: Vec<u16>,
+        version_key: u64,
+    ) -> EvmResult<()> {
+        let origin = Self::associated_hotkey_origin(handle, netuid, hotkey)?;
+        let call = pallet_subtensor::Call::<R>::reveal_weights {
+            netuid,
+            uids,
+            values,
+            salt,
+            version_key,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, origin)?;
+
+        Self::record_neuron_event(handle, SELECTOR_LOG_WEIGHTS_REVEALED, netuid, Vec::new())
+    }
+
+    #[precompile::public(
+        "serveAxonForHotkey(uint16,bytes32,uint32,uint128,uint16,uint8,uint8,uint8,uint8)"
+    )]
+    #[precompile::payable]
+    #[allow(clippy::too_many_arguments)]
+    fn serve_axon_for_hotkey(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        hotkey: H256,
+        version: u32,
+        ip: u128,
+        port: u16,
+        ip_type: u8,
+        protocol: u8,
+        placeholder1: u8,
+        placeholder2: u8,
+    ) -> EvmResult<()> {
+        let origin = Self::associated_hotkey_origin(handle, netuid, hotkey)?;
+        let call = pallet_subtensor::Call::<R>::serve_axon {
+            netuid,
+            version,
+            ip,
+            port,
+            ip_type,
+            protocol,
+            placeholder1,
+            placeholder2,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, origin)?;
+
+        Self::record_neuron_event(handle, SELECTOR_LOG_AXON_SERVED, netuid, Vec::new())
+    }
+
+    /// Returns the origin of `hotkey` if the caller is the EVM key associated with it on
+    /// `netuid` through `associate_evm_key`, or the contract associated with it through
+    /// `associate_evm_contract`.
+    fn associated_hotkey_origin(
+        handle: &impl PrecompileHandle,
+        netuid: u16,
+        hotkey: H256,
+    ) -> EvmResult<RawOrigin<R::AccountId>> {
+        let hotkey = R::AccountId::from(hotkey.0);
+        pallet_subtensor::Pallet::<R>::ensure_evm_key_associated(
+            netuid,
+            &hotkey,
+            handle.context().caller,
         )
+        .map_err(|_| PrecompileFailure::Error {
+            exit_status: ExitError::Other("caller is not the EVM key of the hotkey".into()),
+        })?;
+
+        Ok(RawOrigin::Signed(hotkey))
+    }
+
+    fn record_neuron_event(
+        handle: &mut impl PrecompileHandle,
+        selector: [u8; 32],
+        netuid: u16,
+        data: Vec<u8>,
+    ) -> EvmResult<()> {
+        let topics = vec![
+            H256(selector),
+            netuid_topic(netuid),
+            handle.context().caller.into(),
+        ];
+        handle.record_event(topics, data)
     }
 }
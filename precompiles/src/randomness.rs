@@ -0,0 +1,122 @@
+use core::marker::PhantomData;
+
+use fp_evm::{ExitError, PrecompileFailure, PrecompileHandle};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_drand::{RandomnessProvider, types::Pulse};
+use precompile_utils::{EvmResult, prelude::UnboundedBytes};
+use sp_core::H256;
+
+use crate::PrecompileExt;
+
+pub struct RandomnessPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for RandomnessPrecompile<R>
+where
+    R: frame_system::Config + pallet_drand::Config,
+    R::AccountId: From<[u8; 32]>,
+{
+    const INDEX: u64 = 2055;
+}
+
+#[precompile_utils::precompile]
+impl<R> RandomnessPrecompile<R>
+where
+    R: frame_system::Config + pallet_drand::Config,
+{
+    #[precompile::public("getLastRound()")]
+    #[precompile::view]
+    fn get_last_round(_: &mut impl PrecompileHandle) -> EvmResult<u64> {
+        Ok(pallet_drand::Pallet::<R>::last_stored_round(
+            pallet_drand::types::DEFAULT_BEACON_ID,
+        ))
+    }
+
+    #[precompile::public("getPulse(uint64)")]
+    #[precompile::view]
+    fn get_pulse(
+        _: &mut impl PrecompileHandle,
+        round: u64,
+    ) -> EvmResult<(u64, H256, UnboundedBytes)> {
+        let pulse = Self::pulse_at(round)?;
+        Ok(Self::encode_pulse(pulse))
+    }
+
+    #[precompile::public("getPulseAtBlock(uint64)")]
+    #[precompile::view]
+    fn get_pulse_at_block(
+        _: &mut impl PrecompileHandle,
+        block_number: u64,
+    ) -> EvmResult<(u64, H256, UnboundedBytes)> {
+        let round = Self::round_at_block(block_number)?;
+        let pulse = Self::pulse_at(round)?;
+        Ok(Self::encode_pulse(pulse))
+    }
+
+    #[precompile::public("getRandomness(uint64,bytes32)")]
+    #[precompile::view]
+    fn get_randomness(_: &mut impl PrecompileHandle, round: u64, subject: H256) -> EvmResult<H256> {
+        pallet_drand::Pallet::<R>::random_for(
+            pallet_drand::types::DEFAULT_BEACON_ID,
+            round,
+            subject.as_bytes(),
+        )
+        .map(H256::from)
+        .ok_or(Self::not_found())
+    }
+
+    #[precompile::public("getRandomnessAtBlock(uint64,bytes32)")]
+    #[precompile::view]
+    fn get_randomness_at_block(
+        _: &mut impl PrecompileHandle,
+        block_number: u64,
+        subject: H256,
+    ) -> EvmResult<H256> {
+        pallet_drand::Pallet::<R>::random_at_block(
+            Self::block_number(block_number)?,
+            subject.as_bytes(),
+        )
+        .map(H256::from)
+        .ok_or(Self::not_found())
+    }
+}
+
+impl<R> RandomnessPrecompile<R>
+where
+    R: frame_system::Config + pallet_drand::Config,
+{
+    fn pulse_at(round: u64) -> EvmResult<Pulse> {
+        pallet_drand::Pallet::<R>::pulse_at(pallet_drand::types::DEFAULT_BEACON_ID, round)
+            .ok_or(Self::not_found())
+    }
+
+    fn round_at_block(block_number: u64) -> EvmResult<u64> {
+        pallet_drand::Pallet::<R>::round_at_block(Self::block_number(block_number)?)
+            .ok_or(Self::not_found())
+    }
+
+    fn block_number(block_number: u64) -> EvmResult<BlockNumberFor<R>> {
+        BlockNumberFor::<R>::try_from(block_number).map_err(|_| PrecompileFailure::Error {
+            exit_status: ExitError::InvalidRange,
+        })
+    }
+
+    fn encode_pulse(pulse: Pulse) -> (u64, H256, UnboundedBytes) {
+        let randomness: [u8; 32] = pulse
+            .randomness
+            .into_inner()
+            .try_into()
+            .unwrap_or([0u8; 32]);
+
+        (
+            pulse.round,
+            H256::from(randomness),
+            pulse.signature.into_inner().into(),
+        )
+    }
+
+    fn not_found() -> PrecompileFailure {
+        PrecompileFailure::Error {
+            exit_status: ExitError::Other("pulse not found".into()),
+        }
+    }
+}
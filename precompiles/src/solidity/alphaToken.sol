@@ -0,0 +1,82 @@
+pragma solidity ^0.8.0;
+
+address constant IALPHA_TOKEN_REGISTRY_ADDRESS = 0x0000000000000000000000000000000000000808;
+
+// Alpha token `n` lives at address `0x0A1700000000 + n`.
+interface IAlphaTokenRegistry {
+    /**
+     * @dev Registers an ERC-20 token wrapping the stake of `hotkey` on `netuid`.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param netuid The subnet of the wrapped stake.
+     * @return The address of the new alpha token.
+     */
+    function registerAlphaToken(
+        bytes32 hotkey,
+        uint16 netuid
+    ) external returns (address);
+
+    /**
+     * @dev Returns the alpha token wrapping the stake of `hotkey` on `netuid`, or the zero address
+     * if none is registered.
+     */
+    function getAlphaToken(
+        bytes32 hotkey,
+        uint16 netuid
+    ) external view returns (address);
+}
+
+// ERC-20 view of one (hotkey, netuid) stake position. Amounts are in alpha with 9 decimals.
+interface IAlphaToken {
+    event Transfer(address indexed from, address indexed to, uint256 value);
+    event Approval(
+        address indexed owner,
+        address indexed spender,
+        uint256 value
+    );
+
+    function name() external view returns (string memory);
+
+    function symbol() external view returns (string memory);
+
+    function decimals() external view returns (uint8);
+
+    /**
+     * @dev Returns the hotkey of the wrapped position.
+     */
+    function hotkey() external view returns (bytes32);
+
+    /**
+     * @dev Returns the subnet of the wrapped position.
+     */
+    function netuid() external view returns (uint16);
+
+    /**
+     * @dev Returns the total alpha staked to the hotkey on the subnet.
+     */
+    function totalSupply() external view returns (uint256);
+
+    /**
+     * @dev Returns the alpha the coldkey mapped from `owner` holds on the position.
+     */
+    function balanceOf(address owner) external view returns (uint256);
+
+    function allowance(
+        address owner,
+        address spender
+    ) external view returns (uint256);
+
+    /**
+     * @dev Moves stake to the coldkey mapped from `to`, keeping the hotkey and subnet, like
+     * `transferStake`.
+     */
+    function transfer(address to, uint256 value) external returns (bool);
+
+    function approve(address spender, uint256 value) external returns (bool);
+
+    function transferFrom(
+        address from,
+        address to,
+        uint256 value
+    ) external returns (bool);
+}
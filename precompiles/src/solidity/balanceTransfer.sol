@@ -3,5 +3,7 @@ pragma solidity ^0.8.0;
 address constant ISUBTENSOR_BALANCE_TRANSFER_ADDRESS = 0x0000000000000000000000000000000000000800;
 
 interface ISubtensorBalanceTransfer {
+    event Transfer(address indexed from, bytes32 indexed to, uint256 value);
+
     function transfer(bytes32 data) external payable;
 }
\ No newline at end of file
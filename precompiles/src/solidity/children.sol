@@ -0,0 +1,91 @@
+pragma solidity ^0.8.0;
+
+address constant ICHILDREN_ADDRESS = 0x000000000000000000000000000000000000080a;
+
+// A child-key relationship. `proportion` is the share of the parent's stake, normalized to
+// type(uint64).max.
+struct ChildKey {
+    bytes32 hotkey;
+    uint64 proportion;
+}
+
+interface IChildren {
+    event ChildrenSet(bytes32 indexed hotkey, uint16 indexed netuid);
+    event ChildkeyTakeSet(
+        bytes32 indexed hotkey,
+        uint16 indexed netuid,
+        uint16 take
+    );
+    event ChildkeyAccepted(
+        bytes32 indexed parent,
+        bytes32 indexed child,
+        uint16 indexed netuid
+    );
+    event ChildkeyResigned(
+        bytes32 indexed parent,
+        bytes32 indexed child,
+        uint16 indexed netuid
+    );
+
+    function getChildren(
+        bytes32 hotkey,
+        uint16 netuid
+    ) external view returns (ChildKey[] memory);
+
+    function getParents(
+        bytes32 hotkey,
+        uint16 netuid
+    ) external view returns (ChildKey[] memory);
+
+    /// Returns the scheduled children of `hotkey` and the block after which they apply.
+    function getPendingChildren(
+        bytes32 hotkey,
+        uint16 netuid
+    ) external view returns (ChildKey[] memory, uint64);
+
+    function getChildkeyTake(
+        bytes32 hotkey,
+        uint16 netuid
+    ) external view returns (uint16);
+
+    /// Schedules the children of a hotkey owned by the caller, replacing the current ones
+    /// once the pending cooldown has passed.
+    function setChildren(
+        bytes32 hotkey,
+        uint16 netuid,
+        bytes32[] memory children,
+        uint64[] memory proportions
+    ) external payable;
+
+    /// Schedules the children under a `term` in blocks, zero meaning no expiry. Relationships
+    /// with `autoRenew` are renewed for another term when they expire.
+    function setChildrenWithTerms(
+        bytes32 hotkey,
+        uint16 netuid,
+        bytes32[] memory children,
+        uint64[] memory proportions,
+        uint64 term,
+        bool autoRenew
+    ) external payable;
+
+    /// Accepts the pending schedule of `parent` for a `child` hotkey owned by the caller.
+    /// Schedules only apply once all of their children have accepted.
+    function acceptChildkey(
+        bytes32 child,
+        bytes32 parent,
+        uint16 netuid
+    ) external payable;
+
+    /// Ends the relationship of a `child` hotkey owned by the caller with `parent`.
+    function resignChildkey(
+        bytes32 child,
+        bytes32 parent,
+        uint16 netuid
+    ) external payable;
+
+    function setChildkeyTake(
+        bytes32 hotkey,
+        uint16 netuid,
+        uint16 take
+    ) external payable;
+}
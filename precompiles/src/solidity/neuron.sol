@@ -3,6 +3,21 @@ pragma solidity ^0.8.0;
 address constant INeuron_ADDRESS = 0x0000000000000000000000000000000000000804;
 
 interface INeuron {
+    event WeightsSet(uint16 indexed netuid, address indexed hotkey);
+    event WeightsCommitted(
+        uint16 indexed netuid,
+        address indexed hotkey,
+        bytes32 commitHash
+    );
+    event WeightsRevealed(uint16 indexed netuid, address indexed hotkey);
+    event NeuronRegistered(
+        uint16 indexed netuid,
+        bytes32 indexed hotkey,
+        uint16 uid
+    );
+    event AxonServed(uint16 indexed netuid, address indexed hotkey);
+    event PrometheusServed(uint16 indexed netuid, address indexed hotkey);
+
     /**
      * @dev Registers a neuron by calling `do_burned_registration` internally with the origin set to the ss58 mirror of the H160 address.
      * This allows the H160 to further call neuron-related methods and receive emissions.
@@ -119,4 +134,43 @@ interface INeuron {
         uint16[] memory salt,
         uint64 versionKey
     ) external payable;
+
+    // The `...ForHotkey` calls act as `hotkey`. The caller must be the EVM key associated with
+    // the hotkey on `netuid` through `associate_evm_key`. Contracts cannot sign the association
+    // message, so the coldkey of the hotkey associates them with `associate_evm_contract` instead.
+
+    function setWeightsForHotkey(
+        uint16 netuid,
+        bytes32 hotkey,
+        uint16[] memory dests,
+        uint16[] memory weights,
+        uint64 versionKey
+    ) external payable;
+
+    function commitWeightsForHotkey(
+        uint16 netuid,
+        bytes32 hotkey,
+        bytes32 commitHash
+    ) external payable;
+
+    function revealWeightsForHotkey(
+        uint16 netuid,
+        bytes32 hotkey,
+        uint16[] memory uids,
+        uint16[] memory values,
+        uint16[] memory salt,
+        uint64 versionKey
+    ) external payable;
+
+    function serveAxonForHotkey(
+        uint16 netuid,
+        bytes32 hotkey,
+        uint32 version,
+        uint128 ip,
+        uint16 port,
+        uint8 ipType,
+        uint8 protocol,
+        uint8 placeholder1,
+        uint8 placeholder2
+    ) external payable;
 }
@@ -0,0 +1,59 @@
+pragma solidity ^0.8.0;
+
+address constant IRANDOMNESS_ADDRESS = 0x0000000000000000000000000000000000000807;
+
+interface IRandomness {
+  /**
+   * @dev Returns the last drand round stored on chain.
+   * @return The last drand round stored on chain.
+   */
+  function getLastRound() external view returns (uint64);
+
+  /**
+   * @dev Returns the drand pulse of a round. Reverts if the pulse is not stored.
+   * The randomness is the sha256 hash of the BLS signature, which can be checked against
+   * the drand quicknet public key.
+   * @param round The drand round.
+   * @return round The drand round.
+   * @return randomness The randomness of the round.
+   * @return signature The BLS signature of the round.
+   */
+  function getPulse(
+    uint64 round
+  ) external view returns (uint64, bytes32, bytes memory);
+
+  /**
+   * @dev Returns the last drand pulse stored as of a block. Reverts if the block is not recorded.
+   * @param blockNumber The block number.
+   * @return round The drand round.
+   * @return randomness The randomness of the round.
+   * @return signature The BLS signature of the round.
+   */
+  function getPulseAtBlock(
+    uint64 blockNumber
+  ) external view returns (uint64, bytes32, bytes memory);
+
+  /**
+   * @dev Returns the randomness of a round derived for a subject,
+   * keccak256(abi.encodePacked(randomness, subject)). Reverts if the pulse is not stored.
+   * @param round The drand round.
+   * @param subject The subject the randomness is derived for.
+   * @return The derived randomness.
+   */
+  function getRandomness(
+    uint64 round,
+    bytes32 subject
+  ) external view returns (bytes32);
+
+  /**
+   * @dev Returns the randomness of the last round stored as of a block derived for a subject.
+   * Reverts if the block is not recorded.
+   * @param blockNumber The block number.
+   * @param subject The subject the randomness is derived for.
+   * @return The derived randomness.
+   */
+  function getRandomnessAtBlock(
+    uint64 blockNumber,
+    bytes32 subject
+  ) external view returns (bytes32);
+}
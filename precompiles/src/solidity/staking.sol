@@ -3,6 +3,23 @@ pragma solidity ^0.8.0;
 address constant ISTAKING_ADDRESS = 0x0000000000000000000000000000000000000801;
 
 interface IStaking {
+    event StakeAdded(
+        address indexed staker,
+        bytes32 indexed hotkey,
+        uint16 indexed netuid,
+        uint256 tao,
+        uint256 alpha
+    );
+    event StakeRemoved(
+        address indexed staker,
+        bytes32 indexed hotkey,
+        uint16 indexed netuid,
+        uint256 alpha,
+        uint256 tao
+    );
+    event ProxyAdded(address indexed account, bytes32 indexed delegate);
+    event ProxyRemoved(address indexed account, bytes32 indexed delegate);
+
     /**
      * @dev Adds a subtensor stake corresponding to the value sent with the transaction, associated
      * with the `hotkey`.
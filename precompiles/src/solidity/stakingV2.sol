@@ -2,7 +2,77 @@ pragma solidity ^0.8.0;
 
 address constant ISTAKING_ADDRESS = 0x0000000000000000000000000000000000000805;
 
+struct StakeInfo {
+    bytes32 hotkey;
+    bytes32 coldkey;
+    uint16 netuid;
+    uint64 stake;
+    uint64 locked;
+    uint64 emission;
+    uint64 tao_emission;
+    uint64 drain;
+    bool is_registered;
+}
+
 interface IStaking {
+    event StakeAdded(
+        address indexed staker,
+        bytes32 indexed hotkey,
+        uint16 indexed netuid,
+        uint256 tao,
+        uint256 alpha
+    );
+    event StakeRemoved(
+        address indexed staker,
+        bytes32 indexed hotkey,
+        uint16 indexed netuid,
+        uint256 alpha,
+        uint256 tao
+    );
+    event ProxyAdded(address indexed account, bytes32 indexed delegate);
+    event ProxyRemoved(address indexed account, bytes32 indexed delegate);
+    event StakeMoved(
+        address indexed staker,
+        bytes32 indexed originHotkey,
+        bytes32 indexed destinationHotkey,
+        uint16 originNetuid,
+        uint16 destinationNetuid,
+        uint256 alpha
+    );
+    event StakeTransferred(
+        address indexed staker,
+        bytes32 indexed destinationColdkey,
+        bytes32 indexed hotkey,
+        uint16 originNetuid,
+        uint16 destinationNetuid,
+        uint256 alpha
+    );
+    event StakeSwapped(
+        address indexed staker,
+        bytes32 indexed hotkey,
+        uint16 originNetuid,
+        uint16 destinationNetuid,
+        uint256 alpha
+    );
+    event AllStakeRemoved(
+        address indexed staker,
+        bytes32 indexed hotkey,
+        uint256 tao
+    );
+    event AllAlphaUnstaked(address indexed staker, bytes32 indexed hotkey);
+    event AlphaRecycled(
+        address indexed staker,
+        bytes32 indexed hotkey,
+        uint16 indexed netuid,
+        uint256 alpha
+    );
+    event AlphaBurned(
+        address indexed staker,
+        bytes32 indexed hotkey,
+        uint16 indexed netuid,
+        uint256 alpha
+    );
+
     /**
      * @dev Adds a subtensor stake `amount` associated with the `hotkey`.
      *
@@ -249,4 +319,191 @@ interface IStaking {
         bool allow_partial,
         uint256 netuid
     ) external;
+
+    /**
+     * @dev Swaps a subtensor stake `amount` of the `hotkey` from one subnet to another.
+     *
+     * This function effectively calls `swap_stake` on the subtensor pallet with the coldkey being the
+     * hashed address mapping of H160 sender address.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param origin_netuid The subnet to swap stake from (uint256).
+     * @param destination_netuid The subnet to swap stake to (uint256).
+     * @param amount The amount to swap in alpha.
+     */
+    function swapStake(
+        bytes32 hotkey,
+        uint256 origin_netuid,
+        uint256 destination_netuid,
+        uint256 amount
+    ) external;
+
+    /**
+     * @dev Swaps a subtensor stake `amount` of the `hotkey` from one subnet to another within a price limit.
+     *
+     * This function effectively calls `swap_stake_limit` on the subtensor pallet with the coldkey being the
+     * hashed address mapping of H160 sender address.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param origin_netuid The subnet to swap stake from (uint256).
+     * @param destination_netuid The subnet to swap stake to (uint256).
+     * @param amount The amount to swap in alpha.
+     * @param limit_price The limit of the price of the origin alpha in the destination alpha, in rao.
+     * @param allow_partial Whether to allow partial swap.
+     */
+    function swapStakeLimit(
+        bytes32 hotkey,
+        uint256 origin_netuid,
+        uint256 destination_netuid,
+        uint256 amount,
+        uint256 limit_price,
+        bool allow_partial
+    ) external;
+
+    /**
+     * @dev Unstakes all the stake of the sender from the `hotkey` on every subnet, into TAO.
+     *
+     * This function effectively calls `unstake_all` on the subtensor pallet.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     */
+    function unstakeAll(bytes32 hotkey) external;
+
+    /**
+     * @dev Unstakes all the alpha of the sender from the `hotkey` on every subnet, and restakes it
+     * on the root network.
+     *
+     * This function effectively calls `unstake_all_alpha` on the subtensor pallet.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     */
+    function unstakeAllAlpha(bytes32 hotkey) external;
+
+    /**
+     * @dev Recycles an `amount` of alpha staked by the sender to the `hotkey`, reducing the alpha
+     * outstanding on the subnet.
+     *
+     * This function effectively calls `recycle_alpha` on the subtensor pallet.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param amount The amount to recycle in alpha.
+     * @param netuid The subnet of the alpha (uint256).
+     */
+    function recycleAlpha(
+        bytes32 hotkey,
+        uint256 amount,
+        uint256 netuid
+    ) external;
+
+    /**
+     * @dev Burns an `amount` of alpha staked by the sender to the `hotkey`.
+     *
+     * This function effectively calls `burn_alpha` on the subtensor pallet.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param amount The amount to burn in alpha.
+     * @param netuid The subnet of the alpha (uint256).
+     */
+    function burnAlpha(
+        bytes32 hotkey,
+        uint256 amount,
+        uint256 netuid
+    ) external;
+
+    /**
+     * @dev Returns the current price of the alpha of a subnet.
+     *
+     * @param netuid The subnet (uint256).
+     * @return The price in rao per alpha.
+     */
+    function getAlphaPrice(uint256 netuid) external view returns (uint256);
+
+    /**
+     * @dev Returns the moving average price of the alpha of a subnet.
+     *
+     * @param netuid The subnet (uint256).
+     * @return The price in rao per alpha.
+     */
+    function getMovingAlphaPrice(uint256 netuid) external view returns (uint256);
+
+    /**
+     * @dev Simulates the swap of an `amount` of TAO for the alpha of a subnet.
+     *
+     * @param netuid The subnet (uint256).
+     * @param amount The amount of TAO in rao.
+     * @return The amount of alpha received, 0 if the swap is not possible.
+     */
+    function simSwapTaoForAlpha(
+        uint256 netuid,
+        uint256 amount
+    ) external view returns (uint256);
+
+    /**
+     * @dev Simulates the swap of an `amount` of the alpha of a subnet for TAO.
+     *
+     * @param netuid The subnet (uint256).
+     * @param amount The amount of alpha.
+     * @return The amount of TAO received in rao, 0 if the swap is not possible.
+     */
+    function simSwapAlphaForTao(
+        uint256 netuid,
+        uint256 amount
+    ) external view returns (uint256);
+
+    /**
+     * @dev Returns the fee of staking an `amount` of TAO to the `hotkey` on a subnet.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param netuid The subnet to stake to (uint256).
+     * @param amount The amount to stake in rao.
+     * @return The fee in rao.
+     */
+    function getAddStakeFee(
+        bytes32 hotkey,
+        uint256 netuid,
+        uint256 amount
+    ) external view returns (uint256);
+
+    /**
+     * @dev Returns the fee of unstaking an `amount` of alpha from the `hotkey` on a subnet.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param netuid The subnet to unstake from (uint256).
+     * @param amount The amount to unstake in alpha.
+     * @return The fee in rao.
+     */
+    function getRemoveStakeFee(
+        bytes32 hotkey,
+        uint256 netuid,
+        uint256 amount
+    ) external view returns (uint256);
+
+    /**
+     * @dev Returns the fee of moving, swapping or transferring an `amount` of alpha between hotkeys
+     * and subnets.
+     *
+     * @param origin_hotkey The origin hotkey public key (32 bytes).
+     * @param destination_hotkey The destination hotkey public key (32 bytes).
+     * @param origin_netuid The subnet to move stake from (uint256).
+     * @param destination_netuid The subnet to move stake to (uint256).
+     * @param amount The amount to move in alpha.
+     * @return The fee in rao.
+     */
+    function getMoveStakeFee(
+        bytes32 origin_hotkey,
+        bytes32 destination_hotkey,
+        uint256 origin_netuid,
+        uint256 destination_netuid,
+        uint256 amount
+    ) external view returns (uint256);
+
+    /**
+     * @dev Returns the stakes of a coldkey on every hotkey and subnet.
+     *
+     * @param coldkey The coldkey public key (32 bytes).
+     * @return The stakes of the coldkey.
+     */
+    function getStakeInfoForColdkey(
+        bytes32 coldkey
+    ) external view returns (StakeInfo[] memory);
 }
@@ -3,6 +3,22 @@ pragma solidity ^0.8.0;
 address constant ISUBNET_ADDRESS = 0x0000000000000000000000000000000000000803;
 
 interface ISubnet {
+    event NetworkRegistered(
+        uint16 indexed netuid,
+        bytes32 indexed hotkey,
+        address indexed owner
+    );
+    event HyperparameterSet(
+        uint16 indexed netuid,
+        uint16 indexed id,
+        uint64 value
+    );
+    event AlphaValuesSet(
+        uint16 indexed netuid,
+        uint16 alphaLow,
+        uint16 alphaHigh
+    );
+
     /// Registers a new network without specifying details.
     function registerNetwork(bytes32 hotkey) external payable;
     /// Registers a new network with specified subnet name, GitHub repository, and contact information.
@@ -177,4 +193,19 @@ interface ISubnet {
         uint16 netuid,
         uint64 commitRevealWeightsInterval
     ) external payable;
+
+    /// Returns the value of a hyperparameter by its registry id.
+    /// Booleans are returned as 0 or 1.
+    function getHyperparameter(
+        uint16 netuid,
+        uint16 id
+    ) external view returns (uint64);
+
+    /// Sets a hyperparameter by its registry id, enforcing the registry bounds.
+    /// Booleans are passed as 0 or 1.
+    function setHyperparameter(
+        uint16 netuid,
+        uint16 id,
+        uint64 value
+    ) external payable;
 }
@@ -0,0 +1,122 @@
+pragma solidity ^0.8.0;
+
+address constant ISUBNET_OWNER_ADDRESS = 0x0000000000000000000000000000000000000809;
+
+// Owner operations of a subnet. Calls are checked against the substrate account mapped from
+// `msg.sender`, exactly as for a substrate signer. Hyperparameters are set through ISubnet.
+interface ISubnetOwner {
+    event CallStarted(uint16 indexed netuid, uint64 firstEmissionBlock);
+    event SubnetIdentitySet(uint16 indexed netuid);
+    event SubnetOwnerHotkeySet(uint16 indexed netuid, bytes32 indexed hotkey);
+    event SubnetOwnershipOffered(
+        uint16 indexed netuid,
+        bytes32 indexed newOwner
+    );
+    event SubnetOwnershipOfferCancelled(uint16 indexed netuid);
+    event SubnetOwnershipAccepted(
+        uint16 indexed netuid,
+        address indexed newOwner
+    );
+    event SubnetListedForSale(
+        uint16 indexed netuid,
+        uint64 price,
+        uint64 deadline
+    );
+    event SubnetListingCancelled(uint16 indexed netuid);
+    event ChildrenSet(bytes32 indexed hotkey, uint16 indexed netuid);
+    event ChildkeyTakeSet(
+        bytes32 indexed hotkey,
+        uint16 indexed netuid,
+        uint16 take
+    );
+    event HyperparameterAnnouncementDelaySet(
+        uint16 indexed netuid,
+        uint32 delay
+    );
+    event HyperparameterChangeAnnounced(
+        uint16 indexed netuid,
+        uint16 indexed id,
+        uint32 changeId,
+        uint64 value
+    );
+
+    function getSubnetOwner(uint16 netuid) external view returns (bytes32);
+
+    function getSubnetOwnerHotkey(
+        uint16 netuid
+    ) external view returns (bytes32);
+
+    /// Starts the emissions of the subnet.
+    function startCall(uint16 netuid) external payable;
+
+    function setSubnetIdentity(
+        uint16 netuid,
+        string memory subnetName,
+        string memory githubRepo,
+        string memory subnetContact,
+        string memory subnetUrl,
+        string memory discord,
+        string memory description,
+        string memory additional
+    ) external payable;
+
+    function setSubnetOwnerHotkey(
+        uint16 netuid,
+        bytes32 hotkey
+    ) external payable;
+
+    /// Offers the subnet to `newOwner`, who must accept it with `acceptSubnetOwnership`.
+    function offerSubnetOwnership(
+        uint16 netuid,
+        bytes32 newOwner
+    ) external payable;
+
+    /// Offers the subnet to the multisig account of `coOwners` with `threshold`.
+    function offerSubnetOwnershipToCoOwners(
+        uint16 netuid,
+        bytes32[] memory coOwners,
+        uint16 threshold
+    ) external payable;
+
+    function cancelSubnetOwnershipOffer(uint16 netuid) external payable;
+
+    /// Accepts an ownership offer, `hotkey` becomes the subnet owner hotkey.
+    function acceptSubnetOwnership(uint16 netuid, bytes32 hotkey) external payable;
+
+    /// Lists the subnet for sale at `price` RAO. A `deadline` of zero means no deadline.
+    function listSubnetForSale(
+        uint16 netuid,
+        uint64 price,
+        uint64 deadline
+    ) external payable;
+
+    function cancelSubnetListing(uint16 netuid) external payable;
+
+    /// Schedules the children of a hotkey owned by the caller. `proportions` are u64 normalized.
+    function setChildren(
+        bytes32 hotkey,
+        uint16 netuid,
+        bytes32[] memory children,
+        uint64[] memory proportions
+    ) external payable;
+
+    function setChildkeyTake(
+        bytes32 hotkey,
+        uint16 netuid,
+        uint16 take
+    ) external payable;
+
+    /// Sets the number of blocks an announced hyperparameter change waits before it is applied.
+    /// The delay can be raised right away, lowering it must be announced like other changes.
+    function setHyperparameterAnnouncementDelay(
+        uint16 netuid,
+        uint32 delay
+    ) external payable;
+
+    /// Announces a `setHyperparameter` change and returns the id of the announced change.
+    function announceHyperparameterChange(
+        uint16 netuid,
+        uint16 id,
+        uint64 value
+    ) external payable returns (uint32);
+}
@@ -33,13 +33,35 @@ use pallet_evm::{
     AddressMapping, BalanceConverter, EvmBalance, ExitError, PrecompileFailure, PrecompileHandle,
     SubstrateBalance,
 };
-use precompile_utils::EvmResult;
+use precompile_utils::prelude::keccak256;
+use precompile_utils::{EvmResult, solidity, solidity::Codec};
 use sp_core::{H256, U256};
 use sp_runtime::traits::{Dispatchable, StaticLookup, UniqueSaturatedInto};
 use sp_std::vec;
+use substrate_fixed::types::U96F32;
 use subtensor_runtime_common::ProxyType;
 
-use crate::{PrecompileExt, PrecompileHandleExt};
+use crate::{PrecompileExt, PrecompileHandleExt, netuid_topic};
+
+const SELECTOR_LOG_STAKE_ADDED: [u8; 32] =
+    keccak256!("StakeAdded(address,bytes32,uint16,uint256,uint256)");
+const SELECTOR_LOG_STAKE_REMOVED: [u8; 32] =
+    keccak256!("StakeRemoved(address,bytes32,uint16,uint256,uint256)");
+const SELECTOR_LOG_STAKE_MOVED: [u8; 32] =
+    keccak256!("StakeMoved(address,bytes32,bytes32,uint16,uint16,uint256)");
+const SELECTOR_LOG_STAKE_TRANSFERRED: [u8; 32] =
+    keccak256!("StakeTransferred(address,bytes32,bytes32,uint16,uint16,uint256)");
+const SELECTOR_LOG_STAKE_SWAPPED: [u8; 32] =
+    keccak256!("StakeSwapped(address,bytes32,uint16,uint16,uint256)");
+const SELECTOR_LOG_ALL_STAKE_REMOVED: [u8; 32] =
+    keccak256!("AllStakeRemoved(address,bytes32,uint256)");
+const SELECTOR_LOG_ALL_ALPHA_UNSTAKED: [u8; 32] = keccak256!("AllAlphaUnstaked(address,bytes32)");
+const SELECTOR_LOG_ALPHA_RECYCLED: [u8; 32] =
+    keccak256!("AlphaRecycled(address,bytes32,uint16,uint256)");
+const SELECTOR_LOG_ALPHA_BURNED: [u8; 32] =
+    keccak256!("AlphaBurned(address,bytes32,uint16,uint256)");
+const SELECTOR_LOG_PROXY_ADDED: [u8; 32] = keccak256!("ProxyAdded(address,bytes32)");
+const SELECTOR_LOG_PROXY_REMOVED: [u8; 32] = keccak256!("ProxyRemoved(address,bytes32)");
 
 // Old StakingPrecompile had ETH-precision in values, which was not alligned with Substrate API. So
 // it's kinda deprecated, but exists for backward compatibility. Eventually, we should remove it
@@ -92,13 +114,17 @@ where
         let amount_staked = amount_rao.unique_saturated_into();
         let hotkey = R::AccountId::from(address.0);
         let netuid = try_u16_from_u256(netuid)?;
+        let before = stake_snapshot::<R>(&hotkey, &account_id, netuid);
         let call = pallet_subtensor::Call::<R>::add_stake {
-            hotkey,
+            hotkey: hotkey.clone(),
             netuid,
             amount_staked,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let after = stake_snapshot::<R>(&hotkey, &account_id, netuid);
+        record_stake_added(handle, address, netuid, before, after)
     }
 
     #[precompile::public("removeStake(bytes32,uint256,uint256)")]
@@ -112,13 +138,17 @@ where
         let hotkey = R::AccountId::from(address.0);
         let netuid = try_u16_from_u256(netuid)?;
         let amount_unstaked = amount_alpha.unique_saturated_into();
+        let before = stake_snapshot::<R>(&hotkey, &account_id, netuid);
         let call = pallet_subtensor::Call::<R>::remove_stake {
-            hotkey,
+            hotkey: hotkey.clone(),
             netuid,
             amount_unstaked,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let after = stake_snapshot::<R>(&hotkey, &account_id, netuid);
+        record_stake_removed(handle, address, netuid, before, after)
     }
 
     #[precompile::public("moveStake(bytes32,bytes32,uint256,uint256,uint256)")]
@@ -131,20 +161,34 @@ where
         amount_alpha: U256,
     ) -> EvmResult<()> {
         let account_id = handle.caller_account_id::<R>();
-        let origin_hotkey = R::AccountId::from(origin_hotkey.0);
-        let destination_hotkey = R::AccountId::from(destination_hotkey.0);
+        let origin_hotkey_id = R::AccountId::from(origin_hotkey.0);
         let origin_netuid = try_u16_from_u256(origin_netuid)?;
         let destination_netuid = try_u16_from_u256(destination_netuid)?;
         let alpha_amount = amount_alpha.unique_saturated_into();
+        let (_, alpha_before) = stake_snapshot::<R>(&origin_hotkey_id, &account_id, origin_netuid);
         let call = pallet_subtensor::Call::<R>::move_stake {
-            origin_hotkey,
-            destination_hotkey,
+            origin_hotkey: origin_hotkey_id.clone(),
+            destination_hotkey: R::AccountId::from(destination_hotkey.0),
             origin_netuid,
             destination_netuid,
             alpha_amount,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let (_, alpha_after) = stake_snapshot::<R>(&origin_hotkey_id, &account_id, origin_netuid);
+        let topics = vec![
+            H256(SELECTOR_LOG_STAKE_MOVED),
+            handle.context().caller.into(),
+            origin_hotkey,
+            destination_hotkey,
+        ];
+        let data = solidity::encode_event_data((
+            origin_netuid,
+            destination_netuid,
+            U256::from(alpha_before.saturating_sub(alpha_after)),
+        ));
+        handle.record_event(topics, data)
     }
 
     #[precompile::public("transferStake(bytes32,bytes32,uint256,uint256,uint256)")]
@@ -157,20 +201,34 @@ where
         amount_alpha: U256,
     ) -> EvmResult<()> {
         let account_id = handle.caller_account_id::<R>();
-        let destination_coldkey = R::AccountId::from(destination_coldkey.0);
-        let hotkey = R::AccountId::from(hotkey.0);
+        let hotkey_id = R::AccountId::from(hotkey.0);
         let origin_netuid = try_u16_from_u256(origin_netuid)?;
         let destination_netuid = try_u16_from_u256(destination_netuid)?;
         let alpha_amount = amount_alpha.unique_saturated_into();
+        let (_, alpha_before) = stake_snapshot::<R>(&hotkey_id, &account_id, origin_netuid);
         let call = pallet_subtensor::Call::<R>::transfer_stake {
-            destination_coldkey,
-            hotkey,
+            destination_coldkey: R::AccountId::from(destination_coldkey.0),
+            hotkey: hotkey_id.clone(),
             origin_netuid,
             destination_netuid,
             alpha_amount,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let (_, alpha_after) = stake_snapshot::<R>(&hotkey_id, &account_id, origin_netuid);
+        let topics = vec![
+            H256(SELECTOR_LOG_STAKE_TRANSFERRED),
+            handle.context().caller.into(),
+            destination_coldkey,
+            hotkey,
+        ];
+        let data = solidity::encode_event_data((
+            origin_netuid,
+            destination_netuid,
+            U256::from(alpha_before.saturating_sub(alpha_after)),
+        ));
+        handle.record_event(topics, data)
     }
 
     #[precompile::public("getTotalColdkeyStake(bytes32)")]
@@ -253,29 +311,31 @@ where
     #[precompile::public("addProxy(bytes32)")]
     fn add_proxy(handle: &mut impl PrecompileHandle, delegate: H256) -> EvmResult<()> {
         let account_id = handle.caller_account_id::<R>();
-        let delegate = R::AccountId::from(delegate.0);
-        let delegate = <R as frame_system::Config>::Lookup::unlookup(delegate);
+        let delegate_id = R::AccountId::from(delegate.0);
         let call = pallet_proxy::Call::<R>::add_proxy {
-            delegate,
+            delegate: <R as frame_system::Config>::Lookup::unlookup(delegate_id),
             proxy_type: ProxyType::Staking,
             delay: 0u32.into(),
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+
+        record_proxy_event(handle, SELECTOR_LOG_PROXY_ADDED, delegate)
     }
 
     #[precompile::public("removeProxy(bytes32)")]
     fn remove_proxy(handle: &mut impl PrecompileHandle, delegate: H256) -> EvmResult<()> {
         let account_id = handle.caller_account_id::<R>();
-        let delegate = R::AccountId::from(delegate.0);
-        let delegate = <R as frame_system::Config>::Lookup::unlookup(delegate);
+        let delegate_id = R::AccountId::from(delegate.0);
         let call = pallet_proxy::Call::<R>::remove_proxy {
-            delegate,
+            delegate: <R as frame_system::Config>::Lookup::unlookup(delegate_id),
             proxy_type: ProxyType::Staking,
             delay: 0u32.into(),
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+
+        record_proxy_event(handle, SELECTOR_LOG_PROXY_REMOVED, delegate)
     }
 
     #[precompile::public("addStakeLimit(bytes32,uint256,uint256,bool,uint256)")]
@@ -292,15 +352,19 @@ where
         let limit_price = limit_price_rao.unique_saturated_into();
         let hotkey = R::AccountId::from(address.0);
         let netuid = try_u16_from_u256(netuid)?;
+        let before = stake_snapshot::<R>(&hotkey, &account_id, netuid);
         let call = pallet_subtensor::Call::<R>::add_stake_limit {
-            hotkey,
+            hotkey: hotkey.clone(),
             netuid,
             amount_staked,
             limit_price,
             allow_partial,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let after = stake_snapshot::<R>(&hotkey, &account_id, netuid);
+        record_stake_added(handle, address, netuid, before, after)
     }
 
     #[precompile::public("removeStakeLimit(bytes32,uint256,uint256,bool,uint256)")]
@@ -317,16 +381,363 @@ where
         let netuid = try_u16_from_u256(netuid)?;
         let amount_unstaked = amount_alpha.unique_saturated_into();
         let limit_price = limit_price_rao.unique_saturated_into();
+        let before = stake_snapshot::<R>(&hotkey, &account_id, netuid);
         let call = pallet_subtensor::Call::<R>::remove_stake_limit {
-            hotkey,
+            hotkey: hotkey.clone(),
             netuid,
             amount_unstaked,
             limit_price,
             allow_partial,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let after = stake_snapshot::<R>(&hotkey, &account_id, netuid);
+        record_stake_removed(handle, address, netuid, before, after)
+    }
+
+    #[precompile::public("swapStake(bytes32,uint256,uint256,uint256)")]
+    fn swap_stake(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        origin_netuid: U256,
+        destination_netuid: U256,
+        amount_alpha: U256,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey_id = R::AccountId::from(hotkey.0);
+        let origin_netuid = try_u16_from_u256(origin_netuid)?;
+        let destination_netuid = try_u16_from_u256(destination_netuid)?;
+        let alpha_amount = amount_alpha.unique_saturated_into();
+        let (_, alpha_before) = stake_snapshot::<R>(&hotkey_id, &account_id, origin_netuid);
+        let call = pallet_subtensor::Call::<R>::swap_stake {
+            hotkey: hotkey_id.clone(),
+            origin_netuid,
+            destination_netuid,
+            alpha_amount,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let (_, alpha_after) = stake_snapshot::<R>(&hotkey_id, &account_id, origin_netuid);
+        record_stake_swapped(
+            handle,
+            hotkey,
+            origin_netuid,
+            destination_netuid,
+            alpha_before.saturating_sub(alpha_after),
+        )
+    }
+
+    #[precompile::public("swapStakeLimit(bytes32,uint256,uint256,uint256,uint256,bool)")]
+    fn swap_stake_limit(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        origin_netuid: U256,
+        destination_netuid: U256,
+        amount_alpha: U256,
+        limit_price_rao: U256,
+        allow_partial: bool,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey_id = R::AccountId::from(hotkey.0);
+        let origin_netuid = try_u16_from_u256(origin_netuid)?;
+        let destination_netuid = try_u16_from_u256(destination_netuid)?;
+        let alpha_amount = amount_alpha.unique_saturated_into();
+        let limit_price = limit_price_rao.unique_saturated_into();
+        let (_, alpha_before) = stake_snapshot::<R>(&hotkey_id, &account_id, origin_netuid);
+        let call = pallet_subtensor::Call::<R>::swap_stake_limit {
+            hotkey: hotkey_id.clone(),
+            origin_netuid,
+            destination_netuid,
+            alpha_amount,
+            limit_price,
+            allow_partial,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let (_, alpha_after) = stake_snapshot::<R>(&hotkey_id, &account_id, origin_netuid);
+        record_stake_swapped(
+            handle,
+            hotkey,
+            origin_netuid,
+            destination_netuid,
+            alpha_before.saturating_sub(alpha_after),
+        )
+    }
+
+    #[precompile::public("unstakeAll(bytes32)")]
+    fn unstake_all(handle: &mut impl PrecompileHandle, hotkey: H256) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let balance_before = pallet_subtensor::Pallet::<R>::get_coldkey_balance(&account_id);
+        let call = pallet_subtensor::Call::<R>::unstake_all {
+            hotkey: R::AccountId::from(hotkey.0),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let balance_after = pallet_subtensor::Pallet::<R>::get_coldkey_balance(&account_id);
+        let topics = vec![
+            H256(SELECTOR_LOG_ALL_STAKE_REMOVED),
+            handle.context().caller.into(),
+            hotkey,
+        ];
+        let data =
+            solidity::encode_event_data(U256::from(balance_after.saturating_sub(balance_before)));
+        handle.record_event(topics, data)
+    }
+
+    #[precompile::public("unstakeAllAlpha(bytes32)")]
+    fn unstake_all_alpha(handle: &mut impl PrecompileHandle, hotkey: H256) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let call = pallet_subtensor::Call::<R>::unstake_all_alpha {
+            hotkey: R::AccountId::from(hotkey.0),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_ALL_ALPHA_UNSTAKED),
+            handle.context().caller.into(),
+            hotkey,
+        ];
+        handle.record_event(topics, Vec::new())
+    }
+
+    #[precompile::public("recycleAlpha(bytes32,uint256,uint256)")]
+    fn recycle_alpha(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        amount_alpha: U256,
+        netuid: U256,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey_id = R::AccountId::from(hotkey.0);
+        let amount = amount_alpha.unique_saturated_into();
+        let netuid = try_u16_from_u256(netuid)?;
+        let (_, alpha_before) = stake_snapshot::<R>(&hotkey_id, &account_id, netuid);
+        let call = pallet_subtensor::Call::<R>::recycle_alpha {
+            hotkey: hotkey_id.clone(),
+            amount,
+            netuid,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let (_, alpha_after) = stake_snapshot::<R>(&hotkey_id, &account_id, netuid);
+        let topics = vec![
+            H256(SELECTOR_LOG_ALPHA_RECYCLED),
+            handle.context().caller.into(),
+            hotkey,
+            netuid_topic(netuid),
+        ];
+        let data =
+            solidity::encode_event_data(U256::from(alpha_before.saturating_sub(alpha_after)));
+        handle.record_event(topics, data)
+    }
+
+    #[precompile::public("burnAlpha(bytes32,uint256,uint256)")]
+    fn burn_alpha(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        amount_alpha: U256,
+        netuid: U256,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey_id = R::AccountId::from(hotkey.0);
+        let amount = amount_alpha.unique_saturated_into();
+        let netuid = try_u16_from_u256(netuid)?;
+        let (_, alpha_before) = stake_snapshot::<R>(&hotkey_id, &account_id, netuid);
+        let call = pallet_subtensor::Call::<R>::burn_alpha {
+            hotkey: hotkey_id.clone(),
+            amount,
+            netuid,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let (_, alpha_after) = stake_snapshot::<R>(&hotkey_id, &account_id, netuid);
+        let topics = vec![
+            H256(SELECTOR_LOG_ALPHA_BURNED),
+            handle.context().caller.into(),
+            hotkey,
+            netuid_topic(netuid),
+        ];
+        let data =
+            solidity::encode_event_data(U256::from(alpha_before.saturating_sub(alpha_after)));
+        handle.record_event(topics, data)
+    }
+
+    #[precompile::public("getAlphaPrice(uint256)")]
+    #[precompile::view]
+    fn get_alpha_price(_handle: &mut impl PrecompileHandle, netuid: U256) -> EvmResult<U256> {
+        let netuid = try_u16_from_u256(netuid)?;
+        let price = pallet_subtensor::Pallet::<R>::get_alpha_price(netuid);
+
+        Ok(price_to_rao(price).into())
+    }
+
+    #[precompile::public("getMovingAlphaPrice(uint256)")]
+    #[precompile::view]
+    fn get_moving_alpha_price(
+        _handle: &mut impl PrecompileHandle,
+        netuid: U256,
+    ) -> EvmResult<U256> {
+        let netuid = try_u16_from_u256(netuid)?;
+        let price = pallet_subtensor::Pallet::<R>::get_moving_alpha_price(netuid);
+
+        Ok(price_to_rao(price).into())
+    }
+
+    #[precompile::public("simSwapTaoForAlpha(uint256,uint256)")]
+    #[precompile::view]
+    fn sim_swap_tao_for_alpha(
+        _handle: &mut impl PrecompileHandle,
+        netuid: U256,
+        amount_rao: U256,
+    ) -> EvmResult<U256> {
+        let netuid = try_u16_from_u256(netuid)?;
+        let alpha = pallet_subtensor::Pallet::<R>::sim_swap_tao_for_alpha(
+            netuid,
+            amount_rao.unique_saturated_into(),
+        )
+        .unwrap_or_default();
+
+        Ok(alpha.into())
+    }
+
+    #[precompile::public("simSwapAlphaForTao(uint256,uint256)")]
+    #[precompile::view]
+    fn sim_swap_alpha_for_tao(
+        _handle: &mut impl PrecompileHandle,
+        netuid: U256,
+        amount_alpha: U256,
+    ) -> EvmResult<U256> {
+        let netuid = try_u16_from_u256(netuid)?;
+        let tao = pallet_subtensor::Pallet::<R>::sim_swap_alpha_for_tao(
+            netuid,
+            amount_alpha.unique_saturated_into(),
+        )
+        .unwrap_or_default();
+
+        Ok(tao.into())
+    }
+
+    #[precompile::public("getAddStakeFee(bytes32,uint256,uint256)")]
+    #[precompile::view]
+    fn get_add_stake_fee(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: U256,
+        amount_rao: U256,
+    ) -> EvmResult<U256> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(hotkey.0);
+        let netuid = try_u16_from_u256(netuid)?;
+        let fee = pallet_subtensor::Pallet::<R>::get_stake_fee(
+            None,
+            account_id.clone(),
+            Some((hotkey, netuid)),
+            account_id,
+            amount_rao.unique_saturated_into(),
+        );
+
+        Ok(fee.into())
     }
+
+    #[precompile::public("getRemoveStakeFee(bytes32,uint256,uint256)")]
+    #[precompile::view]
+    fn get_remove_stake_fee(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: U256,
+        amount_alpha: U256,
+    ) -> EvmResult<U256> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(hotkey.0);
+        let netuid = try_u16_from_u256(netuid)?;
+        let fee = pallet_subtensor::Pallet::<R>::get_stake_fee(
+            Some((hotkey, netuid)),
+            account_id.clone(),
+            None,
+            account_id,
+            amount_alpha.unique_saturated_into(),
+        );
+
+        Ok(fee.into())
+    }
+
+    #[precompile::public("getMoveStakeFee(bytes32,bytes32,uint256,uint256,uint256)")]
+    #[precompile::view]
+    fn get_move_stake_fee(
+        handle: &mut impl PrecompileHandle,
+        origin_hotkey: H256,
+        destination_hotkey: H256,
+        origin_netuid: U256,
+        destination_netuid: U256,
+        amount_alpha: U256,
+    ) -> EvmResult<U256> {
+        let account_id = handle.caller_account_id::<R>();
+        let origin_hotkey = R::AccountId::from(origin_hotkey.0);
+        let destination_hotkey = R::AccountId::from(destination_hotkey.0);
+        let origin_netuid = try_u16_from_u256(origin_netuid)?;
+        let destination_netuid = try_u16_from_u256(destination_netuid)?;
+        let fee = pallet_subtensor::Pallet::<R>::get_stake_fee(
+            Some((origin_hotkey, origin_netuid)),
+            account_id.clone(),
+            Some((destination_hotkey, destination_netuid)),
+            account_id,
+            amount_alpha.unique_saturated_into(),
+        );
+
+        Ok(fee.into())
+    }
+
+    #[precompile::public("getStakeInfoForColdkey(bytes32)")]
+    #[precompile::view]
+    fn get_stake_info_for_coldkey(
+        _handle: &mut impl PrecompileHandle,
+        coldkey: H256,
+    ) -> EvmResult<Vec<StakeInfo>> {
+        let coldkey = R::AccountId::from(coldkey.0);
+        let stake_info = pallet_subtensor::Pallet::<R>::get_stake_info_for_coldkey(coldkey)
+            .into_iter()
+            .map(|info| StakeInfo {
+                hotkey: H256::from(info.hotkey.into()),
+                coldkey: H256::from(info.coldkey.into()),
+                netuid: info.netuid.0,
+                stake: info.stake.0,
+                locked: info.locked.0,
+                emission: info.emission.0,
+                tao_emission: info.tao_emission.0,
+                drain: info.drain.0,
+                is_registered: info.is_registered,
+            })
+            .collect();
+
+        Ok(stake_info)
+    }
+}
+
+#[derive(Codec)]
+struct StakeInfo {
+    hotkey: H256,
+    coldkey: H256,
+    netuid: u16,
+    stake: u64,
+    locked: u64,
+    emission: u64,
+    tao_emission: u64,
+    drain: u64,
+    is_registered: bool,
+}
+
+/// Converts a price of alpha in TAO into rao per alpha, the unit of the limit prices.
+fn price_to_rao(price: U96F32) -> u64 {
+    price
+        .saturating_mul(U96F32::saturating_from_num(1_000_000_000))
+        .saturating_to_num::<u64>()
 }
 
 // Deprecated, exists for backward compatibility.
@@ -383,13 +794,17 @@ where
         let amount_sub = handle.try_convert_apparent_value::<R>()?;
         let hotkey = R::AccountId::from(address.0);
         let netuid = try_u16_from_u256(netuid)?;
+        let before = stake_snapshot::<R>(&hotkey, &account_id, netuid);
         let call = pallet_subtensor::Call::<R>::add_stake {
-            hotkey,
+            hotkey: hotkey.clone(),
             netuid,
             amount_staked: amount_sub.unique_saturated_into(),
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let after = stake_snapshot::<R>(&hotkey, &account_id, netuid);
+        record_stake_added(handle, address, netuid, before, after)
     }
 
     #[precompile::public("removeStake(bytes32,uint256,uint256)")]
@@ -407,13 +822,17 @@ where
             <R as pallet_evm::Config>::BalanceConverter::into_substrate_balance(amount)
                 .map(|amount| amount.into_u64_saturating())
                 .ok_or(ExitError::OutOfFund)?;
+        let before = stake_snapshot::<R>(&hotkey, &account_id, netuid);
         let call = pallet_subtensor::Call::<R>::remove_stake {
-            hotkey,
+            hotkey: hotkey.clone(),
             netuid,
             amount_unstaked,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let after = stake_snapshot::<R>(&hotkey, &account_id, netuid);
+        record_stake_removed(handle, address, netuid, before, after)
     }
 
     #[precompile::public("getTotalColdkeyStake(bytes32)")]
@@ -479,29 +898,31 @@ where
     #[precompile::public("addProxy(bytes32)")]
     fn add_proxy(handle: &mut impl PrecompileHandle, delegate: H256) -> EvmResult<()> {
         let account_id = handle.caller_account_id::<R>();
-        let delegate = R::AccountId::from(delegate.0);
-        let delegate = <R as frame_system::Config>::Lookup::unlookup(delegate);
+        let delegate_id = R::AccountId::from(delegate.0);
         let call = pallet_proxy::Call::<R>::add_proxy {
-            delegate,
+            delegate: <R as frame_system::Config>::Lookup::unlookup(delegate_id),
             proxy_type: ProxyType::Staking,
             delay: 0u32.into(),
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+
+        record_proxy_event(handle, SELECTOR_LOG_PROXY_ADDED, delegate)
     }
 
     #[precompile::public("removeProxy(bytes32)")]
     fn remove_proxy(handle: &mut impl PrecompileHandle, delegate: H256) -> EvmResult<()> {
         let account_id = handle.caller_account_id::<R>();
-        let delegate = R::AccountId::from(delegate.0);
-        let delegate = <R as frame_system::Config>::Lookup::unlookup(delegate);
+        let delegate_id = R::AccountId::from(delegate.0);
         let call = pallet_proxy::Call::<R>::remove_proxy {
-            delegate,
+            delegate: <R as frame_system::Config>::Lookup::unlookup(delegate_id),
             proxy_type: ProxyType::Staking,
             delay: 0u32.into(),
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+
+        record_proxy_event(handle, SELECTOR_LOG_PROXY_REMOVED, delegate)
     }
 
     fn transfer_back_to_caller(
@@ -542,6 +963,86 @@ where
     }
 }
 
+/// Returns the free balance of `coldkey` and its alpha staked to `hotkey` on `netuid`. Taken
+/// around a dispatch, the difference is what the call actually moved, which is what the events
+/// report rather than the requested amounts.
+fn stake_snapshot<R>(hotkey: &R::AccountId, coldkey: &R::AccountId, netuid: u16) -> (u64, u64)
+where
+    R: pallet_subtensor::Config,
+{
+    (
+        pallet_subtensor::Pallet::<R>::get_coldkey_balance(coldkey),
+        pallet_subtensor::Pallet::<R>::get_stake_for_hotkey_and_coldkey_on_subnet(
+            hotkey, coldkey, netuid,
+        ),
+    )
+}
+
+fn record_stake_added(
+    handle: &mut impl PrecompileHandle,
+    hotkey: H256,
+    netuid: u16,
+    (balance_before, alpha_before): (u64, u64),
+    (balance_after, alpha_after): (u64, u64),
+) -> EvmResult<()> {
+    let topics = vec![
+        H256(SELECTOR_LOG_STAKE_ADDED),
+        handle.context().caller.into(),
+        hotkey,
+        netuid_topic(netuid),
+    ];
+    let data = solidity::encode_event_data((
+        U256::from(balance_before.saturating_sub(balance_after)),
+        U256::from(alpha_after.saturating_sub(alpha_before)),
+    ));
+    handle.record_event(topics, data)
+}
+
+fn record_stake_removed(
+    handle: &mut impl PrecompileHandle,
+    hotkey: H256,
+    netuid: u16,
+    (balance_before, alpha_before): (u64, u64),
+    (balance_after, alpha_after): (u64, u64),
+) -> EvmResult<()> {
+    let topics = vec![
+        H256(SELECTOR_LOG_STAKE_REMOVED),
+        handle.context().caller.into(),
+        hotkey,
+        netuid_topic(netuid),
+    ];
+    let data = solidity::encode_event_data((
+        U256::from(alpha_before.saturating_sub(alpha_after)),
+        U256::from(balance_after.saturating_sub(balance_before)),
+    ));
+    handle.record_event(topics, data)
+}
+
+fn record_stake_swapped(
+    handle: &mut impl PrecompileHandle,
+    hotkey: H256,
+    origin_netuid: u16,
+    destination_netuid: u16,
+    alpha: u64,
+) -> EvmResult<()> {
+    let topics = vec![
+        H256(SELECTOR_LOG_STAKE_SWAPPED),
+        handle.context().caller.into(),
+        hotkey,
+    ];
+    let data = solidity::encode_event_data((origin_netuid, destination_netuid, U256::from(alpha)));
+    handle.record_event(topics, data)
+}
+
+fn record_proxy_event(
+    handle: &mut impl PrecompileHandle,
+    selector: [u8; 32],
+    delegate: H256,
+) -> EvmResult<()> {
+    let topics = vec![H256(selector), handle.context().caller.into(), delegate];
+    handle.record_event(topics, Vec::new())
+}
+
 fn try_u16_from_u256(value: U256) -> Result<u16, PrecompileFailure> {
     value.try_into().map_err(|_| PrecompileFailure::Error {
         exit_status: ExitError::Other("the value is outside of u16 bounds".into()),
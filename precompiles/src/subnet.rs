@@ -3,12 +3,22 @@ use core::marker::PhantomData;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use frame_support::traits::ConstU32;
 use frame_system::RawOrigin;
-use pallet_evm::{AddressMapping, PrecompileHandle};
-use precompile_utils::{EvmResult, prelude::BoundedString};
+use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
+use pallet_subtensor::utils::hyperparameters::HyperparameterId;
+use precompile_utils::prelude::{BoundedString, keccak256};
+use precompile_utils::{EvmResult, solidity};
 use sp_core::H256;
 use sp_runtime::traits::Dispatchable;
+use sp_std::vec;
+use sp_std::vec::Vec;
 
-use crate::{PrecompileExt, PrecompileHandleExt};
+use crate::{PrecompileExt, PrecompileHandleExt, netuid_topic};
+
+const SELECTOR_LOG_NETWORK_REGISTERED: [u8; 32] =
+    keccak256!("NetworkRegistered(uint16,bytes32,address)");
+const SELECTOR_LOG_HYPERPARAMETER_SET: [u8; 32] =
+    keccak256!("HyperparameterSet(uint16,uint16,uint64)");
+const SELECTOR_LOG_ALPHA_VALUES_SET: [u8; 32] = keccak256!("AlphaValuesSet(uint16,uint16,uint16)");
 
 pub struct SubnetPrecompile<R>(PhantomData<R>);
 
@@ -45,16 +55,24 @@ where
     #[precompile::public("registerNetwork(bytes32)")]
     #[precompile::payable]
     fn register_network(handle: &mut impl PrecompileHandle, hotkey: H256) -> EvmResult<()> {
-        let hotkey = R::AccountId::from(hotkey.0);
+        let netuid = pallet_subtensor::Pallet::<R>::get_next_netuid();
         let call = pallet_subtensor::Call::<R>::register_network_with_identity {
-            hotkey,
+            hotkey: R::AccountId::from(hotkey.0),
             identity: None,
         };
 
         handle.try_dispatch_runtime_call::<R, _>(
             call,
             RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        )?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_NETWORK_REGISTERED),
+            netuid_topic(netuid),
+            hotkey,
+            handle.context().caller.into(),
+        ];
+        handle.record_event(topics, Vec::new())
     }
 
     #[precompile::public(
@@ -73,7 +91,7 @@ where
         description: BoundedString<ConstU32<1024>>,
         additional: BoundedString<ConstU32<1024>>,
     ) -> EvmResult<()> {
-        let hotkey = R::AccountId::from(hotkey.0);
+        let netuid = pallet_subtensor::Pallet::<R>::get_next_netuid();
         let identity = pallet_subtensor::SubnetIdentityOfV2 {
             subnet_name: subnet_name.into(),
             github_repo: github_repo.into(),
@@ -85,14 +103,22 @@ where
         };
 
         let call = pallet_subtensor::Call::<R>::register_network_with_identity {
-            hotkey,
+            hotkey: R::AccountId::from(hotkey.0),
             identity: Some(identity),
         };
 
         handle.try_dispatch_runtime_call::<R, _>(
             call,
             RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        )?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_NETWORK_REGISTERED),
+            netuid_topic(netuid),
+            hotkey,
+            handle.context().caller.into(),
+        ];
+        handle.record_event(topics, Vec::new())
     }
 
     #[precompile::public("getServingRateLimit(uint16)")]
@@ -113,10 +139,7 @@ where
             serving_rate_limit,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::ServingRateLimit)
     }
 
     #[precompile::public("getMinDifficulty(uint16)")]
@@ -137,10 +160,7 @@ where
             min_difficulty,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::MinDifficulty)
     }
 
     #[precompile::public("getMaxDifficulty(uint16)")]
@@ -161,10 +181,7 @@ where
             max_difficulty,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::MaxDifficulty)
     }
 
     #[precompile::public("getWeightsVersionKey(uint16)")]
@@ -185,10 +202,7 @@ where
             weights_version_key,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::WeightsVersionKey)
     }
 
     #[precompile::public("getWeightsSetRateLimit(uint16)")]
@@ -226,10 +240,7 @@ where
             adjustment_alpha,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::AdjustmentAlpha)
     }
 
     #[precompile::public("getMaxWeightLimit(uint16)")]
@@ -250,10 +261,7 @@ where
             max_weight_limit,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::MaxWeightsLimit)
     }
 
     #[precompile::public("getImmunityPeriod(uint16)")]
@@ -274,10 +282,7 @@ where
             immunity_period,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::ImmunityPeriod)
     }
 
     #[precompile::public("getMinAllowedWeights(uint16)")]
@@ -298,10 +303,7 @@ where
             min_allowed_weights,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::MinAllowedWeights)
     }
 
     #[precompile::public("getKappa(uint16)")]
@@ -315,10 +317,7 @@ where
     fn set_kappa(handle: &mut impl PrecompileHandle, netuid: u16, kappa: u16) -> EvmResult<()> {
         let call = pallet_admin_utils::Call::<R>::sudo_set_kappa { netuid, kappa };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::Kappa)
     }
 
     #[precompile::public("getRho(uint16)")]
@@ -338,10 +337,7 @@ where
     fn set_rho(handle: &mut impl PrecompileHandle, netuid: u16, rho: u16) -> EvmResult<()> {
         let call = pallet_admin_utils::Call::<R>::sudo_set_rho { netuid, rho };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::Rho)
     }
 
     #[precompile::public("setAlphaSigmoidSteepness(uint16,uint16)")]
@@ -354,9 +350,11 @@ where
         let call =
             pallet_admin_utils::Call::<R>::sudo_set_alpha_sigmoid_steepness { netuid, steepness };
 
-        handle.try_dispatch_runtime_call::<R, _>(
+        Self::dispatch_hyperparameter(
+            handle,
             call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
+            netuid,
+            HyperparameterId::AlphaSigmoidSteepness,
         )
     }
 
@@ -378,10 +376,7 @@ where
             activity_cutoff,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::ActivityCutoff)
     }
 
     #[precompile::public("getNetworkRegistrationAllowed(uint16)")]
@@ -407,10 +402,7 @@ where
             registration_allowed,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::RegistrationAllowed)
     }
 
     #[precompile::public("getNetworkPowRegistrationAllowed(uint16)")]
@@ -436,9 +428,11 @@ where
             registration_allowed,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
+        Self::dispatch_hyperparameter(
+            handle,
             call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
+            netuid,
+            HyperparameterId::PowRegistrationAllowed,
         )
     }
 
@@ -491,10 +485,7 @@ where
     ) -> EvmResult<()> {
         let call = pallet_admin_utils::Call::<R>::sudo_set_difficulty { netuid, difficulty };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::Difficulty)
     }
 
     #[precompile::public("getBondsMovingAverage(uint16)")]
@@ -515,10 +506,7 @@ where
             bonds_moving_average,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::BondsMovingAverage)
     }
 
     #[precompile::public("getCommitRevealWeightsEnabled(uint16)")]
@@ -544,9 +532,11 @@ where
             enabled,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
+        Self::dispatch_hyperparameter(
+            handle,
             call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
+            netuid,
+            HyperparameterId::CommitRevealWeightsEnabled,
         )
     }
 
@@ -565,10 +555,7 @@ where
     ) -> EvmResult<()> {
         let call = pallet_admin_utils::Call::<R>::sudo_set_liquid_alpha_enabled { netuid, enabled };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::LiquidAlphaEnabled)
     }
 
     #[precompile::public("getYuma3Enabled(uint16)")]
@@ -586,10 +573,7 @@ where
     ) -> EvmResult<()> {
         let call = pallet_admin_utils::Call::<R>::sudo_set_yuma3_enabled { netuid, enabled };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::Yuma3Enabled)
     }
 
     #[precompile::public("getAlphaValues(uint16)")]
@@ -615,7 +599,10 @@ where
         handle.try_dispatch_runtime_call::<R, _>(
             call,
             RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        )?;
+
+        let topics = vec![H256(SELECTOR_LOG_ALPHA_VALUES_SET), netuid_topic(netuid)];
+        handle.record_event(topics, solidity::encode_event_data((alpha_low, alpha_high)))
     }
 
     #[precompile::public("getCommitRevealWeightsInterval(uint16)")]
@@ -639,10 +626,7 @@ where
             interval,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::CommitRevealPeriod)
     }
 
     #[precompile::public("toggleTransfers(uint16,bool)")]
@@ -654,9 +638,59 @@ where
     ) -> EvmResult<()> {
         let call = pallet_admin_utils::Call::<R>::sudo_set_toggle_transfer { netuid, toggle };
 
+        Self::dispatch_hyperparameter(handle, call, netuid, HyperparameterId::TransferEnabled)
+    }
+
+    #[precompile::public("getHyperparameter(uint16,uint16)")]
+    #[precompile::view]
+    fn get_hyperparameter(_: &mut impl PrecompileHandle, netuid: u16, id: u16) -> EvmResult<u64> {
+        let id = Self::parse_hyperparameter_id(id)?;
+        Ok(pallet_subtensor::Pallet::<R>::get_hyperparameter(
+            netuid, id,
+        ))
+    }
+
+    #[precompile::public("setHyperparameter(uint16,uint16,uint64)")]
+    #[precompile::payable]
+    fn set_hyperparameter(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        id: u16,
+        value: u64,
+    ) -> EvmResult<()> {
+        let id = Self::parse_hyperparameter_id(id)?;
+        let call = pallet_admin_utils::Call::<R>::set_hyperparameter { netuid, id, value };
+
+        Self::dispatch_hyperparameter(handle, call, netuid, id)
+    }
+
+    /// Dispatches a hyperparameter setter and logs the value the subnet ends up with.
+    fn dispatch_hyperparameter<Call>(
+        handle: &mut impl PrecompileHandle,
+        call: Call,
+        netuid: u16,
+        id: HyperparameterId,
+    ) -> EvmResult<()>
+    where
+        <R as frame_system::Config>::RuntimeCall: From<Call>,
+    {
         handle.try_dispatch_runtime_call::<R, _>(
             call,
             RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        )?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_HYPERPARAMETER_SET),
+            netuid_topic(netuid),
+            H256::from_low_u64_be(u64::from(u16::from(id))),
+        ];
+        let value = pallet_subtensor::Pallet::<R>::get_hyperparameter(netuid, id);
+        handle.record_event(topics, solidity::encode_event_data(value))
+    }
+
+    pub(crate) fn parse_hyperparameter_id(id: u16) -> EvmResult<HyperparameterId> {
+        HyperparameterId::try_from(id).map_err(|_| PrecompileFailure::Error {
+            exit_status: ExitError::Other("invalid hyperparameter id".into()),
+        })
     }
 }
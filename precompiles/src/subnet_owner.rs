@@ -0,0 +1,385 @@
+use core::marker::PhantomData;
+
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::ConstU32;
+use frame_system::RawOrigin;
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_evm::{AddressMapping, PrecompileHandle};
+use precompile_utils::prelude::{BoundedString, keccak256};
+use precompile_utils::{EvmResult, solidity};
+use sp_core::{H256, crypto::ByteArray};
+use sp_runtime::traits::Dispatchable;
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+use crate::{
+    ChildrenPrecompile, PrecompileExt, PrecompileHandleExt, SubnetPrecompile, netuid_topic,
+};
+
+const SELECTOR_LOG_CALL_STARTED: [u8; 32] = keccak256!("CallStarted(uint16,uint64)");
+const SELECTOR_LOG_SUBNET_IDENTITY_SET: [u8; 32] = keccak256!("SubnetIdentitySet(uint16)");
+const SELECTOR_LOG_SUBNET_OWNER_HOTKEY_SET: [u8; 32] =
+    keccak256!("SubnetOwnerHotkeySet(uint16,bytes32)");
+const SELECTOR_LOG_OWNERSHIP_OFFERED: [u8; 32] =
+    keccak256!("SubnetOwnershipOffered(uint16,bytes32)");
+const SELECTOR_LOG_OWNERSHIP_OFFER_CANCELLED: [u8; 32] =
+    keccak256!("SubnetOwnershipOfferCancelled(uint16)");
+const SELECTOR_LOG_OWNERSHIP_ACCEPTED: [u8; 32] =
+    keccak256!("SubnetOwnershipAccepted(uint16,address)");
+const SELECTOR_LOG_LISTED_FOR_SALE: [u8; 32] =
+    keccak256!("SubnetListedForSale(uint16,uint64,uint64)");
+const SELECTOR_LOG_LISTING_CANCELLED: [u8; 32] = keccak256!("SubnetListingCancelled(uint16)");
+const SELECTOR_LOG_ANNOUNCEMENT_DELAY_SET: [u8; 32] =
+    keccak256!("HyperparameterAnnouncementDelaySet(uint16,uint32)");
+const SELECTOR_LOG_HYPERPARAMETER_CHANGE_ANNOUNCED: [u8; 32] =
+    keccak256!("HyperparameterChangeAnnounced(uint16,uint16,uint32,uint64)");
+
+/// Owner operations of a subnet for owners acting from the EVM, e.g. through a multisig
+/// contract. Every call is dispatched with the H160-mapped account of the caller, so the pallets
+/// apply the same permission checks as for a substrate signer. Hyperparameter setters live in
+/// the subnet precompile, the child-key calls are shared with the children precompile.
+pub struct SubnetOwnerPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for SubnetOwnerPrecompile<R>
+where
+    R: frame_system::Config
+        + pallet_evm::Config
+        + pallet_subtensor::Config
+        + pallet_admin_utils::Config,
+    R::AccountId: From<[u8; 32]> + ByteArray,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + From<pallet_admin_utils::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    const INDEX: u64 = 2057;
+}
+
+#[precompile_utils::precompile]
+impl<R> SubnetOwnerPrecompile<R>
+where
+    R: frame_system::Config
+        + pallet_evm::Config
+        + pallet_subtensor::Config
+        + pallet_admin_utils::Config,
+    R::AccountId: From<[u8; 32]> + ByteArray,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + From<pallet_admin_utils::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    #[precompile::public("getSubnetOwner(uint16)")]
+    #[precompile::view]
+    fn get_subnet_owner(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<H256> {
+        let owner = pallet_subtensor::SubnetOwner::<R>::get(netuid);
+        Ok(H256::from_slice(owner.as_slice()))
+    }
+
+    #[precompile::public("getSubnetOwnerHotkey(uint16)")]
+    #[precompile::view]
+    fn get_subnet_owner_hotkey(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<H256> {
+        let hotkey = pallet_subtensor::SubnetOwnerHotkey::<R>::get(netuid);
+        Ok(H256::from_slice(hotkey.as_slice()))
+    }
+
+    #[precompile::public("startCall(uint16)")]
+    #[precompile::payable]
+    fn start_call(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<()> {
+        let call = pallet_subtensor::Call::<R>::start_call { netuid };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        let first_emission_block =
+            pallet_subtensor::FirstEmissionBlockNumber::<R>::get(netuid).unwrap_or_default();
+        let topics = vec![H256(SELECTOR_LOG_CALL_STARTED), netuid_topic(netuid)];
+        handle.record_event(topics, solidity::encode_event_data(first_emission_block))
+    }
+
+    #[precompile::public(
+        "setSubnetIdentity(uint16,string,string,string,string,string,string,string)"
+    )]
+    #[precompile::payable]
+    #[allow(clippy::too_many_arguments)]
+    fn set_subnet_identity(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        subnet_name: BoundedString<ConstU32<256>>,
+        github_repo: BoundedString<ConstU32<1024>>,
+        subnet_contact: BoundedString<ConstU32<1024>>,
+        subnet_url: BoundedString<ConstU32<1024>>,
+        discord: BoundedString<ConstU32<256>>,
+        description: BoundedString<ConstU32<1024>>,
+        additional: BoundedString<ConstU32<1024>>,
+    ) -> EvmResult<()> {
+        let call = pallet_subtensor::Call::<R>::set_subnet_identity {
+            netuid,
+            subnet_name: subnet_name.into(),
+            github_repo: github_repo.into(),
+            subnet_contact: subnet_contact.into(),
+            subnet_url: subnet_url.into(),
+            discord: discord.into(),
+            description: description.into(),
+            additional: additional.into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        let topics = vec![H256(SELECTOR_LOG_SUBNET_IDENTITY_SET), netuid_topic(netuid)];
+        handle.record_event(topics, Vec::new())
+    }
+
+    #[precompile::public("setSubnetOwnerHotkey(uint16,bytes32)")]
+    #[precompile::payable]
+    fn set_subnet_owner_hotkey(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        hotkey: H256,
+    ) -> EvmResult<()> {
+        let call = pallet_admin_utils::Call::<R>::sudo_set_sn_owner_hotkey {
+            netuid,
+            hotkey: R::AccountId::from(hotkey.0),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_SUBNET_OWNER_HOTKEY_SET),
+            netuid_topic(netuid),
+            hotkey,
+        ];
+        handle.record_event(topics, Vec::new())
+    }
+
+    #[precompile::public("offerSubnetOwnership(uint16,bytes32)")]
+    #[precompile::payable]
+    fn offer_subnet_ownership(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        new_owner: H256,
+    ) -> EvmResult<()> {
+        let call = pallet_subtensor::Call::<R>::offer_subnet_ownership {
+            netuid,
+            new_owner: R::AccountId::from(new_owner.0),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        Self::record_ownership_offered(handle, netuid)
+    }
+
+    #[precompile::public("offerSubnetOwnershipToCoOwners(uint16,bytes32[],uint16)")]
+    #[precompile::payable]
+    fn offer_subnet_ownership_to_co_owners(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        co_owners: Vec<H256>,
+        threshold: u16,
+    ) -> EvmResult<()> {
+        let call = pallet_subtensor::Call::<R>::offer_subnet_ownership_to_co_owners {
+            netuid,
+            co_owners: co_owners
+                .into_iter()
+                .map(|co_owner| R::AccountId::from(co_owner.0))
+                .collect(),
+            threshold,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        Self::record_ownership_offered(handle, netuid)
+    }
+
+    #[precompile::public("cancelSubnetOwnershipOffer(uint16)")]
+    #[precompile::payable]
+    fn cancel_subnet_ownership_offer(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+    ) -> EvmResult<()> {
+        let call = pallet_subtensor::Call::<R>::cancel_subnet_ownership_offer { netuid };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_OWNERSHIP_OFFER_CANCELLED),
+            netuid_topic(netuid),
+        ];
+        handle.record_event(topics, Vec::new())
+    }
+
+    #[precompile::public("acceptSubnetOwnership(uint16,bytes32)")]
+    #[precompile::payable]
+    fn accept_subnet_ownership(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        hotkey: H256,
+    ) -> EvmResult<()> {
+        let call = pallet_subtensor::Call::<R>::accept_subnet_ownership {
+            netuid,
+            hotkey: R::AccountId::from(hotkey.0),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_OWNERSHIP_ACCEPTED),
+            netuid_topic(netuid),
+            handle.context().caller.into(),
+        ];
+        handle.record_event(topics, Vec::new())
+    }
+
+    /// A `deadline` of zero lists the subnet without a deadline.
+    #[precompile::public("listSubnetForSale(uint16,uint64,uint64)")]
+    #[precompile::payable]
+    fn list_subnet_for_sale(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        price: u64,
+        deadline: u64,
+    ) -> EvmResult<()> {
+        let call = pallet_subtensor::Call::<R>::list_subnet_for_sale {
+            netuid,
+            price,
+            deadline: (deadline != 0).then_some(deadline),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        let topics = vec![H256(SELECTOR_LOG_LISTED_FOR_SALE), netuid_topic(netuid)];
+        handle.record_event(topics, solidity::encode_event_data((price, deadline)))
+    }
+
+    #[precompile::public("cancelSubnetListing(uint16)")]
+    #[precompile::payable]
+    fn cancel_subnet_listing(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<()> {
+        let call = pallet_subtensor::Call::<R>::cancel_subnet_listing { netuid };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        let topics = vec![H256(SELECTOR_LOG_LISTING_CANCELLED), netuid_topic(netuid)];
+        handle.record_event(topics, Vec::new())
+    }
+
+    #[precompile::public("setChildren(bytes32,uint16,bytes32[],uint64[])")]
+    #[precompile::payable]
+    fn set_children(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        children: Vec<H256>,
+        proportions: Vec<u64>,
+    ) -> EvmResult<()> {
+        ChildrenPrecompile::<R>::set_children(handle, hotkey, netuid, children, proportions)
+    }
+
+    #[precompile::public("setChildkeyTake(bytes32,uint16,uint16)")]
+    #[precompile::payable]
+    fn set_childkey_take(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        take: u16,
+    ) -> EvmResult<()> {
+        ChildrenPrecompile::<R>::set_childkey_take(handle, hotkey, netuid, take)
+    }
+
+    #[precompile::public("setHyperparameterAnnouncementDelay(uint16,uint32)")]
+    #[precompile::payable]
+    fn set_hyperparameter_announcement_delay(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        delay: u32,
+    ) -> EvmResult<()> {
+        let call = pallet_admin_utils::Call::<R>::sudo_set_hyperparameter_announcement_delay {
+            netuid,
+            delay: BlockNumberFor::<R>::from(delay),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_ANNOUNCEMENT_DELAY_SET),
+            netuid_topic(netuid),
+        ];
+        handle.record_event(topics, solidity::encode_event_data(delay))
+    }
+
+    /// Announces a `setHyperparameter` change, applied once the announcement delay has passed.
+    /// Returns the id of the announced change.
+    #[precompile::public("announceHyperparameterChange(uint16,uint16,uint64)")]
+    #[precompile::payable]
+    fn announce_hyperparameter_change(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        id: u16,
+        value: u64,
+    ) -> EvmResult<u32> {
+        let hyperparameter = SubnetPrecompile::<R>::parse_hyperparameter_id(id)?;
+        let change_id = pallet_admin_utils::NextHyperparameterChangeId::<R>::get();
+        let call = pallet_admin_utils::Call::<R>::announce_hyperparameter_change {
+            netuid,
+            hyperparameter,
+            value,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        let topics = vec![
+            H256(SELECTOR_LOG_HYPERPARAMETER_CHANGE_ANNOUNCED),
+            netuid_topic(netuid),
+            H256::from_low_u64_be(u64::from(id)),
+        ];
+        handle.record_event(topics, solidity::encode_event_data((change_id, value)))?;
+
+        Ok(change_id)
+    }
+
+    /// Logs the account the pending ownership offer of `netuid` was made to.
+    fn record_ownership_offered(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<()> {
+        let new_owner = pallet_subtensor::PendingSubnetOwner::<R>::get(netuid)
+            .map(|offer| H256::from_slice(offer.new_owner.as_slice()))
+            .unwrap_or_default();
+
+        let topics = vec![
+            H256(SELECTOR_LOG_OWNERSHIP_OFFERED),
+            netuid_topic(netuid),
+            new_owner,
+        ];
+        handle.record_event(topics, Vec::new())
+    }
+}
@@ -15,7 +15,7 @@ use codec::{Compact, Decode, Encode};
 use frame_support::traits::{Imbalance, InsideBoth};
 use frame_support::{
     PalletId,
-    dispatch::DispatchResultWithPostInfo,
+    dispatch::{DispatchResultWithPostInfo, GetDispatchInfo},
     genesis_builder_helper::{build_state, get_preset},
     pallet_prelude::Get,
     traits::{
@@ -40,6 +40,7 @@ use pallet_subtensor::rpc_info::{
     stake_info::StakeInfo,
     subnet_info::{SubnetHyperparams, SubnetInfo, SubnetInfov2},
 };
+use pallet_subtensor::utils::hyperparameters::HyperparameterInfo;
 use smallvec::smallvec;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -53,7 +54,7 @@ use sp_runtime::{
     impl_opaque_keys,
     traits::{
         AccountIdLookup, BlakeTwo256, Block as BlockT, DispatchInfoOf, Dispatchable, NumberFor,
-        One, PostDispatchInfoOf, UniqueSaturatedInto, Verify,
+        One, PostDispatchInfoOf, SignedExtension, UniqueSaturatedInto, Verify,
     },
     transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
 };
@@ -103,9 +104,10 @@ use pallet_evm::{
 impl pallet_drand::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AuthorityId = pallet_drand::crypto::TestAuthId;
-    type Verifier = pallet_drand::verifier::QuicknetVerifier;
+    type Verifier = pallet_drand::verifier::SchemeVerifier;
     type UnsignedPriority = ConstU64<{ 1 << 20 }>;
     type HttpFetchTimeout = ConstU64<1_000>;
+    type MaxPulsesPrunedPerBlock = ConstU32<256>;
 }
 
 impl frame_system::offchain::SigningTypes for Runtime {
@@ -209,7 +211,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 277,
+    spec_version: 302,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -581,6 +583,56 @@ impl CollectiveInterface<AccountId, Hash, u32> for TriumvirateVotes {
     }
 }
 
+pub struct CrowdloanAdapter;
+impl pallet_subtensor::CrowdloanInterface<AccountId> for CrowdloanAdapter {
+    fn finalizing_crowdloan() -> Option<pallet_subtensor::FinalizingCrowdloan<AccountId>> {
+        let id = pallet_crowdloan::CurrentCrowdloanId::<Runtime>::get()?;
+        pallet_crowdloan::Crowdloans::<Runtime>::get(id).map(|crowdloan| {
+            pallet_subtensor::FinalizingCrowdloan {
+                id,
+                creator: crowdloan.creator,
+                funds_account: crowdloan.funds_account,
+                raised: crowdloan.raised,
+            }
+        })
+    }
+
+    fn contributions(crowdloan_id: u32) -> Vec<(AccountId, u64)> {
+        pallet_crowdloan::Contributions::<Runtime>::iter_prefix(crowdloan_id).collect()
+    }
+
+    fn max_contributors() -> u32 {
+        MaxContributors::get()
+    }
+}
+
+pub struct LeaseBeneficiaryProxy;
+impl pallet_subtensor::ProxyInterface<AccountId> for LeaseBeneficiaryProxy {
+    fn add_lease_beneficiary_proxy(
+        lease: &AccountId,
+        beneficiary: &AccountId,
+    ) -> sp_runtime::DispatchResult {
+        Proxy::add_proxy_delegate(
+            lease,
+            beneficiary.clone(),
+            ProxyType::SubnetLeaseBeneficiary,
+            0,
+        )
+    }
+
+    fn remove_lease_beneficiary_proxy(
+        lease: &AccountId,
+        beneficiary: &AccountId,
+    ) -> sp_runtime::DispatchResult {
+        Proxy::remove_proxy_delegate(
+            lease,
+            beneficiary.clone(),
+            ProxyType::SubnetLeaseBeneficiary,
+            0,
+        )
+    }
+}
+
 type EnsureMajoritySenate =
     pallet_collective::EnsureProportionMoreThan<AccountId, TriumvirateCollective, 1, 2>;
 
@@ -687,6 +739,15 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                         pallet_subtensor::Call::schedule_swap_coldkey { .. }
                     )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_coldkey { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::execute_scheduled_coldkey_swap { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::cancel_coldkey_swap { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::contest_coldkey_swap { .. }
+                    )
             ),
             ProxyType::NonFungibile => !matches!(
                 c,
@@ -723,6 +784,15 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                         pallet_subtensor::Call::schedule_swap_coldkey { .. }
                     )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_coldkey { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::execute_scheduled_coldkey_swap { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::cancel_coldkey_swap { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::contest_coldkey_swap { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_hotkey { .. })
             ),
             ProxyType::Transfer => matches!(
@@ -833,6 +903,15 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 c,
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_hotkey { .. })
             ),
+            ProxyType::SubnetLeaseBeneficiary => {
+                matches!(c, RuntimeCall::AdminUtils(..))
+                    && !matches!(
+                        c,
+                        RuntimeCall::AdminUtils(
+                            pallet_admin_utils::Call::sudo_set_sn_owner_hotkey { .. }
+                        )
+                    )
+            }
         }
     }
     fn is_superset(&self, o: &Self) -> bool {
@@ -982,6 +1061,8 @@ parameter_types! {
     pub const MaxCommitFieldsInner: u32 = 3;
     pub const CommitmentInitialDeposit: Balance = 0; // Free
     pub const CommitmentFieldDeposit: Balance = 0; // Free
+    pub const MaxCommitmentHistory: u32 = 10;
+    pub const CommitmentHistoryDeposit: Balance = 1_000_000; // 0.001 TAO per kept commitment
 }
 
 #[subtensor_macros::freeze_struct("7c76bd954afbb54e")]
@@ -1007,6 +1088,14 @@ impl CanCommit<AccountId> for AllowCommitments {
     }
 }
 
+pub struct CommitmentsSubnetOwner;
+impl pallet_commitments::IsSubnetOwner<AccountId> for CommitmentsSubnetOwner {
+    fn is_subnet_owner(netuid: u16, who: &AccountId) -> bool {
+        SubtensorModule::if_subnet_exist(netuid)
+            && SubtensorModule::get_subnet_owner(netuid) == *who
+    }
+}
+
 pub struct ResetBondsOnCommit;
 impl OnMetadataCommitment<AccountId> for ResetBondsOnCommit {
     #[cfg(not(feature = "runtime-benchmarks"))]
@@ -1024,11 +1113,14 @@ impl pallet_commitments::Config for Runtime {
     type WeightInfo = pallet_commitments::weights::SubstrateWeight<Runtime>;
 
     type CanCommit = AllowCommitments;
+    type SubnetOwner = CommitmentsSubnetOwner;
     type OnMetadataCommitment = ResetBondsOnCommit;
 
     type MaxFields = MaxCommitFields;
     type InitialDeposit = CommitmentInitialDeposit;
     type FieldDeposit = CommitmentFieldDeposit;
+    type MaxCommitmentHistory = MaxCommitmentHistory;
+    type HistoryDeposit = CommitmentHistoryDeposit;
     type TempoInterface = TempoInterface;
 }
 
@@ -1134,6 +1226,8 @@ impl pallet_subtensor::Config for Runtime {
     type CouncilOrigin = EnsureMajoritySenate;
     type SenateMembers = ManageSenateMembers;
     type TriumvirateInterface = TriumvirateVotes;
+    type CrowdloanInterface = CrowdloanAdapter;
+    type ProxyInterface = LeaseBeneficiaryProxy;
     type Scheduler = Scheduler;
     type InitialRho = SubtensorInitialRho;
     type InitialAlphaSigmoidSteepness = SubtensorInitialAlphaSigmoidSteepness;
@@ -1497,6 +1591,7 @@ parameter_types! {
     };
     pub const RefundContributorsLimit: u32 = 50;
     pub const MaxContributors: u32 = 500;
+    pub const MaxContributionTiers: u32 = 8;
 }
 
 impl pallet_crowdloan::Config for Runtime {
@@ -1512,6 +1607,41 @@ impl pallet_crowdloan::Config for Runtime {
     type MaximumBlockDuration = MaximumBlockDuration;
     type RefundContributorsLimit = RefundContributorsLimit;
     type MaxContributors = MaxContributors;
+    type MaxContributionTiers = MaxContributionTiers;
+}
+
+// Timelock
+parameter_types! {
+    pub const TimelockMaxCiphertextLen: u32 = 2048;
+    pub const TimelockMaxCallsPerRound: u32 = 32;
+    pub TimelockMaxWeightPerBlock: Weight = Perbill::from_percent(10) * MAXIMUM_BLOCK_WEIGHT;
+    pub const TimelockDepositBase: Balance = 100_000_000; // 0.1 TAO
+    pub const TimelockDepositPerByte: Balance = 100_000; // 0.0001 TAO
+}
+
+impl pallet_timelock::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type Currency = Balances;
+    type WeightInfo = pallet_timelock::weights::SubstrateWeight<Runtime>;
+    type MaxCiphertextLen = TimelockMaxCiphertextLen;
+    type MaxCallsPerRound = TimelockMaxCallsPerRound;
+    type MaxWeightPerBlock = TimelockMaxWeightPerBlock;
+    type DepositBase = TimelockDepositBase;
+    type DepositPerByte = TimelockDepositPerByte;
+    type CallValidator = TimelockCallValidator;
+}
+
+/// Runs the validation of `SubtensorSignedExtension` on the decrypted timelocked calls, so they
+/// are held to the swap schedule blocks and rate limits of the calls submitted directly.
+pub struct TimelockCallValidator;
+
+impl pallet_timelock::CallValidator<AccountId, RuntimeCall> for TimelockCallValidator {
+    fn validate(who: &AccountId, call: &RuntimeCall) -> Result<(), TransactionValidityError> {
+        pallet_subtensor::SubtensorSignedExtension::<Runtime>::new()
+            .validate(who, call, &call.get_dispatch_info(), call.encoded_size())
+            .map(|_| ())
+    }
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -1550,6 +1680,8 @@ construct_runtime!(
         Drand: pallet_drand = 26,
 
         Crowdloan: pallet_crowdloan = 27,
+
+        Timelock: pallet_timelock = 28,
     }
 );
 
@@ -1620,6 +1752,7 @@ mod benches {
         [pallet_subtensor, SubtensorModule]
         [pallet_drand, Drand]
         [pallet_crowdloan, Crowdloan]
+        [pallet_timelock, Timelock]
     );
 }
 
@@ -2287,6 +2420,52 @@ impl_runtime_apis! {
             SubtensorModule::get_network_lock_cost()
         }
     }
+
+    impl subtensor_custom_rpc_runtime_api::HyperparameterRuntimeApi<Block> for Runtime {
+        fn get_subnet_hyperparameters(netuid: u16) -> Option<Vec<HyperparameterInfo>> {
+            SubtensorModule::get_hyperparameters_info(netuid)
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::HyperparameterAnnouncementRuntimeApi<Block> for Runtime {
+        fn get_pending_hyperparameter_changes(netuid: u16) -> Vec<(u32, pallet_admin_utils::PendingHyperparameterChange<AccountId32, BlockNumber>)> {
+            AdminUtils::get_pending_hyperparameter_changes(netuid)
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::CrowdloanRuntimeApi<Block> for Runtime {
+        fn get_contributions(contributor: AccountId32) -> Vec<(pallet_crowdloan::CrowdloanId, u64)> {
+            Crowdloan::get_contributions(&contributor)
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::DrandRuntimeApi<Block> for Runtime {
+        fn get_retained_rounds() -> Vec<(u16, u64, u64)> {
+            Drand::retained_rounds()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::CommitmentsRuntimeApi<Block> for Runtime {
+        fn get_commitment_schema(netuid: u16) -> Option<Vec<pallet_commitments::CommitmentSchemaField>> {
+            pallet_commitments::CommitmentSchemas::<Runtime>::get(netuid).map(|schema| schema.into_inner())
+        }
+
+        fn get_structured_commitment(netuid: u16, account: AccountId32) -> Option<Vec<pallet_commitments::StructuredField>> {
+            Commitments::get_structured_commitment(netuid, &account)
+        }
+
+        fn get_structured_commitments(netuid: u16) -> Vec<(AccountId32, Vec<pallet_commitments::StructuredField>)> {
+            Commitments::get_structured_commitments(netuid)
+        }
+
+        fn get_commitment_history(netuid: u16, account: AccountId32) -> Vec<(u64, Vec<pallet_commitments::Data>)> {
+            Commitments::get_commitment_history(netuid, &account)
+        }
+
+        fn get_commitment_diffs(netuid: u16, account: AccountId32) -> Vec<(u64, Vec<pallet_commitments::CommitmentFieldChange>)> {
+            Commitments::get_commitment_diffs(netuid, &account)
+        }
+    }
 }
 
 #[test]
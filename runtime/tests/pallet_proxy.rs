@@ -183,6 +183,7 @@ fn test_proxy_pallet() {
         ProxyType::Governance,
         ProxyType::Staking,
         ProxyType::Registration,
+        ProxyType::SubnetLeaseBeneficiary,
     ];
 
     let calls = [